@@ -0,0 +1,12 @@
+//! BITD decoder. Input: width, height, bit depth, transparent index, data
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use openwilly_player::assets::{bitmap, palette};
+
+fuzz_target!(|data: &[u8]| {
+    let [w, h, depth, transparent, bitd @ ..] = data else { return };
+    let palette = palette::windows_palette();
+    let bmp = bitmap::decode_bitd(bitd, *w as u16 * 3, *h as u16 * 3, *depth, &palette, Some(*transparent));
+    assert_eq!(bmp.pixels.len(), (bmp.width * bmp.height * 4) as usize);
+});
@@ -0,0 +1,16 @@
+//! RIFX/XFIR container + cast member parser (afterburned files included),
+//! then every bitmap member through the decoder
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use openwilly_player::assets::{bitmap, director::DirectorFile, palette};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(df) = DirectorFile::parse_bytes("FUZZ.CST".into(), data.to_vec()) else { return };
+    let palette = palette::windows_palette();
+    for member in df.cast_members.values() {
+        if let (Some(bi), Some(bitd)) = (&member.bitmap_info, member.linked_data.get("BITD")) {
+            bitmap::decode_bitd(bitd, bi.width, bi.height, bi.bit_depth, &palette, Some(0));
+        }
+    }
+});
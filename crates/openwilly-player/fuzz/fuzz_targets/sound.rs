@@ -0,0 +1,14 @@
+//! sndS → PCM → WAV. Input: sample rate (u32 BE), bits (u16 BE), samples
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use openwilly_player::assets::sound::DecodedSound;
+
+fuzz_target!(|data: &[u8]| {
+    let [r0, r1, r2, r3, b0, b1, samples @ ..] = data else { return };
+    let rate = u32::from_be_bytes([*r0, *r1, *r2, *r3]);
+    let bits = u16::from_be_bytes([*b0, *b1]);
+    let sound = DecodedSound::from_raw_pcm(samples, rate, bits);
+    sound.duration_ms();
+    assert!(sound.to_wav().len() >= 44);
+});
@@ -0,0 +1,438 @@
+//! Afterburner (Shockwave .DCR/.CCT) container unpacking
+//!
+//! Some localized releases ship their casts "afterburned": the RIFX codec
+//! is FGDM (movie) or FGDC (cast) instead of MV93/MC95, and the chunks are
+//! zlib streams indexed by a compressed resource map instead of an mmap.
+//! `unpack` rebuilds a plain RIFX/XFIR file from it (same endianness, chunk
+//! slot = resource id), so `DirectorFile::parse_bytes` reads it like any
+//! other cast.
+//!
+//! Layout (ProjectorRays / ScummVM documentation):
+//!   Fver  varint len, version info
+//!   Fcdr  varint len, zlib: u16 count, count × MoaID, count × C string
+//!   ABMP  varint len, varint compression, varint uncompressed len,
+//!         zlib: varint, varint, varint count,
+//!               count × (id, offset, comp len, len, compression, fourcc)
+//!   FGEI  varint, then the resource bodies. Resource 2 is the
+//!         "initial load segment" (ILS), a zlib stream of (varint id, bytes)
+//!         holding most small chunks; the others sit at their offset.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+
+use anyhow::{bail, ensure, Context, Result};
+
+/// Resource id of the initial load segment
+const ILS_ID: u32 = 2;
+
+/// Chunk compression, identified by the first MoaID field in Fcdr
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Compression {
+    Zlib,
+    None,
+    /// Shockwave Audio (SWA) — needs the Macromedia sound codec
+    Sound,
+    /// Font map — Director regenerates it, not needed for playback
+    FontMap,
+    Unknown(u32),
+}
+
+impl Compression {
+    fn from_moa_id(data1: u32) -> Self {
+        match data1 {
+            0xAC99E904 => Compression::Zlib,
+            0xAC99982E => Compression::None,
+            0x7204A889 => Compression::Sound,
+            0x8A4679A1 => Compression::FontMap,
+            other => Compression::Unknown(other),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Resource {
+    offset: i32,
+    comp_len: usize,
+    len: usize,
+    compression: u32,
+    fourcc: u32,
+}
+
+/// Little byte cursor with Director's big-endian varints
+struct Stream<'a> {
+    data: &'a [u8],
+    pos: usize,
+    big_endian: bool,
+}
+
+impl<'a> Stream<'a> {
+    fn new(data: &'a [u8], big_endian: bool) -> Self {
+        Self { data, pos: 0, big_endian }
+    }
+
+    fn eof(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos.checked_add(len).filter(|&end| end <= self.data.len())
+            .with_context(|| format!("Unexpected end of data at {} (+{})", self.pos, len))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let b: [u8; 2] = self.bytes(2)?.try_into()?;
+        Ok(if self.big_endian { u16::from_be_bytes(b) } else { u16::from_le_bytes(b) })
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let b: [u8; 4] = self.bytes(4)?.try_into()?;
+        Ok(if self.big_endian { u32::from_be_bytes(b) } else { u32::from_le_bytes(b) })
+    }
+
+    /// 7 bits per byte, most significant first, high bit = more follows
+    fn varint(&mut self) -> Result<u32> {
+        let mut value = 0u32;
+        loop {
+            let b = self.u8()?;
+            value = (value << 7) | (b & 0x7F) as u32;
+            if b & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn expect_tag(&mut self, tag: &[u8; 4]) -> Result<()> {
+        let found = self.u32()?.to_be_bytes();
+        ensure!(&found == tag, "Expected {} chunk, found {}",
+            String::from_utf8_lossy(tag), String::from_utf8_lossy(&found));
+        Ok(())
+    }
+}
+
+/// Preallocation cap — the expected length is read from the file
+const MAX_PREALLOC: usize = 16 << 20;
+
+fn inflate(data: &[u8], expected_len: usize) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(expected_len.min(MAX_PREALLOC));
+    flate2::read::ZlibDecoder::new(data).read_to_end(&mut out).context("zlib stream")?;
+    Ok(out)
+}
+
+/// Container codec of a RIFX/XFIR file ("MV93", "FGDC", ...)
+pub fn codec(data: &[u8]) -> Option<String> {
+    let mut tag: [u8; 4] = data.get(8..12)?.try_into().ok()?;
+    if data.starts_with(b"XFIR") {
+        tag.reverse();
+    }
+    Some(String::from_utf8_lossy(&tag).into_owned())
+}
+
+/// Afterburned (Shockwave) movie or cast?
+pub fn is_afterburned(data: &[u8]) -> bool {
+    matches!(codec(data).as_deref(), Some("FGDM" | "FGDC"))
+}
+
+/// Rebuild a plain RIFX/XFIR file from an afterburned one
+pub fn unpack(data: &[u8]) -> Result<Vec<u8>> {
+    let big_endian = data.starts_with(b"RIFX");
+    let mut s = Stream::new(data, big_endian);
+    s.pos = 12; // magic, size, codec
+
+    // Fver: version info, not needed
+    s.expect_tag(b"Fver")?;
+    let len = s.varint()? as usize;
+    s.bytes(len)?;
+
+    // Fcdr: compression types (MoaIDs)
+    s.expect_tag(b"Fcdr")?;
+    let len = s.varint()? as usize;
+    let fcdr = inflate(s.bytes(len)?, 64)?;
+    let mut fs = Stream::new(&fcdr, big_endian);
+    let count = fs.u16()?;
+    let mut compressions = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        compressions.push(Compression::from_moa_id(fs.u32()?));
+        fs.bytes(12)?; // rest of the MoaID
+    }
+
+    // ABMP: resource map
+    s.expect_tag(b"ABMP")?;
+    let len = s.varint()? as usize;
+    let end = s.pos + len;
+    let _compression = s.varint()?;
+    let map_len = s.varint()? as usize;
+    let map_start = s.pos;
+    let map = inflate(s.bytes(end.saturating_sub(map_start))?, map_len)?;
+    let mut ms = Stream::new(&map, big_endian);
+    ms.varint()?;
+    ms.varint()?;
+    let count = ms.varint()?;
+    let mut resources = BTreeMap::new();
+    for _ in 0..count {
+        let id = ms.varint()?;
+        resources.insert(id, Resource {
+            offset: ms.varint()? as i32,
+            comp_len: ms.varint()? as usize,
+            len: ms.varint()? as usize,
+            compression: ms.varint()?,
+            fourcc: ms.u32()?,
+        });
+    }
+
+    // FGEI: resource bodies follow
+    s.expect_tag(b"FGEI")?;
+    s.varint()?;
+    let body = s.pos;
+
+    let mut chunks: BTreeMap<u32, Vec<u8>> = BTreeMap::new();
+    let ils = resources.get(&ILS_ID).context("No initial load segment")?;
+    let ils_start = body + ils.offset.max(0) as usize;
+    let ils_data = inflate(
+        data.get(ils_start..ils_start + ils.comp_len).context("ILS out of range")?,
+        ils.len,
+    )?;
+    let mut is = Stream::new(&ils_data, big_endian);
+    while !is.eof() {
+        let id = is.varint()?;
+        let res = resources.get(&id).with_context(|| format!("ILS resource {} not in the map", id))?;
+        chunks.insert(id, is.bytes(res.len)?.to_vec());
+    }
+
+    for (&id, res) in &resources {
+        if id == ILS_ID || res.offset < 0 || chunks.contains_key(&id) {
+            continue;
+        }
+        let start = body + res.offset as usize;
+        let raw = data.get(start..start + res.comp_len)
+            .with_context(|| format!("Resource {} out of range", id))?;
+        let compression = compressions.get(res.compression as usize).copied()
+            // No (usable) Fcdr: sizes tell compressed from stored
+            .unwrap_or(if res.comp_len == res.len { Compression::None } else { Compression::Zlib });
+        let fourcc = String::from_utf8_lossy(&res.fourcc.to_be_bytes()).into_owned();
+        let chunk = match compression {
+            Compression::Zlib => inflate(raw, res.len)?,
+            Compression::None => raw.to_vec(),
+            Compression::Sound => {
+                tracing::warn!("  Afterburner: {} #{} uses Shockwave Audio compression (not supported), skipped", fourcc, id);
+                continue;
+            }
+            Compression::FontMap => continue,
+            Compression::Unknown(moa) => {
+                bail!("Afterburner: {} #{} uses unsupported compression {:08X}", fourcc, id, moa)
+            }
+        };
+        chunks.insert(id, chunk);
+    }
+
+    let tags: BTreeMap<u32, u32> = resources.iter().map(|(&id, r)| (id, r.fourcc)).collect();
+    rebuild(&chunks, &tags, data.starts_with(b"RIFX"), codec(data).as_deref() == Some("FGDC"))
+}
+
+/// Write a RIFX/XFIR file with imap + mmap, chunk slot = resource id
+pub(super) fn rebuild(chunks: &BTreeMap<u32, Vec<u8>>, tags: &BTreeMap<u32, u32>, big_endian: bool, cast: bool) -> Result<Vec<u8>> {
+    let put = |out: &mut Vec<u8>, v: u32| {
+        out.extend_from_slice(&if big_endian { v.to_be_bytes() } else { v.to_le_bytes() });
+    };
+    let tag = |s: &[u8; 4]| u32::from_be_bytes(*s);
+
+    const MMAP_OFFSET: u32 = 28;
+    const ENTRY_LEN: u32 = 20;
+    // Resource ids are the mmap slots of the original movie, which only ever
+    // has a few free slots between them — a far-off id is a broken map, not
+    // a reason to write gigabytes of free entries
+    const MAX_FREE_SLOTS: usize = 1024;
+    let slots = chunks.keys().next_back().map_or(3, |&id| id as usize + 1).max(3);
+    ensure!(slots <= chunks.len() * 8 + MAX_FREE_SLOTS,
+        "Afterburner: resource id {} is far beyond the {} resources in the map", slots - 1, chunks.len());
+    let slots = slots as u32;
+    let mmap_len = 24 + slots * ENTRY_LEN;
+    let mut offset = MMAP_OFFSET + 8 + mmap_len;
+
+    let mut out = Vec::new();
+    put(&mut out, tag(b"RIFX"));
+    put(&mut out, 0); // size, patched below
+    put(&mut out, tag(if cast { b"MC95" } else { b"MV93" }));
+    // imap: count, mmap offset (lengths are always little-endian)
+    put(&mut out, tag(b"imap"));
+    out.extend_from_slice(&8u32.to_le_bytes());
+    put(&mut out, 1);
+    put(&mut out, MMAP_OFFSET);
+    // mmap header
+    put(&mut out, tag(b"mmap"));
+    out.extend_from_slice(&mmap_len.to_le_bytes());
+    put(&mut out, 24);
+    out.extend_from_slice(&ENTRY_LEN.to_le_bytes());
+    put(&mut out, slots);
+    out.extend_from_slice(&slots.to_le_bytes());
+    out.extend_from_slice(&(-1i32).to_le_bytes());
+    out.extend_from_slice(&(-1i32).to_le_bytes());
+    for slot in 0..slots {
+        match chunks.get(&slot) {
+            Some(chunk) => {
+                put(&mut out, tags[&slot]);
+                put(&mut out, chunk.len() as u32);
+                put(&mut out, offset);
+                offset = u32::try_from(chunk.len() + (chunk.len() & 1) + 8).ok()
+                    .and_then(|len| offset.checked_add(len))
+                    .context("Afterburner: rebuilt file exceeds 4 GB")?;
+            }
+            None => {
+                put(&mut out, tag(b"free"));
+                put(&mut out, 0);
+                put(&mut out, 0);
+            }
+        }
+        put(&mut out, 0);
+        put(&mut out, 0);
+    }
+    for (&id, chunk) in chunks {
+        put(&mut out, tags[&id]);
+        put(&mut out, chunk.len() as u32);
+        out.extend_from_slice(chunk);
+        if chunk.len() % 2 == 1 {
+            out.push(0);
+        }
+    }
+    let size = out.len() as u32 - 8;
+    out[4..8].copy_from_slice(&if big_endian { size.to_be_bytes() } else { size.to_le_bytes() });
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::director::DirectorFile;
+    use std::io::Write;
+
+    fn deflate(data: &[u8]) -> Vec<u8> {
+        let mut enc = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        enc.write_all(data).unwrap();
+        enc.finish().unwrap()
+    }
+
+    fn varint(out: &mut Vec<u8>, mut v: u32) {
+        let mut groups = vec![(v & 0x7F) as u8];
+        v >>= 7;
+        while v > 0 {
+            groups.push((v & 0x7F) as u8 | 0x80);
+            v >>= 7;
+        }
+        out.extend(groups.iter().rev());
+    }
+
+    fn chunk(out: &mut Vec<u8>, tag: &[u8; 4], body: &[u8]) {
+        out.extend_from_slice(tag);
+        varint(out, body.len() as u32);
+        out.extend_from_slice(body);
+    }
+
+    /// Big-endian FGDC: VWCF in the ILS, DRCF stored, sound with SWA
+    fn afterburned_cast(sound_moa: u32) -> Vec<u8> {
+        let mut vwcf = vec![0u8; 12];
+        vwcf[8..10].copy_from_slice(&300u16.to_be_bytes()); // height
+        vwcf[10..12].copy_from_slice(&400u16.to_be_bytes()); // width
+        let drcf = [0x04u8, 0xC7, 0, 0];
+        let snd = [1u8, 2, 3];
+
+        let mut fcdr = 3u16.to_be_bytes().to_vec();
+        for moa in [0xAC99E904u32, 0xAC99982E, sound_moa] {
+            fcdr.extend_from_slice(&moa.to_be_bytes());
+            fcdr.extend_from_slice(&[0; 12]);
+        }
+
+        let mut ils = Vec::new();
+        varint(&mut ils, 5);
+        ils.extend_from_slice(&vwcf);
+        let ils = deflate(&ils);
+
+        // Bodies after FGEI: ILS, then DRCF and the sound
+        let entries = [
+            (ILS_ID, 0i32, ils.len(), vwcf.len() + 1, 0, b"ILS "),
+            (5, -1, vwcf.len(), vwcf.len(), 0, b"VWCF"),
+            (6, ils.len() as i32, drcf.len(), drcf.len(), 1, b"DRCF"),
+            (7, (ils.len() + drcf.len()) as i32, snd.len(), 9, 2, b"sndS"),
+        ];
+        let mut map = Vec::new();
+        varint(&mut map, 0);
+        varint(&mut map, 0);
+        varint(&mut map, entries.len() as u32);
+        for (id, offset, comp, len, compression, tag) in entries {
+            varint(&mut map, id);
+            varint(&mut map, offset as u32);
+            varint(&mut map, comp as u32);
+            varint(&mut map, len as u32);
+            varint(&mut map, compression);
+            map.extend_from_slice(tag);
+        }
+        let mut abmp = Vec::new();
+        varint(&mut abmp, 0);
+        varint(&mut abmp, map.len() as u32);
+        abmp.extend_from_slice(&deflate(&map));
+
+        let mut file = b"RIFX\0\0\0\0FGDC".to_vec();
+        chunk(&mut file, b"Fver", &[0x05]);
+        chunk(&mut file, b"Fcdr", &deflate(&fcdr));
+        chunk(&mut file, b"ABMP", &abmp);
+        file.extend_from_slice(b"FGEI");
+        varint(&mut file, 0);
+        file.extend_from_slice(&ils);
+        file.extend_from_slice(&drcf);
+        file.extend_from_slice(&snd);
+        file
+    }
+
+    #[test]
+    fn varint_decoding() {
+        let mut data = Vec::new();
+        for v in [0, 127, 128, 300, 0xFFFFFFFF] {
+            varint(&mut data, v);
+        }
+        let mut s = Stream::new(&data, true);
+        assert_eq!(s.varint().unwrap(), 0);
+        assert_eq!(s.varint().unwrap(), 127);
+        assert_eq!(s.varint().unwrap(), 128);
+        assert_eq!(s.varint().unwrap(), 300);
+        assert_eq!(s.varint().unwrap() as i32, -1);
+        assert!(s.eof());
+    }
+
+    #[test]
+    fn unpacked_cast_parses() {
+        let file = afterburned_cast(0x7204A889);
+        assert!(is_afterburned(&file));
+        let plain = unpack(&file).unwrap();
+        assert_eq!(codec(&plain).as_deref(), Some("MC95"));
+        assert!(!is_afterburned(&plain));
+
+        let df = DirectorFile::parse_bytes("TEST.CCT".into(), file).unwrap();
+        assert_eq!((df.movie_width, df.movie_height), (400, 300));
+        assert_eq!(df.version, "6.0");
+    }
+
+    #[test]
+    fn unknown_compression_is_named() {
+        let err = unpack(&afterburned_cast(0x12345678)).unwrap_err();
+        let msg = format!("{:#}", err);
+        assert!(msg.contains("sndS #7") && msg.contains("12345678"), "{}", msg);
+    }
+
+    #[test]
+    fn far_off_resource_id_is_refused() {
+        let tag = u32::from_be_bytes(*b"BITD");
+        for id in [u32::MAX, 1 << 24] {
+            let chunks = BTreeMap::from([(id, vec![1, 2, 3])]);
+            let tags = BTreeMap::from([(id, tag)]);
+            assert!(rebuild(&chunks, &tags, true, false).is_err(), "id {}", id);
+        }
+        let chunks = BTreeMap::from([(40, vec![1, 2, 3])]);
+        let tags = BTreeMap::from([(40, tag)]);
+        assert!(rebuild(&chunks, &tags, true, false).is_ok());
+    }
+}
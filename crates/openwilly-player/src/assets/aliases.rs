@@ -0,0 +1,118 @@
+//! Name aliases for other editions of the game
+//!
+//! The game logic (scenes, PartsDB, the dialog tables) refers to files and
+//! cast members by their names on the German disc. The Swedish original
+//! (Mulle Meck) names some of them differently. An edition is recognized
+//! by the ISO volume ID or by a signature file; its aliases rename files
+//! and members to the German names right after parsing, so everything
+//! after the AssetStore runs unchanged.
+//!
+//! The table (data/editions.json, replaced by `<game_dir>/mods/editions.json`)
+//! lists the editions, their detection and the aliases.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::director::DirectorFile;
+
+/// One release that differs from the German naming
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Edition {
+    pub name: String,
+    /// ISO volume ID prefixes (case-insensitive)
+    pub volume_ids: Vec<String>,
+    /// Any of these files (relative to an asset root) marks the edition
+    pub detect: Vec<String>,
+    /// File name on the disc → name the game uses (case-insensitive)
+    pub files: HashMap<String, String>,
+    /// Member name on the disc → name the game uses
+    pub members: HashMap<String, String>,
+}
+
+impl Edition {
+    /// Name the game uses for a file on this edition's disc
+    pub fn file_name(&self, name: &str) -> String {
+        self.files.iter()
+            .find(|(disc, _)| disc.eq_ignore_ascii_case(name))
+            .map(|(_, game)| game.clone())
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Rename a parsed file and its members, returns the number of renamed
+    /// members
+    pub fn apply(&self, name: String, mut df: DirectorFile) -> (String, DirectorFile, usize) {
+        let name = self.file_name(&name);
+        df.filename = name.clone();
+        let mut renamed = 0;
+        for member in df.cast_members.values_mut() {
+            if let Some(game) = self.members.get(&member.name) {
+                member.name = game.clone();
+                renamed += 1;
+            }
+        }
+        (name, df, renamed)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct EditionTable {
+    #[serde(default)]
+    editions: Vec<Edition>,
+}
+
+impl EditionTable {
+    /// Built-in table, replaced by `<game_dir>/mods/editions.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("editions.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(table) => {
+                    tracing::info!("Editions loaded from {}", path.display());
+                    return table;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/editions.json"))
+            .expect("Failed to parse editions.json")
+    }
+
+    /// Edition of the game data, None for the German release
+    pub fn detect(&self, volume_id: Option<&str>, has_file: impl Fn(&str) -> bool) -> Option<&Edition> {
+        let volume_id = volume_id.unwrap_or("").trim().to_uppercase();
+        self.editions.iter().find(|e| {
+            let by_volume = !volume_id.is_empty()
+                && e.volume_ids.iter().any(|v| volume_id.starts_with(&v.to_uppercase()));
+            by_volume || e.detect.iter().any(|f| has_file(f))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_by_volume_or_file() {
+        let table = EditionTable::builtin();
+        assert!(table.detect(Some("WILLY_WERKEL"), |_| false).is_none());
+        assert!(table.detect(None, |_| false).is_none());
+        let edition = table.detect(Some("mulle_meck"), |_| false).expect("Swedish volume");
+        assert!(!edition.name.is_empty());
+
+        let table: EditionTable = serde_json::from_str(
+            r#"{ "editions": [{ "name": "Test", "detect": ["MULLE.EXE"],
+                 "files": { "bilar.dxr": "05.DXR" } }] }"#,
+        ).unwrap();
+        let edition = table.detect(None, |f| f == "MULLE.EXE").unwrap();
+        assert_eq!(edition.file_name("BILAR.DXR"), "05.DXR");
+        assert_eq!(edition.file_name("00.CXT"), "00.CXT");
+    }
+}
@@ -7,6 +7,10 @@
 //! - 16-bit uncompressed/RLE
 //! - 32-bit planar RLE (A,R,G,B channels, no inversion)
 
+/// Largest bitmap decoded (Director 6 stage sizes are far below). Corrupt
+/// bounds would otherwise ask for gigabytes.
+const MAX_PIXELS: usize = 4096 * 4096;
+
 /// Decoded bitmap in RGBA format, ready for rendering
 #[derive(Debug, Clone)]
 pub struct DecodedBitmap {
@@ -28,9 +32,12 @@ pub fn decode_bitd(
     palette: &[[u8; 3]],
     transparent_color: Option<u8>,
 ) -> DecodedBitmap {
-    let w = width as u32;
-    let h = height as u32;
-    let mut pixels = vec![0u8; (w * h * 4) as usize];
+    let (mut w, mut h) = (width as u32, height as u32);
+    if w as usize * h as usize > MAX_PIXELS {
+        tracing::warn!("Bitmap too large ({}×{}), skipped", w, h);
+        (w, h) = (0, 0);
+    }
+    let mut pixels = vec![0u8; w as usize * h as usize * 4];
 
     match bit_depth {
         1 => decode_1bit(data, w, h, &mut pixels),
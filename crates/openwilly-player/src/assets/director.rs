@@ -1,11 +1,13 @@
 //! Parser for Macromedia Director 6 RIFX/XFIR file format
 //!
-//! Supports .DIR, .DXR, .CXT, .CST files.
+//! Supports .DIR, .DXR, .CXT, .CST files, and afterburned .DCR/.CCT
+//! (unpacked first, see `afterburner`).
 //! Based on ShockwaveParser.py analysis + Director 6 format documentation.
 
 use std::collections::HashMap;
 use std::io::{self, Read, Seek, SeekFrom};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use anyhow::{bail, Context, Result};
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
@@ -108,6 +110,76 @@ pub struct CastMember {
     pub linked_data: HashMap<String, Vec<u8>>,
 }
 
+/// File-level sanity data for the startup asset check (bad rips are
+/// usually cut off at the end — the chunk table still parses)
+#[derive(Debug, Clone, Default)]
+pub struct Integrity {
+    /// Length promised by the RIFX header
+    pub declared_len: u64,
+    /// Length actually read
+    pub file_len: u64,
+    /// Chunks whose data lies (partly) past the end of the file
+    pub chunks_out_of_range: usize,
+    /// Where the bytes came from, read again for the checksum
+    pub source: Source,
+    crc32: OnceLock<Option<u32>>,
+}
+
+/// Origin of a parsed file
+#[derive(Debug, Clone, Default)]
+pub enum Source {
+    /// Handed over as bytes, nothing to read again
+    #[default]
+    Memory,
+    File(PathBuf),
+    Iso { image: PathBuf, entry: super::iso::IsoEntry },
+}
+
+impl Source {
+    fn read(&self) -> Result<Option<Vec<u8>>> {
+        Ok(match self {
+            Source::Memory => None,
+            Source::File(path) => Some(std::fs::read(path)?),
+            Source::Iso { image, entry } => Some(super::iso::IsoImage::open(image)?.read(entry)?),
+        })
+    }
+}
+
+impl Integrity {
+    fn of(data: &[u8]) -> Self {
+        let size: [u8; 4] = data[4..8].try_into().unwrap_or_default();
+        let size = if data.starts_with(b"XFIR") { u32::from_le_bytes(size) } else { u32::from_be_bytes(size) };
+        Self {
+            declared_len: size as u64 + 8,
+            file_len: data.len() as u64,
+            ..Default::default()
+        }
+    }
+
+    /// CRC-32 of the file as stored. Only the manifest check and
+    /// OPENWILLY_WRITE_MANIFEST need it, so the file is read again on the first
+    /// call instead of checksumming every file while loading. None for
+    /// in-memory files and sources that can't be read any more.
+    pub fn crc32(&self) -> Option<u32> {
+        *self.crc32.get_or_init(|| match self.source.read() {
+            Ok(data) => data.map(|data| {
+                let mut crc = flate2::Crc::new();
+                crc.update(&data);
+                crc.sum()
+            }),
+            Err(e) => {
+                tracing::warn!("Checksum: {:#}", e);
+                None
+            }
+        })
+    }
+
+    /// File shorter than its header says
+    pub fn is_truncated(&self) -> bool {
+        self.file_len < self.declared_len
+    }
+}
+
 /// A parsed Director file
 #[derive(Debug)]
 pub struct DirectorFile {
@@ -119,6 +191,9 @@ pub struct DirectorFile {
     pub created_by: String,
     pub modified_by: String,
     pub cast_members: HashMap<u32, CastMember>,
+    pub integrity: Integrity,
+    /// First tempo the score sets (fps), None for casts and scores without one
+    pub tempo: Option<u8>,
     /// Raw chunk directory
     chunks: Vec<ChunkEntry>,
 }
@@ -153,6 +228,55 @@ struct ChunkEntry {
 // Parser
 // ============================================================================
 
+/// Offset of the tempo byte in a score frame (main channels, Director 5/6)
+const SCORE_TEMPO_OFFSET: usize = 21;
+/// Highest tempo Director accepts; larger values are wait commands
+const MAX_TEMPO: u8 = 120;
+
+/// First tempo set in a VWSC score chunk (body, always big-endian).
+///
+/// Layout: header (total length, -3, 12, entry count, count + 1, size sum),
+/// entry offsets, then entry 0 = frame data: frames end, unknown, frame
+/// count, type, channel size, last channel max, last channel (20 bytes),
+/// followed by delta-encoded frames — u16 frame length, then runs of
+/// (u16 length, u16 offset, bytes) patching the previous frame.
+fn score_tempo(body: &[u8]) -> Option<u8> {
+    let be16 = |at: usize| body.get(at..at + 2).map(|b| u16::from_be_bytes([b[0], b[1]]) as usize);
+    let be32 = |at: usize| body.get(at..at + 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as usize);
+
+    let entry_count = be32(12)?;
+    let entries = 24usize.checked_add(entry_count.checked_add(1)?.checked_mul(4)?)?;
+    let start = entries.checked_add(be32(24)?)?;
+    let end = entries.checked_add(be32(28)?)?.min(body.len());
+
+    let mut main = [0u8; SCORE_TEMPO_OFFSET + 1];
+    let mut pos = start.checked_add(20)?;
+    while pos + 2 <= end {
+        let frame_len = be16(pos)?;
+        if frame_len < 2 {
+            return None;
+        }
+        let frame_end = (pos + frame_len).min(end);
+        let mut run = pos + 2;
+        while run + 4 <= frame_end {
+            let (len, offset) = (be16(run)?, be16(run + 2)?);
+            let data = body.get(run + 4..run + 4 + len)?;
+            for (i, &byte) in data.iter().enumerate() {
+                if let Some(slot) = main.get_mut(offset + i) {
+                    *slot = byte;
+                }
+            }
+            run += 4 + len;
+        }
+        let tempo = main[SCORE_TEMPO_OFFSET];
+        if (1..=MAX_TEMPO).contains(&tempo) {
+            return Some(tempo);
+        }
+        pos = frame_end;
+    }
+    None
+}
+
 /// Endianness-aware reader wrapper
 struct DirReader<R: Read + Seek> {
     inner: R,
@@ -224,9 +348,13 @@ impl<R: Read + Seek> DirReader<R> {
         Ok(String::from_utf8_lossy(&buf).into_owned())
     }
 
+    /// Lengths come from the file — don't allocate more than is there
     fn read_bytes(&mut self, len: usize) -> io::Result<Vec<u8>> {
-        let mut buf = vec![0u8; len];
-        self.inner.read_exact(&mut buf)?;
+        let mut buf = Vec::new();
+        (&mut self.inner).take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
         Ok(buf)
     }
 
@@ -271,6 +399,27 @@ impl DirectorFile {
         let data = std::fs::read(path)
             .with_context(|| format!("Reading {}", path.display()))?;
 
+        let mut df = Self::parse_bytes(filename, data)?;
+        df.integrity.source = Source::File(path.to_path_buf());
+        Ok(df)
+    }
+
+    /// Parse a Director file already read into memory (e.g. streamed from the ISO)
+    pub fn parse_bytes(filename: String, data: Vec<u8>) -> Result<Self> {
+        if data.len() < 12 {
+            bail!("Not a Director file: {} bytes", data.len());
+        }
+        if data.starts_with(b"MZ") {
+            bail!("Director projector (EXE), not a movie or cast — extract the embedded files first");
+        }
+        let mut integrity = Integrity::of(&data);
+        let data = if super::afterburner::is_afterburned(&data) {
+            tracing::debug!("  [{}] Afterburner container, unpacking", filename);
+            super::afterburner::unpack(&data)
+                .with_context(|| format!("Unpacking afterburned {}", filename))?
+        } else {
+            data
+        };
         let cursor = io::Cursor::new(data);
 
         // Read magic to determine endianness
@@ -285,6 +434,7 @@ impl DirectorFile {
             _ => bail!("Not a Director file: magic={}", magic_str),
         };
 
+        let data_len = cursor.get_ref().len();
         let mut reader = DirReader::new(cursor, big_endian);
 
         // Skip magic (already read)
@@ -296,6 +446,11 @@ impl DirectorFile {
         // File signature (e.g. "MV93", "MC95")
         let file_sign = reader.read_fourcc()?;
         tracing::debug!("  Sign: {} (big_endian={})", file_sign, big_endian);
+        match file_sign.as_str() {
+            "MV93" | "MC95" => {}
+            "APPL" => bail!("Director projector resource ({}), not a movie or cast", file_sign),
+            other => tracing::warn!("  [{}] Unknown container codec {}, trying anyway", filename, other),
+        }
 
         // Read IMAP -> get MMAP offset
         // Note: IMAP/MMAP chunk headers (fourcc + length) are ALWAYS Little-Endian
@@ -317,6 +472,9 @@ impl DirectorFile {
         let _something4 = reader.inner.read_u32::<LittleEndian>()?; // Always LE
 
         tracing::debug!("  MMAP: {} chunks", file_num);
+        if file_num as usize > data_len / 20 {
+            bail!("Corrupt chunk map: {} entries in a {} byte file", file_num, data_len);
+        }
 
         // Read chunk entries
         let mut chunks = Vec::with_capacity(file_num as usize);
@@ -333,6 +491,13 @@ impl DirectorFile {
                 linked_entries: Vec::new(),
             });
         }
+        integrity.chunks_out_of_range = chunks.iter()
+            .filter(|c| c.offset != 0 && c.offset as u64 + 8 + c.length as u64 > data_len as u64)
+            .count();
+        if integrity.is_truncated() || integrity.chunks_out_of_range > 0 {
+            tracing::warn!("  [{}] damaged: {} of {} bytes, {} chunks past the end",
+                filename, integrity.file_len, integrity.declared_len, integrity.chunks_out_of_range);
+        }
 
         // First pass: read metadata chunks
         let mut version = String::new();
@@ -340,6 +505,7 @@ impl DirectorFile {
         let mut movie_height = 480u16;
         let mut created_by = String::new();
         let mut modified_by = String::new();
+        let mut tempo = None;
         // MCsL: cast library info (firstCast offsets)
         let mut cast_lib_first_cast: Vec<u32> = Vec::new(); // firstCast per library
 
@@ -397,6 +563,14 @@ impl DirectorFile {
                     };
                     tracing::debug!("  Version: {}", version);
                 }
+                "VWSC" => {
+                    // A damaged score only costs the tempo
+                    reader.seek(SeekFrom::Start(chunk.offset as u64 + 8))?;
+                    tempo = reader.read_bytes(chunk.length as usize).ok().and_then(|body| score_tempo(&body));
+                    if let Some(t) = tempo {
+                        tracing::debug!("  Score tempo: {} fps", t);
+                    }
+                }
                 "VWCF" => {
                     reader.seek(SeekFrom::Start(chunk.offset as u64 + 8))?;
                     reader.skip(8)?;
@@ -516,6 +690,8 @@ impl DirectorFile {
             created_by,
             modified_by,
             cast_members,
+            integrity,
+            tempo,
             chunks,
         })
     }
@@ -599,11 +775,20 @@ impl DirectorFile {
                 let _unknown4 = reader.read_u16_be()?;
                 let palette_ref = reader.read_i16_be()?;
 
+                // Bounds are (top, left, bottom, right) — corrupt ones must not wrap
+                let (Some(width), Some(height), Some(reg_x), Some(reg_y)) = (
+                    width_raw.checked_sub(pos_x).filter(|w| *w >= 0),
+                    height_raw.checked_sub(pos_y).filter(|h| *h >= 0),
+                    reg_x_raw.checked_sub(pos_x),
+                    reg_y_raw.checked_sub(pos_y),
+                ) else {
+                    bail!("Bitmap member {}: invalid bounds", member_num);
+                };
                 bitmap_info = Some(BitmapInfo {
-                    width: (width_raw - pos_x) as u16,
-                    height: (height_raw - pos_y) as u16,
-                    reg_x: reg_x_raw - pos_x,
-                    reg_y: reg_y_raw - pos_y,
+                    width: width as u16,
+                    height: height as u16,
+                    reg_x,
+                    reg_y,
                     pos_x,
                     pos_y,
                     bit_depth,
@@ -671,7 +856,7 @@ impl DirectorFile {
                     // Parse color lookup table
                     reader.seek(SeekFrom::Start(linked.offset as u64 + 8))?;
                     let num = (linked.length / 6) as usize;
-                    let mut colors = Vec::with_capacity(num);
+                    let mut colors = Vec::with_capacity(num.min(256));
                     for _ in 0..num {
                         let r1 = reader.read_u8()?;
                         let _r2 = reader.read_u8()?;
@@ -703,7 +888,7 @@ impl DirectorFile {
                     reader.seek(SeekFrom::Start(linked.offset as u64 + 8))?;
                     let entry_count = reader.read_u32_be()?;
                     let sample_rate = sound_info.as_ref().map(|s| s.sample_rate).unwrap_or(22050);
-                    let mut cue_points = Vec::with_capacity(entry_count as usize);
+                    let mut cue_points = Vec::with_capacity((entry_count as usize).min(64));
                     for _ in 0..entry_count {
                         let _unknown = reader.read_u16_be()?;
                         let sample_offset = reader.read_u16_be()?;
@@ -898,4 +1083,132 @@ mod tests {
             }
         }
     }
+
+    /// RIFX with one 8×4 8-bit bitmap member: KEY* 3, CAS* 4, CASt 5, BITD 6
+    fn bitmap_cast() -> Vec<u8> {
+        let mut key = vec![0u8; 8];
+        key.extend_from_slice(&1u32.to_be_bytes());
+        key.extend_from_slice(&6u32.to_be_bytes());
+        key.extend_from_slice(&5u32.to_be_bytes());
+        key.extend_from_slice(b"BITD");
+
+        let mut cast = Vec::new();
+        cast.extend_from_slice(&1u32.to_be_bytes()); // Bitmap
+        cast.extend_from_slice(&0u32.to_be_bytes()); // no info block
+        cast.extend_from_slice(&28i32.to_be_bytes());
+        for v in [0i16, 10, 20, 14, 28] {
+            cast.extend_from_slice(&v.to_be_bytes()); // unknown, top, left, bottom, right
+        }
+        cast.extend_from_slice(&[0; 8]);
+        cast.extend_from_slice(&12i16.to_be_bytes()); // reg y
+        cast.extend_from_slice(&24i16.to_be_bytes()); // reg x
+        cast.extend_from_slice(&[0, 8, 0, 0]); // alpha, depth, unknown
+        cast.extend_from_slice(&(-1i16).to_be_bytes()); // system palette
+
+        let tag = |t: &[u8; 4]| u32::from_be_bytes(*t);
+        let chunks = std::collections::BTreeMap::from([
+            (3, key),
+            (4, 5u32.to_be_bytes().to_vec()),
+            (5, cast),
+            (6, (0..32).collect()),
+        ]);
+        let tags = std::collections::BTreeMap::from([
+            (3, tag(b"KEY*")), (4, tag(b"CAS*")), (5, tag(b"CASt")), (6, tag(b"BITD")),
+        ]);
+        super::super::afterburner::rebuild(&chunks, &tags, true, true).unwrap()
+    }
+
+    fn decode_all(df: &DirectorFile) {
+        let palette = super::super::palette::windows_palette();
+        for member in df.cast_members.values() {
+            if let (Some(bi), Some(bitd)) = (&member.bitmap_info, member.linked_data.get("BITD")) {
+                super::super::bitmap::decode_bitd(bitd, bi.width, bi.height, bi.bit_depth, &palette, Some(0));
+            }
+        }
+    }
+
+    /// VWSC body with the given frames, each a list of (offset, bytes) runs
+    fn score(frames: &[&[(u16, &[u8])]]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for v in [0u32, 0, 0, 0, 0] {
+            data.extend_from_slice(&v.to_be_bytes()); // frames end, unknown, count, type + channel size, …
+        }
+        for frame in frames {
+            let len: usize = 2 + frame.iter().map(|(_, b)| 4 + b.len()).sum::<usize>();
+            data.extend_from_slice(&(len as u16).to_be_bytes());
+            for (offset, bytes) in *frame {
+                data.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+                data.extend_from_slice(&offset.to_be_bytes());
+                data.extend_from_slice(bytes);
+            }
+        }
+        let mut body = Vec::new();
+        for v in [0i32, -3, 12, 1, 2, 0] {
+            body.extend_from_slice(&v.to_be_bytes());
+        }
+        body.extend_from_slice(&0u32.to_be_bytes());
+        body.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        body.extend_from_slice(&data);
+        body
+    }
+
+    #[test]
+    fn reads_the_first_score_tempo() {
+        // Frame 1 sets a sprite only, frame 2 the tempo, frame 3 another one
+        let body = score(&[&[(60, &[1, 2])], &[(20, &[0, 12])], &[(21, &[30])]]);
+        assert_eq!(score_tempo(&body), Some(12));
+        assert_eq!(score_tempo(&score(&[&[(21, &[200])]])), None); // wait command
+        assert_eq!(score_tempo(&body[..40]), None);
+        assert_eq!(score_tempo(&[]), None);
+    }
+
+    #[test]
+    fn parses_synthetic_bitmap_cast() {
+        let df = DirectorFile::parse_bytes("TEST.CST".into(), bitmap_cast()).unwrap();
+        let bi = df.cast_members[&1].bitmap_info.as_ref().unwrap();
+        assert_eq!((bi.width, bi.height, bi.reg_x, bi.reg_y), (8, 4, 4, 2));
+        assert_eq!(df.cast_members[&1].linked_data["BITD"].len(), 32);
+    }
+
+    /// Truncated and corrupted files give errors (or garbage), never panics.
+    /// The cargo-fuzz targets in fuzz/ go further.
+    #[test]
+    fn detects_truncation() {
+        let file = bitmap_cast();
+        let df = DirectorFile::parse_bytes("TEST.CST".into(), file.clone()).unwrap();
+        assert!(!df.integrity.is_truncated());
+        assert_eq!(df.integrity.chunks_out_of_range, 0);
+
+        // Cut into the BITD data: the chunk table still parses
+        let df = DirectorFile::parse_bytes("CUT.CST".into(), file[..file.len() - 10].to_vec()).unwrap();
+        assert!(df.integrity.is_truncated());
+        assert_eq!(df.integrity.chunks_out_of_range, 1);
+        let path = std::env::temp_dir().join(format!("openwilly_crc_{}.cst", std::process::id()));
+        std::fs::write(&path, &file[..file.len() - 10]).unwrap();
+        let cut = DirectorFile::parse(&path).unwrap().integrity.crc32();
+        std::fs::write(&path, &file).unwrap();
+        let whole = DirectorFile::parse(&path).unwrap().integrity.crc32();
+        std::fs::remove_file(&path).unwrap();
+        assert!(cut.is_some() && whole.is_some());
+        assert_ne!(cut, whole);
+    }
+
+    #[test]
+    fn corrupt_files_do_not_panic() {
+        let file = bitmap_cast();
+        for len in 0..file.len() {
+            if let Ok(df) = DirectorFile::parse_bytes("CUT.CST".into(), file[..len].to_vec()) {
+                decode_all(&df);
+            }
+        }
+        for pos in 0..file.len() {
+            for value in [0x00, 0x7F, 0x80, 0xFF] {
+                let mut bad = file.clone();
+                bad[pos] = value;
+                if let Ok(df) = DirectorFile::parse_bytes("BAD.CST".into(), bad) {
+                    decode_all(&df);
+                }
+            }
+        }
+    }
 }
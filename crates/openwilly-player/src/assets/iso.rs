@@ -0,0 +1,300 @@
+//! Read-only ISO 9660 access — Director files straight from the CD image
+//!
+//! Indexes the directory tree once (raw sector parsing, same layout rules
+//! as the extraction in main.rs) and reads single files on demand, so the
+//! player can start from an unextracted .iso. Interleaved files (unit/gap
+//! in the directory record) are read unit by unit.
+
+use std::collections::HashSet;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+const SECTOR_SIZE: u64 = 2048;
+/// Guards against directory loops in broken images
+const MAX_DEPTH: usize = 8;
+/// Largest directory extent read (the game CD's biggest is a few sectors)
+const MAX_DIR_SIZE: u64 = 1024 * 1024;
+
+/// One file in the image
+#[derive(Debug, Clone)]
+pub struct IsoEntry {
+    /// Path inside the image without version suffix (e.g. "Movies/05.DXR")
+    pub path: String,
+    pub lba: u64,
+    pub size: u64,
+    /// Interleave unit and gap in sectors (0 = contiguous)
+    pub unit: u8,
+    pub gap: u8,
+}
+
+impl IsoEntry {
+    /// File name without directories
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+
+    /// Director file (.CXT/.DXR/.CST/.DIR, afterburned .CCT/.DCR)?
+    pub fn is_director_file(&self) -> bool {
+        let upper = self.name().to_uppercase();
+        [".CXT", ".DXR", ".CST", ".DIR", ".CCT", ".DCR"].iter().any(|ext| upper.ends_with(ext))
+    }
+
+    /// Byte offset just past the file's last byte in the image
+    fn end(&self) -> u64 {
+        let start = self.lba * SECTOR_SIZE;
+        if self.unit == 0 || self.gap == 0 || self.size == 0 {
+            return start + self.size;
+        }
+        let unit_bytes = self.unit as u64 * SECTOR_SIZE;
+        let stride = (self.unit as u64 + self.gap as u64) * SECTOR_SIZE;
+        let last = (self.size - 1) / unit_bytes;
+        start + last * stride + (self.size - last * unit_bytes)
+    }
+}
+
+/// Indexed ISO image over any seekable reader
+pub struct IsoImage<R: Read + Seek> {
+    reader: R,
+    /// Image length in bytes; no extent may reach past it
+    len: u64,
+    /// Directory extents already indexed (loops in broken images)
+    visited: HashSet<u64>,
+    pub entries: Vec<IsoEntry>,
+    /// Volume ID from the primary volume descriptor (trimmed)
+    pub volume_id: String,
+}
+
+impl IsoImage<std::fs::File> {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Opening {}", path.display()))?;
+        Self::new(file)
+    }
+}
+
+impl<R: Read + Seek> IsoImage<R> {
+    pub fn new(mut reader: R) -> Result<Self> {
+        // Primary Volume Descriptor (sector 16)
+        let mut pvd = [0u8; SECTOR_SIZE as usize];
+        reader.seek(SeekFrom::Start(16 * SECTOR_SIZE))?;
+        reader.read_exact(&mut pvd).context("Reading ISO volume descriptor")?;
+        if &pvd[1..6] != b"CD001" {
+            bail!("Not a valid ISO 9660 image (missing CD001 signature)");
+        }
+        // Root directory record at PVD offset 156
+        let root_lba = le_u32(&pvd[158..162]) as u64;
+        let root_size = le_u32(&pvd[166..170]) as u64;
+
+        let volume_id = String::from_utf8_lossy(&pvd[40..72]).trim().to_string();
+
+        let len = reader.seek(SeekFrom::End(0))?;
+        let mut image = Self { reader, len, visited: HashSet::new(), entries: Vec::new(), volume_id };
+        image.index_directory(root_lba, root_size, "", 0)?;
+        tracing::info!("ISO indexed: {} files, volume '{}'", image.entries.len(), image.volume_id);
+        Ok(image)
+    }
+
+    fn index_directory(&mut self, lba: u64, size: u64, prefix: &str, depth: usize) -> Result<()> {
+        if depth > MAX_DEPTH || !self.visited.insert(lba) {
+            return Ok(());
+        }
+        if size > MAX_DIR_SIZE || lba * SECTOR_SIZE + size > self.len {
+            bail!("ISO directory '{}' out of range ({} bytes at LBA {})", prefix, size, lba);
+        }
+        let mut dir = vec![0u8; size as usize];
+        self.reader.seek(SeekFrom::Start(lba * SECTOR_SIZE))?;
+        self.reader.read_exact(&mut dir)
+            .with_context(|| format!("Reading ISO directory '{}'", prefix))?;
+
+        let mut offset = 0usize;
+        while offset < dir.len() {
+            let record_len = dir[offset] as usize;
+            if record_len == 0 {
+                // Padding up to the next sector
+                offset = (offset / SECTOR_SIZE as usize + 1) * SECTOR_SIZE as usize;
+                continue;
+            }
+            if record_len < 34 || offset + record_len > dir.len() {
+                break;
+            }
+            let record = &dir[offset..offset + record_len];
+            offset += record_len;
+
+            let name_len = record[32] as usize;
+            if name_len == 0 || 33 + name_len > record.len() {
+                continue;
+            }
+            let name_bytes = &record[33..33 + name_len];
+            // "." and ".."
+            if name_bytes == [0x00] || name_bytes == [0x01] {
+                continue;
+            }
+            let name = String::from_utf8_lossy(name_bytes);
+            let name = name.split(';').next().unwrap_or_default();
+            let path = if prefix.is_empty() { name.to_string() } else { format!("{}/{}", prefix, name) };
+
+            let entry_lba = le_u32(&record[2..6]) as u64;
+            let entry_size = le_u32(&record[10..14]) as u64;
+            if record[25] & 0x02 != 0 {
+                self.index_directory(entry_lba, entry_size, &path, depth + 1)?;
+            } else {
+                self.entries.push(IsoEntry {
+                    path,
+                    lba: entry_lba,
+                    size: entry_size,
+                    unit: record[26],
+                    gap: record[28],
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a whole file
+    pub fn read(&mut self, entry: &IsoEntry) -> Result<Vec<u8>> {
+        if entry.end() > self.len {
+            bail!("{} reaches past the end of the image", entry.path);
+        }
+        let mut data = vec![0u8; entry.size as usize];
+        if entry.unit > 0 && entry.gap > 0 {
+            let unit_bytes = entry.unit as u64 * SECTOR_SIZE;
+            let stride = (entry.unit as u64 + entry.gap as u64) * SECTOR_SIZE;
+            for (i, chunk) in data.chunks_mut(unit_bytes as usize).enumerate() {
+                self.reader.seek(SeekFrom::Start(entry.lba * SECTOR_SIZE + i as u64 * stride))?;
+                self.reader.read_exact(chunk)
+                    .with_context(|| format!("Reading {} (interleaved)", entry.path))?;
+            }
+        } else {
+            self.reader.seek(SeekFrom::Start(entry.lba * SECTOR_SIZE))?;
+            self.reader.read_exact(&mut data)
+                .with_context(|| format!("Reading {}", entry.path))?;
+        }
+        Ok(data)
+    }
+
+    /// Director files to load, one per file name — root directory first,
+    /// then Data/, Movies/ and Autos/ (same precedence as `AssetStore::load`)
+    pub fn director_files(&self) -> Vec<IsoEntry> {
+        let rank = |e: &IsoEntry| {
+            let dir = e.path.rsplit_once('/').map(|(d, _)| d.to_uppercase());
+            match dir.as_deref() {
+                None => Some(0),
+                Some("DATA") => Some(1),
+                Some("MOVIES") => Some(2),
+                Some("AUTOS") => Some(3),
+                Some(_) => None,
+            }
+        };
+        let mut files: Vec<(usize, &IsoEntry)> = self.entries.iter()
+            .filter(|e| e.is_director_file())
+            .filter_map(|e| rank(e).map(|r| (r, e)))
+            .collect();
+        files.sort_by_key(|&(r, _)| r);
+        let mut seen = std::collections::HashSet::new();
+        files.into_iter()
+            .filter(|(_, e)| seen.insert(e.name().to_string()))
+            .map(|(_, e)| e.clone())
+            .collect()
+    }
+}
+
+fn le_u32(b: &[u8]) -> u32 {
+    u32::from_le_bytes([b[0], b[1], b[2], b[3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Directory record (both-endian fields only filled little-endian)
+    fn record(name: &[u8], lba: u32, size: u32, flags: u8, unit: u8, gap: u8) -> Vec<u8> {
+        let len = 33 + name.len() + (name.len() + 1) % 2;
+        let mut r = vec![0u8; len];
+        r[0] = len as u8;
+        r[2..6].copy_from_slice(&lba.to_le_bytes());
+        r[10..14].copy_from_slice(&size.to_le_bytes());
+        r[25] = flags;
+        r[26] = unit;
+        r[28] = gap;
+        r[32] = name.len() as u8;
+        r[33..33 + name.len()].copy_from_slice(name);
+        r
+    }
+
+    fn sector(image: &mut [u8], lba: usize) -> &mut [u8] {
+        &mut image[lba * 2048..(lba + 1) * 2048]
+    }
+
+    /// Root (LBA 18) with DATA.CST and Movies/ (LBA 19) holding an
+    /// interleaved 05.DXR (1 sector units, 1 sector gaps, from LBA 21)
+    fn test_image() -> Vec<u8> {
+        let mut image = vec![0u8; 26 * 2048];
+        let pvd = sector(&mut image, 16);
+        pvd[0] = 1;
+        pvd[1..6].copy_from_slice(b"CD001");
+        pvd[156..190].copy_from_slice(&record(&[0], 18, 2048, 2, 0, 0));
+
+        let root: Vec<u8> = [
+            record(&[0], 18, 2048, 2, 0, 0),
+            record(&[1], 18, 2048, 2, 0, 0),
+            record(b"DATA.CST;1", 20, 5, 0, 0, 0),
+            record(b"MOVIES", 19, 2048, 2, 0, 0),
+        ].concat();
+        sector(&mut image, 18)[..root.len()].copy_from_slice(&root);
+        let movies: Vec<u8> = [
+            record(&[0], 19, 2048, 2, 0, 0),
+            record(b"05.DXR;1", 21, 2048 + 3, 0, 1, 1),
+            record(b"DATA.CST;1", 25, 1, 0, 0, 0),
+        ].concat();
+        sector(&mut image, 19)[..movies.len()].copy_from_slice(&movies);
+
+        sector(&mut image, 20)[..5].copy_from_slice(b"RIFX!");
+        sector(&mut image, 21).fill(b'a');
+        sector(&mut image, 22).fill(b'-'); // gap
+        sector(&mut image, 23)[..3].copy_from_slice(b"bcd");
+        image
+    }
+
+    #[test]
+    fn indexes_tree_and_reads_files() {
+        let mut iso = IsoImage::new(Cursor::new(test_image())).unwrap();
+        let paths: Vec<&str> = iso.entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["DATA.CST", "MOVIES/05.DXR", "MOVIES/DATA.CST"]);
+
+        let files = iso.director_files();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "DATA.CST"); // root wins over the duplicate
+
+        assert_eq!(iso.read(&files[0]).unwrap(), b"RIFX!");
+        let dxr = iso.read(&files[1]).unwrap();
+        assert_eq!(dxr.len(), 2048 + 3);
+        assert!(dxr[..2048].iter().all(|&b| b == b'a'));
+        assert_eq!(&dxr[2048..], b"bcd");
+    }
+
+    #[test]
+    fn broken_extents_are_refused() {
+        // Movies/ points back at the root: indexed once, no endless loop
+        // (its record follows ".", ".." and DATA.CST: 34 + 34 + 44 bytes)
+        let mut image = test_image();
+        sector(&mut image, 18)[112..152].copy_from_slice(&record(b"MOVIES", 18, 2048, 2, 0, 0));
+        let mut iso = IsoImage::new(Cursor::new(image)).unwrap();
+        assert_eq!(iso.entries.len(), 1);
+
+        // A file or directory size beyond the image is not allocated
+        let mut entry = iso.entries[0].clone();
+        entry.size = u32::MAX as u64;
+        assert!(iso.read(&entry).is_err());
+        let mut image = test_image();
+        sector(&mut image, 18)[112..152].copy_from_slice(&record(b"MOVIES", 19, u32::MAX, 2, 0, 0));
+        assert!(IsoImage::new(Cursor::new(image)).is_err());
+    }
+
+    #[test]
+    fn rejects_non_iso() {
+        assert!(IsoImage::new(Cursor::new(vec![0u8; 20 * 2048])).is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! Release diff — what differs between two game ISOs
+//!
+//! Compares the file lists of two images (German, Swedish, Dutch release,
+//! or two pressings of one), by size and CRC-32, and for changed Director
+//! files the cast members by number: added, removed, renamed, other type
+//! or other data. The text report documents the differences; the patch set
+//! turns the old release into the new one:
+//!
+//! ```text
+//! <dir>/patch.json        volumes and per file: add / replace / remove,
+//!                         CRC-32 of the old file (replace, remove) and the new one
+//! <dir>/files/<path>      new contents of added and replaced files
+//! ```
+//!
+//! `openwilly diff-iso OLD.iso NEW.iso [PATCH_DIR]` runs it from the command line.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Seek};
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{ensure, Context, Result};
+use serde::Serialize;
+
+use super::director::{CastType, DirectorFile};
+use super::iso::IsoImage;
+
+/// Size and checksum of one file in an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileSummary {
+    pub size: u64,
+    pub crc32: u32,
+}
+
+/// What identifies a cast member's content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberSummary {
+    pub name: String,
+    pub cast_type: CastType,
+    /// CRC-32 over the linked chunks (sorted by tag) and the text
+    pub crc32: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberChange {
+    Added(u32, String),
+    Removed(u32, String),
+    Renamed { num: u32, old: String, new: String },
+    /// Other type or other data (name unchanged)
+    Changed(u32, String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileChange {
+    Added(FileSummary),
+    Removed(FileSummary),
+    /// Members only for Director files that parse in both releases
+    Changed { old: FileSummary, new: FileSummary, members: Vec<MemberChange> },
+}
+
+/// Differences between two releases
+#[derive(Debug, Default)]
+pub struct IsoDiff {
+    pub old_volume: String,
+    pub new_volume: String,
+    /// Keyed by path inside the image (upper case)
+    pub files: BTreeMap<String, FileChange>,
+    pub unchanged: usize,
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    crc.update(data);
+    crc.sum()
+}
+
+/// Members of a parsed file by number
+pub fn summarize(df: &DirectorFile) -> BTreeMap<u32, MemberSummary> {
+    df.cast_members.iter()
+        .map(|(&num, member)| {
+            let mut crc = flate2::Crc::new();
+            let mut chunks: Vec<_> = member.linked_data.iter().collect();
+            chunks.sort_by(|a, b| a.0.cmp(b.0));
+            for (tag, data) in chunks {
+                crc.update(tag.as_bytes());
+                crc.update(data);
+            }
+            if let Some(text) = &member.text_content {
+                crc.update(text.as_bytes());
+            }
+            let summary = MemberSummary { name: member.name.clone(), cast_type: member.cast_type, crc32: crc.sum() };
+            (num, summary)
+        })
+        .collect()
+}
+
+/// Member changes between two versions of a cast
+pub fn diff_members(old: &BTreeMap<u32, MemberSummary>, new: &BTreeMap<u32, MemberSummary>) -> Vec<MemberChange> {
+    let mut changes = Vec::new();
+    for (&num, o) in old {
+        match new.get(&num) {
+            None => changes.push(MemberChange::Removed(num, o.name.clone())),
+            Some(n) if n.name != o.name => {
+                changes.push(MemberChange::Renamed { num, old: o.name.clone(), new: n.name.clone() });
+            }
+            Some(n) if n != o => changes.push(MemberChange::Changed(num, n.name.clone())),
+            Some(_) => {}
+        }
+    }
+    for (&num, n) in new.iter().filter(|(num, _)| !old.contains_key(num)) {
+        changes.push(MemberChange::Added(num, n.name.clone()));
+    }
+    changes.sort_by_key(|c| match c {
+        MemberChange::Added(num, _) | MemberChange::Removed(num, _) | MemberChange::Changed(num, _) => *num,
+        MemberChange::Renamed { num, .. } => *num,
+    });
+    changes
+}
+
+/// Compare two images file by file (reads every file of both)
+pub fn diff<A: Read + Seek, B: Read + Seek>(old: &mut IsoImage<A>, new: &mut IsoImage<B>) -> Result<IsoDiff> {
+    let mut result = IsoDiff {
+        old_volume: old.volume_id.clone(),
+        new_volume: new.volume_id.clone(),
+        ..Default::default()
+    };
+    let old_entries: BTreeMap<String, _> = old.entries.iter().map(|e| (e.path.to_uppercase(), e.clone())).collect();
+    let new_entries: BTreeMap<String, _> = new.entries.iter().map(|e| (e.path.to_uppercase(), e.clone())).collect();
+
+    for (path, entry) in &old_entries {
+        let old_data = old.read(entry)?;
+        let old_sum = FileSummary { size: old_data.len() as u64, crc32: crc32(&old_data) };
+        let Some(new_entry) = new_entries.get(path) else {
+            result.files.insert(path.clone(), FileChange::Removed(old_sum));
+            continue;
+        };
+        let new_data = new.read(new_entry)?;
+        let new_sum = FileSummary { size: new_data.len() as u64, crc32: crc32(&new_data) };
+        if old_sum == new_sum {
+            result.unchanged += 1;
+            continue;
+        }
+        let members = if entry.is_director_file() {
+            let name = entry.name().to_string();
+            match (DirectorFile::parse_bytes(name.clone(), old_data), DirectorFile::parse_bytes(name, new_data)) {
+                (Ok(o), Ok(n)) => diff_members(&summarize(&o), &summarize(&n)),
+                _ => {
+                    tracing::warn!("{}: not parsed in both releases, members not compared", path);
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        result.files.insert(path.clone(), FileChange::Changed { old: old_sum, new: new_sum, members });
+    }
+    for (path, entry) in new_entries.iter().filter(|(p, _)| !old_entries.contains_key(*p)) {
+        let data = new.read(entry)?;
+        result.files.insert(path.clone(), FileChange::Added(FileSummary { size: data.len() as u64, crc32: crc32(&data) }));
+    }
+    Ok(result)
+}
+
+#[derive(Serialize)]
+struct PatchFile<'a> {
+    path: &'a str,
+    action: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_crc32: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct PatchManifest<'a> {
+    from_volume: &'a str,
+    to_volume: &'a str,
+    files: Vec<PatchFile<'a>>,
+}
+
+impl IsoDiff {
+    /// Human-readable report
+    pub fn report(&self) -> String {
+        let mut lines = vec![
+            format!("{} -> {}", self.old_volume, self.new_volume),
+            format!("{} files unchanged, {} differ", self.unchanged, self.files.len()),
+        ];
+        for (path, change) in &self.files {
+            match change {
+                FileChange::Added(s) => lines.push(format!("+ {} ({} bytes)", path, s.size)),
+                FileChange::Removed(s) => lines.push(format!("- {} ({} bytes)", path, s.size)),
+                FileChange::Changed { old, new, members } => {
+                    lines.push(format!(
+                        "~ {} ({} -> {} bytes, CRC {:08X} -> {:08X})",
+                        path, old.size, new.size, old.crc32, new.crc32,
+                    ));
+                    lines.extend(members.iter().map(|member| match member {
+                        MemberChange::Added(num, name) => format!("    + #{} '{}'", num, name),
+                        MemberChange::Removed(num, name) => format!("    - #{} '{}'", num, name),
+                        MemberChange::Renamed { num, old, new } => format!("    #{} '{}' -> '{}'", num, old, new),
+                        MemberChange::Changed(num, name) => format!("    ~ #{} '{}'", num, name),
+                    }));
+                }
+            }
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Write the patch set (see module docs) with the new files from `new`
+    pub fn write_patch<R: Read + Seek>(&self, new: &mut IsoImage<R>, dir: &Path) -> Result<()> {
+        let mut files = Vec::new();
+        for (path, change) in &self.files {
+            let out = patch_file(dir, path)?;
+            let (action, base, target) = match change {
+                FileChange::Added(s) => ("add", None, Some(s.crc32)),
+                FileChange::Removed(s) => ("remove", Some(s.crc32), None),
+                FileChange::Changed { old, new, .. } => ("replace", Some(old.crc32), Some(new.crc32)),
+            };
+            if target.is_some() {
+                let entry = new.entries.iter().find(|e| e.path.eq_ignore_ascii_case(path))
+                    .with_context(|| format!("{} not in the new image", path))?
+                    .clone();
+                if let Some(parent) = out.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&out, new.read(&entry)?)
+                    .with_context(|| format!("Writing {}", out.display()))?;
+            }
+            files.push(PatchFile { path, action, base_crc32: base, crc32: target });
+        }
+        std::fs::create_dir_all(dir)?;
+        let manifest = PatchManifest { from_volume: &self.old_volume, to_volume: &self.new_volume, files };
+        std::fs::write(dir.join("patch.json"), serde_json::to_string_pretty(&manifest)?)?;
+        Ok(())
+    }
+}
+
+/// Where a file of the image goes in the patch set. Image paths come from
+/// the disc's directory records, so anything but plain names (`..`, a root,
+/// a drive prefix) is refused instead of written outside `dir`.
+fn patch_file(dir: &Path, path: &str) -> Result<PathBuf> {
+    let relative = Path::new(path);
+    ensure!(
+        relative.components().count() > 0 && relative.components().all(|c| matches!(c, Component::Normal(_))),
+        "Unsafe path in the image: {:?}", path
+    );
+    Ok(dir.join("files").join(relative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, crc32: u32) -> MemberSummary {
+        MemberSummary { name: name.into(), cast_type: CastType::Bitmap, crc32 }
+    }
+
+    #[test]
+    fn member_changes_by_number() {
+        let old = BTreeMap::from([
+            (1, member("mulle", 1)), (2, member("figge", 2)), (3, member("salka", 3)), (4, member("kran", 4)),
+        ]);
+        let new = BTreeMap::from([
+            (1, member("mulle", 1)), (2, member("figge", 9)), (3, member("hund", 3)), (5, member("bil", 5)),
+        ]);
+        assert_eq!(diff_members(&old, &new), vec![
+            MemberChange::Changed(2, "figge".into()),
+            MemberChange::Renamed { num: 3, old: "salka".into(), new: "hund".into() },
+            MemberChange::Removed(4, "kran".into()),
+            MemberChange::Added(5, "bil".into()),
+        ]);
+        assert!(diff_members(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn patch_paths_stay_inside_the_patch_dir() {
+        let dir = Path::new("patch");
+        assert_eq!(patch_file(dir, "Movies/05.DXR").unwrap(), Path::new("patch/files/Movies/05.DXR"));
+        for path in ["../evil.dll", "Movies/../../evil.dll", "/etc/passwd", "./05.DXR", ""] {
+            assert!(patch_file(dir, path).is_err(), "{}", path);
+        }
+    }
+}
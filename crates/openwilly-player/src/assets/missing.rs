@@ -0,0 +1,142 @@
+//! Missing cast members — visible placeholders instead of silent gaps
+//!
+//! Regional discs and partial rips lack members the scenes expect. The
+//! lookups used to Option-chain quietly, which left invisible buttons or
+//! empty backgrounds. Scene code now asks through `AssetStore::require_*`:
+//! a miss is recorded here (once per member in the log, with a count) and
+//! a checkerboard placeholder or a short beep stands in. The list is logged
+//! on exit, included in bug reports and counted in the dev menu.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use super::bitmap::DecodedBitmap;
+use super::sound::DecodedSound;
+
+/// Side length of a placeholder whose member size is unknown
+pub const PLACEHOLDER_SIZE: u32 = 32;
+/// Checkerboard cell size
+const CELL: u32 = 4;
+const BEEP_RATE: u32 = 22050;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MissingKind {
+    Bitmap,
+    Sound,
+}
+
+impl MissingKind {
+    fn label(self) -> &'static str {
+        match self {
+            MissingKind::Bitmap => "bitmap",
+            MissingKind::Sound => "sound",
+        }
+    }
+}
+
+/// Members missed this session: (kind, "file#num" or member name) → lookups
+#[derive(Default)]
+pub struct MissingAssets {
+    seen: Mutex<BTreeMap<(MissingKind, String), u32>>,
+}
+
+impl MissingAssets {
+    /// Note a failed lookup (logged the first time)
+    pub fn record(&self, kind: MissingKind, member: &str) {
+        let Ok(mut seen) = self.seen.lock() else { return };
+        let count = seen.entry((kind, member.to_string())).or_insert(0);
+        if *count == 0 {
+            tracing::warn!("Missing {} {}, using a placeholder", kind.label(), member);
+        }
+        *count += 1;
+    }
+
+    /// Distinct members missed so far
+    pub fn count(&self) -> usize {
+        self.seen.lock().map(|seen| seen.len()).unwrap_or(0)
+    }
+
+    /// One line per missing member, e.g. "bitmap 03.DXR#34 (2x)"
+    pub fn report(&self) -> Vec<String> {
+        let Ok(seen) = self.seen.lock() else { return Vec::new() };
+        seen.iter()
+            .map(|((kind, member), count)| format!("{} {} ({}x)", kind.label(), member, count))
+            .collect()
+    }
+
+    /// Log the session's missing members (on exit)
+    pub fn log_report(&self) {
+        let lines = self.report();
+        if lines.is_empty() {
+            return;
+        }
+        tracing::warn!("{} cast members were missing this session:", lines.len());
+        for line in lines {
+            tracing::warn!("  {}", line);
+        }
+    }
+}
+
+/// Magenta/black checkerboard with a white frame, opaque
+pub fn placeholder_bitmap(width: u32, height: u32) -> DecodedBitmap {
+    let (width, height) = (width.max(1), height.max(1));
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let rgb = if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+                [0xFF, 0xFF, 0xFF]
+            } else if (x / CELL + y / CELL) & 1 == 0 {
+                [0xFF, 0x00, 0xFF]
+            } else {
+                [0x00, 0x00, 0x00]
+            };
+            pixels.extend_from_slice(&rgb);
+            pixels.push(0xFF);
+        }
+    }
+    DecodedBitmap { width, height, pixels }
+}
+
+/// Two short low beeps, quieter than the cast sounds
+pub fn placeholder_sound() -> DecodedSound {
+    let len = BEEP_RATE as usize / 5;
+    let pcm = (0..len)
+        .map(|i| {
+            // 80 ms on, 40 ms off, 80 ms on — square wave at ~440 Hz
+            let gap = (len * 2 / 5..len * 3 / 5).contains(&i);
+            let high = (i * 440 * 2 / BEEP_RATE as usize) & 1 == 0;
+            match (gap, high) {
+                (true, _) => 128,
+                (false, true) => 160,
+                (false, false) => 96,
+            }
+        })
+        .collect();
+    DecodedSound { sample_rate: BEEP_RATE, channels: 1, bits_per_sample: 8, pcm_data: pcm }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_each_member_once_with_count() {
+        let missing = MissingAssets::default();
+        missing.record(MissingKind::Bitmap, "03.DXR#34");
+        missing.record(MissingKind::Sound, "02e015v0");
+        missing.record(MissingKind::Bitmap, "03.DXR#34");
+        assert_eq!(missing.count(), 2);
+        assert_eq!(missing.report(), vec!["bitmap 03.DXR#34 (2x)", "sound 02e015v0 (1x)"]);
+    }
+
+    #[test]
+    fn placeholders_are_visible() {
+        let bmp = placeholder_bitmap(10, 6);
+        assert_eq!(bmp.pixels.len(), 10 * 6 * 4);
+        assert!(bmp.pixels.chunks(4).all(|px| px[3] == 0xFF));
+        assert_eq!(placeholder_bitmap(0, 0).width, 1);
+        let beep = placeholder_sound();
+        assert_eq!(beep.duration_ms(), 200);
+        assert!(beep.pcm_data.iter().any(|&s| s != 128));
+    }
+}
@@ -1,106 +1,245 @@
 //! Director 6 file format parser and asset store
 //!
-//! Parses .DIR, .DXR, .CXT, .CST files (RIFX/XFIR container format)
+//! Parses .DIR, .DXR, .CXT, .CST files (RIFX/XFIR container format),
+//! afterburned .DCR/.CCT are unpacked first
 //! Extracts bitmaps, sounds, palettes, text, and scripts.
 
+pub mod afterburner;
+pub mod aliases;
 pub mod director;
 pub mod bitmap;
+pub mod iso;
+pub mod iso_diff;
+pub mod missing;
 pub mod palette;
 pub mod sound;
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use anyhow::Result;
 
+/// Files parsed before the window opens when streaming from an ISO:
+/// boot and menu movies plus the shared casts
+const ISO_BOOT_FILES: &[&str] = &["18.DXR", "10.DXR", "00.CXT", "00.DXR"];
+/// Warmed first in the background — garage and world map
+const ISO_WARM_FIRST: &[&str] = &["03.DXR", "05.DXR", "05.CXT"];
+
 /// Central asset store — loads all Director files and provides access to cast members
 pub struct AssetStore {
-    /// Parsed Director files, keyed by filename (e.g. "00.CXT", "03.DXR")
-    pub files: HashMap<String, director::DirectorFile>,
+    /// Parsed Director files, keyed by filename (e.g. "00.CXT", "03.DXR"),
+    /// in name order so lookups across files resolve the same way every run
+    pub files: BTreeMap<String, director::DirectorFile>,
     /// Base path to game data
     pub game_dir: PathBuf,
+    /// Director files that could not be parsed: (filename, error)
+    pub failed: Vec<(String, String)>,
+    /// Files still being parsed in the background (ISO streaming)
+    pub pending: Option<mpsc::Receiver<(String, Result<director::DirectorFile>)>>,
+    /// Members scene code needed but didn't find (placeholders shown)
+    pub missing: missing::MissingAssets,
+    /// Detected non-German edition whose names are aliased on load
+    pub edition: Option<aliases::Edition>,
 }
 
 impl AssetStore {
     /// Load all Director files from the game directory
     pub fn load(game_dir: &Path) -> Result<Self> {
-        let mut files = HashMap::new();
+        Self::load_roots(game_dir, &[])
+    }
 
-        for entry in std::fs::read_dir(game_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if !path.is_file() {
-                continue;
-            }
-            let ext = path
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("")
-                .to_uppercase();
-
-            if matches!(ext.as_str(), "CXT" | "DXR" | "CST" | "DIR") {
-                let name = path
-                    .file_name()
-                    .unwrap()
-                    .to_string_lossy()
-                    .into_owned();
-                tracing::info!("Parsing: {}", name);
-                match director::DirectorFile::parse(&path) {
+    /// Load Director files from several asset roots — releases that keep
+    /// Data/ installed and Movies/ on the CD. `game_dir` (saves, mods)
+    /// comes first, `extra_roots` follow in priority order. A file found
+    /// in more than one root is taken from the first root that has a
+    /// readable copy.
+    pub fn load_roots(game_dir: &Path, extra_roots: &[PathBuf]) -> Result<Self> {
+        let mut roots = vec![director_files_in(game_dir)?];
+        for root in extra_roots {
+            roots.push(director_files_in(root).unwrap_or_else(|e| {
+                tracing::warn!("Asset root {} skipped: {}", root.display(), e);
+                Vec::new()
+            }));
+        }
+
+        let mut files = BTreeMap::new();
+        let mut failed = Vec::new();
+        let mut used = vec![0usize; roots.len()];
+        for (name, copies) in merge_roots(&roots) {
+            let mut errors = Vec::new();
+            for (root, path) in &copies {
+                tracing::info!("Parsing: {}", path.display());
+                match director::DirectorFile::parse(path) {
                     Ok(df) => {
                         tracing::info!("  {}", df.info_line());
-                        files.insert(name, df);
+                        used[*root] += 1;
+                        files.insert(name.clone(), df);
+                        break;
                     }
                     Err(e) => {
                         tracing::warn!("Failed to parse {}: {}", path.display(), e);
+                        errors.push(format!("{:#}", e));
                     }
                 }
             }
+            if errors.len() == copies.len() {
+                failed.push((name, errors.join("; ")));
+            } else if copies.len() > 1 {
+                tracing::debug!("{}: {} copies, root {} used", name, copies.len(), copies[0].0);
+            }
+        }
+
+        // Merged layout
+        let all_roots: Vec<&Path> =
+            std::iter::once(game_dir).chain(extra_roots.iter().map(PathBuf::as_path)).collect();
+        for (i, root) in all_roots.iter().enumerate() {
+            tracing::info!("Asset root {}: {} ({} files used)", i, root.display(), used[i]);
         }
 
-        // Also scan Data/ and Movies/ subdirectories
-        for subdir_name in &["Data", "Movies", "Autos"] {
-            let subdir = game_dir.join(subdir_name);
-            if !subdir.is_dir() {
-                continue;
+        let edition = aliases::EditionTable::load(game_dir)
+            .detect(None, |f| all_roots.iter().any(|r| r.join(f).is_file()))
+            .cloned();
+        let mut store = Self {
+            files: BTreeMap::new(),
+            game_dir: game_dir.to_path_buf(),
+            failed,
+            pending: None,
+            missing: Default::default(),
+            edition,
+        };
+        store.adopt_all(files);
+        Ok(store)
+    }
+
+    /// Load Director files straight from an unextracted ISO image.
+    /// Boot and menu files are parsed right away, everything else (garage,
+    /// world map tiles, destinations) is read and parsed on a background
+    /// thread and picked up by `poll_background` / `finish_loading`.
+    /// `game_dir` only holds saves and mods.
+    pub fn load_iso(iso_path: &Path, game_dir: &Path) -> Result<Self> {
+        let mut image = iso::IsoImage::open(iso_path)?;
+        let edition = aliases::EditionTable::load(game_dir)
+            .detect(Some(&image.volume_id), |f| {
+                image.entries.iter().any(|e| e.path.eq_ignore_ascii_case(f))
+            })
+            .cloned();
+        let mut entries = image.director_files();
+        let game_name = |e: &iso::IsoEntry| match &edition {
+            Some(edition) => edition.file_name(e.name()),
+            None => e.name().to_string(),
+        };
+        if !entries.iter().any(|e| game_name(e).eq_ignore_ascii_case("05.DXR")) {
+            anyhow::bail!("No Willy Werkel movies found in {}", iso_path.display());
+        }
+        let rank = |e: &iso::IsoEntry| {
+            let name = game_name(e).to_uppercase();
+            if name.ends_with(".CST") || ISO_BOOT_FILES.contains(&name.as_str()) {
+                0
+            } else if ISO_WARM_FIRST.contains(&name.as_str()) {
+                1
+            } else {
+                2
             }
-            for entry in std::fs::read_dir(&subdir)? {
-                let entry = entry?;
-                let path = entry.path();
-                if !path.is_file() {
-                    continue;
-                }
-                let ext = path
-                    .extension()
-                    .and_then(|e| e.to_str())
-                    .unwrap_or("")
-                    .to_uppercase();
-
-                if matches!(ext.as_str(), "CXT" | "DXR" | "CST" | "DIR") {
-                    let name = path
-                        .file_name()
-                        .unwrap()
-                        .to_string_lossy()
-                        .into_owned();
-                    if files.contains_key(&name) {
-                        continue; // Skip duplicates
-                    }
-                    tracing::info!("Parsing: {}/{}", subdir_name, name);
-                    match director::DirectorFile::parse(&path) {
-                        Ok(df) => {
-                            tracing::info!("  {}", df.info_line());
-                            files.insert(name, df);
-                        }
-                        Err(e) => {
-                            tracing::warn!("Failed to parse {}: {}", path.display(), e);
-                        }
-                    }
+        };
+        entries.sort_by_key(rank);
+        let split = entries.iter().position(|e| rank(e) > 0).unwrap_or(entries.len());
+        let background = entries.split_off(split);
+
+        let mut files = BTreeMap::new();
+        let mut failed = Vec::new();
+        for entry in &entries {
+            match parse_iso_entry(&mut image, iso_path, entry) {
+                (name, Ok(df)) => {
+                    files.insert(name, df);
                 }
+                (name, Err(e)) => failed.push((name, format!("{:#}", e))),
             }
         }
 
-        Ok(Self {
-            files,
+        let (tx, rx) = mpsc::channel();
+        let iso_path = iso_path.to_path_buf();
+        std::thread::spawn(move || {
+            let mut image = match iso::IsoImage::open(&iso_path) {
+                Ok(image) => image,
+                Err(e) => {
+                    tracing::error!("ISO background loading failed: {:#}", e);
+                    return;
+                }
+            };
+            for entry in &background {
+                if tx.send(parse_iso_entry(&mut image, &iso_path, entry)).is_err() {
+                    return;
+                }
+            }
+            tracing::info!("ISO background loading done ({} files)", background.len());
+        });
+
+        let mut store = Self {
+            files: BTreeMap::new(),
             game_dir: game_dir.to_path_buf(),
-        })
+            failed,
+            pending: Some(rx),
+            missing: Default::default(),
+            edition,
+        };
+        store.adopt_all(files);
+        Ok(store)
+    }
+
+    /// Take over parsed files under the names the game uses
+    fn adopt_all(&mut self, files: BTreeMap<String, director::DirectorFile>) {
+        if let Some(edition) = &self.edition {
+            tracing::info!("Edition: {} (names aliased)", edition.name);
+        }
+        for (name, df) in files {
+            self.adopt(name, df);
+        }
+    }
+
+    fn adopt(&mut self, name: String, df: director::DirectorFile) {
+        let Some(edition) = &self.edition else {
+            self.files.insert(name, df);
+            return;
+        };
+        let (alias, df, renamed) = edition.apply(name.clone(), df);
+        if alias != name || renamed > 0 {
+            tracing::debug!("{} → {} ({} members renamed)", name, alias, renamed);
+        }
+        self.files.insert(alias, df);
+    }
+
+    /// Background loading still running?
+    pub fn is_loading(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Take over files parsed in the background so far (non-blocking)
+    pub fn poll_background(&mut self) {
+        loop {
+            let Some(rx) = &self.pending else { return };
+            match rx.try_recv() {
+                Ok((name, Ok(df))) => self.adopt(name, df),
+                Ok((name, Err(e))) => self.failed.push((name, format!("{:#}", e))),
+                Err(mpsc::TryRecvError::Empty) => return,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.pending = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Wait for the background loading to finish. Lookups search all
+    /// files by member name, so scenes only start once everything is in.
+    pub fn finish_loading(&mut self) {
+        let Some(rx) = self.pending.take() else { return };
+        tracing::info!("Waiting for ISO background loading...");
+        for (name, parsed) in rx {
+            match parsed {
+                Ok(df) => self.adopt(name, df),
+                Err(e) => self.failed.push((name, format!("{:#}", e))),
+            }
+        }
     }
 
     pub fn total_files(&self) -> usize {
@@ -122,7 +261,7 @@ impl AssetStore {
         file: &str,
         num: u32,
     ) -> Option<bitmap::DecodedBitmap> {
-        self.decode_bitmap_inner(file, num, None)
+        self.decode_bitmap_inner(file, num, None, None)
     }
 
     /// Decode a bitmap cast member to RGBA with white (idx 255) as transparent
@@ -132,7 +271,31 @@ impl AssetStore {
         file: &str,
         num: u32,
     ) -> Option<bitmap::DecodedBitmap> {
-        self.decode_bitmap_inner(file, num, Some(255))
+        self.decode_bitmap_inner(file, num, Some(255), None)
+    }
+
+    /// `decode_bitmap_transparent` through another palette — the cursors
+    /// take on the palette of the movie they are shown over
+    pub fn decode_bitmap_in_palette(
+        &self,
+        file: &str,
+        num: u32,
+        palette: &[[u8; 3]],
+    ) -> Option<bitmap::DecodedBitmap> {
+        self.decode_bitmap_inner(file, num, Some(255), Some(palette))
+    }
+
+    /// Palette member of a movie (the lowest-numbered one), None if the
+    /// movie uses the system palette
+    pub fn movie_palette(&self, file: &str) -> Option<Vec<[u8; 3]>> {
+        let df = self.files.get(file)?;
+        df.cast_members.iter()
+            .filter(|(_, m)| m.cast_type == director::CastType::Palette)
+            .min_by_key(|(&num, _)| num)
+            .and_then(|(_, m)| {
+                m.palette_data.clone()
+                    .or_else(|| m.linked_data.get("CLUT").map(|clut| palette::parse_clut(clut)))
+            })
     }
 
     fn decode_bitmap_inner(
@@ -140,6 +303,7 @@ impl AssetStore {
         file: &str,
         num: u32,
         transparent_color: Option<u8>,
+        palette_override: Option<&[[u8; 3]]>,
     ) -> Option<bitmap::DecodedBitmap> {
         let df = self.files.get(file)?;
         let member = match df.cast_members.get(&num) {
@@ -172,7 +336,10 @@ impl AssetStore {
             tracing::trace!("Bitmap #{} has alpha channel (bit_alpha={})", num, bitmap_info.bit_alpha);
         }
 
-        let palette = self.resolve_palette(file, bitmap_info.palette_ref);
+        let palette = match palette_override {
+            Some(pal) => pal.to_vec(),
+            None => self.resolve_palette(file, bitmap_info.palette_ref),
+        };
 
         Some(bitmap::decode_bitd(
             bitd_data,
@@ -184,6 +351,39 @@ impl AssetStore {
         ))
     }
 
+    /// `decode_bitmap` for members a scene can't do without: a miss is
+    /// recorded and replaced by a placeholder of the member's size
+    pub fn require_bitmap(&self, file: &str, num: u32) -> bitmap::DecodedBitmap {
+        self.decode_bitmap(file, num)
+            .unwrap_or_else(|| self.member_placeholder(file, num))
+    }
+
+    /// `decode_bitmap_transparent` with a placeholder, see `require_bitmap`
+    pub fn require_bitmap_transparent(&self, file: &str, num: u32) -> bitmap::DecodedBitmap {
+        self.decode_bitmap_transparent(file, num)
+            .unwrap_or_else(|| self.member_placeholder(file, num))
+    }
+
+    /// `find_bitmap_by_name` with a placeholder, see `require_bitmap`
+    pub fn require_bitmap_by_name(&self, name: &str) -> bitmap::DecodedBitmap {
+        self.find_bitmap_by_name(name).unwrap_or_else(|| {
+            self.missing.record(missing::MissingKind::Bitmap, name);
+            let size = missing::PLACEHOLDER_SIZE;
+            missing::placeholder_bitmap(size, size)
+        })
+    }
+
+    fn member_placeholder(&self, file: &str, num: u32) -> bitmap::DecodedBitmap {
+        self.missing.record(missing::MissingKind::Bitmap, &format!("{}#{}", file, num));
+        let size = missing::PLACEHOLDER_SIZE;
+        let (w, h) = self.get_member(file, num)
+            .and_then(|m| m.bitmap_info.as_ref())
+            .map(|bi| (bi.width as u32, bi.height as u32))
+            .filter(|&(w, h)| w > 0 && h > 0 && w * h <= 640 * 480)
+            .unwrap_or((size, size));
+        missing::placeholder_bitmap(w, h)
+    }
+
     /// Decode a sound cast member to a DecodedSound
     pub fn decode_sound(&self, file: &str, num: u32) -> Option<sound::DecodedSound> {
         let df = self.files.get(file)?;
@@ -215,63 +415,57 @@ impl AssetStore {
     /// Returns (filename, member_num) if found.
     /// Comparison is case-insensitive (Director names may be mixed case).
     pub fn find_sound_by_name(&self, name: &str) -> Option<(String, u32)> {
-        for (fname, df) in &self.files {
-            for (num, member) in &df.cast_members {
-                if member.cast_type == director::CastType::Sound
-                    && member.name.eq_ignore_ascii_case(name)
-                {
-                    return Some((fname.clone(), *num));
-                }
-            }
-        }
-        None
+        self.find_member(|m| {
+            m.cast_type == director::CastType::Sound && m.name.eq_ignore_ascii_case(name)
+        })
+        .map(|(fname, num, _)| (fname.to_string(), num))
     }
 
     /// Find cue points for a sound cast member by name.
     /// Returns the cue point list (empty if none found).
     /// Comparison is case-insensitive.
     pub fn find_cue_points(&self, name: &str) -> Vec<director::CuePoint> {
-        for (_fname, df) in &self.files {
-            for (_num, member) in &df.cast_members {
-                if member.cast_type == director::CastType::Sound
-                    && member.name.eq_ignore_ascii_case(name)
-                {
-                    if let Some(si) = &member.sound_info {
-                        return si.cue_points.clone();
-                    }
-                }
-            }
-        }
-        Vec::new()
+        self.find_member(|m| {
+            m.cast_type == director::CastType::Sound
+                && m.name.eq_ignore_ascii_case(name)
+                && m.sound_info.is_some()
+        })
+        .and_then(|(_, _, m)| m.sound_info.as_ref())
+        .map(|si| si.cue_points.clone())
+        .unwrap_or_default()
     }
 
     /// Find a bitmap cast member by name across all files,
     /// decode it as transparent, and return the decoded bitmap.
     /// Member names are like "20b001v2" (found mainly in CDDATA.CXT).
     pub fn find_bitmap_by_name(&self, name: &str) -> Option<bitmap::DecodedBitmap> {
-        for (fname, df) in &self.files {
-            for (num, member) in &df.cast_members {
-                if member.cast_type == director::CastType::Bitmap && member.name == name {
-                    return self.decode_bitmap_transparent(fname, *num);
-                }
-            }
-        }
-        None
+        let (fname, num, _) = self.find_member(|m| {
+            m.cast_type == director::CastType::Bitmap && m.name == name
+        })?;
+        self.decode_bitmap_transparent(fname, num)
     }
 
     /// Find a bitmap cast member by name across all files.
     /// Returns (filename, member_num, BitmapInfo) if found.
     pub fn find_bitmap_info_by_name(&self, name: &str) -> Option<(String, u32, &director::BitmapInfo)> {
-        for (fname, df) in &self.files {
-            for (num, member) in &df.cast_members {
-                if member.cast_type == director::CastType::Bitmap && member.name == name {
-                    if let Some(bi) = &member.bitmap_info {
-                        return Some((fname.clone(), *num, bi));
-                    }
-                }
-            }
-        }
-        None
+        let (fname, num, member) = self.find_member(|m| {
+            m.cast_type == director::CastType::Bitmap && m.name == name && m.bitmap_info.is_some()
+        })?;
+        Some((fname.to_string(), num, member.bitmap_info.as_ref()?))
+    }
+
+    /// First member matching `pred`, by file name and then member number —
+    /// names used in more than one file resolve the same way every run
+    fn find_member(
+        &self,
+        pred: impl Fn(&director::CastMember) -> bool,
+    ) -> Option<(&str, u32, &director::CastMember)> {
+        self.files.iter().find_map(|(fname, df)| {
+            df.cast_members.iter()
+                .filter(|(_, m)| pred(m))
+                .min_by_key(|(&num, _)| num)
+                .map(|(&num, m)| (fname.as_str(), num, m))
+        })
     }
 
     /// Resolve a palette reference to actual RGB data
@@ -309,3 +503,113 @@ impl AssetStore {
         }
     }
 }
+
+/// Director files of one asset root: the root itself, then Data/, Movies/
+/// and Autos/
+fn director_files_in(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    for (i, dir) in ["", "Data", "Movies", "Autos"].iter().enumerate() {
+        let dir = child_ignore_case(root, dir).unwrap_or_else(|| root.join(dir));
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            // Only the root itself has to exist
+            Err(_) if i > 0 => continue,
+            Err(e) => return Err(e.into()),
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .map(|e| e.map(|e| e.path()))
+            .collect::<std::io::Result<_>>()?;
+        paths.retain(|p| p.is_file() && is_director_file(p));
+        paths.sort();
+        found.extend(paths);
+    }
+    Ok(found)
+}
+
+/// `dir/name`, matched case-insensitively where the file system isn't
+/// (a CD mounted on Linux shows `movies/` or `MOVIES/` instead of `Movies/`)
+pub fn child_ignore_case(dir: &Path, name: &str) -> Option<PathBuf> {
+    let exact = dir.join(name);
+    if name.is_empty() || exact.exists() {
+        return Some(exact);
+    }
+    std::fs::read_dir(dir).ok()?
+        .flatten()
+        .find(|e| e.file_name().to_string_lossy().eq_ignore_ascii_case(name))
+        .map(|e| e.path())
+}
+
+fn is_director_file(path: &Path) -> bool {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_uppercase();
+    matches!(ext.as_str(), "CXT" | "DXR" | "CST" | "DIR" | "CCT" | "DCR")
+}
+
+/// Group the files of all roots by name (case-insensitive), sorted by
+/// name. Each name lists its copies as (root index, path), best first;
+/// the name is spelled as in the best copy.
+fn merge_roots(roots: &[Vec<PathBuf>]) -> Vec<(String, Vec<(usize, PathBuf)>)> {
+    let mut merged: std::collections::BTreeMap<String, (String, Vec<(usize, PathBuf)>)> =
+        std::collections::BTreeMap::new();
+    for (root, paths) in roots.iter().enumerate() {
+        for path in paths {
+            let Some(name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else { continue };
+            merged.entry(name.to_uppercase())
+                .or_insert_with(|| (name, Vec::new()))
+                .1.push((root, path.clone()));
+        }
+    }
+    merged.into_values().collect()
+}
+
+/// Read and parse one Director file from the ISO (errors are logged)
+fn parse_iso_entry(
+    image: &mut iso::IsoImage<std::fs::File>,
+    iso_path: &Path,
+    entry: &iso::IsoEntry,
+) -> (String, Result<director::DirectorFile>) {
+    let name = entry.name().to_string();
+    tracing::info!("Parsing (ISO): {}", entry.path);
+    let parsed = image.read(entry)
+        .and_then(|data| director::DirectorFile::parse_bytes(name.clone(), data))
+        .map(|mut df| {
+            df.integrity.source = director::Source::Iso { image: iso_path.to_path_buf(), entry: entry.clone() };
+            df
+        });
+    match &parsed {
+        Ok(df) => tracing::info!("  {}", df.info_line()),
+        Err(e) => tracing::warn!("Failed to parse {}: {:#}", entry.path, e),
+    }
+    (name, parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_root_wins_for_duplicates() {
+        let install = vec![PathBuf::from("inst/Data/00.CXT"), PathBuf::from("inst/Data/DATA.CST")];
+        let cd = vec![PathBuf::from("cd/00.cxt"), PathBuf::from("cd/Movies/05.DXR")];
+        let merged = merge_roots(&[install, cd]);
+        let names: Vec<&str> = merged.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["00.CXT", "05.DXR", "DATA.CST"]);
+        assert_eq!(merged[0].1, vec![(0, PathBuf::from("inst/Data/00.CXT")), (1, PathBuf::from("cd/00.cxt"))]);
+        assert_eq!(merged[1].1[0].0, 1);
+        assert!(is_director_file(Path::new("x/18.dxr")) && !is_director_file(Path::new("WILLY32.EXE")));
+    }
+
+    #[test]
+    fn subdirectories_found_in_any_case() {
+        let root = std::env::temp_dir().join(format!("openwilly_case_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("MOVIES")).unwrap();
+        std::fs::write(root.join("MOVIES").join("05.dxr"), b"").unwrap();
+        assert_eq!(child_ignore_case(&root, "Movies"), Some(root.join("MOVIES")));
+        assert_eq!(child_ignore_case(&root, "Autos"), None);
+        assert_eq!(director_files_in(&root).unwrap(), vec![root.join("MOVIES").join("05.dxr")]);
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -57,11 +57,12 @@ impl DecodedSound {
 
     /// Encode as WAV file bytes
     pub fn to_wav(&self) -> Vec<u8> {
-        let byte_rate =
-            self.sample_rate * self.channels as u32 * self.bits_per_sample as u32 / 8;
-        let block_align = self.channels * self.bits_per_sample / 8;
+        // Header values come from sndH — saturate instead of overflowing
+        let byte_rate = self.sample_rate
+            .saturating_mul(self.channels as u32 * self.bits_per_sample as u32) / 8;
+        let block_align = self.channels.saturating_mul(self.bits_per_sample) / 8;
         let data_len = self.pcm_data.len() as u32;
-        let file_len = 36 + data_len;
+        let file_len = data_len.saturating_add(36);
 
         let mut wav = Vec::with_capacity(file_len as usize + 8);
 
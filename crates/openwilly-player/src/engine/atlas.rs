@@ -0,0 +1,278 @@
+//! Sprite atlas — small, often drawn bitmaps packed as precomputed spans
+//!
+//! Cursor frames, dashboard needles, toolbox icons and the like are drawn
+//! every frame, mostly from the same shared pixels (sprite cache, scene
+//! sprites). The plain blit reads RGBA bytes and tests the alpha of every
+//! pixel, transparent ones included. The atlas converts such a bitmap once
+//! into runs of visible pixels per row, stored as ARGB in a few shared
+//! pages: opaque runs are copied in one go, only edge pixels are blended,
+//! transparent pixels are never touched. Blending matches `blit_sprite_rows`
+//! exactly.
+//!
+//! The compositor's atlas packs a bitmap once it was drawn in `HOT_FRAMES`
+//! frames and drops it after `EVICT_FRAMES` frames unused; when the pages
+//! are full it starts over (hot bitmaps are packed again within a few frames).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{Sprite, SpritePixels};
+
+/// Pixels per page (256×256 ARGB, 256 KB)
+const PAGE_PIXELS: usize = 256 * 256;
+/// Pages before the atlas starts over (4 MB)
+const MAX_PAGES: usize = 16;
+/// Larger bitmaps (backgrounds, cars) stay with the plain blit
+pub const MAX_PIXELS: usize = 128 * 128;
+/// Frames a bitmap has to be drawn in before it is packed
+const HOT_FRAMES: u32 = 3;
+/// Packed bitmaps unused this long are dropped (10 s at 60 fps)
+const EVICT_FRAMES: u64 = 600;
+
+/// One run of visible pixels in a row
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    y: u16,
+    x: u16,
+    len: u16,
+    /// All pixels fully opaque (copied without blending)
+    opaque: bool,
+    /// First pixel in the page
+    start: u32,
+}
+
+/// Handle of a packed bitmap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packed {
+    pub width: u32,
+    pub height: u32,
+    page: u32,
+    /// Range in `SpriteAtlas::spans` (sorted by row)
+    spans: (u32, u32),
+}
+
+/// A packed shared bitmap; holding the pixels keeps their address unique
+struct Entry {
+    _pixels: SpritePixels,
+    packed: Packed,
+    last_used: u64,
+}
+
+#[derive(Default)]
+pub struct SpriteAtlas {
+    pages: Vec<Vec<u32>>,
+    spans: Vec<Span>,
+    /// Shared pixels (by address) → packed bitmap
+    entries: HashMap<usize, Entry>,
+    /// Frames a not yet packed bitmap was drawn in
+    seen: HashMap<usize, u32>,
+    frame: u64,
+    /// Frame of the last eviction sweep
+    swept: u64,
+}
+
+fn key(pixels: &SpritePixels) -> usize {
+    Arc::as_ptr(pixels) as usize
+}
+
+impl SpriteAtlas {
+    /// Pack an RGBA bitmap. None if it is too large, malformed or the
+    /// pages are full.
+    pub fn pack(&mut self, width: u32, height: u32, pixels: &[u8]) -> Option<Packed> {
+        let (w, h) = (width as usize, height as usize);
+        if w * h > MAX_PIXELS || pixels.len() != w * h * 4 || w > u16::MAX as usize {
+            return None;
+        }
+        let visible = pixels.chunks_exact(4).filter(|p| p[3] > 0).count();
+        let page = match self.pages.last() {
+            Some(last) if last.len() + visible <= PAGE_PIXELS => self.pages.len() - 1,
+            _ if self.pages.len() < MAX_PAGES => {
+                self.pages.push(Vec::with_capacity(PAGE_PIXELS));
+                self.pages.len() - 1
+            }
+            _ => return None,
+        };
+        let first_span = self.spans.len() as u32;
+        let out = &mut self.pages[page];
+        for y in 0..h {
+            let row = &pixels[y * w * 4..(y + 1) * w * 4];
+            let mut x = 0;
+            while x < w {
+                if row[x * 4 + 3] == 0 {
+                    x += 1;
+                    continue;
+                }
+                let span_x = x;
+                let start = out.len() as u32;
+                let mut opaque = true;
+                while x < w && row[x * 4 + 3] > 0 {
+                    let p = &row[x * 4..x * 4 + 4];
+                    opaque &= p[3] == 255;
+                    out.push((p[3] as u32) << 24 | (p[0] as u32) << 16 | (p[1] as u32) << 8 | p[2] as u32);
+                    x += 1;
+                }
+                let len = (x - span_x) as u16;
+                self.spans.push(Span { y: y as u16, x: span_x as u16, len, opaque, start });
+            }
+        }
+        Some(Packed { width, height, page: page as u32, spans: (first_span, self.spans.len() as u32) })
+    }
+
+    /// Count this frame's small visible bitmaps and pack the ones drawn
+    /// often enough (call once per frame before composing)
+    pub fn prepare(&mut self, sprites: &[Sprite]) {
+        self.frame += 1;
+        for sprite in sprites {
+            let pixels = sprite.width as usize * sprite.height as usize;
+            if !sprite.visible || pixels == 0 || pixels > MAX_PIXELS {
+                continue;
+            }
+            let k = key(&sprite.pixels);
+            if let Some(entry) = self.entries.get_mut(&k) {
+                entry.last_used = self.frame;
+                continue;
+            }
+            let count = self.seen.entry(k).or_insert(0);
+            *count += 1;
+            if *count < HOT_FRAMES {
+                continue;
+            }
+            self.seen.remove(&k);
+            let packed = match self.pack(sprite.width, sprite.height, &sprite.pixels) {
+                Some(packed) => packed,
+                None if !self.pages.is_empty() && self.pages.len() >= MAX_PAGES => {
+                    tracing::debug!("Sprite atlas full ({} bitmaps), starting over", self.entries.len());
+                    self.clear();
+                    continue;
+                }
+                None => continue,
+            };
+            let entry = Entry { _pixels: sprite.pixels.clone(), packed, last_used: self.frame };
+            self.entries.insert(k, entry);
+        }
+        if self.frame - self.swept >= EVICT_FRAMES {
+            let frame = self.frame;
+            self.swept = frame;
+            self.entries.retain(|_, e| frame - e.last_used < EVICT_FRAMES);
+            self.seen.clear();
+        }
+    }
+
+    /// Forget all packed bitmaps
+    pub fn clear(&mut self) {
+        self.pages.clear();
+        self.spans.clear();
+        self.entries.clear();
+        self.seen.clear();
+    }
+
+    /// Packed form of a sprite's bitmap, if any
+    pub fn lookup(&self, sprite: &Sprite) -> Option<Packed> {
+        let entry = self.entries.get(&key(&sprite.pixels))?;
+        let packed = entry.packed;
+        (packed.width == sprite.width && packed.height == sprite.height).then_some(packed)
+    }
+
+    /// Number of packed bitmaps
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Blit a packed bitmap at (x, y) onto the rows `y0..y1` of a u32 ARGB
+    /// framebuffer holding only these rows (like `blit_sprite_rows`)
+    pub fn blit_rows(&self, packed: Packed, fb: &mut [u32], fb_w: usize, (y0, y1): (i32, i32), (x, y): (i32, i32)) {
+        let page = &self.pages[packed.page as usize];
+        let spans = &self.spans[packed.spans.0 as usize..packed.spans.1 as usize];
+        let first = spans.partition_point(|s| y + (s.y as i32) < y0);
+        for span in &spans[first..] {
+            let dy = y + span.y as i32;
+            if dy >= y1 {
+                break;
+            }
+            // Clip the run to the framebuffer width
+            let sx0 = (-(x + span.x as i32)).max(0);
+            let sx1 = (fb_w as i32 - (x + span.x as i32)).min(span.len as i32);
+            if sx0 >= sx1 {
+                continue;
+            }
+            let src = &page[(span.start as i32 + sx0) as usize..(span.start as i32 + sx1) as usize];
+            let row = (dy - y0) as usize * fb_w;
+            let dx = (x + span.x as i32 + sx0) as usize;
+            let dst = &mut fb[row + dx..row + dx + src.len()];
+            if span.opaque {
+                dst.copy_from_slice(src);
+                continue;
+            }
+            for (d, &s) in dst.iter_mut().zip(src) {
+                *d = blend(*d, s);
+            }
+        }
+    }
+}
+
+/// Alpha blend an ARGB source pixel over the framebuffer pixel
+fn blend(dst: u32, src: u32) -> u32 {
+    let a = src >> 24;
+    if a >= 255 {
+        return src;
+    }
+    let inv_a = 255 - a;
+    let channel = |shift: u32| ((src >> shift & 0xFF) * a + (dst >> shift & 0xFF) * inv_a) / 255;
+    0xFF000000 | channel(16) << 16 | channel(8) << 8 | channel(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::blit_sprite_rows;
+
+    fn sprite(x: i32, y: i32, w: u32, h: u32) -> Sprite {
+        let pixels = (0..w * h)
+            .flat_map(|p| {
+                // Transparent holes, opaque runs and soft edges
+                let a = match p % 7 { 0 | 1 => 0, 2 => 90, _ => 255 };
+                [(p * 3) as u8, (p * 7) as u8, (p * 11) as u8, a]
+            })
+            .collect::<Vec<u8>>();
+        Sprite {
+            x, y, width: w, height: h,
+            pixels: Arc::new(pixels),
+            visible: true,
+            z_order: 0,
+            name: "icon".into(),
+            interactive: false,
+            member_num: 0,
+        }
+    }
+
+    #[test]
+    fn packed_blit_matches_plain_blit() {
+        const W: usize = 64;
+        // Inside, across the band border and the left/right edges
+        for s in [sprite(5, 3, 21, 17), sprite(-9, 12, 30, 9), sprite(50, -4, 25, 26)] {
+            let mut atlas = SpriteAtlas::default();
+            for _ in 0..HOT_FRAMES {
+                atlas.prepare(std::slice::from_ref(&s));
+            }
+            let packed = atlas.lookup(&s).expect("packed after HOT_FRAMES");
+            for (y0, y1) in [(0, 32), (8, 16)] {
+                let background: Vec<u32> = (0..W * (y1 - y0) as usize).map(|i| 0xFF000000 | (i as u32).wrapping_mul(2654435761)).collect();
+                let mut plain = background.clone();
+                blit_sprite_rows(&mut plain, W, y0, y1, &s);
+                let mut fast = background;
+                atlas.blit_rows(packed, &mut fast, W, (y0, y1), (s.x, s.y));
+                assert!(plain == fast, "sprite at ({}, {}), rows {}..{}", s.x, s.y, y0, y1);
+            }
+        }
+        let mut atlas = SpriteAtlas::default();
+        let big = sprite(0, 0, 200, 100);
+        for _ in 0..HOT_FRAMES {
+            atlas.prepare(std::slice::from_ref(&big));
+        }
+        assert!(atlas.lookup(&big).is_none() && atlas.is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+//! Off-screen canvas — composites sprites and text into a buffer of any size
+//!
+//! Uses the same blitting and font code as the window framebuffer; used for
+//! exported images (gallery cards) and cached thumbnails (profile avatars),
+//! which are copied onto the framebuffer with `draw_to`.
+
+use std::io::{BufRead, BufWriter, Seek, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use super::{blit_sprite_sized, font, Sprite, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+/// A u32 ARGB pixel buffer detached from the window
+#[derive(Clone)]
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u32>,
+}
+
+impl Canvas {
+    /// Create a canvas filled with `color`
+    pub fn new(width: usize, height: usize, color: u32) -> Self {
+        Self { width, height, pixels: vec![color; width * height] }
+    }
+
+    /// Alpha-blit a sprite at its own (x, y) position
+    pub fn blit(&mut self, sprite: &Sprite) {
+        if !sprite.visible || sprite.width == 0 || sprite.height == 0 {
+            return;
+        }
+        blit_sprite_sized(&mut self.pixels, self.width, self.height, sprite);
+    }
+
+    /// Fill a rectangle (opaque)
+    pub fn fill_rect(&mut self, x: i32, y: i32, w: i32, h: i32, color: u32) {
+        let x0 = x.clamp(0, self.width as i32) as usize;
+        let y0 = y.clamp(0, self.height as i32) as usize;
+        let x1 = (x + w).clamp(0, self.width as i32) as usize;
+        let y1 = (y + h).clamp(0, self.height as i32) as usize;
+        for row in y0..y1 {
+            self.pixels[row * self.width + x0..row * self.width + x1].fill(color);
+        }
+    }
+
+    /// Draw a 1px rectangle outline
+    pub fn outline_rect(&mut self, x: i32, y: i32, w: i32, h: i32, color: u32) {
+        self.fill_rect(x, y, w, 1, color);
+        self.fill_rect(x, y + h - 1, w, 1, color);
+        self.fill_rect(x, y, 1, h, color);
+        self.fill_rect(x + w - 1, y, 1, h, color);
+    }
+
+    /// Draw text with the built-in 6×8 font
+    pub fn text(&mut self, x: i32, y: i32, text: &str, color: u32) {
+        font::draw_text_sized(&mut self.pixels, self.width, self.height, x, y, text, color);
+    }
+
+    /// Draw text horizontally centered on the canvas
+    pub fn text_centered(&mut self, y: i32, text: &str, color: u32) {
+        let x = (self.width as i32 - font::text_width(text)) / 2;
+        self.text(x, y, text, color);
+    }
+
+    /// Convert to tightly packed RGBA bytes
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.pixels.iter()
+            .flat_map(|&p| [(p >> 16) as u8, (p >> 8) as u8, p as u8, 0xFF])
+            .collect()
+    }
+
+    /// Box-filtered copy at a smaller size (thumbnails)
+    pub fn scaled_down(&self, width: usize, height: usize) -> Canvas {
+        // Source pixel range [start, end) covered by destination pixel i
+        let span = |i: usize, src: usize, dst: usize| {
+            let start = i * src / dst;
+            (start, ((i + 1) * src / dst).max(start + 1))
+        };
+        let mut out = Canvas::new(width, height, 0);
+        for y in 0..height {
+            let (sy0, sy1) = span(y, self.height, height);
+            for x in 0..width {
+                let (sx0, sx1) = span(x, self.width, width);
+                let mut sum = [0u32; 3];
+                for row in sy0..sy1 {
+                    for &p in &self.pixels[row * self.width + sx0..row * self.width + sx1] {
+                        sum[0] += (p >> 16) & 0xFF;
+                        sum[1] += (p >> 8) & 0xFF;
+                        sum[2] += p & 0xFF;
+                    }
+                }
+                let n = ((sy1 - sy0) * (sx1 - sx0)) as u32;
+                out.pixels[y * width + x] = 0xFF000000 | ((sum[0] / n) << 16) | ((sum[1] / n) << 8) | (sum[2] / n);
+            }
+        }
+        out
+    }
+
+    /// Copy the canvas onto the 640×480 framebuffer at (x, y), clipped
+    pub fn draw_to(&self, fb: &mut [u32], x: i32, y: i32) {
+        for row in 0..self.height as i32 {
+            let dy = y + row;
+            if dy < 0 || dy >= SCREEN_HEIGHT as i32 {
+                continue;
+            }
+            let x0 = x.max(0);
+            let x1 = (x + self.width as i32).min(SCREEN_WIDTH as i32);
+            if x0 >= x1 {
+                return;
+            }
+            let src = row as usize * self.width + (x0 - x) as usize;
+            let dst = dy as usize * SCREEN_WIDTH + x0 as usize;
+            let n = (x1 - x0) as usize;
+            fb[dst..dst + n].copy_from_slice(&self.pixels[src..src + n]);
+        }
+    }
+
+    /// Read an 8-bit RGBA PNG (as written by `save_png`)
+    pub fn load_png(path: &Path) -> Result<Canvas> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Cannot open {}", path.display()))?;
+        Self::read_png(std::io::BufReader::new(file)).with_context(|| path.display().to_string())
+    }
+
+    /// Decode an 8-bit RGBA PNG from memory (as written by `encode_png`)
+    pub fn decode_png(data: &[u8]) -> Result<Canvas> {
+        Self::read_png(std::io::Cursor::new(data))
+    }
+
+    fn read_png<R: BufRead + Seek>(input: R) -> Result<Canvas> {
+        let mut reader = png::Decoder::new(input).read_info()?;
+        let info = reader.info();
+        if info.color_type != png::ColorType::Rgba || info.bit_depth != png::BitDepth::Eight {
+            anyhow::bail!("not an 8-bit RGBA PNG");
+        }
+        let (width, height) = (info.width as usize, info.height as usize);
+        let mut rgba = vec![0u8; width * height * 4];
+        reader.next_frame(&mut rgba)?;
+        let pixels = rgba.chunks_exact(4)
+            .map(|p| 0xFF000000 | (p[0] as u32) << 16 | (p[1] as u32) << 8 | p[2] as u32)
+            .collect();
+        Ok(Canvas { width, height, pixels })
+    }
+
+    /// Write the canvas as an RGBA PNG file
+    pub fn save_png(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("Cannot create {}", path.display()))?;
+        self.write_png(BufWriter::new(file))
+    }
+
+    /// Encode the canvas as an RGBA PNG in memory
+    pub fn encode_png(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_png(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn write_png<W: Write>(&self, out: W) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_rgba())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_rect_clips_to_canvas() {
+        let mut c = Canvas::new(4, 3, 0xFF000000);
+        c.fill_rect(-2, 1, 10, 5, 0xFFFF0000);
+        assert_eq!(c.pixels[0], 0xFF000000);
+        assert!(c.pixels[4..].iter().all(|&p| p == 0xFFFF0000));
+    }
+
+    #[test]
+    fn rgba_conversion() {
+        let c = Canvas::new(1, 1, 0xFF102030);
+        assert_eq!(c.to_rgba(), vec![0x10, 0x20, 0x30, 0xFF]);
+    }
+
+    #[test]
+    fn scaled_down_averages() {
+        let mut c = Canvas::new(4, 2, 0xFF000000);
+        c.fill_rect(0, 0, 1, 2, 0xFFFFFFFF);
+        let small = c.scaled_down(2, 1);
+        assert_eq!(small.pixels, vec![0xFF7F7F7F, 0xFF000000]);
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let mut c = Canvas::new(3, 2, 0xFF102030);
+        c.fill_rect(1, 1, 1, 1, 0xFFFFEEDD);
+        let path = std::env::temp_dir().join(format!("openwilly_canvas_{}.png", std::process::id()));
+        c.save_png(&path).unwrap();
+        let loaded = Canvas::load_png(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!((loaded.width, loaded.height), (3, 2));
+        assert_eq!(loaded.pixels, c.pixels);
+    }
+}
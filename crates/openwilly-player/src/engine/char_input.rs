@@ -0,0 +1,56 @@
+//! Typed characters as delivered by the OS
+//!
+//! Text entry and the '#' dev-menu trigger used to map minifb key codes to
+//! characters with a German layout in mind (`#` on the US backslash key),
+//! which broke on US, French or Swedish keyboards. The window now reports
+//! the characters the OS produced for the active layout (dead keys and
+//! AltGr included) through minifb's input callback; the engine loop drains
+//! them once per frame.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Queue shared between the window callback and the engine loop
+#[derive(Clone, Default)]
+pub struct CharInput {
+    queue: Rc<RefCell<Vec<char>>>,
+}
+
+impl CharInput {
+    /// Characters typed since the last call
+    pub fn take(&self) -> Vec<char> {
+        std::mem::take(&mut *self.queue.borrow_mut())
+    }
+}
+
+/// Printable characters only: control keys (Enter, Backspace, …) arrive as
+/// key events, macOS reports function/arrow keys in the private use area
+fn typed(uni_char: u32) -> Option<char> {
+    char::from_u32(uni_char).filter(|c| !c.is_control() && !('\u{E000}'..='\u{F8FF}').contains(c))
+}
+
+impl minifb::InputCallback for CharInput {
+    fn add_char(&mut self, uni_char: u32) {
+        if let Some(ch) = typed(uni_char) {
+            self.queue.borrow_mut().push(ch);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minifb::InputCallback;
+
+    #[test]
+    fn keeps_printable_characters() {
+        let input = CharInput::default();
+        let mut callback = input.clone();
+        for c in ['#', 'å', 'é', '\r', '\x08', '\u{F704}', 'x'] {
+            callback.add_char(c as u32);
+        }
+        callback.add_char(0xD800); // lone surrogate
+        assert_eq!(input.take(), vec!['#', 'å', 'é', 'x']);
+        assert!(input.take().is_empty());
+    }
+}
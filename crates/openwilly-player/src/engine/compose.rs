@@ -0,0 +1,156 @@
+//! Sprite composition — blit the frame's sprites in parallel bands
+//!
+//! Busy scenes (junkyard piles, the yard with a finished car, driving with
+//! traffic) have a few hundred sprites with alpha, which on weak CPUs takes
+//! a good part of the frame. The framebuffer is split into horizontal bands
+//! of `BAND_ROWS` rows that rayon composites on all cores. Every band blits
+//! the sprites touching it in the usual z-order, so blending stays exactly
+//! as in the sequential path (bands never share a pixel). Frames with only
+//! a handful of sprites stay on the calling thread, there the thread
+//! hand-off costs more than it saves. On a single core the benchmark below
+//! shows nothing but that cost, so those machines stay sequential too.
+//! What the bands gain on 2 or more cores has not been measured yet.
+//! Sprites the atlas has packed are drawn from their spans.
+
+use rayon::prelude::*;
+
+use super::atlas::SpriteAtlas;
+use super::{blit_sprite_rows, Sprite, SCREEN_WIDTH};
+
+/// Rows per band (480 / 32 = 15 bands)
+pub const BAND_ROWS: usize = 32;
+/// Below this many visible sprites the frame is composed sequentially
+const MIN_PARALLEL_SPRITES: usize = 16;
+
+/// Blit all visible sprites (already in z-order) onto the framebuffer
+pub fn compose(fb: &mut [u32], sprites: &[Sprite], atlas: &SpriteAtlas) {
+    let visible: Vec<&Sprite> = sprites
+        .iter()
+        .filter(|s| s.visible && s.width > 0 && s.height > 0)
+        .collect();
+    if visible.len() < MIN_PARALLEL_SPRITES || rayon::current_num_threads() < 2 {
+        compose_sequential(fb, &visible, atlas);
+    } else {
+        compose_bands(fb, &visible, atlas);
+    }
+}
+
+/// One sprite onto the rows `y0..y1`, from the atlas if packed there
+fn blit(fb: &mut [u32], y0: i32, y1: i32, sprite: &Sprite, atlas: &SpriteAtlas) {
+    match atlas.lookup(sprite) {
+        Some(packed) => atlas.blit_rows(packed, fb, SCREEN_WIDTH, (y0, y1), (sprite.x, sprite.y)),
+        None => blit_sprite_rows(fb, SCREEN_WIDTH, y0, y1, sprite),
+    }
+}
+
+fn compose_sequential(fb: &mut [u32], sprites: &[&Sprite], atlas: &SpriteAtlas) {
+    let rows = (fb.len() / SCREEN_WIDTH) as i32;
+    for sprite in sprites {
+        blit(fb, 0, rows, sprite, atlas);
+    }
+}
+
+fn compose_bands(fb: &mut [u32], sprites: &[&Sprite], atlas: &SpriteAtlas) {
+    fb.par_chunks_mut(SCREEN_WIDTH * BAND_ROWS)
+        .enumerate()
+        .for_each(|(i, band)| {
+            let y0 = (i * BAND_ROWS) as i32;
+            let y1 = y0 + (band.len() / SCREEN_WIDTH) as i32;
+            for sprite in sprites {
+                if sprite.y < y1 && sprite.y + sprite.height as i32 > y0 {
+                    blit(band, y0, y1, sprite, atlas);
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::SCREEN_HEIGHT;
+
+    /// Overlapping half-transparent sprites, some across band borders
+    /// and partly off-screen
+    fn test_sprites(count: usize) -> Vec<Sprite> {
+        (0..count)
+            .map(|i| {
+                let (w, h) = (40 + (i * 7) % 90, 20 + (i * 13) % 70);
+                let pixels = (0..w * h)
+                    .flat_map(|p| [(i * 31) as u8, (p % 251) as u8, (i * 5 + p) as u8, ((i * 37 + p) % 256) as u8])
+                    .collect::<Vec<u8>>()
+                    .into();
+                Sprite {
+                    x: (i as i32 * 53) % 680 - 30,
+                    y: (i as i32 * 29) % 520 - 20,
+                    width: w as u32,
+                    height: h as u32,
+                    pixels,
+                    visible: i % 11 != 3,
+                    z_order: i as i32,
+                    name: format!("test{}", i),
+                    interactive: false,
+                    member_num: 0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bands_match_sequential() {
+        let sprites = test_sprites(200);
+        let visible: Vec<&Sprite> = sprites.iter().filter(|s| s.visible).collect();
+        let mut sequential = vec![0xFF000000u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        compose_sequential(&mut sequential, &visible, &SpriteAtlas::default());
+        // Bands directly: `compose` stays sequential on a single core
+        let mut parallel = vec![0xFF000000u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        compose_bands(&mut parallel, &visible, &SpriteAtlas::default());
+        assert!(sequential == parallel);
+
+        // Same picture with the small sprites packed in the atlas
+        let mut atlas = SpriteAtlas::default();
+        for _ in 0..3 {
+            atlas.prepare(&sprites);
+        }
+        assert!(!atlas.is_empty());
+        let mut packed = vec![0xFF000000u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        compose_bands(&mut packed, &visible, &atlas);
+        assert!(sequential == packed);
+    }
+
+    /// Timing on 2 and 8 worker threads:
+    /// `cargo test --release compose_benchmark -- --ignored --nocapture`
+    ///
+    /// Measured so far only on a single-core VM (Xeon), 400 sprites:
+    /// sequential 10.4 ms/frame, 2 threads 10.8 ms (1.0x), 8 threads
+    /// 11.6 ms (0.9x) — no cores to spread over, only the hand-off cost.
+    /// Not run on 2- or 8-core machines yet; until it is, whether the
+    /// parallel path pays off there is open.
+    #[test]
+    #[ignore]
+    fn compose_benchmark() {
+        let sprites = test_sprites(400);
+        let visible: Vec<&Sprite> = sprites.iter().filter(|s| s.visible).collect();
+        let mut fb = vec![0xFF000000u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        let atlas = SpriteAtlas::default();
+        const FRAMES: u32 = 200;
+
+        let start = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            compose_sequential(&mut fb, &visible, &atlas);
+        }
+        let base = start.elapsed() / FRAMES;
+        println!("sequential: {:?}/frame", base);
+
+        for threads in [2, 8] {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            let start = std::time::Instant::now();
+            pool.install(|| {
+                for _ in 0..FRAMES {
+                    compose_bands(&mut fb, &visible, &atlas);
+                }
+            });
+            let t = start.elapsed() / FRAMES;
+            println!("{} threads: {:?}/frame ({:.1}x)", threads, t, base.as_secs_f64() / t.as_secs_f64());
+        }
+    }
+}
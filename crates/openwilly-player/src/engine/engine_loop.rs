@@ -0,0 +1,236 @@
+//! Adaptive engine sound — pitch-shifted, crossfaded engine loop
+//!
+//! The original game switches between fixed engine samples (idle, speed 1-4)
+//! which makes acceleration audibly step. Instead, two base samples of the
+//! car's engine type (idle and a driving sample) are looped at the same
+//! time: both are resampled with a variable playback rate that follows the
+//! car's speed, and a load value crossfades from the idle layer to the
+//! driving layer while accelerating. Pitch and load are set from the game
+//! loop through `EngineControl` and smoothed per sample, so changes never
+//! click.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::assets::sound::DecodedSound;
+
+/// Output sample rate of the engine loop
+const OUTPUT_RATE: u32 = 22050;
+/// Loop seam crossfade (samples at the layer's own rate)
+const SEAM_SAMPLES: usize = 512;
+/// Fade-in after starting, so the loop doesn't pop in under the startup sound
+const FADE_IN_SAMPLES: u32 = OUTPUT_RATE / 2;
+/// Per-sample smoothing of pitch and load (≈ 50 ms time constant)
+const SMOOTHING: f32 = 1.0 / (OUTPUT_RATE as f32 * 0.05);
+/// Targets are re-read from the control every few samples
+const CONTROL_INTERVAL: u32 = 64;
+
+/// Pitch and load targets, shared between the game loop and the audio thread
+#[derive(Debug)]
+pub struct EngineControl {
+    pitch: AtomicU32,
+    load: AtomicU32,
+    stopped: AtomicBool,
+}
+
+impl EngineControl {
+    fn new() -> Self {
+        Self {
+            pitch: AtomicU32::new(1.0f32.to_bits()),
+            load: AtomicU32::new(0.0f32.to_bits()),
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    /// Playback rate (1.0 = original pitch) and load (0.0 idle – 1.0 full)
+    pub fn set(&self, pitch: f32, load: f32) {
+        self.pitch.store(pitch.clamp(0.5, 2.5).to_bits(), Ordering::Relaxed);
+        self.load.store(load.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// End the loop (the source finishes on its next sample)
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    fn targets(&self) -> (f32, f32) {
+        (
+            f32::from_bits(self.pitch.load(Ordering::Relaxed)),
+            f32::from_bits(self.load.load(Ordering::Relaxed)),
+        )
+    }
+}
+
+/// One looped base sample
+struct Layer {
+    samples: Vec<f32>,
+    /// Source rate relative to the output rate
+    rate: f64,
+    pos: f64,
+}
+
+impl Layer {
+    fn new(sound: &DecodedSound) -> Option<Self> {
+        let samples = seamless_loop(to_f32(sound));
+        if samples.len() < 2 || sound.sample_rate == 0 {
+            return None;
+        }
+        Some(Self { samples, rate: sound.sample_rate as f64 / OUTPUT_RATE as f64, pos: 0.0 })
+    }
+
+    /// Next sample at the given pitch (linear interpolation)
+    fn next(&mut self, pitch: f32) -> f32 {
+        let len = self.samples.len();
+        let i = self.pos as usize;
+        let frac = (self.pos - i as f64) as f32;
+        let a = self.samples[i % len];
+        let b = self.samples[(i + 1) % len];
+        self.pos = (self.pos + self.rate * pitch as f64) % len as f64;
+        a + (b - a) * frac
+    }
+}
+
+/// Endless engine loop, see module docs
+pub struct EngineLoop {
+    idle: Layer,
+    load_layer: Layer,
+    control: Arc<EngineControl>,
+    pitch: f32,
+    load: f32,
+    target: (f32, f32),
+    tick: u32,
+    fade_in: u32,
+}
+
+impl EngineLoop {
+    /// Loop from the idle and driving samples of an engine type.
+    /// Returns None if a sample is empty or not 8/16-bit PCM.
+    pub fn new(idle: &DecodedSound, driving: &DecodedSound) -> Option<(Self, Arc<EngineControl>)> {
+        let control = Arc::new(EngineControl::new());
+        let source = Self {
+            idle: Layer::new(idle)?,
+            load_layer: Layer::new(driving)?,
+            control: control.clone(),
+            pitch: 1.0,
+            load: 0.0,
+            target: (1.0, 0.0),
+            tick: 0,
+            fade_in: 0,
+        };
+        Some((source, control))
+    }
+}
+
+impl Iterator for EngineLoop {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.tick % CONTROL_INTERVAL == 0 {
+            if self.control.stopped.load(Ordering::Relaxed) {
+                return None;
+            }
+            self.target = self.control.targets();
+        }
+        self.tick = self.tick.wrapping_add(1);
+        self.pitch += (self.target.0 - self.pitch) * SMOOTHING;
+        self.load += (self.target.1 - self.load) * SMOOTHING;
+
+        let idle = self.idle.next(self.pitch);
+        let driving = self.load_layer.next(self.pitch);
+        // Equal-power crossfade keeps the loudness steady mid-fade
+        let angle = self.load * std::f32::consts::FRAC_PI_2;
+        let mut out = idle * angle.cos() + driving * angle.sin();
+        if self.fade_in < FADE_IN_SAMPLES {
+            out *= self.fade_in as f32 / FADE_IN_SAMPLES as f32;
+            self.fade_in += 1;
+        }
+        Some(out)
+    }
+}
+
+impl Source for EngineLoop {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        OUTPUT_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// PCM bytes → samples in -1.0..1.0 (8-bit is unsigned, 16-bit little-endian)
+fn to_f32(sound: &DecodedSound) -> Vec<f32> {
+    match sound.bits_per_sample {
+        8 => sound.pcm_data.iter().map(|&b| (b as f32 - 128.0) / 128.0).collect(),
+        16 => sound.pcm_data.chunks_exact(2)
+            .map(|c| i16::from_le_bytes([c[0], c[1]]) as f32 / 32768.0)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Blend the sample's tail into its head so the loop point has no click
+fn seamless_loop(mut samples: Vec<f32>) -> Vec<f32> {
+    let seam = SEAM_SAMPLES.min(samples.len() / 4);
+    if seam == 0 {
+        return samples;
+    }
+    let tail_start = samples.len() - seam;
+    for i in 0..seam {
+        let t = i as f32 / seam as f32;
+        samples[i] = samples[i] * t + samples[tail_start + i] * (1.0 - t);
+    }
+    samples.truncate(tail_start);
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 8-bit sound with a constant level
+    fn flat(level: u8, len: usize) -> DecodedSound {
+        DecodedSound { sample_rate: OUTPUT_RATE, channels: 1, bits_per_sample: 8, pcm_data: vec![level; len] }
+    }
+
+    #[test]
+    fn seam_is_continuous() {
+        let ramp: Vec<f32> = (0..4096).map(|i| i as f32).collect();
+        let looped = seamless_loop(ramp);
+        assert_eq!(looped.len(), 4096 - SEAM_SAMPLES);
+        // Wrapping from the last sample to the first continues the tail
+        assert_eq!(looped[0], (4096 - SEAM_SAMPLES) as f32);
+    }
+
+    #[test]
+    fn pitch_changes_playback_rate() {
+        let mut layer = Layer::new(&flat(128, 8192)).unwrap();
+        for _ in 0..100 {
+            layer.next(2.0);
+        }
+        assert!((layer.pos - 200.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn load_crossfades_layers() {
+        let (mut engine, control) = EngineLoop::new(&flat(128, 8192), &flat(192, 8192)).unwrap();
+        let settle = |engine: &mut EngineLoop| engine.by_ref().take(OUTPUT_RATE as usize).last().unwrap();
+        assert!(settle(&mut engine).abs() < 1e-3); // idle layer is silent
+        control.set(1.5, 1.0);
+        assert!((settle(&mut engine) - 0.5).abs() < 1e-2);
+        assert!((engine.pitch - 1.5).abs() < 1e-2);
+        control.stop();
+        assert!(engine.by_ref().take(CONTROL_INTERVAL as usize + 1).count() <= CONTROL_INTERVAL as usize);
+    }
+}
@@ -1,27 +1,33 @@
 //! Minimal 6×8 pixel font for UI text rendering
 //!
-//! Supports ASCII 32–126 (space through tilde).
+//! Supports ASCII 32–126 (space through tilde) plus German umlauts/ß and
+//! the Swedish å/Å.
 //! Each glyph is stored as 8 bytes, one per row, 6 bits wide (MSB-first).
 
-use super::SCREEN_WIDTH;
+use super::{SCREEN_WIDTH, SCREEN_HEIGHT};
 
 /// Draw a string onto a u32 ARGB framebuffer
 pub fn draw_text(fb: &mut [u32], x: i32, y: i32, text: &str, color: u32) {
+    draw_text_sized(fb, SCREEN_WIDTH, SCREEN_HEIGHT, x, y, text, color);
+}
+
+/// Draw a string onto a framebuffer of arbitrary size (off-screen canvases)
+pub fn draw_text_sized(fb: &mut [u32], fb_w: usize, fb_h: usize, x: i32, y: i32, text: &str, color: u32) {
     let mut cx = x;
     for ch in text.chars() {
-        draw_char(fb, cx, y, ch, color);
+        draw_char(fb, (fb_w, fb_h), cx, y, ch, color);
         cx += 6;
     }
 }
 
-/// Draw a single character onto the framebuffer
-fn draw_char(fb: &mut [u32], x: i32, y: i32, ch: char, color: u32) {
+/// Glyph bitmap of a character, None if the font can't draw it
+fn glyph(ch: char) -> Option<&'static [u8]> {
     let glyph: &[u8] = match ch {
         ' '..='~' => {
             let idx = ch as usize - 32;
             &FONT_DATA[idx * 8..idx * 8 + 8]
         }
-        // German special characters → extra glyphs appended after ASCII
+        // German / Swedish special characters → extra glyphs appended after ASCII
         '\u{00E4}' => &FONT_EXTRA[0..8],   // ä
         '\u{00F6}' => &FONT_EXTRA[8..16],  // ö
         '\u{00FC}' => &FONT_EXTRA[16..24], // ü
@@ -29,22 +35,35 @@ fn draw_char(fb: &mut [u32], x: i32, y: i32, ch: char, color: u32) {
         '\u{00D6}' => &FONT_EXTRA[32..40], // Ö
         '\u{00DC}' => &FONT_EXTRA[40..48], // Ü
         '\u{00DF}' => &FONT_EXTRA[48..56], // ß
-        _ => &FONT_DATA[63 * 8..63 * 8 + 8], // '?' for unknown
+        '\u{00E5}' => &FONT_EXTRA[56..64], // å
+        '\u{00C5}' => &FONT_EXTRA[64..72], // Å
+        _ => return None,
     };
+    Some(glyph)
+}
+
+/// Whether the font has a glyph for `ch`
+pub fn has_glyph(ch: char) -> bool {
+    glyph(ch).is_some()
+}
+
+/// Draw a single character onto the framebuffer
+fn draw_char(fb: &mut [u32], (fb_w, fb_h): (usize, usize), x: i32, y: i32, ch: char, color: u32) {
+    let glyph = glyph(ch).unwrap_or(&FONT_DATA[63 * 8..63 * 8 + 8]); // '?' for unknown
 
     for row in 0..8 {
         let dy = y + row as i32;
-        if dy < 0 || dy >= 480 {
+        if dy < 0 || dy >= fb_h as i32 {
             continue;
         }
         let bits = glyph[row];
         for col in 0..6 {
             let dx = x + col as i32;
-            if dx < 0 || dx >= SCREEN_WIDTH as i32 {
+            if dx < 0 || dx >= fb_w as i32 {
                 continue;
             }
             if (bits >> (7 - col)) & 1 != 0 {
-                let offset = dy as usize * SCREEN_WIDTH + dx as usize;
+                let offset = dy as usize * fb_w + dx as usize;
                 if offset < fb.len() {
                     fb[offset] = color;
                 }
@@ -342,4 +361,8 @@ static FONT_EXTRA: &[u8] = &[
     0x50, 0x00, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00,
     // ß (sharp s / Eszett)
     0x70, 0x88, 0x90, 0xA0, 0x90, 0x88, 0xB0, 0x00,
+    // å (a with ring)
+    0x20, 0x50, 0x70, 0x08, 0x78, 0x88, 0x78, 0x00,
+    // Å (A with ring)
+    0x20, 0x50, 0x70, 0x88, 0xF8, 0x88, 0x88, 0x00,
 ];
@@ -0,0 +1,325 @@
+//! GPU presentation — scaling and detail noise in a fragment shader
+//!
+//! At 4K fullscreen `scale_to_viewport` touches ~8 million output pixels
+//! per frame, about a third of the CPU time. With the `gpu` feature the
+//! 640×480 framebuffer is uploaded as a texture instead and scaled into the
+//! viewport on the GPU (wgpu, surface on the minifb window). The shader
+//! does the same nearest-neighbour mapping as the CPU path, the detail noise
+//! uses a per-pixel hash instead of the `NoiseMap` (same strength, same
+//! rules: only duplicated pixels, never UI pixels). The UI mask travels in
+//! the alpha channel. If no adapter/surface is available or presenting
+//! fails, the engine falls back to the CPU path; `OPENWILLY_NO_GPU=1`
+//! forces the fallback.
+
+use anyhow::{anyhow, Context, Result};
+use minifb::Window;
+
+use super::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+const SHADER: &str = r#"
+struct Params {
+    // vx, vy, vw, vh
+    viewport: vec4<u32>,
+    // noise on, sRGB surface, -, -
+    flags: vec4<u32>,
+}
+
+@group(0) @binding(0) var frame: texture_2d<f32>;
+@group(0) @binding(1) var<uniform> params: Params;
+
+@vertex
+fn vs_main(@builtin(vertex_index) i: u32) -> @builtin(position) vec4<f32> {
+    // Fullscreen triangle
+    let x = f32(i32(i & 1u) * 4 - 1);
+    let y = f32(i32(i >> 1u) * 4 - 1);
+    return vec4<f32>(x, y, 0.0, 1.0);
+}
+
+fn hash(x: u32, y: u32) -> u32 {
+    var h = x * 374761393u + y * 668265263u;
+    h = (h ^ (h >> 13u)) * 1274126177u;
+    return h ^ (h >> 16u);
+}
+
+@fragment
+fn fs_main(@builtin(position) pos: vec4<f32>) -> @location(0) vec4<f32> {
+    let v = params.viewport;
+    let px = u32(pos.x);
+    let py = u32(pos.y);
+    if px < v.x || py < v.y || px >= v.x + v.z || py >= v.y + v.w {
+        return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    }
+    let dx = px - v.x;
+    let dy = py - v.y;
+    let sx = dx * 640u / v.z;
+    let sy = dy * 480u / v.w;
+    let texel = textureLoad(frame, vec2<u32>(sx, sy), 0);
+    var rgb = texel.rgb;
+
+    // Detail noise: duplicated neighbour pixels, not on UI (alpha 0)
+    let duplicate = dx > 0u && (dx - 1u) * 640u / v.z == sx;
+    if params.flags.x != 0u && duplicate && texel.a > 0.5 {
+        let noise = f32(hash(dx, dy) % 27u) - 13.0;
+        rgb = clamp(rgb + vec3<f32>(noise / 255.0), vec3<f32>(0.0), vec3<f32>(1.0));
+    }
+
+    // The framebuffer holds sRGB values; an sRGB surface would encode again
+    if params.flags.y != 0u {
+        rgb = pow(rgb, vec3<f32>(2.2));
+    }
+    return vec4<f32>(rgb, 1.0);
+}
+"#;
+
+pub struct GpuPresenter {
+    surface: wgpu::Surface<'static>,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    config: wgpu::SurfaceConfiguration,
+    pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    texture: wgpu::Texture,
+    params: wgpu::Buffer,
+    srgb: bool,
+    /// Upload staging: framebuffer with the UI mask in the alpha byte
+    upload: Vec<u8>,
+}
+
+impl GpuPresenter {
+    /// Set up the GPU path for `window`. The presenter must be dropped
+    /// before the window (the surface keeps its raw handle).
+    pub fn new(window: &Window) -> Result<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        // SAFETY: the engine drops the presenter before the window it was
+        // created for (declared after it in the same scope)
+        let surface = unsafe {
+            let target = wgpu::SurfaceTargetUnsafe::from_window(window)
+                .map_err(|e| anyhow!("Window handle: {}", e))?;
+            instance.create_surface_unsafe(target)?
+        };
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: Some(&surface),
+            force_fallback_adapter: false,
+        }))
+        .context("No GPU adapter")?;
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("openwilly"),
+                required_limits: wgpu::Limits::downlevel_webgl2_defaults()
+                    .using_resolution(adapter.limits()),
+                ..Default::default()
+            },
+            None,
+        ))?;
+
+        let (w, h) = window.get_size();
+        let caps = surface.get_capabilities(&adapter);
+        let format = caps.formats.iter().copied()
+            .find(|f| !f.is_srgb())
+            .or_else(|| caps.formats.first().copied())
+            .context("Surface has no formats")?;
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: w.max(1) as u32,
+            height: h.max(1) as u32,
+            present_mode: wgpu::PresentMode::AutoVsync,
+            desired_maximum_frame_latency: 2,
+            alpha_mode: caps.alpha_modes[0],
+            view_formats: Vec::new(),
+        };
+        surface.configure(&device, &config);
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("framebuffer"),
+            size: frame_extent(),
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            // ARGB u32 in little endian = B, G, R, A bytes
+            format: wgpu::TextureFormat::Bgra8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let params = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("params"),
+            size: 32,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: params.as_entire_binding() },
+            ],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("present"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("present"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(format.into())],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        tracing::info!("GPU presentation: {} ({:?})", adapter.get_info().name, format);
+        Ok(Self {
+            surface,
+            device,
+            queue,
+            config,
+            pipeline,
+            bind_group,
+            texture,
+            params,
+            srgb: format.is_srgb(),
+            upload: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT * 4],
+        })
+    }
+
+    /// Scale `fb` (640×480) into the viewport of an `out_w`×`out_h` surface
+    pub fn present(
+        &mut self,
+        fb: &[u32],
+        ui_mask: &[bool],
+        out_w: usize,
+        out_h: usize,
+        viewport: (usize, usize, usize, usize),
+        noise: bool,
+    ) -> Result<()> {
+        if self.config.width != out_w as u32 || self.config.height != out_h as u32 {
+            self.config.width = out_w.max(1) as u32;
+            self.config.height = out_h.max(1) as u32;
+            self.surface.configure(&self.device, &self.config);
+        }
+
+        for ((dst, &px), &is_ui) in self.upload.chunks_exact_mut(4).zip(fb).zip(ui_mask) {
+            let alpha = if is_ui { 0 } else { 0xFF };
+            dst.copy_from_slice(&((px & 0x00FFFFFF) | (alpha << 24)).to_le_bytes());
+        }
+        self.queue.write_texture(
+            self.texture.as_image_copy(),
+            &self.upload,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(SCREEN_WIDTH as u32 * 4),
+                rows_per_image: Some(SCREEN_HEIGHT as u32),
+            },
+            frame_extent(),
+        );
+
+        let (vx, vy, vw, vh) = viewport;
+        let params = [vx, vy, vw, vh, noise as usize, self.srgb as usize, 0, 0];
+        let bytes: Vec<u8> = params.iter().flat_map(|&v| (v as u32).to_le_bytes()).collect();
+        self.queue.write_buffer(&self.params, 0, &bytes);
+
+        let frame = match self.surface.get_current_texture() {
+            Ok(frame) => frame,
+            Err(wgpu::SurfaceError::Outdated | wgpu::SurfaceError::Lost) => {
+                // Window changed under us — reconfigure, skip this frame
+                self.surface.configure(&self.device, &self.config);
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("present"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &self.bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+        self.queue.submit(Some(encoder.finish()));
+        frame.present();
+        Ok(())
+    }
+}
+
+fn frame_extent() -> wgpu::Extent3d {
+    wgpu::Extent3d {
+        width: SCREEN_WIDTH as u32,
+        height: SCREEN_HEIGHT as u32,
+        depth_or_array_layers: 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wgpu::naga;
+
+    #[test]
+    fn shader_validates() {
+        let module = naga::front::wgsl::parse_str(SHADER).unwrap();
+        naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+            .validate(&module)
+            .unwrap();
+    }
+}
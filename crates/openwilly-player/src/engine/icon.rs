@@ -1,48 +1,47 @@
-//! Extract the Willy Werkel icon from WILLY32.EXE at runtime
+//! Extract the game icon from the game's executable at runtime
 //!
 //! Parses the PE resource section to find RT_GROUP_ICON / RT_ICON entries,
 //! builds a standard .ico file, writes it to a temp location, and sets
-//! the minifb window icon.
+//! the minifb window icon. Other installs (and the other games of the
+//! series) name their .ICO / .EXE differently, so the game directory is
+//! scanned case-insensitively instead of trying fixed names.
 
 use std::path::{Path, PathBuf};
 
+/// Executables that carry an installer / helper icon, not the game's
+const SKIPPED_EXES: [&str; 5] = ["SETUP", "INSTALL", "UNINST", "UNINSTALL", "AUTORUN"];
+
 /// Try to set the window icon from game data.
 ///
-/// Search order:
-/// 1. `MULLE.ICO` in game dir (or `Data/MULLE.ICO`)
-/// 2. Extract from `WILLY32.EXE` PE resources
+/// Search order (game dir, then `Data/`):
+/// 1. `.ICO` files, `MULLE.ICO` first
+/// 2. Icons extracted from `.EXE` PE resources, `WILLY32.EXE` first
 ///
 /// Returns the path to the icon file (for logging), or None if not found.
 pub fn set_window_icon(window: &mut minifb::Window, game_dir: &Path) -> Option<PathBuf> {
+    let sources = icon_sources(game_dir);
+
     // 1. Try existing .ico file
-    for candidate in &["MULLE.ICO", "Data/MULLE.ICO", "mulle.ico", "data/mulle.ico"] {
-        let ico_path = game_dir.join(candidate);
-        if ico_path.is_file() {
-            if try_set_icon(window, &ico_path) {
-                return Some(ico_path);
-            }
+    for ico_path in sources.iter().filter(|p| has_ext(p, "ico")) {
+        if try_set_icon(window, ico_path) {
+            return Some(ico_path.clone());
         }
     }
 
-    // 2. Extract from WILLY32.EXE
-    for exe_name in &["WILLY32.EXE", "willy32.exe", "Willy32.exe"] {
-        let exe_path = game_dir.join(exe_name);
-        if exe_path.is_file() {
-            match extract_icon_from_pe(&exe_path) {
-                Ok(ico_data) => {
-                    // Write to temp file
-                    let tmp = std::env::temp_dir().join("openwilly_icon.ico");
-                    if std::fs::write(&tmp, &ico_data).is_ok() {
-                        if try_set_icon(window, &tmp) {
-                            tracing::info!("Icon extracted from {}", exe_path.display());
-                            return Some(tmp);
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::debug!("Failed to extract icon from {}: {}", exe_path.display(), e);
+    // 2. Extract from the game executable
+    for exe_path in sources.iter().filter(|p| has_ext(p, "exe")) {
+        match extract_icon_from_pe(exe_path) {
+            Ok(ico_data) => {
+                // Write to temp file
+                let tmp = std::env::temp_dir().join("openwilly_icon.ico");
+                if std::fs::write(&tmp, &ico_data).is_ok() && try_set_icon(window, &tmp) {
+                    tracing::info!("Icon extracted from {}", exe_path.display());
+                    return Some(tmp);
                 }
             }
+            Err(e) => {
+                tracing::debug!("Failed to extract icon from {}: {}", exe_path.display(), e);
+            }
         }
     }
 
@@ -50,6 +49,42 @@ pub fn set_window_icon(window: &mut minifb::Window, game_dir: &Path) -> Option<P
     None
 }
 
+fn has_ext(path: &Path, ext: &str) -> bool {
+    path.extension().is_some_and(|e| e.eq_ignore_ascii_case(ext))
+}
+
+/// Search rank of a file name, lower first — None if it can't hold the
+/// game icon
+fn icon_rank(file_name: &str) -> Option<u8> {
+    let upper = file_name.to_ascii_uppercase();
+    let (stem, ext) = upper.rsplit_once('.')?;
+    match ext {
+        "ICO" if stem == "MULLE" => Some(0),
+        "ICO" => Some(1),
+        "EXE" if stem == "WILLY32" => Some(2),
+        "EXE" if SKIPPED_EXES.contains(&stem) => None,
+        "EXE" => Some(3),
+        _ => None,
+    }
+}
+
+/// Icon and executable files in the game dir and `Data/`, best first
+fn icon_sources(game_dir: &Path) -> Vec<PathBuf> {
+    let mut found: Vec<(u8, usize, PathBuf)> = Vec::new();
+    for (depth, dir) in [game_dir.to_path_buf(), game_dir.join("Data")].into_iter().enumerate() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let rank = path.file_name().and_then(|n| n.to_str()).and_then(icon_rank);
+            if let Some(rank) = rank.filter(|_| path.is_file()) {
+                found.push((rank, depth, path));
+            }
+        }
+    }
+    found.sort();
+    found.into_iter().map(|(_, _, path)| path).collect()
+}
+
 /// Set window icon from an .ico file path.
 /// Uses minifb's set_icon API — we keep the wide string alive for the call.
 fn try_set_icon(window: &mut minifb::Window, ico_path: &Path) -> bool {
@@ -367,6 +402,16 @@ mod tests {
         assert_eq!(read_u32(&data, 0), 0x04030201);
     }
 
+    #[test]
+    fn test_icon_rank() {
+        assert_eq!(icon_rank("mulle.ico"), Some(0));
+        assert_eq!(icon_rank("Willy32.exe"), Some(2));
+        assert!(icon_rank("BOATS.ICO") < icon_rank("BOATS32.EXE"));
+        assert_eq!(icon_rank("Setup.exe"), None);
+        assert_eq!(icon_rank("README.TXT"), None);
+        assert_eq!(icon_rank("EXE"), None);
+    }
+
     #[test]
     fn test_extract_from_willy32() {
         // Only run if WILLY32.EXE is available
@@ -2,15 +2,35 @@
 //!
 //! Uses a 640×480 pixel framebuffer with 32-bit ARGB pixels.
 
+pub mod atlas;
+pub mod canvas;
+pub mod char_input;
+pub mod compose;
+pub mod engine_loop;
 pub mod font;
+#[cfg(feature = "gpu")]
+pub mod gpu;
 pub mod icon;
+pub mod rumble;
+pub mod sound_audit;
+pub mod sound_cues;
 pub mod sound_engine;
+pub mod sprite_cache;
+pub mod ui_sound;
+pub mod zoom;
+
+use std::sync::Arc;
 
 use anyhow::Result;
 use minifb::{Key, MouseButton, MouseMode, Window, WindowOptions};
 
 use crate::assets::AssetStore;
+use crate::game::autolaunch::LaunchArgs;
+use crate::game::debug_http::DebugServer;
+use crate::game::demo::{self, Demo, FrameInput, KeyEvent, Playback};
+use crate::game::options::OptionItem;
 use crate::game::GameState;
+use ui_sound::UiSound;
 
 pub const SCREEN_WIDTH: usize = 640;
 pub const SCREEN_HEIGHT: usize = 480;
@@ -21,12 +41,17 @@ const FPS: u64 = 30;
 enum EngineState {
     Playing,
     EscapeMenu { selected: usize },
+    /// Options screen; `capture` while waiting for the new dev-menu key
+    Options { selected: usize, capture: bool },
 }
 
-const ESCAPE_MENU_COUNT: usize = 5; // resume, fullscreen, display mode, detail noise, quit
+const ESCAPE_MENU_COUNT: usize = 4; // resume, options, fullscreen, quit
+const ESCAPE_MENU_OPTIONS: usize = 1;
+const MENU_W: i32 = 300;
+const MENU_ROW_H: i32 = 26;
 
 /// Display scaling mode
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum DisplayMode {
     /// Fill entire window, may distort aspect ratio on non-4:3 displays
     Stretch,
@@ -46,7 +71,7 @@ impl DisplayMode {
         }
     }
 
-    /// Short label for the current mode (used in escape menu)
+    /// Short label for the current mode (used in the options screen)
     pub fn label(self) -> &'static str {
         match self {
             DisplayMode::Stretch => "Stretch",
@@ -56,14 +81,20 @@ impl DisplayMode {
     }
 }
 
-/// Sprite rendered by the engine
+/// RGBA pixels (4 bytes per pixel) shared between a decoded bitmap and
+/// the sprites showing it. Owned buffers convert with `.into()` (no copy).
+pub type SpritePixels = Arc<Vec<u8>>;
+
+/// Sprite rendered by the engine: a shared bitmap placed on screen.
+/// Cloning only bumps the bitmap's reference count, so scenes can hand out
+/// their sprites every frame without copying pixels.
 #[derive(Clone, Debug)]
 pub struct Sprite {
     pub x: i32,
     pub y: i32,
     pub width: u32,
     pub height: u32,
-    pub pixels: Vec<u8>, // RGBA, 4 bytes per pixel
+    pub pixels: SpritePixels,
     pub visible: bool,
     pub z_order: i32,
     /// Name for debugging / hit detection logging
@@ -71,7 +102,6 @@ pub struct Sprite {
     /// If true, this sprite responds to clicks
     pub interactive: bool,
     /// Member number (for identification)
-    #[allow(dead_code)]
     pub member_num: u32,
 }
 
@@ -245,14 +275,30 @@ fn scale_to_viewport(
     }
 }
 
-/// Draw semi-transparent escape/pause menu overlay onto the 640x480 framebuffer
-fn draw_escape_menu(
-    fb: &mut [u32],
-    selected: usize,
-    detail_noise: bool,
-    display_mode: DisplayMode,
-    lang: crate::game::i18n::Language,
-) {
+/// Height of a pause-style menu box with `rows` entries
+fn menu_height(rows: usize) -> i32 {
+    80 + rows as i32 * MENU_ROW_H
+}
+
+/// Top-left corner of a centered menu box with `rows` entries
+fn menu_origin(rows: usize) -> (i32, i32) {
+    ((SCREEN_WIDTH as i32 - MENU_W) / 2, (SCREEN_HEIGHT as i32 - menu_height(rows)) / 2)
+}
+
+/// Menu row under the mouse, if any
+fn menu_row_at(mx: i32, my: i32, rows: usize) -> Option<usize> {
+    let (box_x, box_y) = menu_origin(rows);
+    if mx < box_x + 6 || mx >= box_x + MENU_W - 6 {
+        return None;
+    }
+    let rel_y = my - (box_y + 44);
+    let idx = (rel_y / MENU_ROW_H) as usize;
+    (rel_y >= 0 && idx < rows).then_some(idx)
+}
+
+/// Draw a semi-transparent pause-style menu overlay onto the 640x480
+/// framebuffer: one row per (label, value) pair, value shown as " [..]"
+fn draw_menu(fb: &mut [u32], title: &str, rows: &[(&str, String)], selected: usize, hint: &str) {
     // Darken the entire framebuffer
     for pixel in fb.iter_mut() {
         let r = (*pixel >> 16) & 0xFF;
@@ -261,61 +307,110 @@ fn draw_escape_menu(
         *pixel = 0xFF000000 | ((r / 3) << 16) | ((g / 3) << 8) | (b / 3);
     }
 
-    let box_w: i32 = 300;
-    let box_h: i32 = 210;
-    let box_x = (SCREEN_WIDTH as i32 - box_w) / 2;
-    let box_y = (SCREEN_HEIGHT as i32 - box_h) / 2;
+    let box_w = MENU_W;
+    let box_h = menu_height(rows.len());
+    let (box_x, box_y) = menu_origin(rows.len());
 
     font::draw_rect(fb, box_x, box_y, box_w, box_h, 0xFF1a1a2e);
     font::draw_rect_outline(fb, box_x, box_y, box_w, box_h, 0xFF6666CC);
     font::draw_rect_outline(fb, box_x + 2, box_y + 2, box_w - 4, box_h - 4, 0xFF444488);
 
-    use crate::game::i18n::t;
-    let title = t(lang, "pause_title");
     font::draw_text_shadow(fb,
         box_x + (box_w - font::text_width(title)) / 2,
         box_y + 14, title, 0xFFFFFF00);
 
-    let item_keys = [
-        "menu_resume",
-        "menu_fullscreen",
-        "menu_display_mode",
-        "menu_detail_noise",
-        "menu_quit",
-    ];
-    for (i, key) in item_keys.iter().enumerate() {
-        let label = t(lang, key);
-        let iy = box_y + 46 + i as i32 * 26;
+    for (i, (label, value)) in rows.iter().enumerate() {
+        let iy = box_y + 46 + i as i32 * MENU_ROW_H;
         let color = if i == selected { 0xFFFFFF00 } else { 0xFFBBBBBB };
         if i == selected {
             font::draw_rect(fb, box_x + 6, iy - 2, box_w - 12, 20, 0xFF333366);
         }
         let prefix = if i == selected { "> " } else { "  " };
-        let mode_label = format!(" [{}]", display_mode.label());
-        let suffix: &str = match i {
-            2 => &mode_label,
-            3 => if detail_noise { " [ON]" } else { " [OFF]" },
-            _ => "",
+        let text = if value.is_empty() {
+            format!("{}{}", prefix, label)
+        } else {
+            format!("{}{} [{}]", prefix, label, value)
         };
-        let text = format!("{}{}{}", prefix, label, suffix);
         font::draw_text_shadow(fb, box_x + 20, iy + 2, &text, color);
     }
 
-    font::draw_text(fb, box_x + 14, box_y + box_h - 22,
-        t(lang, "pause_hint"), 0xFF777799);
+    font::draw_text(fb, box_x + 14, box_y + box_h - 22, hint, 0xFF777799);
+}
+
+/// Escape/pause menu: resume, options, fullscreen, quit
+fn draw_escape_menu(fb: &mut [u32], selected: usize, game: &GameState) {
+    use crate::game::i18n::t;
+    let lang = game.language;
+    let rows: Vec<(&str, String)> = ["menu_resume", "menu_options", "menu_fullscreen", "menu_quit"]
+        .iter()
+        .map(|key| (t(lang, key), String::new()))
+        .collect();
+    draw_menu(fb, t(lang, "pause_title"), &rows, selected, t(lang, "pause_hint"));
+}
+
+/// Options screen (settings saved to openwilly_options.json)
+fn draw_options_menu(fb: &mut [u32], selected: usize, capture: bool, game: &GameState) {
+    use crate::game::i18n::t;
+    let lang = game.language;
+    let rows: Vec<(&str, String)> = OptionItem::ALL
+        .iter()
+        .map(|&item| {
+            let value = if capture && item == OptionItem::DevKey {
+                t(lang, "opt_press_key").to_string()
+            } else {
+                game.option_value(item)
+            };
+            (t(lang, item.i18n_key()), value)
+        })
+        .collect();
+    draw_menu(fb, t(lang, "options_title"), &rows, selected, t(lang, "options_hint"));
 }
 
 /// Run the game engine
-pub fn run(assets: AssetStore) -> Result<()> {
+pub fn run(assets: AssetStore, launch: &LaunchArgs) -> Result<()> {
     let mut game = GameState::new(assets);
-    let mut fullscreen = false;
+    let mut fullscreen = game.options.fullscreen;
     let mut engine_state = EngineState::Playing;
     let mut prev_mouse_down = false;
     let mut prev_right_down = false;
     let mut frame_count: u64 = 0;
+    let mut zoom = zoom::ZoomLens::default();
+
+    // Demo recording / playback (see game::demo)
+    let record_path = std::env::var_os("OPENWILLY_RECORD").map(std::path::PathBuf::from);
+    let mut recording = record_path.as_ref().map(|_| Demo::new(game.rng_seed, game.save_manager.users_db.clone()));
+    let mut playback = match std::env::var_os("OPENWILLY_PLAYBACK") {
+        Some(path) => match Demo::load(std::path::Path::new(&path)) {
+            Ok(demo) => {
+                tracing::info!("Demo playback: {} ({} frames)", path.to_string_lossy(), demo.frames.len());
+                game.start_playback(&demo);
+                Some(Playback::new(demo))
+            }
+            Err(e) => {
+                tracing::warn!("Demo playback disabled: {:#}", e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    // Command-line start in a scene (demos always start on the menu)
+    if launch.is_autolaunch() {
+        if playback.is_some() {
+            tracing::warn!("Autolaunch ignored during demo playback");
+        } else {
+            game.autolaunch(launch);
+        }
+    }
+
+    // Local JSON endpoint for external tools (OPENWILLY_DEBUG_HTTP)
+    let debug_http = DebugServer::from_env();
+
+    // Typed characters from the OS keyboard layout (kept across window re-creation)
+    let char_input = char_input::CharInput::default();
 
     tracing::info!("Engine initialized, entering game loop");
-    tracing::info!("Controls: F1-F9=Szene | Esc=Menü | F11=Vollbild");
+    tracing::info!("Controls: F1-F9=Szene | Esc=Menü | F11=Vollbild | Strg halten=Lupe");
 
     // Outer loop: window (re)creation on fullscreen toggle
     loop {
@@ -346,16 +441,22 @@ pub fn run(assets: AssetStore) -> Result<()> {
             ..Default::default()
         };
 
-        let mut window = Window::new("OpenWilly – Willy Werkel", win_w, win_h, options)
+        let game_title = crate::game::i18n::t(game.language, "window_title");
+        let mut window = Window::new(&format!("OpenWilly – {}", game_title), win_w, win_h, options)
             .map_err(|e| anyhow::anyhow!("Window creation failed: {}", e))?;
         window.set_target_fps(FPS as usize);
         window.set_cursor_visibility(false); // Software cursor rendered on framebuffer
+        window.set_input_callback(Box::new(char_input.clone()));
 
-        // Set window icon from game data (WILLY32.EXE icon or MULLE.ICO)
+        // Set window icon from game data (.ICO file or the game's .EXE)
         icon::set_window_icon(&mut window, &game.assets.game_dir);
 
         // Internal framebuffer at native resolution
         let mut framebuffer = vec![0u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        // Small often drawn bitmaps as precomputed spans
+        let mut atlas = atlas::SpriteAtlas::default();
+        // Scratch buffer for the zoom lens
+        let mut zoom_buf = vec![0u32; SCREEN_WIDTH * SCREEN_HEIGHT];
 
         // Output buffer — sized to match window
         let mut out_w = win_w;
@@ -369,8 +470,22 @@ pub fn run(assets: AssetStore) -> Result<()> {
         let mut noise_map = NoiseMap::generate(init_vw, init_vh);
         let mut prev_scene = game.current_scene;
 
+        // GPU scaling if available (dropped before the window, see gpu.rs)
+        #[cfg(feature = "gpu")]
+        let mut gpu_presenter = if std::env::var_os("OPENWILLY_NO_GPU").is_some() {
+            None
+        } else {
+            gpu::GpuPresenter::new(&window)
+                .map_err(|e| tracing::warn!("GPU presentation unavailable, using CPU scaling: {:#}", e))
+                .ok()
+        };
+
         // Inner loop: game frames
         while window.is_open() {
+            if let Some(server) = &debug_http {
+                server.poll(&mut game);
+            }
+
             // Track window size changes (for resizable windowed mode)
             let (actual_w, actual_h) = window.get_size();
             if actual_w > 0 && actual_h > 0 && (actual_w != out_w || actual_h != out_h) {
@@ -402,6 +517,18 @@ pub fn run(assets: AssetStore) -> Result<()> {
             let mx = mx.clamp(0, SCREEN_WIDTH as i32 - 1);
             let my = my.clamp(0, SCREEN_HEIGHT as i32 - 1);
 
+            // Ctrl held in the building scenes → zoom lens. The game only
+            // sees logical coordinates; the cursor is drawn at the view position.
+            let zoom_held = window.is_key_down(Key::LeftCtrl) || window.is_key_down(Key::RightCtrl);
+            if zoom_held && matches!(engine_state, EngineState::Playing) && game.zoom_allowed() {
+                zoom.begin(mx, my);
+                zoom.scroll(mx, my);
+            } else {
+                zoom.end();
+            }
+            let (mut view_mx, mut view_my) = (mx, my);
+            let (mut mx, mut my) = zoom.to_logical(mx, my);
+
             // F11 → fullscreen toggle (in any state)
             if window.is_key_pressed(Key::F11, minifb::KeyRepeat::No) {
                 toggle_fs = true;
@@ -410,52 +537,63 @@ pub fn run(assets: AssetStore) -> Result<()> {
 
             // Input state
             let esc_pressed = window.is_key_pressed(Key::Escape, minifb::KeyRepeat::No);
+            // Drained every frame, so nothing typed in a menu leaks into the game later
+            let typed = char_input.take();
             let mouse_down = window.get_mouse_down(MouseButton::Left);
             let mouse_clicked = mouse_down && !prev_mouse_down;
 
             match engine_state {
                 EngineState::Playing => {
                     if esc_pressed {
+                        game.play_ui_sound(UiSound::Click);
                         engine_state = EngineState::EscapeMenu { selected: 0 };
                     } else {
-                        // Unified mouse state handling (includes drag & drop)
-                        game.on_mouse_state(mx, my, mouse_down);
-
-                        if mouse_clicked {
-                            game.on_click(mx, my);
-                        }
-
-                        let right_down = window.get_mouse_down(MouseButton::Right);
-                        let right_clicked = right_down && !prev_right_down;
-                        if right_clicked {
-                            game.on_right_click(mx, my);
+                        let played = playback.as_mut().and_then(|p| p.next_frame().cloned());
+                        if playback.is_some() && played.is_none() {
+                            tracing::info!("Demo playback finished");
+                            playback = None;
                         }
-
-                        let keys = window.get_keys_pressed(minifb::KeyRepeat::No);
-                        for key in keys {
-                            if let Some(ch) = key_to_char(
-                                key,
-                                window.is_key_down(Key::LeftShift)
-                                    || window.is_key_down(Key::RightShift),
-                            ) {
-                                game.on_char_input(ch);
+                        let input = match played {
+                            Some(input) => {
+                                (mx, my) = (input.x, input.y);
+                                (view_mx, view_my) = (input.x, input.y);
+                                input
+                            }
+                            None => {
+                                let right_down = window.get_mouse_down(MouseButton::Right);
+                                let mut keys: Vec<KeyEvent> = typed.into_iter().map(KeyEvent::Char).collect();
+                                for key in window.get_keys_pressed(minifb::KeyRepeat::No) {
+                                    if let Some(name) = demo::key_name(key) {
+                                        keys.push(KeyEvent::Key(name.to_string()));
+                                    }
+                                }
+                                FrameInput {
+                                    x: mx,
+                                    y: my,
+                                    down: mouse_down,
+                                    click: mouse_clicked,
+                                    right_click: right_down && !prev_right_down,
+                                    keys,
+                                    // Driving keys are polled (continuous, not event-based)
+                                    arrows: [
+                                        window.is_key_down(Key::Up),
+                                        window.is_key_down(Key::Down),
+                                        window.is_key_down(Key::Left),
+                                        window.is_key_down(Key::Right),
+                                    ],
+                                }
                             }
-                            game.on_key_down(key);
+                        };
+                        if let Some(demo) = &mut recording {
+                            demo.frames.push(input.clone());
                         }
-
-                        // Poll driving keys (continuous, not event-based)
-                        game.update_drive_keys(
-                            window.is_key_down(Key::Up),
-                            window.is_key_down(Key::Down),
-                            window.is_key_down(Key::Left),
-                            window.is_key_down(Key::Right),
-                        );
-
-                        game.update();
+                        // Mouse (incl. drag & drop), clicks, keys, update
+                        game.apply_frame(&input);
                     }
                 }
                 EngineState::EscapeMenu { selected } => {
                     if esc_pressed {
+                        game.play_ui_sound(UiSound::Click);
                         engine_state = EngineState::Playing;
                     } else {
                         let mut sel = selected;
@@ -471,18 +609,14 @@ pub fn run(assets: AssetStore) -> Result<()> {
                         }
 
                         // Mouse hover over menu items
-                        let box_x = (SCREEN_WIDTH as i32 - 300) / 2;
-                        let box_y = (SCREEN_HEIGHT as i32 - 210) / 2;
-                        if mx >= box_x + 6 && mx < box_x + 294 {
-                            let rel_y = my - (box_y + 44);
-                            if rel_y >= 0 {
-                                let idx = (rel_y / 26) as usize;
-                                if idx < ESCAPE_MENU_COUNT {
-                                    sel = idx;
-                                }
-                            }
+                        let hovered = menu_row_at(mx, my, ESCAPE_MENU_COUNT);
+                        if let Some(idx) = hovered {
+                            sel = idx;
                         }
 
+                        if sel != selected {
+                            game.play_ui_sound(UiSound::Hover);
+                        }
                         engine_state = EngineState::EscapeMenu { selected: sel };
 
                         // Activate via Enter or mouse click
@@ -490,31 +624,24 @@ pub fn run(assets: AssetStore) -> Result<()> {
                         if window.is_key_pressed(Key::Enter, minifb::KeyRepeat::No) {
                             action = Some(sel);
                         }
-                        if mouse_clicked && mx >= box_x + 6 && mx < box_x + 294 {
-                            let rel_y = my - (box_y + 44);
-                            if rel_y >= 0 {
-                                let idx = (rel_y / 26) as usize;
-                                if idx < ESCAPE_MENU_COUNT {
-                                    action = Some(idx);
-                                }
-                            }
+                        if mouse_clicked && hovered.is_some() {
+                            action = hovered;
                         }
 
                         if let Some(act) = action {
+                            game.play_ui_sound(UiSound::Click);
                             match act {
                                 0 => engine_state = EngineState::Playing,
-                                1 => toggle_fs = true,
-                                2 => {
-                                    // Cycle display mode
-                                    game.dev_menu.display_mode = game.dev_menu.display_mode.next();
-                                    tracing::info!("Display mode → {:?}", game.dev_menu.display_mode);
+                                ESCAPE_MENU_OPTIONS => {
+                                    engine_state = EngineState::Options { selected: 0, capture: false };
                                 }
+                                2 => toggle_fs = true,
                                 3 => {
-                                    // Toggle detail noise
-                                    game.dev_menu.detail_noise = !game.dev_menu.detail_noise;
-                                    tracing::info!("Detail noise → {}", game.dev_menu.detail_noise);
-                                }
-                                4 => {
+                                    game.quick_save(); // "Continue" on the main menu
+                                    game.save_manager.save(); // play time / statistics
+                                    game.store_options(fullscreen);
+                                    game.assets.missing.log_report();
+                                    save_recording(recording.as_ref(), record_path.as_deref());
                                     tracing::info!("Engine shutdown (menu)");
                                     return Ok(());
                                 }
@@ -523,6 +650,71 @@ pub fn run(assets: AssetStore) -> Result<()> {
                         }
                     }
                 }
+                EngineState::Options { selected, capture: true } => {
+                    // Next named key becomes the dev-menu key, Escape cancels
+                    let pressed = window.get_keys_pressed(minifb::KeyRepeat::No);
+                    let key = pressed.into_iter().find(|k| {
+                        !matches!(k, Key::Enter | Key::Up | Key::Down | Key::Left | Key::Right)
+                            && demo::key_name(*k).is_some()
+                    });
+                    if esc_pressed {
+                        game.play_ui_sound(UiSound::Click);
+                        engine_state = EngineState::Options { selected, capture: false };
+                    } else if let Some(key) = key {
+                        game.play_ui_sound(UiSound::Click);
+                        game.set_dev_key(key);
+                        engine_state = EngineState::Options { selected, capture: false };
+                    }
+                }
+                EngineState::Options { selected, capture: false } => {
+                    let count = OptionItem::ALL.len();
+                    let back = if esc_pressed {
+                        true
+                    } else {
+                        let mut sel = selected;
+                        if window.is_key_pressed(Key::Up, minifb::KeyRepeat::Yes) && sel > 0 {
+                            sel -= 1;
+                        }
+                        if window.is_key_pressed(Key::Down, minifb::KeyRepeat::Yes) && sel < count - 1 {
+                            sel += 1;
+                        }
+                        let hovered = menu_row_at(mx, my, count);
+                        if let Some(idx) = hovered {
+                            sel = idx;
+                        }
+                        if sel != selected {
+                            game.play_ui_sound(UiSound::Hover);
+                        }
+
+                        // Enter/click activate, Left/Right step values
+                        let mut change: Option<bool> = None;
+                        if window.is_key_pressed(Key::Enter, minifb::KeyRepeat::No)
+                            || window.is_key_pressed(Key::Right, minifb::KeyRepeat::Yes)
+                            || (mouse_clicked && hovered.is_some())
+                        {
+                            change = Some(true);
+                        } else if window.is_key_pressed(Key::Left, minifb::KeyRepeat::Yes) {
+                            change = Some(false);
+                        }
+
+                        let item = OptionItem::ALL[sel];
+                        let mut capture = false;
+                        if let Some(forward) = change {
+                            game.play_ui_sound(UiSound::Click);
+                            capture = item == OptionItem::DevKey;
+                            game.change_option(item, forward);
+                        }
+                        engine_state = EngineState::Options { selected: sel, capture };
+                        change.is_some() && item == OptionItem::Back
+                    };
+                    if back {
+                        if esc_pressed {
+                            game.play_ui_sound(UiSound::Click);
+                        }
+                        game.store_options(fullscreen);
+                        engine_state = EngineState::EscapeMenu { selected: ESCAPE_MENU_OPTIONS };
+                    }
+                }
             }
 
             if toggle_fs {
@@ -535,12 +727,8 @@ pub fn run(assets: AssetStore) -> Result<()> {
             framebuffer.fill(0xFF000000);
 
             let sprites = game.get_all_sprites();
-            for sprite in &sprites {
-                if !sprite.visible || sprite.width == 0 || sprite.height == 0 {
-                    continue;
-                }
-                blit_sprite(&mut framebuffer, sprite);
-            }
+            atlas.prepare(&sprites);
+            compose::compose(&mut framebuffer, &sprites, &atlas);
 
             // Debug: draw bounding boxes when enabled via dev menu
             if game.dev_menu.show_hitboxes {
@@ -569,7 +757,11 @@ pub fn run(assets: AssetStore) -> Result<()> {
             let hover_name = game.get_hover_info(mx, my);
 
             // Snapshot the scene-only framebuffer before UI overlays
-            let scene_snap: Vec<u32> = framebuffer.clone();
+            let mut scene_snap: Vec<u32> = framebuffer.clone();
+
+            if game.report_requested {
+                game.write_bug_report(&scene_snap);
+            }
 
             game.draw_ui(&mut framebuffer);
 
@@ -587,14 +779,25 @@ pub fn run(assets: AssetStore) -> Result<()> {
                 }
             }
 
-            // Draw escape menu overlay if paused
-            if let EngineState::EscapeMenu { selected } = engine_state {
-                draw_escape_menu(&mut framebuffer, selected, game.dev_menu.detail_noise,
-                                 game.dev_menu.display_mode, game.language);
+            // Draw escape menu / options overlay if paused
+            match engine_state {
+                EngineState::Playing => {}
+                EngineState::EscapeMenu { selected } => draw_escape_menu(&mut framebuffer, selected, &game),
+                EngineState::Options { selected, capture } => {
+                    draw_options_menu(&mut framebuffer, selected, capture, &game);
+                }
+            }
+
+            // Zoom lens: magnify scene and UI alike (the UI mask stays consistent)
+            if zoom.is_active() {
+                zoom.apply(&framebuffer, &mut zoom_buf);
+                framebuffer.copy_from_slice(&zoom_buf);
+                zoom.apply(&scene_snap, &mut zoom_buf);
+                scene_snap.copy_from_slice(&zoom_buf);
             }
 
-            // Software cursor (drawn last, always on top)
-            game.cursor.blit(&mut framebuffer, SCREEN_WIDTH, SCREEN_HEIGHT, mx, my);
+            // Software cursor (drawn last, always on top, not magnified)
+            game.cursor.blit(&mut framebuffer, SCREEN_WIDTH, SCREEN_HEIGHT, view_mx, view_my);
 
             // Build UI mask: true where UI changed a pixel vs the scene snapshot
             let ui_mask: Vec<bool> = framebuffer.iter()
@@ -607,7 +810,8 @@ pub fn run(assets: AssetStore) -> Result<()> {
             if frame_count % 5 == 0 {
                 let fs_label = if fullscreen { "FS" } else { "Win" };
                 let title = format!(
-                    "OpenWilly – {:?} | {} {}×{} | ({},{}) | {}",
+                    "OpenWilly – {} | {:?} | {} {}×{} | ({},{}) | {}",
+                    crate::game::i18n::t(game.language, "window_title"),
                     game.current_scene,
                     fs_label,
                     out_w,
@@ -625,13 +829,34 @@ pub fn run(assets: AssetStore) -> Result<()> {
                 prev_scene = game.current_scene;
             }
 
+            // Scale on the GPU if possible (minifb then only pumps events)
+            #[cfg(feature = "gpu")]
+            let presented = match gpu_presenter.as_mut().map(|gpu| {
+                gpu.present(&framebuffer, &ui_mask, out_w, out_h, (vx, vy, vw, vh), game.dev_menu.detail_noise)
+            }) {
+                Some(Ok(())) => {
+                    window.update();
+                    true
+                }
+                Some(Err(e)) => {
+                    tracing::warn!("GPU presentation failed, falling back to CPU scaling: {:#}", e);
+                    gpu_presenter = None;
+                    false
+                }
+                None => false,
+            };
+            #[cfg(not(feature = "gpu"))]
+            let presented = false;
+
             // Scale to output size and present
-            let nm = if game.dev_menu.detail_noise { Some(&noise_map) } else { None };
-            scale_to_viewport(&framebuffer, &mut scaled_buf, out_w, out_h,
-                              vx, vy, vw, vh, nm, &ui_mask);
-            window
-                .update_with_buffer(&scaled_buf, out_w, out_h)
-                .map_err(|e| anyhow::anyhow!("Display error: {}", e))?;
+            if !presented {
+                let nm = if game.dev_menu.detail_noise { Some(&noise_map) } else { None };
+                scale_to_viewport(&framebuffer, &mut scaled_buf, out_w, out_h,
+                                  vx, vy, vw, vh, nm, &ui_mask);
+                window
+                    .update_with_buffer(&scaled_buf, out_w, out_h)
+                    .map_err(|e| anyhow::anyhow!("Display error: {}", e))?;
+            }
         }
 
         if toggle_fs {
@@ -647,23 +872,39 @@ pub fn run(assets: AssetStore) -> Result<()> {
         break; // Window was closed
     }
 
+    game.save_manager.save(); // play time / statistics
+    game.store_options(fullscreen);
+    game.assets.missing.log_report();
+    save_recording(recording.as_ref(), record_path.as_deref());
     tracing::info!("Engine shutdown");
     Ok(())
 }
 
-/// Blit an RGBA sprite onto the u32 ARGB framebuffer with alpha blending
-fn blit_sprite(fb: &mut [u32], sprite: &Sprite) {
+/// Write the recorded demo (OPENWILLY_RECORD) on shutdown
+fn save_recording(demo: Option<&Demo>, path: Option<&std::path::Path>) {
+    let (Some(demo), Some(path)) = (demo, path) else { return };
+    match demo.save(path) {
+        Ok(()) => tracing::info!("Demo saved: {} ({} frames)", path.display(), demo.frames.len()),
+        Err(e) => tracing::warn!("Demo not saved: {:#}", e),
+    }
+}
+
+/// Blit a sprite onto a framebuffer of arbitrary size (off-screen canvases)
+pub fn blit_sprite_sized(fb: &mut [u32], fb_w: usize, fb_h: usize, sprite: &Sprite) {
+    blit_sprite_rows(fb, fb_w, 0, fb_h as i32, sprite);
+}
+
+/// Blit an RGBA sprite onto the rows `y0..y1` of a u32 ARGB framebuffer
+/// with alpha blending. `fb` holds only these rows (a band, see `compose`).
+fn blit_sprite_rows(fb: &mut [u32], fb_w: usize, y0: i32, y1: i32, sprite: &Sprite) {
     let sw = sprite.width as i32;
     let sh = sprite.height as i32;
 
-    for sy in 0..sh {
-        let dy = sprite.y + sy;
-        if dy < 0 || dy >= SCREEN_HEIGHT as i32 {
-            continue;
-        }
+    for sy in (y0 - sprite.y).max(0)..(y1 - sprite.y).min(sh) {
+        let dy = sprite.y + sy - y0;
         for sx in 0..sw {
             let dx = sprite.x + sx;
-            if dx < 0 || dx >= SCREEN_WIDTH as i32 {
+            if dx < 0 || dx >= fb_w as i32 {
                 continue;
             }
 
@@ -681,7 +922,7 @@ fn blit_sprite(fb: &mut [u32], sprite: &Sprite) {
                 continue; // Fully transparent
             }
 
-            let dst_idx = (dy as usize) * SCREEN_WIDTH + dx as usize;
+            let dst_idx = (dy as usize) * fb_w + dx as usize;
 
             if a >= 255 {
                 // Fully opaque — no blending needed
@@ -700,37 +941,4 @@ fn blit_sprite(fb: &mut [u32], sprite: &Sprite) {
             }
         }
     }
-}
-
-/// Convert minifb Key to ASCII char for text input
-fn key_to_char(key: Key, shift: bool) -> Option<char> {
-    let ch = match key {
-        Key::A => 'a', Key::B => 'b', Key::C => 'c', Key::D => 'd',
-        Key::E => 'e', Key::F => 'f', Key::G => 'g', Key::H => 'h',
-        Key::I => 'i', Key::J => 'j', Key::K => 'k', Key::L => 'l',
-        Key::M => 'm', Key::N => 'n', Key::O => 'o', Key::P => 'p',
-        Key::Q => 'q', Key::R => 'r', Key::S => 's', Key::T => 't',
-        Key::U => 'u', Key::V => 'v', Key::W => 'w', Key::X => 'x',
-        Key::Y => 'y', Key::Z => 'z',
-        Key::Key0 | Key::NumPad0 => '0',
-        Key::Key1 | Key::NumPad1 => '1',
-        Key::Key2 | Key::NumPad2 => '2',
-        Key::Key3 | Key::NumPad3 => '3',
-        Key::Key4 | Key::NumPad4 => '4',
-        Key::Key5 | Key::NumPad5 => '5',
-        Key::Key6 | Key::NumPad6 => '6',
-        Key::Key7 | Key::NumPad7 => '7',
-        Key::Key8 | Key::NumPad8 => '8',
-        Key::Key9 | Key::NumPad9 => '9',
-        Key::Space => ' ',
-        Key::Period => '.',
-        Key::Minus => '-',
-        Key::Backslash => '#', // German keyboard: # key is at US backslash position
-        _ => return None,
-    };
-    if shift && ch.is_ascii_lowercase() {
-        Some(ch.to_ascii_uppercase())
-    } else {
-        Some(ch)
-    }
 }
\ No newline at end of file
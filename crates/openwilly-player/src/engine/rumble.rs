@@ -0,0 +1,266 @@
+//! Controller rumble — force feedback while driving
+//!
+//! With the `gamepad` feature, connected controllers that support force
+//! feedback (gilrs) get short pulses for wall hits, driving over holes, the
+//! race start/finish and the ferry docking. Wall hits and holes scale with
+//! the speed of the car, the overall strength is set in the escape menu
+//! (100 % / 50 % / off). A pulse doesn't cut off a stronger one that is
+//! still running, so events that repeat every frame (holes) become a steady
+//! rumble instead of restarting the motors 30 times a second. Without the
+//! feature or without a pad everything here is a no-op.
+
+use std::time::{Duration, Instant};
+
+/// What the car just did
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RumbleEvent {
+    /// Frontal wall hit, speed before the hit as a fraction of top speed
+    WallHit { impact: f32 },
+    /// Driving over (or stuck in) holes, fraction of top speed
+    Holes { impact: f32 },
+    RaceStart,
+    RaceFinish,
+    /// Ferry lands on the other shore
+    FerryDock,
+}
+
+/// One force feedback pulse, motor strengths 0.0 – 1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pulse {
+    /// Low-frequency motor (heavy shake)
+    pub strong: f32,
+    /// High-frequency motor (buzz)
+    pub weak: f32,
+    pub duration_ms: u32,
+}
+
+impl RumbleEvent {
+    pub fn pulse(self) -> Pulse {
+        match self {
+            RumbleEvent::WallHit { impact } => {
+                let impact = impact.clamp(0.0, 1.0);
+                Pulse { strong: 0.3 + 0.7 * impact, weak: 0.5 * impact, duration_ms: 150 + (150.0 * impact) as u32 }
+            }
+            RumbleEvent::Holes { impact } => {
+                let impact = impact.clamp(0.0, 1.0);
+                Pulse { strong: 0.15 * impact, weak: 0.25 + 0.35 * impact, duration_ms: 120 }
+            }
+            RumbleEvent::RaceStart => Pulse { strong: 0.4, weak: 0.6, duration_ms: 300 },
+            RumbleEvent::RaceFinish => Pulse { strong: 0.8, weak: 0.8, duration_ms: 600 },
+            RumbleEvent::FerryDock => Pulse { strong: 0.6, weak: 0.2, duration_ms: 500 },
+        }
+    }
+}
+
+impl Pulse {
+    fn scaled(self, scale: f32) -> Self {
+        Pulse { strong: self.strong * scale, weak: self.weak * scale, ..self }
+    }
+
+    fn strength(&self) -> f32 {
+        self.strong.max(self.weak)
+    }
+}
+
+/// Rumble strength (options screen)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum RumbleLevel {
+    #[default]
+    Full,
+    Half,
+    Off,
+}
+
+impl RumbleLevel {
+    /// Cycle to the next level
+    pub fn next(self) -> Self {
+        match self {
+            RumbleLevel::Full => RumbleLevel::Half,
+            RumbleLevel::Half => RumbleLevel::Off,
+            RumbleLevel::Off => RumbleLevel::Full,
+        }
+    }
+
+    /// Short label (used in the options screen)
+    pub fn label(self) -> &'static str {
+        match self {
+            RumbleLevel::Full => "100%",
+            RumbleLevel::Half => "50%",
+            RumbleLevel::Off => "OFF",
+        }
+    }
+
+    fn scale(self) -> f32 {
+        match self {
+            RumbleLevel::Full => 1.0,
+            RumbleLevel::Half => 0.5,
+            RumbleLevel::Off => 0.0,
+        }
+    }
+}
+
+/// Pulse currently playing: end time and strength
+type Active = (Instant, f32);
+
+/// Whether `pulse` should replace the one playing
+fn should_play(active: Option<Active>, pulse: &Pulse, now: Instant) -> bool {
+    pulse.strength() > 0.0
+        && match active {
+            Some((until, strength)) => now >= until || pulse.strength() > strength,
+            None => true,
+        }
+}
+
+pub struct Rumble {
+    pub level: RumbleLevel,
+    active: Option<Active>,
+    #[cfg(feature = "gamepad")]
+    backend: Option<backend::Backend>,
+}
+
+impl Default for Rumble {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rumble {
+    /// Open the gamepad backend (no-op without the `gamepad` feature)
+    pub fn new() -> Self {
+        Self {
+            level: RumbleLevel::default(),
+            active: None,
+            #[cfg(feature = "gamepad")]
+            backend: backend::Backend::new(),
+        }
+    }
+
+    /// No controller access (headless game state)
+    pub fn disabled() -> Self {
+        Self {
+            level: RumbleLevel::Off,
+            active: None,
+            #[cfg(feature = "gamepad")]
+            backend: None,
+        }
+    }
+
+    /// Keep the controller list current, call once per frame
+    pub fn update(&mut self) {
+        #[cfg(feature = "gamepad")]
+        if let Some(backend) = &mut self.backend {
+            backend.update();
+        }
+    }
+
+    pub fn play(&mut self, event: RumbleEvent) {
+        let pulse = event.pulse().scaled(self.level.scale());
+        let now = Instant::now();
+        if !should_play(self.active, &pulse, now) {
+            return;
+        }
+        self.active = Some((now + Duration::from_millis(pulse.duration_ms as u64), pulse.strength()));
+        tracing::trace!("Rumble {:?}: {:?}", event, pulse);
+        #[cfg(feature = "gamepad")]
+        if let Some(backend) = &mut self.backend {
+            backend.play(pulse);
+        }
+    }
+}
+
+#[cfg(feature = "gamepad")]
+mod backend {
+    use gilrs::ff::{BaseEffect, BaseEffectType, Effect, EffectBuilder, Repeat, Replay, Ticks};
+    use gilrs::{GamepadId, Gilrs};
+
+    use super::Pulse;
+
+    pub struct Backend {
+        gilrs: Gilrs,
+        /// Playing effect (dropping it stops the motors)
+        effect: Option<Effect>,
+    }
+
+    fn magnitude(strength: f32) -> u16 {
+        (strength.clamp(0.0, 1.0) * u16::MAX as f32) as u16
+    }
+
+    impl Backend {
+        pub fn new() -> Option<Self> {
+            match Gilrs::new() {
+                Ok(gilrs) => {
+                    let pads = gilrs.gamepads().filter(|(_, pad)| pad.is_ff_supported()).count();
+                    tracing::info!("Gamepad rumble ready ({} controller(s) with force feedback)", pads);
+                    Some(Self { gilrs, effect: None })
+                }
+                Err(e) => {
+                    tracing::warn!("Gamepads unavailable, no rumble: {}", e);
+                    None
+                }
+            }
+        }
+
+        /// Drain gilrs events so (dis)connected pads show up
+        pub fn update(&mut self) {
+            while self.gilrs.next_event().is_some() {}
+        }
+
+        pub fn play(&mut self, pulse: Pulse) {
+            let pads: Vec<GamepadId> = self.gilrs.gamepads()
+                .filter(|(_, pad)| pad.is_ff_supported())
+                .map(|(id, _)| id)
+                .collect();
+            if pads.is_empty() {
+                return;
+            }
+            let duration = Ticks::from_ms(pulse.duration_ms);
+            let scheduling = Replay { play_for: duration, ..Default::default() };
+            let result = EffectBuilder::new()
+                .add_effect(BaseEffect {
+                    kind: BaseEffectType::Strong { magnitude: magnitude(pulse.strong) },
+                    scheduling,
+                    ..Default::default()
+                })
+                .add_effect(BaseEffect {
+                    kind: BaseEffectType::Weak { magnitude: magnitude(pulse.weak) },
+                    scheduling,
+                    ..Default::default()
+                })
+                .gamepads(&pads)
+                .repeat(Repeat::For(duration))
+                .finish(&mut self.gilrs)
+                .and_then(|effect| effect.play().map(|_| effect));
+            match result {
+                Ok(effect) => self.effect = Some(effect),
+                Err(e) => tracing::debug!("Rumble failed: {}", e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wall_hits_scale_with_impact() {
+        let soft = RumbleEvent::WallHit { impact: 0.1 }.pulse();
+        let hard = RumbleEvent::WallHit { impact: 1.0 }.pulse();
+        assert!(hard.strong > soft.strong && hard.duration_ms > soft.duration_ms);
+        assert!(hard.strong <= 1.0 && hard.weak <= 1.0);
+        let off = hard.scaled(RumbleLevel::Off.scale());
+        assert!(!should_play(None, &off, Instant::now()));
+    }
+
+    #[test]
+    fn weaker_pulses_wait_for_the_running_one() {
+        let now = Instant::now();
+        let hit = RumbleEvent::WallHit { impact: 1.0 }.pulse();
+        let holes = RumbleEvent::Holes { impact: 0.5 }.pulse();
+        let active = Some((now + Duration::from_millis(hit.duration_ms as u64), hit.strength()));
+        assert!(!should_play(active, &holes, now));
+        assert!(should_play(active, &holes, now + Duration::from_millis(400)));
+        let weak_active = Some((now + Duration::from_millis(100), holes.strength()));
+        assert!(should_play(weak_active, &hit, now));
+    }
+}
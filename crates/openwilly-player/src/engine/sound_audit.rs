@@ -0,0 +1,151 @@
+//! Sound channel audit — finds channels that outlive their scene
+//!
+//! Every sink the SoundEngine opens (effects, background loops, loops
+//! fading out, the engine loop) carries a tag: what it plays, which scene
+//! started it and when. Channels stop when they are dropped, so a scene
+//! switch (`stop_all`) leaves nothing behind; the audit checks that it
+//! really doesn't and that no one-shot or fade hangs around forever.
+//!
+//! On in debug builds or with `OPENWILLY_SOUND_AUDIT=1` (warnings per
+//! leak and a summary per scene); `OPENWILLY_SOUND_AUDIT=assert` panics
+//! on the first leak.
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Longer than any one-shot in the game (the longest dialog lines)
+const MAX_SFX_AGE: Duration = Duration::from_secs(90);
+/// Fade-outs are a few seconds at most
+const MAX_FADE_AGE: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelKind {
+    Sfx,
+    Background,
+    Fading,
+    Engine,
+}
+
+/// Who opened a channel
+#[derive(Debug, Clone)]
+pub struct ChannelTag {
+    pub id: u64,
+    pub kind: ChannelKind,
+    pub name: String,
+    /// Scene that was active when it started
+    pub scene: String,
+    pub started: Instant,
+}
+
+/// Channels that should be gone: (channel id, reason)
+pub fn find_leaks(alive: &[&ChannelTag], scene: &str, now: Instant) -> Vec<(u64, &'static str)> {
+    alive.iter()
+        .filter_map(|tag| {
+            let age = now.saturating_duration_since(tag.started);
+            let reason = if tag.scene != scene {
+                "outlived its scene"
+            } else if tag.kind == ChannelKind::Sfx && age > MAX_SFX_AGE {
+                "one-shot never finished"
+            } else if tag.kind == ChannelKind::Fading && age > MAX_FADE_AGE {
+                "fade never ended"
+            } else {
+                return None;
+            };
+            Some((tag.id, reason))
+        })
+        .collect()
+}
+
+/// Tags channels and reports leaks
+#[derive(Debug, Default)]
+pub struct SoundAudit {
+    enabled: bool,
+    /// Panic instead of warning
+    strict: bool,
+    scene: String,
+    next_id: u64,
+    /// Channels started / leaks found in the current scene
+    started: usize,
+    leaked: usize,
+    /// Already reported channel IDs (each leak is logged once)
+    reported: HashSet<u64>,
+}
+
+impl SoundAudit {
+    pub fn from_env() -> Self {
+        let mode = std::env::var("OPENWILLY_SOUND_AUDIT").unwrap_or_default();
+        Self {
+            enabled: cfg!(debug_assertions) || !mode.is_empty(),
+            strict: mode.eq_ignore_ascii_case("assert"),
+            ..Default::default()
+        }
+    }
+
+    /// Tag for a new channel
+    pub fn tag(&mut self, kind: ChannelKind, name: &str) -> ChannelTag {
+        self.next_id += 1;
+        self.started += 1;
+        ChannelTag {
+            id: self.next_id,
+            kind,
+            name: name.to_string(),
+            scene: self.scene.clone(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Look for leaks among the live channels
+    pub fn check(&mut self, alive: &[&ChannelTag]) {
+        if !self.enabled {
+            return;
+        }
+        for (id, reason) in find_leaks(alive, &self.scene, Instant::now()) {
+            if !self.reported.insert(id) {
+                continue;
+            }
+            self.leaked += 1;
+            let tag = alive.iter().find(|t| t.id == id).expect("leak of a live channel");
+            let msg = format!(
+                "Sound channel #{} {:?} '{}' from {} {} ({:.1} s)",
+                id, tag.kind, tag.name, tag.scene, reason, tag.started.elapsed().as_secs_f32(),
+            );
+            if self.strict {
+                panic!("{}", msg);
+            }
+            tracing::warn!("{}", msg);
+        }
+    }
+
+    /// Scene switch, after `stop_all`: whatever is still alive leaked
+    pub fn enter_scene(&mut self, scene: &str, alive: &[&ChannelTag]) {
+        self.check(alive);
+        if self.enabled && !self.scene.is_empty() {
+            tracing::debug!(
+                "Sound audit {}: {} channels, {} leaked, {} still open",
+                self.scene, self.started, self.leaked, alive.len(),
+            );
+        }
+        self.scene = scene.to_string();
+        self.started = 0;
+        self.leaked = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaks_by_scene_and_age() {
+        let mut audit = SoundAudit { scene: "Yard".into(), ..Default::default() };
+        let sfx = audit.tag(ChannelKind::Sfx, "31e004v0");
+        let bg = audit.tag(ChannelKind::Background, "02e016v0");
+        let now = Instant::now();
+        assert!(find_leaks(&[&sfx, &bg], "Yard", now).is_empty());
+        assert_eq!(find_leaks(&[&sfx, &bg], "Garage", now), vec![(1, "outlived its scene"), (2, "outlived its scene")]);
+
+        let later = now + MAX_SFX_AGE + Duration::from_secs(1);
+        assert_eq!(find_leaks(&[&sfx, &bg], "Yard", later), vec![(1, "one-shot never finished")]);
+        assert_eq!(audit.started, 2);
+    }
+}
@@ -0,0 +1,67 @@
+//! Sound cues — cooldowns and variation for repeated event sounds
+//!
+//! Events like wall hits fire every frame while the player keeps pushing
+//! against the obstacle. A cue groups 1–3 samples for one event: it only
+//! plays again after its cooldown, and cycles through its samples so
+//! repeated hits don't sound identical.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A repeatable event sound
+#[derive(Debug)]
+pub struct SoundCue {
+    /// Cooldown key (one per event kind)
+    pub name: &'static str,
+    /// Variation pool, played round-robin
+    pub samples: &'static [&'static str],
+    /// Minimum time between two plays
+    pub cooldown: Duration,
+}
+
+/// Last play time and next sample per cue
+#[derive(Debug, Default)]
+pub struct SoundCues {
+    state: HashMap<&'static str, (Instant, usize)>,
+}
+
+impl SoundCues {
+    /// Sample to play for `cue` now, or None while it is cooling down
+    pub fn pick(&mut self, cue: &SoundCue, now: Instant) -> Option<&'static str> {
+        if cue.samples.is_empty() {
+            return None;
+        }
+        let next = match self.state.get(cue.name) {
+            Some(&(last, _)) if now.duration_since(last) < cue.cooldown => return None,
+            Some(&(_, next)) => next,
+            None => 0,
+        };
+        let sample = cue.samples[next % cue.samples.len()];
+        self.state.insert(cue.name, (now, (next + 1) % cue.samples.len()));
+        Some(sample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUE: SoundCue = SoundCue {
+        name: "test",
+        samples: &["a", "b", "c"],
+        cooldown: Duration::from_millis(300),
+    };
+
+    #[test]
+    fn cooldown_and_round_robin() {
+        let mut cues = SoundCues::default();
+        let t0 = Instant::now();
+        let at = |ms: u64| t0 + Duration::from_millis(ms);
+        assert_eq!(cues.pick(&CUE, at(0)), Some("a"));
+        assert_eq!(cues.pick(&CUE, at(33)), None);
+        assert_eq!(cues.pick(&CUE, at(299)), None);
+        assert_eq!(cues.pick(&CUE, at(300)), Some("b"));
+        assert_eq!(cues.pick(&CUE, at(700)), Some("c"));
+        assert_eq!(cues.pick(&CUE, at(1100)), Some("a"));
+    }
+}
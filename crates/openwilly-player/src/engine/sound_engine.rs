@@ -3,24 +3,36 @@
 //! Plays Director 6 sounds (decoded via DecodedSound → WAV → rodio).
 //! Supports one-shot playback, looping background music, named sound lookup,
 //! and playback handles for cue-point based dialog synchronization.
+//! The driving engine sound runs on its own sink (see `engine_loop`).
+//! Background loops crossfade on scene changes and duck under dialog, the
+//! per-scene mix comes from `game::ambience`.
+//! Every sink is wrapped in a `Channel` that stops it when dropped, so
+//! clearing a list is all it takes to silence it; `sound_audit` checks
+//! that nothing outlives its scene.
 
 use std::io::Cursor;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
 
 use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
 
+use crate::assets::missing::{self, MissingKind};
 use crate::assets::sound::DecodedSound;
 use crate::assets::AssetStore;
+use crate::engine::engine_loop::{EngineControl, EngineLoop};
+use crate::engine::sound_audit::{ChannelKind, ChannelTag, SoundAudit};
+use crate::engine::sound_cues::{SoundCue, SoundCues};
+use crate::engine::ui_sound::UiSound;
 
 /// A handle to a playing sound — tracks elapsed time for cue-point polling
 #[derive(Debug)]
 pub struct PlaybackHandle {
     /// When playback started
     start_time: Instant,
-    /// Index into sfx_sinks (for checking if still playing)
+    /// Channel ID (stays valid across gc())
     #[allow(dead_code)] // Used when checking playback status
-    sink_index: usize,
+    channel: u64,
 }
 
 impl PlaybackHandle {
@@ -30,6 +42,63 @@ impl PlaybackHandle {
     }
 }
 
+/// Frame time of the game loop (fades advance once per frame)
+const FRAME_MS: f32 = 1000.0 / 30.0;
+/// Ducking reaches its level in ~200 ms, and recovers as fast
+const DUCK_STEP: f32 = FRAME_MS / 200.0;
+
+/// Mix of a background loop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BgMix {
+    /// Loop volume relative to the master volume
+    pub volume: f32,
+    pub fade_in_ms: u32,
+    /// Fade-out of the loop this one replaces
+    pub fade_out_ms: u32,
+    /// Volume factor while dialog is playing
+    pub duck: f32,
+}
+
+impl Default for BgMix {
+    fn default() -> Self {
+        Self { volume: 0.6, fade_in_ms: 0, fade_out_ms: 0, duck: 1.0 }
+    }
+}
+
+/// A sink and its audit tag — stops playing when dropped
+struct Channel {
+    sink: Sink,
+    tag: ChannelTag,
+}
+
+impl Channel {
+    fn is_playing(&self) -> bool {
+        !self.sink.empty()
+    }
+}
+
+impl Drop for Channel {
+    fn drop(&mut self) {
+        self.sink.stop();
+    }
+}
+
+/// A playing background loop and its fade
+struct BgLoop {
+    channel: Channel,
+    name: String,
+    mix: BgMix,
+    /// Fade level 0.0 – 1.0
+    level: f32,
+    /// Level change per frame (negative while fading out)
+    step: f32,
+}
+
+/// Per-frame fade step for a fade of `ms` (instant for 0)
+fn fade_step(ms: u32) -> f32 {
+    if ms == 0 { 1.0 } else { (FRAME_MS / ms as f32).min(1.0) }
+}
+
 /// Central sound engine — manages output stream and active playback channels
 pub struct SoundEngine {
     /// rodio output stream (must be kept alive)
@@ -37,13 +106,27 @@ pub struct SoundEngine {
     /// Handle for creating new sinks
     handle: OutputStreamHandle,
     /// Background music / ambient loop
-    bg_sink: Option<Sink>,
+    bg: Option<BgLoop>,
+    /// Previous loops fading out
+    bg_fading: Vec<BgLoop>,
+    /// Current ducking factor (1.0 = none) and where it is heading
+    duck: f32,
+    ducked: bool,
     /// One-shot sound effects (kept alive until finished)
-    sfx_sinks: Vec<Sink>,
-    /// Current background sound name (to avoid restarting same track)
-    current_bg: String,
+    sfx: Vec<Channel>,
     /// Master volume (0.0 – 1.0)
     volume: f32,
+    /// Max simultaneous SFX (low-spec mode); the oldest sound is cut off
+    max_channels: Option<usize>,
+    /// Adaptive engine loop while driving
+    engine: Option<(Channel, Arc<EngineControl>)>,
+    /// Cooldown / variation state of repeated event sounds
+    cues: SoundCues,
+    /// Volume of menu hover/click sounds relative to the master volume
+    ui_volume: f32,
+    /// Cast sounds started since the last `take_played` (captions)
+    played: Vec<String>,
+    audit: SoundAudit,
 }
 
 impl SoundEngine {
@@ -55,10 +138,18 @@ impl SoundEngine {
                 Some(Self {
                     _stream: stream,
                     handle,
-                    bg_sink: None,
-                    sfx_sinks: Vec::new(),
-                    current_bg: String::new(),
+                    bg: None,
+                    bg_fading: Vec::new(),
+                    duck: 1.0,
+                    ducked: false,
+                    sfx: Vec::new(),
                     volume: 1.0,
+                    max_channels: None,
+                    engine: None,
+                    cues: SoundCues::default(),
+                    ui_volume: 0.8,
+                    played: Vec::new(),
+                    audit: SoundAudit::from_env(),
                 })
             }
             Err(e) => {
@@ -70,19 +161,50 @@ impl SoundEngine {
 
     /// Play a one-shot sound effect from a DecodedSound.
     /// Returns a PlaybackHandle for tracking elapsed time (used by cue-point system).
+    #[allow(dead_code)] // cast sounds go through play_by_name with their name
     pub fn play_sound(&mut self, sound: &DecodedSound) -> Option<PlaybackHandle> {
-        let wav_bytes = sound.to_wav();
+        self.play_wav("(sound)", sound.to_wav(), 1.0)
+    }
+
+    /// Play a one-shot WAV file from disk (user-provided mod sounds).
+    pub fn play_file(&mut self, path: &Path) -> Option<PlaybackHandle> {
+        match std::fs::read(path) {
+            Ok(bytes) => self.play_wav(&path.to_string_lossy(), bytes, 1.0),
+            Err(e) => {
+                tracing::warn!("Cannot read sound file {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Limit the number of simultaneously playing sound effects (None = unlimited)
+    pub fn set_max_channels(&mut self, max: Option<usize>) {
+        self.max_channels = max;
+    }
+
+    /// Decode WAV bytes and play them on a new SFX sink (`gain` scales the master volume)
+    fn play_wav(&mut self, name: &str, wav_bytes: Vec<u8>, gain: f32) -> Option<PlaybackHandle> {
+        // Channel limit: drop the oldest playing sound
+        if let Some(max) = self.max_channels {
+            let playing = self.sfx.iter().filter(|c| c.is_playing()).count();
+            if playing >= max.max(1) {
+                if let Some(oldest) = self.sfx.iter().position(|c| c.is_playing()) {
+                    self.sfx.remove(oldest);
+                }
+            }
+        }
         match Decoder::new(Cursor::new(wav_bytes)) {
             Ok(source) => {
                 match Sink::try_new(&self.handle) {
                     Ok(sink) => {
-                        sink.set_volume(self.volume);
+                        sink.set_volume(self.volume * gain.clamp(0.0, 1.0));
                         sink.append(source);
-                        let index = self.sfx_sinks.len();
-                        self.sfx_sinks.push(sink);
+                        let tag = self.audit.tag(ChannelKind::Sfx, name);
+                        let id = tag.id;
+                        self.sfx.push(Channel { sink, tag });
                         Some(PlaybackHandle {
                             start_time: Instant::now(),
-                            sink_index: index,
+                            channel: id,
                         })
                     }
                     Err(e) => {
@@ -102,28 +224,67 @@ impl SoundEngine {
     /// Searches all loaded files for the named sound.
     /// Returns a PlaybackHandle for cue-point tracking.
     pub fn play_by_name(&mut self, name: &str, assets: &AssetStore) -> Option<PlaybackHandle> {
+        self.played.push(name.to_string());
         if let Some((file, num)) = assets.find_sound_by_name(name) {
             if let Some(decoded) = assets.decode_sound(&file, num) {
                 tracing::debug!("Playing sound '{}' from {}#{}", name, file, num);
-                return self.play_sound(&decoded);
+                return self.play_wav(name, decoded.to_wav(), 1.0);
             } else {
                 tracing::warn!("Sound '{}' found at {}#{} but failed to decode", name, file, num);
             }
         } else {
-            tracing::debug!("Sound '{}' not found in any file", name);
+            // Beep so the gap is noticed; callers still see "no sound"
+            assets.missing.record(MissingKind::Sound, name);
+            self.play_wav(name, missing::placeholder_sound().to_wav(), 1.0);
         }
         None
     }
 
-    /// Start a looping background sound. If the same name is already playing,
-    /// this is a no-op. Pass "" to stop background audio.
-    pub fn play_background(&mut self, name: &str, assets: &AssetStore) {
-        if name == self.current_bg {
+    /// Names of the cast sounds started since the last call
+    pub fn take_played(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.played)
+    }
+
+    /// Play a repeated event sound: skipped while the cue is cooling down,
+    /// otherwise the cue's next variation at `gain` (0.0 – 1.0) of the volume.
+    pub fn play_cue(&mut self, cue: &SoundCue, gain: f32, assets: &AssetStore) -> Option<PlaybackHandle> {
+        let name = self.cues.pick(cue, Instant::now())?;
+        self.played.push(name.to_string());
+        let (file, num) = assets.find_sound_by_name(name)?;
+        let decoded = assets.decode_sound(&file, num)?;
+        tracing::trace!("Cue '{}': '{}' at {:.2}", cue.name, name, gain);
+        self.play_wav(name, decoded.to_wav(), gain)
+    }
+
+    /// Play a menu hover/click sound (original cast sound if present, else generated)
+    pub fn play_ui(&mut self, sound: UiSound, assets: &AssetStore) {
+        let decoded = sound.cast_name()
+            .and_then(|name| assets.find_sound_by_name(name))
+            .and_then(|(file, num)| assets.decode_sound(&file, num))
+            .unwrap_or_else(|| sound.generate());
+        self.play_wav(&format!("{:?}", sound), decoded.to_wav(), self.ui_volume);
+    }
+
+    /// Set the UI sound volume (0.0 – 1.0, relative to the master volume)
+    pub fn set_ui_volume(&mut self, vol: f32) {
+        self.ui_volume = vol.clamp(0.0, 1.0);
+    }
+
+    /// Crossfade to a looping background sound. If the same name is already
+    /// playing it keeps running and only takes over the new mix. Pass "" to
+    /// fade out background audio.
+    pub fn play_background(&mut self, name: &str, mix: BgMix, assets: &AssetStore) {
+        if let Some(bg) = self.bg.as_mut().filter(|bg| bg.name == name) {
+            bg.mix = mix;
             return; // Already playing
         }
 
-        // Stop current background
-        self.stop_background();
+        // Fade out the current background
+        if let Some(mut old) = self.bg.take() {
+            old.step = -fade_step(mix.fade_out_ms);
+            old.channel.tag = self.audit.tag(ChannelKind::Fading, &old.name);
+            self.bg_fading.push(old);
+        }
 
         if name.is_empty() {
             return;
@@ -136,13 +297,14 @@ impl SoundEngine {
                 let wav_arc = Arc::new(wav_bytes);
                 match Sink::try_new(&self.handle) {
                     Ok(sink) => {
-                        sink.set_volume(self.volume * 0.6); // BG slightly quieter
+                        let step = fade_step(mix.fade_in_ms);
+                        sink.set_volume(self.volume * mix.volume * step * self.duck);
                         // Append looping source
                         match Decoder::new(Cursor::new((*wav_arc).clone())) {
                             Ok(source) => {
                                 sink.append(source.repeat_infinite());
-                                self.bg_sink = Some(sink);
-                                self.current_bg = name.to_string();
+                                let channel = Channel { sink, tag: self.audit.tag(ChannelKind::Background, name) };
+                                self.bg = Some(BgLoop { channel, name: name.to_string(), mix, level: step, step });
                                 tracing::debug!("Background loop: '{}' from {}#{}", name, file, num);
                             }
                             Err(e) => tracing::warn!("Failed to decode BG sound '{}': {}", name, e),
@@ -156,44 +318,135 @@ impl SoundEngine {
         }
     }
 
-    /// Stop the background loop
-    pub fn stop_background(&mut self) {
-        if let Some(sink) = self.bg_sink.take() {
-            sink.stop();
+    /// Start the adaptive engine loop from an engine type's idle and driving
+    /// samples. Returns false if a sample is missing or can't be looped.
+    pub fn start_engine(&mut self, idle: &str, driving: &str, assets: &AssetStore) -> bool {
+        self.stop_engine();
+        let decode = |name: &str| {
+            let (file, num) = assets.find_sound_by_name(name)?;
+            assets.decode_sound(&file, num)
+        };
+        let (Some(idle_snd), Some(driving_snd)) = (decode(idle), decode(driving)) else {
+            tracing::debug!("Engine loop samples '{}'/'{}' not found", idle, driving);
+            return false;
+        };
+        let Some((source, control)) = EngineLoop::new(&idle_snd, &driving_snd) else {
+            tracing::warn!("Engine loop samples '{}'/'{}' can't be looped", idle, driving);
+            return false;
+        };
+        match Sink::try_new(&self.handle) {
+            Ok(sink) => {
+                sink.set_volume(self.volume);
+                sink.append(source);
+                let tag = self.audit.tag(ChannelKind::Engine, idle);
+                self.engine = Some((Channel { sink, tag }, control));
+                tracing::debug!("Engine loop: '{}' / '{}'", idle, driving);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to create engine sink: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Update the engine loop: playback rate (1.0 = original) and load (0.0 – 1.0)
+    pub fn set_engine_tone(&self, pitch: f32, load: f32) {
+        if let Some((_, control)) = &self.engine {
+            control.set(pitch, load);
+        }
+    }
+
+    pub fn engine_running(&self) -> bool {
+        self.engine.as_ref().is_some_and(|(channel, _)| channel.is_playing())
+    }
+
+    pub fn stop_engine(&mut self) {
+        if let Some((_channel, control)) = self.engine.take() {
+            control.stop();
+        }
+    }
+
+    /// Duck the background loop (dialog playing) or bring it back
+    pub fn set_ducked(&mut self, ducked: bool) {
+        self.ducked = ducked;
+    }
+
+    /// Advance background fades and ducking by one frame
+    pub fn update_background(&mut self) {
+        let duck_target = match (&self.bg, self.ducked) {
+            (Some(bg), true) => bg.mix.duck,
+            _ => 1.0,
+        };
+        self.duck = if self.duck < duck_target {
+            (self.duck + DUCK_STEP).min(duck_target)
+        } else {
+            (self.duck - DUCK_STEP).max(duck_target)
+        };
+        if let Some(bg) = &mut self.bg {
+            bg.level = (bg.level + bg.step).min(1.0);
+        }
+        for old in &mut self.bg_fading {
+            old.level += old.step;
         }
-        self.current_bg.clear();
+        self.bg_fading.retain(|old| old.level > 0.0);
+        self.apply_bg_volume();
+    }
+
+    fn apply_bg_volume(&self) {
+        for bg in self.bg.iter().chain(&self.bg_fading) {
+            bg.channel.sink.set_volume(self.volume * bg.mix.volume * bg.level.clamp(0.0, 1.0) * self.duck);
+        }
+    }
+
+    /// Stop the background loop (no fade)
+    pub fn stop_background(&mut self) {
+        self.bg = None;
+        self.bg_fading.clear();
     }
 
     /// Stop all sounds (background + SFX)
     pub fn stop_all(&mut self) {
         self.stop_background();
-        for sink in self.sfx_sinks.drain(..) {
-            sink.stop();
-        }
+        self.stop_engine();
+        self.sfx.clear();
+    }
+
+    /// Scene switch (after `stop_all`): audit the channels of the scene
+    /// left, new channels are tagged with `scene`
+    pub fn enter_scene(&mut self, scene: &str) {
+        let (alive, audit) = self.audit_parts();
+        audit.enter_scene(scene, &alive);
+    }
+
+    /// Tags of all live channels, and the audit to check them with
+    fn audit_parts(&mut self) -> (Vec<&ChannelTag>, &mut SoundAudit) {
+        let loops = self.bg.iter().chain(&self.bg_fading).map(|bg| &bg.channel);
+        let engine = self.engine.iter().map(|(channel, _)| channel);
+        let tags = self.sfx.iter().chain(loops).chain(engine).map(|c| &c.tag).collect();
+        (tags, &mut self.audit)
     }
 
     /// Set master volume (0.0 – 1.0)
     pub fn set_volume(&mut self, vol: f32) {
         self.volume = vol.clamp(0.0, 1.0);
-        if let Some(bg) = &self.bg_sink {
-            bg.set_volume(self.volume * 0.6);
+        self.apply_bg_volume();
+        if let Some((channel, _)) = &self.engine {
+            channel.sink.set_volume(self.volume);
         }
     }
 
     /// Check if a playback handle's sound is still playing
     #[allow(dead_code)] // Available for future audio monitoring
     pub fn is_handle_playing(&self, handle: &PlaybackHandle) -> bool {
-        if let Some(sink) = self.sfx_sinks.get(handle.sink_index) {
-            !sink.empty()
-        } else {
-            false
-        }
+        self.sfx.iter().any(|c| c.tag.id == handle.channel && c.is_playing())
     }
 
     /// Clean up finished SFX sinks (called periodically from game loop).
-    /// NOTE: After gc(), existing PlaybackHandle sink_index values may be
-    /// invalidated. Only call gc() when no active handles are being tracked.
+    /// Handles refer to channel IDs, so they stay valid.
     pub fn gc(&mut self) {
-        self.sfx_sinks.retain(|s| !s.empty());
+        self.sfx.retain(Channel::is_playing);
+        let (alive, audit) = self.audit_parts();
+        audit.check(&alive);
     }
 }
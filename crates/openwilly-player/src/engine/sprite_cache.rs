@@ -0,0 +1,89 @@
+//! Decoded bitmaps for sprites that are rebuilt every frame
+//!
+//! The driving car (one cast member per direction and tilt) and Salka on
+//! the back seat used to be decoded from the Director cast on every frame.
+//! `load_*` decodes a bitmap once during `update` (a miss is retried, the
+//! file may still be streaming in); the renderer then places the shared
+//! pixels wherever the sprite is this frame, without copying them.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::assets::AssetStore;
+use crate::engine::{Sprite, SpritePixels};
+
+/// Decoded bitmap: width, height, shared RGBA pixels
+type Shared = (u32, u32, SpritePixels);
+
+#[derive(Default)]
+pub struct SpriteCache {
+    /// (file, member number) → bitmap
+    members: HashMap<(String, u32), Shared>,
+    /// Member name → bitmap
+    named: HashMap<String, Shared>,
+}
+
+impl SpriteCache {
+    /// Decode a cast member (white transparent) unless already cached
+    pub fn load_member(&mut self, assets: &AssetStore, file: &str, num: u32) {
+        if let Entry::Vacant(slot) = self.members.entry((file.to_string(), num)) {
+            if let Some(b) = assets.decode_bitmap_transparent(file, num) {
+                slot.insert((b.width, b.height, b.pixels.into()));
+            }
+        }
+    }
+
+    /// Decode a bitmap looked up by member name unless already cached
+    pub fn load_named(&mut self, assets: &AssetStore, name: &str) {
+        if !self.named.contains_key(name) {
+            if let Some(b) = assets.find_bitmap_by_name(name) {
+                self.named.insert(name.to_string(), (b.width, b.height, b.pixels.into()));
+            }
+        }
+    }
+
+    /// Sprite of a loaded cast member, centered on (x, y)
+    pub fn member(&self, file: &str, num: u32, x: i32, y: i32) -> Option<Sprite> {
+        let bmp = self.members.get(&(file.to_string(), num))?;
+        Some(centered(bmp, x, y, num))
+    }
+
+    /// Sprite of a bitmap loaded by name, centered on (x, y)
+    pub fn named(&self, name: &str, x: i32, y: i32) -> Option<Sprite> {
+        let bmp = self.named.get(name)?;
+        Some(centered(bmp, x, y, 0))
+    }
+}
+
+fn centered((width, height, pixels): &Shared, x: i32, y: i32, member_num: u32) -> Sprite {
+    Sprite {
+        x: x - *width as i32 / 2,
+        y: y - *height as i32 / 2,
+        width: *width,
+        height: *height,
+        pixels: pixels.clone(),
+        visible: true,
+        z_order: 0,
+        name: String::new(),
+        interactive: false,
+        member_num,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn placed_sprites_share_pixels() {
+        let mut cache = SpriteCache::default();
+        cache.named.insert("dog".into(), (4, 2, vec![0; 32].into()));
+        let a = cache.named("dog", 100, 50).unwrap();
+        let b = cache.named("dog", 10, 10).unwrap();
+        assert_eq!((a.x, a.y), (98, 49));
+        assert!(Arc::ptr_eq(&a.pixels, &b.pixels));
+        assert!(cache.named("missing", 0, 0).is_none());
+        assert!(cache.member("05.DXR", 78, 0, 0).is_none());
+    }
+}
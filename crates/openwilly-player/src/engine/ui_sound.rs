@@ -0,0 +1,63 @@
+//! UI sounds — hover and click feedback for the overlay menus
+//!
+//! The escape menu, dev menu, language/continue buttons and the gallery are
+//! OpenWilly additions without sounds of their own. They share two UI
+//! sounds: the hover sound uses the original cast member of the toolbox tab,
+//! the click has no identifiable original and is generated. Both play on
+//! the SFX sinks, scaled by the UI volume (`SoundEngine::set_ui_volume`).
+
+use crate::assets::sound::DecodedSound;
+
+const SAMPLE_RATE: u32 = 22050;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiSound {
+    /// Pointer/selection moved onto an item
+    Hover,
+    /// Item activated
+    Click,
+}
+
+impl UiSound {
+    /// Original cast sound, where one is known
+    pub fn cast_name(self) -> Option<&'static str> {
+        match self {
+            UiSound::Hover => Some("00e040v0"), // toolbox tab hover
+            UiSound::Click => None,
+        }
+    }
+
+    /// Generated fallback: a short, fast-decaying tone
+    pub fn generate(self) -> DecodedSound {
+        let (freq, ms, level) = match self {
+            UiSound::Hover => (1760.0, 25, 0.25),
+            UiSound::Click => (880.0, 45, 0.5),
+        };
+        let len = SAMPLE_RATE as usize * ms / 1000;
+        let pcm: Vec<u8> = (0..len)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                let envelope = (-(i as f32) / len as f32 * 5.0).exp();
+                let s = (t * freq * std::f32::consts::TAU).sin() * envelope * level;
+                (128.0 + s * 127.0) as u8
+            })
+            .collect();
+        DecodedSound { sample_rate: SAMPLE_RATE, channels: 1, bits_per_sample: 8, pcm_data: pcm }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_sounds_are_short_and_decay() {
+        for sound in [UiSound::Hover, UiSound::Click] {
+            let snd = sound.generate();
+            assert!(snd.duration_ms() > 0 && snd.duration_ms() <= 50);
+            let peak = |pcm: &[u8]| pcm.iter().map(|&b| (b as i32 - 128).abs()).max().unwrap();
+            let (head, tail) = snd.pcm_data.split_at(snd.pcm_data.len() / 2);
+            assert!(peak(head) > peak(tail));
+        }
+    }
+}
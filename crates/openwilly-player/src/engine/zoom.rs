@@ -0,0 +1,127 @@
+//! Zoom lens — 2× magnification around the cursor while Ctrl is held
+//!
+//! On small laptop screens the 640×480 picture (and with it many parts) is
+//! tiny. Holding Ctrl in the building scenes magnifies a 320×240 region
+//! around the cursor to the full screen. Only the compositor and the mouse
+//! mapping know about it: the game keeps getting logical 640×480
+//! coordinates. Moving the cursor to the edge of the zoomed view scrolls
+//! the region (so a part can be dragged further than the visible area).
+
+use super::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+const W: i32 = SCREEN_WIDTH as i32;
+const H: i32 = SCREEN_HEIGHT as i32;
+/// Magnified region size (2×)
+const REGION_W: i32 = W / 2;
+const REGION_H: i32 = H / 2;
+/// Edge band (view pixels) that scrolls the region
+const EDGE: i32 = 24;
+/// Scroll speed (logical pixels per frame)
+const SCROLL: i32 = 6;
+
+#[derive(Debug, Default)]
+pub struct ZoomLens {
+    /// Top-left of the magnified region (logical coordinates), None = off
+    origin: Option<(i32, i32)>,
+}
+
+impl ZoomLens {
+    pub fn is_active(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    /// Switch on, centered on the logical position (x, y). The view pixel
+    /// under the cursor keeps showing what was under it before.
+    pub fn begin(&mut self, x: i32, y: i32) {
+        if self.origin.is_none() {
+            // Region so that (x, y) maps onto itself: view = (x - ox) * 2
+            self.origin = Some(clamp_origin(x / 2, y / 2));
+        }
+    }
+
+    pub fn end(&mut self) {
+        self.origin = None;
+    }
+
+    /// View (screen) position → logical game position
+    pub fn to_logical(&self, vx: i32, vy: i32) -> (i32, i32) {
+        match self.origin {
+            Some((ox, oy)) => (ox + vx / 2, oy + vy / 2),
+            None => (vx, vy),
+        }
+    }
+
+    /// Scroll the region while the cursor is at the edge of the view
+    pub fn scroll(&mut self, vx: i32, vy: i32) {
+        let Some((ox, oy)) = self.origin else { return };
+        let dx = if vx < EDGE { -SCROLL } else if vx >= W - EDGE { SCROLL } else { 0 };
+        let dy = if vy < EDGE { -SCROLL } else if vy >= H - EDGE { SCROLL } else { 0 };
+        self.origin = Some(clamp_origin(ox + dx, oy + dy));
+    }
+
+    /// Magnify the region of `src` into `dst` (both 640×480)
+    pub fn apply(&self, src: &[u32], dst: &mut [u32]) {
+        let Some((ox, oy)) = self.origin else {
+            dst.copy_from_slice(src);
+            return;
+        };
+        for (y, row) in dst.chunks_exact_mut(SCREEN_WIDTH).enumerate() {
+            let sy = oy as usize + y / 2;
+            let src_row = &src[sy * SCREEN_WIDTH + ox as usize..][..REGION_W as usize];
+            for (x, px) in row.iter_mut().enumerate() {
+                *px = src_row[x / 2];
+            }
+        }
+    }
+}
+
+fn clamp_origin(x: i32, y: i32) -> (i32, i32) {
+    (x.clamp(0, W - REGION_W), y.clamp(0, H - REGION_H))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_stays_on_its_pixel() {
+        let mut lens = ZoomLens::default();
+        lens.begin(300, 200);
+        assert_eq!(lens.to_logical(300, 200), (300, 200));
+        assert_eq!(lens.to_logical(302, 200), (301, 200));
+
+        lens.end();
+        lens.begin(638, 478);
+        assert_eq!(lens.to_logical(638, 478), (638, 478));
+        assert_eq!(lens.to_logical(0, 0), (319, 239));
+    }
+
+    #[test]
+    fn edge_scrolls_region() {
+        let mut lens = ZoomLens::default();
+        lens.begin(320, 240);
+        let before = lens.to_logical(0, 0);
+        lens.scroll(W - 1, 240);
+        assert_eq!(lens.to_logical(0, 0), (before.0 + SCROLL, before.1));
+        for _ in 0..200 {
+            lens.scroll(0, 0);
+        }
+        assert_eq!(lens.to_logical(0, 0), (0, 0));
+    }
+
+    #[test]
+    fn apply_doubles_pixels() {
+        let src: Vec<u32> = (0..(W * H) as u32).collect();
+        let mut dst = vec![0u32; src.len()];
+        let mut lens = ZoomLens::default();
+        lens.apply(&src, &mut dst);
+        assert_eq!(dst, src);
+
+        lens.begin(0, 0);
+        lens.apply(&src, &mut dst);
+        assert_eq!(dst[0], 0);
+        assert_eq!(dst[1], 0);
+        assert_eq!(dst[2], 1);
+        assert_eq!(dst[SCREEN_WIDTH * 2 + 2], SCREEN_WIDTH as u32 + 1);
+    }
+}
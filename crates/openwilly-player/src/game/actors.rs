@@ -0,0 +1,232 @@
+//! Actor definitions — scene characters from data instead of code
+//!
+//! Which characters a scene has (Mulle, Figge, Salka, the judge, …), where
+//! they stand, their animations and talk/silence pair come from a table
+//! (data/actors.json, replaced by `<game_dir>/mods/actors.json`). Scenes
+//! are keyed by `Scene`'s Debug name; destinations without an entry use
+//! "Destination". "YardDog" is spawned by the game when Salka is with
+//! Mulle. Entries may start from a template and override single fields.
+//! Idle animations are configured separately (`idle_behavior`), by actor
+//! name.
+//!
+//! Frames are member numbers of the actor's file, or member names for
+//! casts where numbers differ between versions. An `optional` actor is
+//! left out when one of its animations finds no frames.
+//!
+//! Not every name is confirmed against a cast listing. The Saftfabrik
+//! conveyor (87b020v0–87b025v0) and tap (87b030v0–87b034v0) follow the
+//! casts' `<file>b<nnn>v0` bitmap naming but were not read from 87.DXR,
+//! which is why these actors are `optional`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// A frame: member number, or member name
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum FrameRef {
+    Member(u32),
+    Name(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnimDef {
+    pub name: String,
+    pub frames: Vec<FrameRef>,
+    /// 0 = one frame per score frame (scene tempo)
+    #[serde(default = "default_fps")]
+    pub fps: u32,
+    #[serde(rename = "loop", default)]
+    pub looping: bool,
+}
+
+fn default_fps() -> u32 {
+    10
+}
+
+/// One actor, every field optional so entries can override a template
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ActorDef {
+    /// Template (in `templates`) this entry starts from
+    pub template: Option<String>,
+    pub name: Option<String>,
+    /// Director file: a full name ("00.CXT") or a stem ("94") resolved
+    /// like the scene's own files
+    pub file: Option<String>,
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    pub z: Option<i32>,
+    pub visible: Option<bool>,
+    pub mouse_track: Option<bool>,
+    /// Talk / silence animations for lip-sync cue points
+    pub talk: Option<String>,
+    pub silence: Option<String>,
+    /// Animation at scene start (default "idle", else the first)
+    pub play: Option<String>,
+    pub optional: Option<bool>,
+    pub animations: Vec<AnimDef>,
+}
+
+impl ActorDef {
+    /// Fields missing here taken from `base`
+    fn over(&self, base: &ActorDef) -> ActorDef {
+        ActorDef {
+            template: None,
+            name: self.name.clone().or_else(|| base.name.clone()),
+            file: self.file.clone().or_else(|| base.file.clone()),
+            x: self.x.or(base.x),
+            y: self.y.or(base.y),
+            z: self.z.or(base.z),
+            visible: self.visible.or(base.visible),
+            mouse_track: self.mouse_track.or(base.mouse_track),
+            talk: self.talk.clone().or_else(|| base.talk.clone()),
+            silence: self.silence.clone().or_else(|| base.silence.clone()),
+            play: self.play.clone().or_else(|| base.play.clone()),
+            optional: self.optional.or(base.optional),
+            animations: if self.animations.is_empty() {
+                base.animations.clone()
+            } else {
+                self.animations.clone()
+            },
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+    }
+
+    pub fn start_anim(&self) -> Option<&str> {
+        self.play.as_deref()
+            .or_else(|| self.animations.iter().find(|a| a.name == "idle").map(|a| a.name.as_str()))
+            .or_else(|| self.animations.first().map(|a| a.name.as_str()))
+    }
+
+    fn has_anim(&self, name: &str) -> bool {
+        self.animations.iter().any(|a| a.name == name)
+    }
+
+    /// What is wrong with a merged entry (empty = usable)
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let name = self.name();
+        if name.is_empty() {
+            problems.push("actor without a name".to_string());
+        }
+        if self.file.is_none() || self.x.is_none() || self.y.is_none() {
+            problems.push(format!("{}: file and position needed", name));
+        }
+        if self.animations.is_empty() {
+            problems.push(format!("{}: no animations", name));
+        }
+        for anim in [&self.play, &self.talk, &self.silence].into_iter().flatten() {
+            if !self.has_anim(anim) {
+                problems.push(format!("{}: unknown animation '{}'", name, anim));
+            }
+        }
+        for anim in self.animations.iter().filter(|a| a.frames.is_empty()) {
+            problems.push(format!("{}: '{}' has no frames", name, anim.name));
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ActorTable {
+    #[serde(default)]
+    templates: HashMap<String, ActorDef>,
+    /// Scene names as in `Scene`'s Debug output ("Garage", "Destination(86)", …)
+    #[serde(default)]
+    scenes: HashMap<String, Vec<ActorDef>>,
+}
+
+impl ActorTable {
+    /// Built-in table, replaced by `<game_dir>/mods/actors.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("actors.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str::<Self>(&json) {
+                Ok(table) => {
+                    for problem in table.problems() {
+                        tracing::warn!("{}: {}", path.display(), problem);
+                    }
+                    tracing::info!("Actors loaded from {}", path.display());
+                    return table;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/actors.json"))
+            .expect("Failed to parse actors.json")
+    }
+
+    /// Merged actor entries of a scene; destinations fall back to the
+    /// "Destination" list. Unusable entries are logged and left out.
+    pub fn actors(&self, scene: &str) -> Vec<ActorDef> {
+        let list = self.scenes.get(scene)
+            .or_else(|| scene.starts_with("Destination(").then(|| self.scenes.get("Destination")).flatten());
+        list.into_iter().flatten()
+            .filter_map(|def| {
+                let merged = self.merge(def);
+                let problems = merged.problems();
+                for problem in &problems {
+                    tracing::warn!("Actor in {}: {}", scene, problem);
+                }
+                problems.is_empty().then_some(merged)
+            })
+            .collect()
+    }
+
+    fn merge(&self, def: &ActorDef) -> ActorDef {
+        match def.template.as_deref().and_then(|t| self.templates.get(t)) {
+            Some(template) => def.over(template),
+            None => def.over(&ActorDef::default()),
+        }
+    }
+
+    /// Every problem in the table (checked for mods when loading)
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (scene, defs) in &self.scenes {
+            let mut names = HashSet::new();
+            for def in defs {
+                if let Some(template) = &def.template {
+                    if !self.templates.contains_key(template) {
+                        problems.push(format!("{}: unknown template '{}'", scene, template));
+                    }
+                }
+                let merged = self.merge(def);
+                if !names.insert(merged.name().to_string()) {
+                    problems.push(format!("{}: actor '{}' twice", scene, merged.name()));
+                }
+                problems.extend(merged.problems().into_iter().map(|p| format!("{}: {}", scene, p)));
+            }
+        }
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_table_is_consistent() {
+        let table = ActorTable::builtin();
+        assert_eq!(table.problems(), Vec::<String>::new());
+
+        // Template fields with the entry's overrides
+        let dog = &table.actors("YardDog")[0];
+        assert_eq!((dog.name(), dog.x, dog.file.as_deref()), ("salkaYard", Some(150), Some("85")));
+        let mulle = &table.actors("Destination(90)")[0];
+        assert_eq!((mulle.name(), mulle.talk.as_deref()), ("mulleDefault", Some("talkRegular")));
+        assert_eq!(table.actors("CarShow")[1].start_anim(), Some("lookLeft"));
+        assert!(table.actors("World").is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! Ambient mix — background loop, volume, fades and dialog ducking per scene
+//!
+//! The table (data/ambience.json, replaced by `<game_dir>/mods/ambience.json`)
+//! maps scenes to their background loop. Every entry may override the
+//! default mix: loop volume, fade-in of the new loop, fade-out of the loop
+//! it replaces, and how far the loop ducks while someone talks. Scenes
+//! without an entry (Menu, Garage, destinations 84 and 87, …) fade out
+//! whatever was playing. Yard and Junkyard share a loop, which keeps
+//! running between them.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::engine::sound_engine::BgMix;
+use crate::game::Scene;
+
+/// Mix fields, all optional so scene entries only list what they change
+#[derive(Debug, Clone, Default, Deserialize)]
+struct MixDef {
+    #[serde(rename = "loop")]
+    loop_name: Option<String>,
+    volume: Option<f32>,
+    fade_in_ms: Option<u32>,
+    fade_out_ms: Option<u32>,
+    duck: Option<f32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Ambience {
+    #[serde(default)]
+    default: MixDef,
+    /// Scene names as in `Scene`'s Debug output ("Yard", "Destination(85)", …)
+    #[serde(default)]
+    scenes: HashMap<String, MixDef>,
+}
+
+impl Ambience {
+    /// Built-in table, replaced by `<game_dir>/mods/ambience.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("ambience.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(ambience) => {
+                    tracing::info!("Ambient mix loaded from {}", path.display());
+                    return ambience;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/ambience.json"))
+            .expect("Failed to parse ambience.json")
+    }
+
+    /// Background loop of a scene ("" = none) and its mix
+    pub fn mix(&self, scene: Scene) -> (&str, BgMix) {
+        let entry = self.scenes.get(&format!("{:?}", scene));
+        let pick = |f: fn(&MixDef) -> Option<f32>| entry.and_then(f).or_else(|| f(&self.default));
+        let pick_ms = |f: fn(&MixDef) -> Option<u32>| entry.and_then(f).or_else(|| f(&self.default));
+        let fallback = BgMix::default();
+        let mix = BgMix {
+            volume: pick(|m| m.volume).unwrap_or(fallback.volume).clamp(0.0, 1.0),
+            fade_in_ms: pick_ms(|m| m.fade_in_ms).unwrap_or(fallback.fade_in_ms),
+            fade_out_ms: pick_ms(|m| m.fade_out_ms).unwrap_or(fallback.fade_out_ms),
+            duck: pick(|m| m.duck).unwrap_or(fallback.duck).clamp(0.0, 1.0),
+        };
+        let loop_name = entry.and_then(|m| m.loop_name.as_deref()).unwrap_or("");
+        (loop_name, mix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_table() {
+        let ambience = Ambience::builtin();
+        let (yard, _) = ambience.mix(Scene::Yard);
+        let (junkyard, _) = ambience.mix(Scene::Junkyard);
+        assert_eq!(yard, "02e010v0");
+        assert_eq!(yard, junkyard);
+        assert_eq!(ambience.mix(Scene::Destination(85)).0, "85e001v0");
+        assert_eq!(ambience.mix(Scene::Garage).0, "");
+    }
+
+    #[test]
+    fn scene_entries_override_the_default() {
+        let ambience: Ambience = serde_json::from_str(r#"{
+            "default": { "volume": 0.6, "fade_in_ms": 1000, "duck": 0.4 },
+            "scenes": { "Destination(92)": { "loop": "92e002v0", "duck": 0.2 } }
+        }"#).unwrap();
+        let (name, mix) = ambience.mix(Scene::Destination(92));
+        assert_eq!(name, "92e002v0");
+        assert_eq!(mix, BgMix { volume: 0.6, fade_in_ms: 1000, fade_out_ms: 0, duck: 0.2 });
+        assert_eq!(ambience.mix(Scene::Menu).1.duck, 0.4);
+    }
+}
@@ -0,0 +1,314 @@
+//! Startup asset check — spot damaged Director files before a scene needs them
+//!
+//! Bad rips are usually cut off: the chunk table at the start still parses,
+//! the missing members only show up as invisible sprites or a crash deep in
+//! some scene. Each Director file is checked as soon as it is loaded (with
+//! ISO streaming that happens gradually in the background): truncation
+//! against the RIFX header, chunks past the end, and — from the manifest
+//! (`data/asset_manifest.json`, replaced by `<game_dir>/mods/asset_manifest.json`)
+//! of the detected game version — missing files, member counts and CRC-32.
+//! Damaged files are logged and listed in a notice that suggests
+//! re-extracting them. `OPENWILLY_WRITE_MANIFEST=<file>` writes a manifest
+//! entry from a known-good installation.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::{director::DirectorFile, AssetStore};
+use crate::engine::{font, SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::game::i18n::{self, Language};
+
+/// Files listed in the notice (the log has all of them)
+const MAX_LISTED: usize = 8;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileSpec {
+    /// Cast members a good copy has
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<usize>,
+    /// CRC-32 of a good copy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crc32: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameVersion {
+    pub name: String,
+    /// Files that identify this version (all present)
+    pub detect: Vec<String>,
+    pub files: BTreeMap<String, FileSpec>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    pub versions: Vec<GameVersion>,
+}
+
+impl Manifest {
+    /// Built-in manifest, replaced by `<game_dir>/mods/asset_manifest.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("asset_manifest.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(manifest) => {
+                    tracing::info!("Asset manifest loaded from {}", path.display());
+                    return manifest;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/asset_manifest.json"))
+            .expect("Failed to parse asset_manifest.json")
+    }
+}
+
+/// Manifest entry describing the loaded (known-good) files
+pub fn version_from_assets(name: &str, assets: &AssetStore) -> GameVersion {
+    let files = assets.files.iter()
+        .map(|(file, df)| (file.to_uppercase(), FileSpec {
+            members: Some(df.cast_members.len()),
+            crc32: df.integrity.crc32(),
+        }))
+        .collect();
+    GameVersion { name: name.to_string(), detect: vec!["00.CXT".into(), "18.DXR".into()], files }
+}
+
+/// Write `version_from_assets` as a one-version manifest
+pub fn write_manifest(assets: &AssetStore, path: &Path) -> Result<()> {
+    let manifest = Manifest { versions: vec![version_from_assets("local", assets)] };
+    std::fs::write(path, serde_json::to_string_pretty(&manifest)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Damage {
+    Missing,
+    Unreadable(String),
+    Truncated { expected: u64, actual: u64 },
+    ChunksPastEnd(usize),
+    FewMembers { expected: usize, found: usize },
+    Checksum,
+}
+
+impl Damage {
+    fn describe(&self, lang: Language) -> String {
+        match self {
+            Damage::Missing => i18n::t(lang, "damage_missing").to_string(),
+            Damage::Unreadable(_) => i18n::t(lang, "damage_unreadable").to_string(),
+            Damage::Truncated { expected, actual } => {
+                format!("{} ({} / {} KB)", i18n::t(lang, "damage_truncated"), actual / 1024, expected / 1024)
+            }
+            Damage::ChunksPastEnd(n) => format!("{} ({})", i18n::t(lang, "damage_chunks"), n),
+            Damage::FewMembers { expected, found } => {
+                format!("{} ({} / {})", i18n::t(lang, "damage_members"), found, expected)
+            }
+            Damage::Checksum => i18n::t(lang, "damage_checksum").to_string(),
+        }
+    }
+}
+
+/// Structural check plus the manifest entry, worst finding first
+fn check_file(df: &DirectorFile, spec: Option<&FileSpec>) -> Option<Damage> {
+    let integrity = &df.integrity;
+    if integrity.is_truncated() {
+        return Some(Damage::Truncated { expected: integrity.declared_len, actual: integrity.file_len });
+    }
+    if integrity.chunks_out_of_range > 0 {
+        return Some(Damage::ChunksPastEnd(integrity.chunks_out_of_range));
+    }
+    let spec = spec?;
+    if let Some(expected) = spec.members.filter(|&m| df.cast_members.len() < m) {
+        return Some(Damage::FewMembers { expected, found: df.cast_members.len() });
+    }
+    if spec.crc32.is_some_and(|crc| integrity.crc32().is_some_and(|actual| actual != crc)) {
+        return Some(Damage::Checksum);
+    }
+    None
+}
+
+#[derive(Default)]
+pub struct AssetCheck {
+    manifest: Manifest,
+    /// Index into the manifest
+    version: Option<usize>,
+    /// Version detection done (files are only checked after it)
+    detected: bool,
+    checked: HashSet<String>,
+    missing_checked: bool,
+    pub damaged: Vec<(String, Damage)>,
+    /// Notice shown (closed by a click, reopens for new findings)
+    pub open: bool,
+}
+
+impl AssetCheck {
+    pub fn new(game_dir: &Path) -> Self {
+        Self { manifest: Manifest::load(game_dir), ..Default::default() }
+    }
+
+    fn spec(&self, file: &str) -> Option<&FileSpec> {
+        let version = &self.manifest.versions[self.version?];
+        version.files.get(&file.to_uppercase())
+    }
+
+    fn report(&mut self, file: String, damage: Damage) {
+        tracing::error!("Damaged game file {}: {:?}", file, damage);
+        self.damaged.push((file, damage));
+        self.open = true;
+    }
+
+    /// Check files loaded since the last call. Cheap when nothing is new,
+    /// call once per frame.
+    pub fn update(&mut self, assets: &AssetStore) {
+        if !self.detected {
+            self.version = self.manifest.versions.iter().position(|v| {
+                v.detect.iter().all(|f| assets.files.keys().any(|k| k.eq_ignore_ascii_case(f)))
+            });
+            // Detection files may still be on their way from the ISO
+            self.detected = self.version.is_some() || !assets.is_loading();
+            if !self.detected {
+                return;
+            }
+            match self.version {
+                Some(v) => tracing::info!("Game version: {}", self.manifest.versions[v].name),
+                None => tracing::info!("Game version not in the asset manifest, only structural checks"),
+            }
+        }
+
+        let new: Vec<&String> = assets.files.keys().filter(|f| !self.checked.contains(*f)).collect();
+        for file in new {
+            self.checked.insert(file.clone());
+            if let Some(damage) = check_file(&assets.files[file], self.spec(file)) {
+                self.report(file.clone(), damage);
+            }
+        }
+        for (file, error) in &assets.failed {
+            if self.checked.insert(file.clone()) {
+                self.report(file.clone(), Damage::Unreadable(error.clone()));
+            }
+        }
+
+        if !self.missing_checked && !assets.is_loading() {
+            self.missing_checked = true;
+            let Some(v) = self.version else { return };
+            let missing: Vec<String> = self.manifest.versions[v].files.keys()
+                .filter(|f| !assets.files.keys().any(|k| k.eq_ignore_ascii_case(f)))
+                .filter(|f| !self.checked.iter().any(|k| k.eq_ignore_ascii_case(f)))
+                .cloned()
+                .collect();
+            for file in missing {
+                self.report(file, Damage::Missing);
+            }
+        }
+    }
+
+    pub fn draw(&self, fb: &mut [u32], lang: Language) {
+        if !self.open || self.damaged.is_empty() {
+            return;
+        }
+        let title = i18n::t(lang, "assets_damaged_title");
+        let mut lines: Vec<String> = self.damaged.iter().take(MAX_LISTED)
+            .map(|(file, damage)| format!("{}: {}", file, damage.describe(lang)))
+            .collect();
+        if self.damaged.len() > MAX_LISTED {
+            lines.push(format!("... +{}", self.damaged.len() - MAX_LISTED));
+        }
+        lines.push(String::new());
+        lines.extend(i18n::t(lang, "assets_damaged_fix").split('\n').map(str::to_string));
+        let hint = i18n::t(lang, "report_hint");
+
+        let w = lines.iter().map(|l| font::text_width(l))
+            .chain([font::text_width(title)])
+            .max()
+            .unwrap_or(0)
+            .clamp(280, SCREEN_WIDTH as i32 - 40)
+            + 32;
+        let h = 76 + lines.len() as i32 * 14;
+        let x = (SCREEN_WIDTH as i32 - w) / 2;
+        let y = (SCREEN_HEIGHT as i32 - h) / 2;
+        font::draw_rect(fb, x, y, w, h, 0xEE2E1A1A);
+        font::draw_rect_outline(fb, x, y, w, h, 0xFFFF6666);
+        font::draw_text_shadow(fb, x + (w - font::text_width(title)) / 2, y + 12, title, 0xFFFF6666);
+        for (i, line) in lines.iter().enumerate() {
+            font::draw_text_shadow(fb, x + 16, y + 36 + i as i32 * 14, line, 0xFFFFFFFF);
+        }
+        font::draw_text(fb, x + (w - font::text_width(hint)) / 2, y + h - 20, hint, 0xFF999999);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(files: BTreeMap<String, DirectorFile>, failed: Vec<(String, String)>) -> AssetStore {
+        AssetStore { files, game_dir: std::env::temp_dir(), failed, pending: None, missing: Default::default(), edition: None }
+    }
+
+    #[test]
+    fn builtin_manifest_parses() {
+        let manifest = Manifest::builtin();
+        assert!(!manifest.versions.is_empty());
+        for v in &manifest.versions {
+            assert!(v.detect.iter().all(|f| v.files.contains_key(f)), "{}", v.name);
+        }
+    }
+
+    #[test]
+    fn reports_missing_and_unreadable_files() {
+        let mut check = AssetCheck {
+            manifest: Manifest {
+                versions: vec![GameVersion {
+                    name: "test".into(),
+                    detect: vec![],
+                    files: BTreeMap::from([("05.DXR".into(), FileSpec::default()), ("00.CXT".into(), FileSpec::default())]),
+                }],
+            },
+            ..Default::default()
+        };
+        let assets = store(BTreeMap::new(), vec![("00.CXT".into(), "Not a Director file".into())]);
+        check.update(&assets);
+        check.update(&assets);
+        assert_eq!(check.damaged, [
+            ("00.CXT".to_string(), Damage::Unreadable("Not a Director file".into())),
+            ("05.DXR".to_string(), Damage::Missing),
+        ]);
+        assert!(check.open);
+    }
+
+    #[test]
+    fn manifest_checks() {
+        let spec = FileSpec { members: Some(3), crc32: None };
+        // Empty cast: header, imap, mmap without entries
+        let mut file = b"RIFX".to_vec();
+        file.extend_from_slice(&52u32.to_be_bytes());
+        file.extend_from_slice(b"MC95imap");
+        file.extend_from_slice(&8u32.to_le_bytes());
+        file.extend_from_slice(&1u32.to_be_bytes());
+        file.extend_from_slice(&28u32.to_be_bytes());
+        file.extend_from_slice(b"mmap");
+        file.extend_from_slice(&24u32.to_le_bytes());
+        file.extend_from_slice(&[0; 24]);
+        let path = std::env::temp_dir().join(format!("openwilly_manifest_{}.cst", std::process::id()));
+        std::fs::write(&path, &file).unwrap();
+        let df = DirectorFile::parse(&path).unwrap();
+        assert_eq!(check_file(&df, None), None);
+        assert_eq!(check_file(&df, Some(&spec)), Some(Damage::FewMembers { expected: 3, found: 0 }));
+        let crc = df.integrity.crc32().unwrap();
+        let spec = FileSpec { members: None, crc32: Some(crc ^ 1) };
+        assert_eq!(check_file(&df, Some(&spec)), Some(Damage::Checksum));
+        let spec = FileSpec { members: None, crc32: Some(crc) };
+        assert_eq!(check_file(&df, Some(&spec)), None);
+        std::fs::remove_file(&path).unwrap();
+
+        // Bytes without a source can't be checksummed, so they pass
+        let df = DirectorFile::parse_bytes("X.CST".into(), file).unwrap();
+        assert_eq!(df.integrity.crc32(), None);
+        assert_eq!(check_file(&df, Some(&spec)), None);
+    }
+}
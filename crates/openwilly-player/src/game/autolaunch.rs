@@ -0,0 +1,144 @@
+//! Autolaunch — start straight in a scene (screenshots, videos, testing)
+//!
+//! ```text
+//! openwilly-player [GAME_DIR|ISO] [--scene garage] [--profile demo] [--car blueprint.json]
+//! ```
+//!
+//! The game path may also be given as `--game-dir PATH`.
+//! `--scene` skips the menu (and the transition cutscene) and enters the
+//! scene with `--profile` logged in ("default" like an empty name in the
+//! menu). `--car` replaces the profile's car: a `.willycar` exchange file
+//! or a JSON blueprint (`{ "parts": [1, 82, 133, ...] }`, a Car Gallery
+//! entry works too). Flags also take `--flag=value`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+
+use crate::game::parts_db::PartsDB;
+use crate::game::{willycar, Scene};
+
+pub const USAGE: &str =
+    "openwilly-player [GAME_DIR|ISO|--game-dir PATH] [--scene NAME] [--profile NAME] [--car FILE]\n\
+     scenes: menu, garage, junkyard, yard, world, cargallery, carshow, destination:82..94";
+
+/// Command line of the player
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LaunchArgs {
+    /// Game directory or ISO (first plain argument)
+    pub game_path: Option<String>,
+    pub scene: Option<Scene>,
+    pub profile: Option<String>,
+    pub car: Option<PathBuf>,
+}
+
+impl LaunchArgs {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut launch = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                ensure!(launch.game_path.is_none(), "unexpected argument '{}'\n{}", arg, USAGE);
+                launch.game_path = Some(arg);
+                continue;
+            };
+            let (flag, value) = match flag.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), value.to_string()),
+                None => {
+                    let value = args.next().with_context(|| format!("--{} needs a value\n{}", flag, USAGE))?;
+                    (flag.to_string(), value)
+                }
+            };
+            match flag.as_str() {
+                "game-dir" => launch.game_path = Some(value),
+                "scene" => launch.scene = Some(parse_scene(&value)?),
+                "profile" => launch.profile = Some(value),
+                "car" => launch.car = Some(PathBuf::from(value)),
+                other => bail!("unknown option --{}\n{}", other, USAGE),
+            }
+        }
+        Ok(launch)
+    }
+
+    /// Anything to do besides loading the game data
+    pub fn is_autolaunch(&self) -> bool {
+        self.scene.is_some() || self.profile.is_some() || self.car.is_some()
+    }
+}
+
+/// Scene by name, case-insensitive ("garage", "destination:86")
+pub fn parse_scene(name: &str) -> Result<Scene> {
+    let name = name.to_ascii_lowercase();
+    Ok(match name.as_str() {
+        "menu" => Scene::Menu,
+        "garage" => Scene::Garage,
+        "junkyard" => Scene::Junkyard,
+        "yard" => Scene::Yard,
+        "world" => Scene::World,
+        "cargallery" => Scene::CarGallery,
+        "carshow" => Scene::CarShow,
+        _ => {
+            let Some(num) = name.strip_prefix("destination") else {
+                bail!("unknown scene '{}'\n{}", name, USAGE);
+            };
+            let n: u8 = num.trim_start_matches(':').parse()
+                .with_context(|| format!("destination needs a number (82..94), got '{}'", name))?;
+            ensure!((82..=94).contains(&n), "no destination {}", n);
+            Scene::Destination(n)
+        }
+    })
+}
+
+/// JSON blueprint: only the parts are used
+#[derive(Deserialize)]
+struct Blueprint {
+    parts: Vec<u32>,
+}
+
+/// Part IDs of a car file; parts the PartsDB doesn't know are left out
+pub fn load_car(path: &Path, parts_db: &PartsDB) -> Result<Vec<u32>> {
+    let bytes = std::fs::read(path).with_context(|| format!("Reading {}", path.display()))?;
+    let is_willycar = path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case(willycar::EXTENSION));
+    let parts = if is_willycar {
+        willycar::WillyCar::from_bytes(&bytes, parts_db)?.entry.parts
+    } else {
+        serde_json::from_slice::<Blueprint>(&bytes)
+            .with_context(|| format!("Parsing {}", path.display()))?
+            .parts
+    };
+    let (known, unknown): (Vec<u32>, Vec<u32>) = parts.into_iter()
+        .partition(|id| parts_db.get(*id).is_some());
+    if !unknown.is_empty() {
+        tracing::warn!("{}: unknown parts {:?} left out", path.display(), unknown);
+    }
+    Ok(known)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<LaunchArgs> {
+        LaunchArgs::parse(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parses_flags_and_game_path() {
+        let launch = args("D:\\ --scene Garage --profile=demo --car car.json").unwrap();
+        assert_eq!(launch.game_path.as_deref(), Some("D:\\"));
+        assert_eq!(launch.scene, Some(Scene::Garage));
+        assert_eq!(launch.profile.as_deref(), Some("demo"));
+        assert_eq!(launch.car, Some(PathBuf::from("car.json")));
+        assert!(launch.is_autolaunch());
+
+        assert!(!args("game.iso").unwrap().is_autolaunch());
+        assert_eq!(args("--game-dir C:\\Willy").unwrap().game_path.as_deref(), Some("C:\\Willy"));
+        assert_eq!(args("--scene destination:86").unwrap().scene, Some(Scene::Destination(86)));
+        assert!(args("--scene destination:95").is_err());
+        assert!(args("--scene").is_err());
+        assert!(args("--fast 1").is_err());
+        assert!(args("a b").is_err());
+    }
+}
@@ -0,0 +1,160 @@
+//! Profile avatars — a small picture of each profile's current car
+//!
+//! Shown next to the profile names on the main menu and next to the
+//! profile name above the Car Gallery. The thumbnail is composited
+//! off-screen from a BuildCar (like the gallery card export) and cached as
+//! `<game_dir>/thumbnails/<profile>_<key>.png`; the key is a CRC-32 of the
+//! part list, so a changed car gets a new file (the profile's old ones are
+//! removed) and an unchanged one is read back instead of rebuilt.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::assets::AssetStore;
+use crate::engine::canvas::Canvas;
+use crate::game::build_car::BuildCar;
+use crate::game::gallery;
+use crate::game::parts_db::PartsDB;
+
+pub const THUMB_WIDTH: usize = 40;
+pub const THUMB_HEIGHT: usize = 20;
+/// Paper colour behind the car (same as the gallery cards)
+const THUMB_BG: u32 = 0xFFF5E6C8;
+
+/// Cache key of a part list
+pub fn parts_key(parts: &[u32]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    for part in parts {
+        crc.update(&part.to_le_bytes());
+    }
+    crc.sum()
+}
+
+/// Composite the car off-screen and scale it into a thumbnail. None if
+/// none of the part bitmaps could be decoded (assets still loading).
+pub fn render(parts: &[u32], parts_db: &PartsDB, assets: &AssetStore) -> Option<Canvas> {
+    let mut car = BuildCar::new(0, 0);
+    car.parts = parts.to_vec();
+    car.refresh(parts_db, assets);
+    let mut sprites = car.all_sprites();
+    let (min_x, min_y, max_x, max_y) = gallery::sprites_bbox(&sprites)?;
+    // Pad the bounding box to the thumbnail's aspect ratio, car centered
+    let (w, h) = (max_x - min_x, max_y - min_y);
+    let cw = w.max(h * THUMB_WIDTH as i32 / THUMB_HEIGHT as i32) + 4;
+    let ch = h.max(w * THUMB_HEIGHT as i32 / THUMB_WIDTH as i32) + 2;
+    let mut canvas = Canvas::new(cw as usize, ch as usize, THUMB_BG);
+    for sprite in &mut sprites {
+        sprite.x += (cw - w) / 2 - min_x;
+        sprite.y += (ch - h) / 2 - min_y;
+        canvas.blit(sprite);
+    }
+    Some(canvas.scaled_down(THUMB_WIDTH, THUMB_HEIGHT))
+}
+
+/// Safe file stem for a profile name
+fn file_stem(profile: &str) -> String {
+    profile.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+pub struct Avatars {
+    dir: PathBuf,
+    /// profile → (parts key, thumbnail)
+    thumbs: HashMap<String, (u32, Canvas)>,
+}
+
+impl Avatars {
+    pub fn new(game_dir: &Path) -> Self {
+        Self { dir: game_dir.join("thumbnails"), thumbs: HashMap::new() }
+    }
+
+    fn path(&self, profile: &str, key: u32) -> PathBuf {
+        self.dir.join(format!("{}_{:08x}.png", file_stem(profile), key))
+    }
+
+    /// Thumbnail of a profile's car: from memory, from the disk cache, or
+    /// freshly rendered (and cached) when the parts changed
+    pub fn get(&mut self, profile: &str, parts: &[u32], parts_db: &PartsDB, assets: &AssetStore) -> Option<&Canvas> {
+        let key = parts_key(parts);
+        if self.thumbs.get(profile).map_or(true, |(k, _)| *k != key) {
+            let path = self.path(profile, key);
+            let thumb = match Canvas::load_png(&path) {
+                Ok(thumb) if (thumb.width, thumb.height) == (THUMB_WIDTH, THUMB_HEIGHT) => thumb,
+                _ => {
+                    let thumb = render(parts, parts_db, assets)?;
+                    self.store(profile, key, &thumb);
+                    thumb
+                }
+            };
+            self.thumbs.insert(profile.to_string(), (key, thumb));
+        }
+        self.thumbs.get(profile).map(|(_, thumb)| thumb)
+    }
+
+    /// Write the thumbnail, removing the profile's outdated ones
+    fn store(&self, profile: &str, key: u32, thumb: &Canvas) {
+        let path = self.path(profile, key);
+        let prefix = format!("{}_", file_stem(profile));
+        if let Ok(entries) = std::fs::read_dir(&self.dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                // <stem>_<8 hex digits>.png of this profile only
+                let is_old = name.strip_prefix(&prefix)
+                    .and_then(|rest| rest.strip_suffix(".png"))
+                    .is_some_and(|k| k.len() == 8 && k.chars().all(|c| c.is_ascii_hexdigit()));
+                if is_old && entry.path() != path {
+                    std::fs::remove_file(entry.path()).ok();
+                }
+            }
+        }
+        let result = std::fs::create_dir_all(&self.dir)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| thumb.save_png(&path));
+        match result {
+            Ok(()) => tracing::debug!("Avatar for '{}' cached at {}", profile, path.display()),
+            Err(e) => tracing::warn!("Cannot cache avatar for '{}': {}", profile, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_follows_parts() {
+        assert_eq!(parts_key(&[1, 82, 133]), parts_key(&[1, 82, 133]));
+        assert_ne!(parts_key(&[1, 82, 133]), parts_key(&[1, 82, 134]));
+        assert_ne!(parts_key(&[1, 82]), parts_key(&[82, 1]));
+    }
+
+    #[test]
+    fn cached_thumbnail_is_reused_and_replaced() {
+        let dir = std::env::temp_dir().join(format!("openwilly_avatars_{}", std::process::id()));
+        let mut avatars = Avatars::new(&dir);
+        let old = Canvas::new(THUMB_WIDTH, THUMB_HEIGHT, 0xFF112233);
+        avatars.store("Anna", parts_key(&[1]), &old);
+        avatars.store("Anna B", parts_key(&[1]), &old);
+
+        // No assets: only the disk cache can answer
+        let assets = AssetStore {
+            files: std::collections::BTreeMap::new(),
+            game_dir: dir.clone(),
+            failed: Vec::new(),
+            pending: None,
+            missing: Default::default(),
+            edition: None,
+        };
+        let parts_db = PartsDB::load();
+        let thumb = avatars.get("Anna", &[1], &parts_db, &assets).map(|t| t.pixels[0]);
+        assert_eq!(thumb, Some(0xFF112233));
+        assert!(avatars.get("Anna", &[1, 2], &parts_db, &assets).is_none());
+
+        // A new car replaces Anna's old file, Anna B's stays
+        avatars.store("Anna", parts_key(&[1, 2]), &old);
+        let files = std::fs::read_dir(avatars.dir.join("")).unwrap().count();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(files, 2);
+    }
+}
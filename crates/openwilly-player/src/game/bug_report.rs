@@ -0,0 +1,241 @@
+//! Bug reports — everything a maintainer needs in one zip
+//!
+//! The dev menu's "Fehler melden" bundles the recent log lines, a screenshot
+//! of the scene, the active save profile, the session's RNG seed and some
+//! system info into `reports/report_<timestamp>.zip` in the game directory.
+//! Nothing is uploaded; a dialog tells the player where the file is and
+//! where to attach it.
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Result;
+use zip::write::SimpleFileOptions;
+
+use crate::engine::canvas::Canvas;
+use crate::engine::font;
+use crate::engine::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::game::i18n::{self, Language};
+
+/// Where reports should be attached
+pub const ISSUES_URL: &str = "https://github.com/ThisLimn0/OpenWilly/issues";
+/// Log lines kept for reports
+const LOG_LINES: usize = 2000;
+
+// ─── Log ring buffer ────────────────────────────────────────────────────
+
+static LOG_RING: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// tracing writer: stdout as before, plus a copy in the ring buffer
+pub struct LogTee;
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for LogTee {
+    type Writer = LogTee;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogTee
+    }
+}
+
+impl Write for LogTee {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        record_log(&String::from_utf8_lossy(buf));
+        std::io::stdout().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        std::io::stdout().flush()
+    }
+}
+
+fn record_log(text: &str) {
+    let Ok(mut ring) = LOG_RING.lock() else { return };
+    for line in text.lines().filter(|l| !l.is_empty()) {
+        if ring.len() >= LOG_LINES {
+            ring.pop_front();
+        }
+        ring.push_back(strip_ansi(line));
+    }
+}
+
+/// Remove terminal color codes (ESC [ ... letter)
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// The last `n` log lines (debug HTTP endpoint)
+pub fn recent_log(n: usize) -> Vec<String> {
+    let Ok(ring) = LOG_RING.lock() else { return Vec::new() };
+    ring.iter().skip(ring.len().saturating_sub(n)).cloned().collect()
+}
+
+fn log_text() -> String {
+    let ring = LOG_RING.lock().map(|r| r.clone()).unwrap_or_default();
+    ring.into_iter().collect::<Vec<_>>().join("\n")
+}
+
+// ─── Report ─────────────────────────────────────────────────────────────
+
+/// Contents of a report (besides the log, which is global)
+pub struct BugReport<'a> {
+    /// Scene framebuffer (ARGB, 640×480)
+    pub screen: &'a [u32],
+    /// Active profile as JSON, if someone is logged in
+    pub save_json: Option<String>,
+    pub rng_seed: u64,
+    /// Free-form game state lines (scene, language, options)
+    pub game_info: Vec<String>,
+}
+
+impl BugReport<'_> {
+    /// Write the zip into `<game_dir>/reports/` and return its path
+    pub fn write(&self, game_dir: &Path) -> Result<PathBuf> {
+        let dir = reports_dir(game_dir);
+        std::fs::create_dir_all(&dir)?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let path = dir.join(format!("report_{}.zip", timestamp));
+        std::fs::write(&path, self.to_zip(&log_text())?)?;
+        Ok(path)
+    }
+
+    fn to_zip(&self, log: &str) -> Result<Vec<u8>> {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+
+        zip.start_file("info.txt", options)?;
+        zip.write_all(self.info_text().as_bytes())?;
+        zip.start_file("log.txt", options)?;
+        zip.write_all(log.as_bytes())?;
+        let screenshot = Canvas {
+            width: SCREEN_WIDTH,
+            height: SCREEN_HEIGHT,
+            pixels: self.screen.to_vec(),
+        };
+        zip.start_file("screenshot.png", options)?;
+        zip.write_all(&screenshot.encode_png()?)?;
+        if let Some(json) = &self.save_json {
+            zip.start_file("save.json", options)?;
+            zip.write_all(json.as_bytes())?;
+        }
+        Ok(zip.finish()?.into_inner())
+    }
+
+    fn info_text(&self) -> String {
+        let mut lines = vec![
+            format!("OpenWilly Player v{}", env!("CARGO_PKG_VERSION")),
+            format!("OS: {} ({})", std::env::consts::OS, std::env::consts::ARCH),
+            format!(
+                "CPU threads: {}",
+                std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+            ),
+            format!("RNG seed: {} (replay with OPENWILLY_SEED={})", self.rng_seed, self.rng_seed),
+        ];
+        lines.extend(self.game_info.iter().cloned());
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Report folder inside the game directory
+pub fn reports_dir(game_dir: &Path) -> PathBuf {
+    game_dir.join("reports")
+}
+
+// ─── Dialog ─────────────────────────────────────────────────────────────
+
+/// Result dialog: where the zip went (or why it failed). Any click or key
+/// closes it.
+pub struct ReportDialog {
+    lines: Vec<String>,
+}
+
+impl ReportDialog {
+    pub fn saved(path: &Path, lang: Language) -> Self {
+        Self {
+            lines: vec![
+                i18n::t(lang, "report_saved").to_string(),
+                path.display().to_string(),
+                String::new(),
+                i18n::t(lang, "report_attach").to_string(),
+                ISSUES_URL.to_string(),
+            ],
+        }
+    }
+
+    pub fn failed(error: &anyhow::Error, lang: Language) -> Self {
+        Self { lines: vec![i18n::t(lang, "report_failed").to_string(), format!("{:#}", error)] }
+    }
+
+    pub fn draw(&self, fb: &mut [u32], lang: Language) {
+        let title = i18n::t(lang, "report_title");
+        let hint = i18n::t(lang, "report_hint");
+        let w = self.lines.iter().map(|l| font::text_width(l))
+            .chain([font::text_width(title), font::text_width(hint)])
+            .max()
+            .unwrap_or(0)
+            .clamp(280, SCREEN_WIDTH as i32 - 20)
+            + 32;
+        let h = 76 + self.lines.len() as i32 * 14;
+        let x = (SCREEN_WIDTH as i32 - w) / 2;
+        let y = (SCREEN_HEIGHT as i32 - h) / 2;
+        font::draw_rect(fb, x, y, w, h, 0xEE1A1A2E);
+        font::draw_rect_outline(fb, x, y, w, h, 0xFFFFD23F);
+        font::draw_text_shadow(fb, x + (w - font::text_width(title)) / 2, y + 12, title, 0xFFFFD23F);
+        for (i, line) in self.lines.iter().enumerate() {
+            font::draw_text_shadow(fb, x + 16, y + 36 + i as i32 * 14, line, 0xFFFFFFFF);
+        }
+        font::draw_text(fb, x + (w - font::text_width(hint)) / 2, y + h - 20, hint, 0xFF999999);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn ansi_codes_are_stripped() {
+        assert_eq!(strip_ansi("\x1b[32m INFO\x1b[0m openwilly: hi"), " INFO openwilly: hi");
+    }
+
+    #[test]
+    fn zip_contains_all_parts() {
+        let screen = vec![0xFF336699u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        let report = BugReport {
+            screen: &screen,
+            save_json: Some("{\"user_id\":\"Test\"}".into()),
+            rng_seed: 42,
+            game_info: vec!["Scene: Garage".into()],
+        };
+        let bytes = report.to_zip("line 1\nline 2").unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        let mut names: Vec<&str> = zip.file_names().collect();
+        names.sort();
+        assert_eq!(names, ["info.txt", "log.txt", "save.json", "screenshot.png"]);
+
+        let mut info = String::new();
+        zip.by_name("info.txt").unwrap().read_to_string(&mut info).unwrap();
+        assert!(info.contains("RNG seed: 42") && info.contains("Scene: Garage"));
+        let mut png = Vec::new();
+        zip.by_name("screenshot.png").unwrap().read_to_end(&mut png).unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+    }
+}
@@ -44,6 +44,91 @@ pub struct PlacedPartSprite {
     pub layer: String,
 }
 
+/// Garage camera view — the turntable position the car is shown from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CarView {
+    #[default]
+    Side,
+    Front,
+    Top,
+}
+
+impl CarView {
+    /// Cycle to the next view (side → front → top → side)
+    pub fn next(self) -> Self {
+        match self {
+            CarView::Side => CarView::Front,
+            CarView::Front => CarView::Top,
+            CarView::Top => CarView::Side,
+        }
+    }
+
+    /// i18n key of the view name (garage view button)
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            CarView::Side => "garage_view_side",
+            CarView::Front => "garage_view_front",
+            CarView::Top => "garage_view_top",
+        }
+    }
+
+    /// Cast member name of a part image in this view.
+    ///
+    /// PartsDB names are side views (`…v2` UseView, `…v3` UseView2); the
+    /// front and top variants sit two and four numbers further up
+    /// (`20b001v2` → `20b001v4` / `20b001v6`).
+    pub fn member_name(self, side_name: &str) -> String {
+        let shift = match self {
+            CarView::Side => return side_name.to_string(),
+            CarView::Front => 2,
+            CarView::Top => 4,
+        };
+        match side_name.rsplit_once('v') {
+            Some((stem, n)) => match n.parse::<u32>() {
+                Ok(n) => format!("{}v{}", stem, n + shift),
+                Err(_) => side_name.to_string(),
+            },
+            None => side_name.to_string(),
+        }
+    }
+
+    /// Project a side-view offset (relative to the car origin) into this view.
+    /// Seen from the front the car is narrow; from the top it is flat.
+    pub fn project(self, offset: (i32, i32)) -> (i32, i32) {
+        match self {
+            CarView::Side => offset,
+            CarView::Front => (offset.0 / 3, offset.1),
+            CarView::Top => (offset.0, offset.1 / 3),
+        }
+    }
+}
+
+/// Squash sprites horizontally around `center_x` (turntable rotation frames).
+/// `scale` is 0.0–1.0; nearest-neighbour sampling.
+pub fn squash_sprites(sprites: &[Sprite], center_x: i32, scale: f32) -> Vec<Sprite> {
+    let scale = scale.clamp(0.05, 1.0);
+    sprites.iter().map(|s| {
+        let new_w = ((s.width as f32 * scale).round() as u32).max(1);
+        let mut pixels = vec![0u8; (new_w * s.height) as usize * 4];
+        for y in 0..s.height {
+            for x in 0..new_w {
+                let sx = ((x as f32 / scale) as u32).min(s.width - 1);
+                let src = ((y * s.width + sx) * 4) as usize;
+                let dst = ((y * new_w + x) * 4) as usize;
+                if src + 4 <= s.pixels.len() {
+                    pixels[dst..dst + 4].copy_from_slice(&s.pixels[src..src + 4]);
+                }
+            }
+        }
+        Sprite {
+            x: center_x + ((s.x - center_x) as f32 * scale).round() as i32,
+            width: new_w,
+            pixels: pixels.into(),
+            ..s.clone()
+        }
+    }).collect()
+}
+
 /// Events that occur during car modifications
 #[derive(Debug, Clone)]
 pub enum CarEvent {
@@ -77,6 +162,8 @@ pub struct BuildCar {
     pub covered_points: HashMap<String, u32>,
     /// Whether the car is locked (no modifications allowed)
     pub locked: bool,
+    /// Garage camera view the sprites are rendered for
+    pub view: CarView,
     /// Cached rendered sprites (rebuilt on refresh)
     part_sprites: Vec<PlacedPartSprite>,
     /// Cached car properties (rebuilt on refresh)
@@ -94,6 +181,7 @@ impl BuildCar {
             used_points: HashMap::new(),
             covered_points: HashMap::new(),
             locked: false,
+            view: CarView::Side,
             part_sprites: Vec::new(),
             properties: CarProperties::default(),
         };
@@ -179,7 +267,11 @@ impl BuildCar {
         })
     }
 
-    /// Check if a part can be attached (required points exist, are free, and not covered)
+    /// Check if a part can be attached: its required points exist, are free
+    /// and not covered. What the part covers may already be covered — in
+    /// the game's parts table every tire covers the brake point of its
+    /// wheel (#a14, #a2), where every brake sits, so exclusive covers would
+    /// leave no car with two tires and a brake (see the test below).
     pub fn can_attach_part(&self, part: &PartData) -> bool {
         for req in &part.requires {
             // Point must exist on the car
@@ -196,12 +288,6 @@ impl BuildCar {
                 return false;
             }
         }
-        // Check that none of the part's covers points are already covered
-        for cover in &part.covers {
-            if self.covered_points.contains_key(cover) {
-                return false;
-            }
-        }
         true
     }
 
@@ -210,6 +296,12 @@ impl BuildCar {
         self.can_attach_part(morph_part)
     }
 
+    /// Switch the camera view and re-render the part sprites
+    pub fn set_view(&mut self, view: CarView, parts_db: &PartsDB, assets: &AssetStore) {
+        self.view = view;
+        self.rebuild_sprites(parts_db, assets);
+    }
+
     /// Get the aggregated car properties
     pub fn properties(&self) -> &CarProperties {
         &self.properties
@@ -223,7 +315,8 @@ impl BuildCar {
     /// Get the world position of a placed part
     fn part_world_position(&self, part_id: u32, parts_db: &PartsDB) -> (i32, i32) {
         if let Some(part) = parts_db.get(part_id) {
-            (self.x + part.offset.0, self.y + part.offset.1)
+            let (ox, oy) = self.view.project(part.offset);
+            (self.x + ox, self.y + oy)
         } else {
             (self.x, self.y)
         }
@@ -339,20 +432,26 @@ impl BuildCar {
     ) -> Option<Sprite> {
         // Member names are like "20b001v2" — resolve from Director file "20.DXR"
         // The "20" prefix refers to the shared cast file
-        // Try to find the member in any loaded Director file
-        let bmp = assets.find_bitmap_by_name(member_name)?;
+        // Try to find the member in any loaded Director file.
+        // Other camera views use their variant; fall back to the side view.
+        let view_name = self.view.member_name(member_name);
+        let (member_name, bmp) = match assets.find_bitmap_by_name(&view_name) {
+            Some(bmp) => (view_name.as_str(), bmp),
+            None => (member_name, assets.find_bitmap_by_name(member_name)?),
+        };
 
         // Use registration point for proper sprite positioning (reg_x/reg_y)
         let (reg_x, reg_y) = assets.find_bitmap_info_by_name(member_name)
             .map(|(_, _, bi)| (bi.reg_x as i32, bi.reg_y as i32))
             .unwrap_or((0, 0));
+        let offset = self.view.project(offset);
 
         Some(Sprite {
             x: self.x + offset.0 - reg_x,
             y: self.y + offset.1 - reg_y,
             width: bmp.width,
             height: bmp.height,
-            pixels: bmp.pixels,
+            pixels: bmp.pixels.into(),
             visible: true,
             z_order: sort_index,
             name: format!("car:{}#{}", member_name, part_id),
@@ -410,7 +509,10 @@ impl BuildCar {
         self.points
             .values()
             .filter(|p| p.occupied_by.is_none() && !self.covered_points.contains_key(&p.id))
-            .map(|p| (p.id.as_str(), self.x + p.offset.0, self.y + p.offset.1))
+            .map(|p| {
+                let (ox, oy) = self.view.project(p.offset);
+                (p.id.as_str(), self.x + ox, self.y + oy)
+            })
             .collect()
     }
 }
@@ -431,6 +533,29 @@ mod tests {
         assert_eq!(car.parts, vec![1, 82, 133, 152]);
     }
 
+    #[test]
+    fn car_view_member_names() {
+        assert_eq!(CarView::Side.member_name("20b001v2"), "20b001v2");
+        assert_eq!(CarView::Front.member_name("20b001v2"), "20b001v4");
+        assert_eq!(CarView::Top.member_name("20b001v3"), "20b001v7");
+        assert_eq!(CarView::Top.next(), CarView::Side);
+        assert_eq!(CarView::Front.project((90, 30)), (30, 30));
+    }
+
+    #[test]
+    fn squash_keeps_center() {
+        let sprite = Sprite {
+            x: 90, y: 0, width: 20, height: 1,
+            pixels: vec![255; 20 * 4].into(),
+            visible: true, z_order: 0, name: String::new(),
+            interactive: false, member_num: 0,
+        };
+        let out = squash_sprites(&[sprite], 100, 0.5);
+        assert_eq!(out[0].width, 10);
+        assert_eq!(out[0].x, 95);
+        assert_eq!(out[0].pixels.len(), 10 * 4);
+    }
+
     #[test]
     fn rebuild_points_from_chassis() {
         let parts_db = PartsDB::load();
@@ -462,6 +587,48 @@ mod tests {
         assert!(free_count > 0, "Should have free attachment points");
     }
 
+    #[test]
+    fn attach_rules_required_points_free_covers_may_overlap() {
+        let parts_db = PartsDB::load();
+        let mut car = BuildCar::new(300, 220);
+        car.rebuild_points(&parts_db);
+        let part = |id| parts_db.get(id).unwrap();
+
+        // Front tire (#a13, covers #a14 like the default brake) and rear tire
+        assert!(car.can_attach_part(part(60)));
+        assert!(car.can_attach_part(part(61)));
+        // Brake on #a14 — taken by the default brake 152
+        assert!(!car.can_attach_part(part(105)));
+
+        car.parts.push(60);
+        car.rebuild_points(&parts_db);
+        // #a13 occupied now, and 149 also needs it
+        assert!(!car.can_attach_part(part(63)));
+        assert!(!car.can_attach_part(part(149)));
+        assert!(car.can_attach_part(part(61)));
+
+        // Without the brake, #a14 is covered by the tire: no brake fits there
+        car.parts.retain(|&id| id != 152);
+        car.rebuild_points(&parts_db);
+        assert!(!car.can_attach_part(part(152)));
+    }
+
+    /// The parts table is why covers may overlap: each tire covers a point
+    /// that every brake of that wheel requires
+    #[test]
+    fn tires_cover_the_brake_points() {
+        let parts_db = PartsDB::load();
+        let brake_points: Vec<&String> = parts_db.iter()
+            .filter(|(_, p)| p.properties.brake > 0)
+            .flat_map(|(_, p)| &p.requires)
+            .collect();
+        assert!(!brake_points.is_empty());
+        assert!(brake_points.iter().all(|&point| point == "#a14" || point == "#a2"));
+        for (id, tire) in parts_db.iter().filter(|(_, p)| p.properties.grip > 0 && p.requires.len() == 1) {
+            assert!(tire.covers.iter().any(|c| c == "#a14" || c == "#a2"), "tire {}", id);
+        }
+    }
+
     #[test]
     fn car_position() {
         let car = BuildCar::new(100, 200);
@@ -0,0 +1,140 @@
+//! Sound captions — closed-caption style hints for important noises
+//!
+//! Speech has subtitles, but some sounds carry information on their own:
+//! the phone ringing in the yard, a horn, the engine dying, the pump at the
+//! gas station. With captions switched on (pause menu), each of these shows
+//! a small speaker icon with a short text in the top-left corner. Sounds are
+//! matched by cast member name (`SoundEngine::take_played`), the texts are
+//! `cc_*` keys in the i18n catalog.
+
+use crate::engine::font;
+use crate::game::driving;
+use crate::game::i18n::{self, Language};
+
+/// How long a caption stays (frames at 30 fps)
+const CAPTION_FRAMES: u32 = 75;
+/// Captions shown at the same time (oldest drops out)
+const MAX_CAPTIONS: usize = 3;
+const X: i32 = 10;
+const Y: i32 = 28;
+const ROW_H: i32 = 22;
+
+/// Caption key for a sound, if it is worth one
+pub fn caption_key(sound: &str) -> Option<&'static str> {
+    if driving::HORN_SOUNDS.contains(&sound) {
+        return Some("cc_horn");
+    }
+    let key = match sound {
+        // Telephone missions (mission.hash delivery 1): ringing + caller
+        "50d001v0" | "50d018v0" | "50d019v0" | "50d020v0" => "cc_phone",
+        // Gas station pump
+        "31e006v0" => "cc_pump",
+        driving::COW_MOO_SOUND => "cc_cow",
+        driving::WBRIDGE_CREAK_SOUND => "cc_bridge_creak",
+        driving::RACING_START_SOUND => "cc_race_start",
+        driving::RACING_FINISH_SOUND => "cc_race_finish",
+        _ => return None,
+    };
+    Some(key)
+}
+
+struct Caption {
+    key: &'static str,
+    frames: u32,
+}
+
+#[derive(Default)]
+pub struct Captions {
+    /// Switched on in the pause menu
+    pub enabled: bool,
+    active: Vec<Caption>,
+}
+
+impl Captions {
+    /// A sound started playing
+    pub fn on_sound(&mut self, sound: &str) {
+        if let Some(key) = caption_key(sound) {
+            self.show(key);
+        }
+    }
+
+    /// Show a caption directly (events without a sound of their own)
+    pub fn show(&mut self, key: &'static str) {
+        if !self.enabled {
+            return;
+        }
+        // Repeated sound → refresh instead of stacking
+        self.active.retain(|c| c.key != key);
+        if self.active.len() >= MAX_CAPTIONS {
+            self.active.remove(0);
+        }
+        self.active.push(Caption { key, frames: CAPTION_FRAMES });
+    }
+
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    /// Draw and count down
+    pub fn draw(&mut self, fb: &mut [u32], lang: Language) {
+        for (i, caption) in self.active.iter().enumerate() {
+            let y = Y + i as i32 * ROW_H;
+            let text = format!("[{}]", i18n::t(lang, caption.key));
+            let w = font::text_width(&text) + 30;
+            font::draw_rect(fb, X, y, w, ROW_H - 4, 0xCC000000);
+            draw_speaker(fb, X + 5, y + 4);
+            font::draw_text(fb, X + 24, y + 5, &text, 0xFFFFFFFF);
+        }
+        for caption in &mut self.active {
+            caption.frames = caption.frames.saturating_sub(1);
+        }
+        self.active.retain(|c| c.frames > 0);
+    }
+}
+
+/// 14×10 speaker with two sound waves
+fn draw_speaker(fb: &mut [u32], x: i32, y: i32) {
+    const COLOR: u32 = 0xFFFFDD44;
+    font::draw_rect(fb, x, y + 3, 3, 4, COLOR);
+    for i in 0..4 {
+        font::draw_rect(fb, x + 3 + i, y + 3 - i, 1, 4 + 2 * i, COLOR);
+    }
+    font::draw_rect(fb, x + 9, y + 3, 1, 4, COLOR);
+    font::draw_rect(fb, x + 12, y + 1, 1, 8, COLOR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_sounds_have_texts() {
+        let sounds = ["50d001v0", "31e006v0", driving::WBRIDGE_CREAK_SOUND, driving::COW_MOO_SOUND, driving::HORN_SOUNDS[4]];
+        for sound in sounds {
+            let key = caption_key(sound).unwrap();
+            for lang in [Language::German, Language::English] {
+                assert_ne!(i18n::t(lang, key), "???", "{}", key);
+            }
+        }
+        assert_eq!(caption_key("03e003v0"), None);
+        // Junkyard ambience loop
+        assert_eq!(caption_key("02e010v0"), None);
+    }
+
+    #[test]
+    fn only_when_enabled_and_no_duplicates() {
+        let mut captions = Captions::default();
+        captions.on_sound("31e006v0");
+        assert!(captions.active.is_empty());
+
+        captions.enabled = true;
+        captions.on_sound("31e006v0");
+        captions.on_sound("31e006v0");
+        assert_eq!(captions.active.len(), 1);
+        for sound in ["50d001v0", driving::WBRIDGE_CREAK_SOUND, driving::COW_MOO_SOUND] {
+            captions.on_sound(sound);
+        }
+        assert_eq!(captions.active.len(), MAX_CAPTIONS);
+        assert_eq!(captions.active[0].key, "cc_phone");
+    }
+}
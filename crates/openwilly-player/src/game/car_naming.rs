@@ -0,0 +1,270 @@
+//! Car naming dialog — shown when entering the car show.
+//!
+//! The player types a name (or picks one of the suggestions) before the
+//! judge rates the car.  Names are trimmed, length-limited and made unique
+//! against the cars already in the profile's gallery ("Flitzer" → "Flitzer 2").
+
+use crate::engine::font;
+use crate::engine::{SCREEN_WIDTH, SCREEN_HEIGHT};
+use crate::game::i18n::{self, Language};
+use crate::game::text_input::TextInput;
+
+/// Maximum car name length (characters)
+pub const MAX_NAME_LEN: usize = 16;
+
+/// Default name suggestions in the UI language — hand-picked, child-friendly
+fn suggestion_names(lang: Language) -> &'static [&'static str] {
+    match lang {
+        Language::German => &[
+            "Flitzer", "Rostlaube", "Blitz", "Brummer", "Knatterkiste",
+            "Sausewind", "Schnecke", "Donnerbuechse", "Hummel", "Klapperkasten",
+        ],
+        Language::English => &[
+            "Zoomer", "Rust Bucket", "Lightning", "Rumbler", "Rattletrap",
+            "Whirlwind", "Snail", "Thunderbox", "Bumblebee", "Clunker",
+        ],
+    }
+}
+
+/// How many suggestion buttons the dialog shows
+const SUGGESTION_COUNT: usize = 3;
+
+/// Why a typed name was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameError {
+    Empty,
+    TooLong,
+}
+
+impl NameError {
+    fn i18n_key(self) -> &'static str {
+        match self {
+            NameError::Empty => "naming_err_empty",
+            NameError::TooLong => "naming_err_long",
+        }
+    }
+}
+
+/// Trim and collapse inner whitespace, then check the length limits.
+pub fn validate_name(input: &str) -> Result<String, NameError> {
+    let name = input.split_whitespace().collect::<Vec<_>>().join(" ");
+    if name.is_empty() {
+        Err(NameError::Empty)
+    } else if name.chars().count() > MAX_NAME_LEN {
+        Err(NameError::TooLong)
+    } else {
+        Ok(name)
+    }
+}
+
+/// Names compare without case, umlauts included ("Bär" = "BÄR")
+fn same_name(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+/// Make `name` unique among `taken` (case-insensitive) by appending a number.
+/// The suffix replaces the tail of long names so the result stays within
+/// `MAX_NAME_LEN`.
+pub fn unique_name(name: &str, taken: &[String]) -> String {
+    let is_taken = |candidate: &str| taken.iter().any(|t| same_name(t, candidate));
+    if !is_taken(name) {
+        return name.to_string();
+    }
+    let mut n = 2;
+    loop {
+        let suffix = format!(" {}", n);
+        let keep = MAX_NAME_LEN.saturating_sub(suffix.len());
+        let base: String = name.chars().take(keep).collect();
+        let candidate = format!("{}{}", base.trim_end(), suffix);
+        if !is_taken(&candidate) {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+/// Pick up to `count` suggestions that aren't used in the gallery yet.
+/// `seed` rotates the starting point so repeated visits offer different names.
+pub fn suggestions(taken: &[String], seed: usize, count: usize, lang: Language) -> Vec<String> {
+    let names = suggestion_names(lang);
+    let n = names.len();
+    (0..n)
+        .map(|i| names[(seed + i) % n])
+        .filter(|s| !taken.iter().any(|t| same_name(t, s)))
+        .take(count)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Result of a naming-dialog interaction
+pub enum NamingResult {
+    /// Still editing
+    None,
+    /// Player confirmed this (validated, unique) name
+    Confirmed(String),
+}
+
+/// The naming dialog state
+pub struct CarNamingDialog {
+    input: TextInput,
+    suggestions: Vec<String>,
+    /// Names already used in the gallery (excluding the car being renamed)
+    taken: Vec<String>,
+    error: Option<NameError>,
+}
+
+// Layout
+const BOX_W: i32 = 360;
+const BOX_H: i32 = 190;
+const FIELD_H: i32 = 24;
+const BTN_H: i32 = 24;
+
+impl CarNamingDialog {
+    /// Open the dialog, pre-filled with the car's current name.
+    pub fn new(current_name: &str, taken: Vec<String>, seed: usize, lang: Language) -> Self {
+        let mut input = TextInput::new(MAX_NAME_LEN);
+        input.set_text(current_name);
+        Self {
+            input,
+            suggestions: suggestions(&taken, seed, SUGGESTION_COUNT, lang),
+            taken,
+            error: None,
+        }
+    }
+
+    pub fn on_char(&mut self, ch: char) {
+        if self.input.on_char(ch) {
+            self.error = None;
+        }
+    }
+
+    /// Enter pressed / OK clicked — validate and confirm
+    pub fn confirm(&mut self) -> NamingResult {
+        match validate_name(self.input.text()) {
+            Ok(name) => NamingResult::Confirmed(unique_name(&name, &self.taken)),
+            Err(e) => {
+                self.error = Some(e);
+                NamingResult::None
+            }
+        }
+    }
+
+    /// Handle a mouse click (suggestion buttons fill the field, OK confirms)
+    pub fn on_click(&mut self, mx: i32, my: i32) -> NamingResult {
+        for (i, rect) in Self::suggestion_rects(self.suggestions.len()).iter().enumerate() {
+            if hit(rect, mx, my) {
+                let name = self.suggestions[i].clone();
+                self.input.set_text(&name);
+                self.error = None;
+                return NamingResult::None;
+            }
+        }
+        if hit(&Self::ok_rect(), mx, my) {
+            return self.confirm();
+        }
+        NamingResult::None
+    }
+
+    // ── Rendering ───────────────────────────────────────────────────────
+
+    pub fn draw(&mut self, fb: &mut [u32], lang: Language) {
+        let (bx, by) = Self::origin();
+        font::draw_rect(fb, bx, by, BOX_W, BOX_H, 0xEE1A1A2E);
+        font::draw_rect_outline(fb, bx, by, BOX_W, BOX_H, 0xFFFFCC44);
+
+        let title = i18n::t(lang, "naming_title");
+        font::draw_text_shadow(fb, bx + (BOX_W - font::text_width(title)) / 2, by + 12, title, 0xFFFFCC44);
+
+        // Text field
+        let (fx, fy, fw) = (bx + 20, by + 36, BOX_W - 40);
+        font::draw_rect(fb, fx, fy, fw, FIELD_H, 0xFFFFFFFF);
+        font::draw_rect_outline(fb, fx, fy, fw, FIELD_H, 0xFF666644);
+        self.input.draw(fb, fx + 6, fy + 8, 0xFF000000, true);
+
+        // Validation error / length hint
+        let (hint, color) = match self.error {
+            Some(e) => (i18n::t(lang, e.i18n_key()).to_string(), 0xFFFF6666),
+            None => (format!("{}/{}", self.input.text().chars().count(), self.input.max_len()), 0xFF888888),
+        };
+        font::draw_text(fb, fx, fy + FIELD_H + 6, &hint, color);
+
+        // Suggestions
+        for (i, rect) in Self::suggestion_rects(self.suggestions.len()).iter().enumerate() {
+            let &(x, y, w, h) = rect;
+            font::draw_rect(fb, x, y, w, h, 0xFF333355);
+            font::draw_rect_outline(fb, x, y, w, h, 0xFF6666CC);
+            let s = &self.suggestions[i];
+            font::draw_text(fb, x + (w - font::text_width(s)) / 2, y + 8, s, 0xFFFFFFFF);
+        }
+
+        // OK button
+        let (x, y, w, h) = Self::ok_rect();
+        font::draw_rect(fb, x, y, w, h, 0xFF228B22);
+        font::draw_rect_outline(fb, x, y, w, h, 0xFF003300);
+        let ok = i18n::t(lang, "naming_ok");
+        font::draw_text_shadow(fb, x + (w - font::text_width(ok)) / 2, y + 8, ok, 0xFFFFFFFF);
+    }
+
+    // ── Layout ──────────────────────────────────────────────────────────
+
+    fn origin() -> (i32, i32) {
+        ((SCREEN_WIDTH as i32 - BOX_W) / 2, (SCREEN_HEIGHT as i32 - BOX_H) / 2)
+    }
+
+    fn suggestion_rects(count: usize) -> Vec<(i32, i32, i32, i32)> {
+        let (bx, by) = Self::origin();
+        let w = (BOX_W - 40 - 10 * (SUGGESTION_COUNT as i32 - 1)) / SUGGESTION_COUNT as i32;
+        (0..count as i32)
+            .map(|i| (bx + 20 + i * (w + 10), by + 100, w, BTN_H))
+            .collect()
+    }
+
+    fn ok_rect() -> (i32, i32, i32, i32) {
+        let (bx, by) = Self::origin();
+        (bx + (BOX_W - 100) / 2, by + BOX_H - BTN_H - 16, 100, BTN_H)
+    }
+}
+
+fn hit(rect: &(i32, i32, i32, i32), x: i32, y: i32) -> bool {
+    let &(rx, ry, rw, rh) = rect;
+    x >= rx && y >= ry && x < rx + rw && y < ry + rh
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_trims_and_limits() {
+        assert_eq!(validate_name("  Rosti   Flitzer "), Ok("Rosti Flitzer".to_string()));
+        assert_eq!(validate_name("   "), Err(NameError::Empty));
+        assert_eq!(validate_name("ABCDEFGHIJKLMNOPQ"), Err(NameError::TooLong));
+    }
+
+    #[test]
+    fn unique_name_appends_number() {
+        let taken = vec!["Blitz".to_string(), "blitz 2".to_string()];
+        assert_eq!(unique_name("Hummel", &taken), "Hummel");
+        assert_eq!(unique_name("Blitz", &taken), "Blitz 3");
+        // Long names are shortened to fit the suffix
+        let taken = vec!["Klapperkasten123".to_string()];
+        let name = unique_name("Klapperkasten123", &taken);
+        assert_eq!(name, "Klapperkasten1 2");
+        assert!(name.len() <= MAX_NAME_LEN);
+        // Not only ASCII letters differ in case
+        let taken = vec!["Bär".to_string()];
+        assert_eq!(unique_name("BÄR", &taken), "BÄR 2");
+    }
+
+    #[test]
+    fn suggestions_skip_taken_names() {
+        let taken = vec!["Flitzer".to_string()];
+        let s = suggestions(&taken, 0, 3, Language::German);
+        assert_eq!(s.len(), 3);
+        assert!(!s.contains(&"Flitzer".to_string()));
+        assert_eq!(suggestions(&taken, 0, 1, Language::English), vec!["Zoomer".to_string()]);
+        for lang in [Language::German, Language::English] {
+            assert!(suggestion_names(lang).iter().all(|s| s.chars().count() <= MAX_NAME_LEN));
+        }
+    }
+}
@@ -1,41 +1,61 @@
 //! Software-rendered cursor system
 //!
-//! Loads 9 cursor sprites from 00.DXR (members 73-81) with per-cursor
-//! hotspots matching the original Director game.  Manages a cursor
-//! **stack** identical to mulle.js `MulleCursor`: pushing a type
-//! overrides the current cursor, popping restores the previous one.
-//! The engine hides the OS cursor and blits the software cursor onto
-//! the framebuffer every frame.
+//! Loads the cursor sprites from 00.DXR (members 73-81, plus the closed
+//! hand and the animated watch by name) with per-cursor hotspots matching
+//! the original Director game. Cursors missing from the cast come from a
+//! set bundled in the binary (`data/cursors.json`). Cast cursors are
+//! decoded in the palette of the current scene's movie, like the stage
+//! showed them.
+//!
+//! Manages a cursor **stack** identical to mulle.js `MulleCursor`:
+//! pushing a type overrides the current cursor, popping restores the
+//! previous one. While the game is busy (loading, cutscenes) the watch
+//! is shown instead. The engine hides the OS cursor and blits the
+//! software cursor onto the framebuffer every frame.
+
+use std::collections::HashMap;
 
+use serde::Deserialize;
+
+use crate::assets::bitmap::DecodedBitmap;
+use crate::assets::director::CastType;
 use crate::assets::AssetStore;
+use crate::engine::atlas::{Packed, SpriteAtlas};
+
+/// Game frames per frame of an animated cursor (the watch)
+const ANIM_TICKS: u32 = 6;
 
-/// All cursor types available in the game (00.DXR members 73-81)
+/// All cursor types available in the game
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CursorType {
-    Standard,  // member 73 — default arrow
-    Grab,      // member 74 — open hand (hover over parts)
-    Left,      // member 75 — left arrow (left door)
-    Click,     // member 76 — pointing finger (clickable)
-    Back,      // member 77 — return arrow
-    Right,     // member 78 — right arrow (right door)
-    MoveLeft,  // member 79 — drag left
-    MoveRight, // member 80 — drag right
-    MoveIn,    // member 81 — drag forward
+    Standard,   // member 73 — default arrow
+    Grab,       // member 74 — open hand (hover over parts)
+    Left,       // member 75 — left arrow (left door)
+    Click,      // member 76 — pointing finger (clickable)
+    Back,       // member 77 — return arrow
+    Right,      // member 78 — right arrow (right door)
+    MoveLeft,   // member 79 — drag left
+    MoveRight,  // member 80 — drag right
+    MoveIn,     // member 81 — drag forward
+    GrabClosed, // closed hand (holding a part)
+    Wait,       // animated watch (loading, cutscenes)
 }
 
 impl CursorType {
-    /// 00.DXR member number
-    fn member(self) -> u32 {
+    /// 00.DXR member number (the newer types are only found by name)
+    fn member(self) -> Option<u32> {
         match self {
-            CursorType::Standard  => 73,
-            CursorType::Grab      => 74,
-            CursorType::Left      => 75,
-            CursorType::Click     => 76,
-            CursorType::Back      => 77,
-            CursorType::Right     => 78,
-            CursorType::MoveLeft  => 79,
-            CursorType::MoveRight => 80,
-            CursorType::MoveIn    => 81,
+            CursorType::Standard   => Some(73),
+            CursorType::Grab       => Some(74),
+            CursorType::Left       => Some(75),
+            CursorType::Click      => Some(76),
+            CursorType::Back       => Some(77),
+            CursorType::Right      => Some(78),
+            CursorType::MoveLeft   => Some(79),
+            CursorType::MoveRight  => Some(80),
+            CursorType::MoveIn     => Some(81),
+            CursorType::GrabClosed => None,
+            CursorType::Wait       => None,
         }
     }
 
@@ -52,6 +72,8 @@ impl CursorType {
             CursorType::MoveLeft  => (20, 17),
             CursorType::MoveRight => (16, 15),
             CursorType::MoveIn    => (15, 16),
+            CursorType::GrabClosed => (13, 10),
+            CursorType::Wait      => (8, 8),
         }
     }
 
@@ -66,10 +88,12 @@ impl CursorType {
             CursorType::MoveLeft  => 6,
             CursorType::MoveRight => 7,
             CursorType::MoveIn    => 8,
+            CursorType::GrabClosed => 9,
+            CursorType::Wait      => 10,
         }
     }
 
-    const ALL: [CursorType; 9] = [
+    const ALL: [CursorType; 11] = [
         CursorType::Standard,
         CursorType::Grab,
         CursorType::Left,
@@ -79,10 +103,13 @@ impl CursorType {
         CursorType::MoveLeft,
         CursorType::MoveRight,
         CursorType::MoveIn,
+        CursorType::GrabClosed,
+        CursorType::Wait,
     ];
 
     /// Director cast member name used for name-based fallback lookup.
     /// In 00.CXT the members are named "C_standard", "C_Grab", etc.
+    /// Animated cursors number their frames ("C_Wait1", "C_Wait2", …).
     fn director_name(self) -> &'static str {
         match self {
             CursorType::Standard  => "C_standard",
@@ -94,6 +121,25 @@ impl CursorType {
             CursorType::MoveLeft  => "C_MoveLeft",
             CursorType::MoveRight => "C_MoveRight",
             CursorType::MoveIn    => "C_MoveIn",
+            CursorType::GrabClosed => "C_GrabClosed",
+            CursorType::Wait      => "C_Wait",
+        }
+    }
+
+    /// Key in `data/cursors.json`
+    fn bundled_key(self) -> &'static str {
+        match self {
+            CursorType::Standard   => "standard",
+            CursorType::Grab       => "grab",
+            CursorType::Left       => "left",
+            CursorType::Click      => "click",
+            CursorType::Back       => "back",
+            CursorType::Right      => "right",
+            CursorType::MoveLeft   => "move_left",
+            CursorType::MoveRight  => "move_right",
+            CursorType::MoveIn     => "move_in",
+            CursorType::GrabClosed => "grab_closed",
+            CursorType::Wait       => "wait",
         }
     }
 }
@@ -105,14 +151,78 @@ struct CursorFrame {
     pixels: Vec<u8>, // RGBA
     hotspot_x: i32,
     hotspot_y: i32,
+    /// Spans in the cursor atlas (set by `load`)
+    packed: Option<Packed>,
+}
+
+impl CursorFrame {
+    fn from_bitmap(bmp: DecodedBitmap, (hotspot_x, hotspot_y): (i32, i32)) -> Self {
+        Self { width: bmp.width, height: bmp.height, pixels: bmp.pixels, hotspot_x, hotspot_y, packed: None }
+    }
+
+    /// Frame from bundled pixel art: '#' black, '.' white, anything else clear
+    fn from_art(rows: &[String], hotspot: (i32, i32)) -> Self {
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0).max(1) as u32;
+        let height = rows.len().max(1) as u32;
+        let mut pixels = vec![0u8; (width * height * 4) as usize];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, ch) in row.bytes().enumerate() {
+                let rgba = match ch {
+                    b'#' => [0x00, 0x00, 0x00, 0xFF],
+                    b'.' => [0xFF, 0xFF, 0xFF, 0xFF],
+                    _ => continue,
+                };
+                let i = (y * width as usize + x) * 4;
+                pixels[i..i + 4].copy_from_slice(&rgba);
+            }
+        }
+        Self::from_bitmap(DecodedBitmap { width, height, pixels }, hotspot)
+    }
+}
+
+/// One cursor of `data/cursors.json`
+#[derive(Deserialize)]
+struct BundledCursor {
+    hotspot: (i32, i32),
+    frames: Vec<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct BundledSet {
+    cursors: HashMap<String, BundledCursor>,
+}
+
+impl BundledSet {
+    fn load() -> Self {
+        serde_json::from_str(include_str!("../../data/cursors.json"))
+            .expect("Failed to parse cursors.json")
+    }
+
+    fn frames(&self, ct: CursorType) -> Vec<CursorFrame> {
+        self.cursors.get(ct.bundled_key())
+            .map(|c| c.frames.iter().map(|rows| CursorFrame::from_art(rows, c.hotspot)).collect())
+            .unwrap_or_default()
+    }
 }
 
 /// Software cursor with a stack for nested states.
+#[derive(Default)]
 pub struct GameCursor {
-    frames: Vec<CursorFrame>,
+    /// Frames per cursor type (by `CursorType::index`), several if animated
+    frames: Vec<Vec<CursorFrame>>,
     /// Stack of cursor types — last entry is the active cursor.
     /// Empty stack → Standard cursor.
     history: Vec<CursorType>,
+    /// Loading or cutscene running → watch, whatever the stack says
+    busy: bool,
+    /// Frame counter for animated cursors
+    ticks: u32,
+    /// Movie whose palette the cast cursors were decoded in
+    palette_movie: Option<String>,
+    /// Whether 00.DXR/CXT was there when the frames were loaded
+    from_cast: bool,
+    /// All frames as precomputed spans
+    atlas: SpriteAtlas,
 }
 
 impl GameCursor {
@@ -124,62 +234,71 @@ impl GameCursor {
     /// based lookup as fallback: the Director member names match the
     /// `CursorType` debug names (e.g. "Standard", "Grab", …).
     pub fn new(assets: &AssetStore) -> Self {
-        let file = if assets.files.contains_key("00.DXR") {
-            "00.DXR"
-        } else if assets.files.contains_key("00.CXT") {
-            "00.CXT"
-        } else {
-            tracing::warn!("Cursor: no 00.DXR/CXT found");
-            return Self { frames: Vec::new(), history: Vec::new() };
-        };
-
-        let mut frames = Vec::with_capacity(9);
-        for ct in &CursorType::ALL {
-            let (hx, hy) = ct.hotspot();
-
-            // Primary: search by Director cast member name (reliable across DXR/CXT builds
-            // where member numbering differs)
-            let bmp = {
-                let name = ct.director_name();
-                let df = assets.files.get(file);
-                df.and_then(|df| {
-                    let (&num, _) = df.cast_members.iter()
-                        .find(|(_, m)| m.name.eq_ignore_ascii_case(name) && m.cast_type == crate::assets::director::CastType::Bitmap)?;
-                    tracing::debug!("Cursor: resolved {:?} by name '{}' → member {}", ct, name, num);
-                    assets.decode_bitmap_transparent(file, num)
-                })
+        let mut cursor = Self::default();
+        cursor.load(assets, None);
+        cursor
+    }
+
+    /// Decode the cursors in the palette of a scene's movie. Called on
+    /// every scene switch; also picks up 00.DXR once it has been streamed in.
+    pub fn set_scene(&mut self, movie: &str, assets: &AssetStore) {
+        if self.from_cast && self.palette_movie.as_deref() == Some(movie) {
+            return;
+        }
+        self.load(assets, Some(movie));
+    }
+
+    fn load(&mut self, assets: &AssetStore, movie: Option<&str>) {
+        let file = ["00.DXR", "00.CXT"].into_iter().find(|f| assets.files.contains_key(*f));
+        if file.is_none() {
+            tracing::warn!("Cursor: no 00.DXR/CXT found, using the bundled cursors");
+        }
+        let palette = movie.and_then(|m| assets.movie_palette(m));
+        let bundled = BundledSet::load();
+
+        self.frames = CursorType::ALL.iter().map(|&ct| {
+            let cast = file.map(|f| cast_frames(assets, f, ct, palette.as_deref())).unwrap_or_default();
+            if !cast.is_empty() {
+                return cast;
+            }
+            if file.is_some() {
+                tracing::debug!("Cursor: {:?} not in the cast, using the bundled one", ct);
             }
-            // Fallback: try hardcoded member number (DXR layout)
-            .or_else(|| assets.decode_bitmap_transparent(file, ct.member()));
-
-            if let Some(bmp) = bmp {
-                frames.push(CursorFrame {
-                    width: bmp.width,
-                    height: bmp.height,
-                    pixels: bmp.pixels,
-                    hotspot_x: hx,
-                    hotspot_y: hy,
-                });
-            } else {
-                tracing::warn!("Cursor: missing member {} ({:?})", ct.member(), ct);
+            let art = bundled.frames(ct);
+            if art.is_empty() {
+                tracing::warn!("Cursor: no frames for {:?}", ct);
                 // 1×1 transparent stub
-                frames.push(CursorFrame {
-                    width: 1, height: 1,
-                    pixels: vec![0, 0, 0, 0],
-                    hotspot_x: 0,
-                    hotspot_y: 0,
-                });
+                return vec![CursorFrame::from_bitmap(DecodedBitmap { width: 1, height: 1, pixels: vec![0; 4] }, (0, 0))];
             }
+            art
+        }).collect();
+        self.atlas.clear();
+        for frame in self.frames.iter_mut().flatten() {
+            frame.packed = self.atlas.pack(frame.width, frame.height, &frame.pixels);
         }
-
-        GameCursor { frames, history: Vec::new() }
+        self.from_cast = file.is_some();
+        self.palette_movie = movie.map(str::to_string);
     }
 
-    /// Current active cursor type (top of stack, or Standard)
+    /// Current active cursor type (watch while busy, else top of stack,
+    /// or Standard)
     pub fn current(&self) -> CursorType {
+        if self.busy {
+            return CursorType::Wait;
+        }
         self.history.last().copied().unwrap_or(CursorType::Standard)
     }
 
+    /// Show the watch (loading, cutscene) until cleared again
+    pub fn set_busy(&mut self, busy: bool) {
+        self.busy = busy;
+    }
+
+    /// Advance animated cursors by one game frame
+    pub fn tick(&mut self) {
+        self.ticks = self.ticks.wrapping_add(1);
+    }
+
     /// Push a cursor type onto the stack (makes it the active cursor).
     pub fn set(&mut self, ct: CursorType) {
         self.history.push(ct);
@@ -212,10 +331,15 @@ impl GameCursor {
             return;
         }
         let ct = self.current();
-        let frame = &self.frames[ct.index()];
+        let frames = &self.frames[ct.index()];
+        let frame = &frames[(self.ticks / ANIM_TICKS) as usize % frames.len()];
 
         let draw_x = mouse_x - frame.hotspot_x;
         let draw_y = mouse_y - frame.hotspot_y;
+        if let Some(packed) = frame.packed {
+            self.atlas.blit_rows(packed, fb, fb_width, (0, fb_height as i32), (draw_x, draw_y));
+            return;
+        }
 
         for sy in 0..frame.height as i32 {
             let dy = draw_y + sy;
@@ -262,13 +386,41 @@ impl GameCursor {
     }
 }
 
+/// A cursor's frames from the cast: by name (numbered frames for animated
+/// ones), then by the DXR member number
+fn cast_frames(assets: &AssetStore, file: &str, ct: CursorType, palette: Option<&[[u8; 3]]>) -> Vec<CursorFrame> {
+    let Some(df) = assets.files.get(file) else { return Vec::new() };
+    let decode = |num: u32| match palette {
+        Some(pal) => assets.decode_bitmap_in_palette(file, num, pal),
+        None => assets.decode_bitmap_transparent(file, num),
+    };
+    let by_name = |name: &str| {
+        df.cast_members.iter()
+            .find(|(_, m)| m.name.eq_ignore_ascii_case(name) && m.cast_type == CastType::Bitmap)
+            .map(|(&num, _)| num)
+    };
+
+    let name = ct.director_name();
+    let numbered = (1..).map_while(|n| by_name(&format!("{}{}", name, n)));
+    let mut members: Vec<u32> = by_name(name).into_iter().chain(numbered).collect();
+    if members.is_empty() {
+        members.extend(ct.member());
+    } else {
+        tracing::debug!("Cursor: resolved {:?} by name '{}' → members {:?}", ct, name, members);
+    }
+    members.into_iter()
+        .filter_map(decode)
+        .map(|bmp| CursorFrame::from_bitmap(bmp, ct.hotspot()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn cursor_stack_operations() {
-        let mut gc = GameCursor { frames: Vec::new(), history: Vec::new() };
+        let mut gc = GameCursor::default();
         assert_eq!(gc.current(), CursorType::Standard);
 
         gc.set(CursorType::Grab);
@@ -294,7 +446,7 @@ mod tests {
 
     #[test]
     fn cursor_reset_clears_all() {
-        let mut gc = GameCursor { frames: Vec::new(), history: Vec::new() };
+        let mut gc = GameCursor::default();
         gc.set(CursorType::Grab);
         gc.set(CursorType::Click);
         gc.set(CursorType::Right);
@@ -311,4 +463,28 @@ mod tests {
         assert_eq!(CursorType::Click.hotspot(), (1, 1));
         assert_eq!(CursorType::Right.hotspot(), (27, 5));
     }
+
+    #[test]
+    fn bundled_set_covers_every_cursor() {
+        let bundled = BundledSet::load();
+        for ct in CursorType::ALL {
+            let frames = bundled.frames(ct);
+            assert!(!frames.is_empty(), "{:?}", ct);
+            for f in &frames {
+                assert_eq!(f.pixels.len(), (f.width * f.height * 4) as usize);
+                assert!((0..f.width as i32).contains(&f.hotspot_x) && (0..f.height as i32).contains(&f.hotspot_y));
+            }
+        }
+        assert!(bundled.frames(CursorType::Wait).len() > 1);
+    }
+
+    #[test]
+    fn busy_shows_the_watch_over_the_stack() {
+        let mut gc = GameCursor::default();
+        gc.set(CursorType::Grab);
+        gc.set_busy(true);
+        assert_eq!(gc.current(), CursorType::Wait);
+        gc.set_busy(false);
+        assert_eq!(gc.current(), CursorType::Grab);
+    }
 }
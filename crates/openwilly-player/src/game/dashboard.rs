@@ -3,13 +3,15 @@
 //! Renders:
 //! - Fuel needle (05.DXR members 27-42, 16 frames)
 //! - Speedometer  (05.DXR member 46, slides horizontally)
+//! - Steering wheel (05.DXR members 47-53, turns with steering input)
+//! - Brake / gas pedals (05.DXR members 54-57, up/pressed)
 //!
 //! The dashboard background (member 25) is loaded as a scene overlay
 //! with z_order=50, so the speedometer sits *below* it (z=49) to
 //! naturally mask the hidden portion.
 
 use crate::assets::AssetStore;
-use crate::engine::Sprite;
+use crate::engine::{Sprite, SpritePixels};
 
 /// Pre-decoded dashboard sprite data
 pub struct Dashboard {
@@ -17,6 +19,12 @@ pub struct Dashboard {
     fuel_frames: Vec<FrameData>,
     /// Speedometer bitmap
     speedo: FrameData,
+    /// Steering wheel frames, hard left → center → hard right
+    wheel_frames: Vec<FrameData>,
+    /// Pedal frames: [brake up, brake down, gas up, gas down]
+    pedal_frames: Vec<FrameData>,
+    /// Smoothed wheel position (-1.0 = hard left, 1.0 = hard right)
+    wheel_pos: f32,
 }
 
 struct FrameData {
@@ -24,7 +32,7 @@ struct FrameData {
     y: i32,
     width: u32,
     height: u32,
-    pixels: Vec<u8>,
+    pixels: SpritePixels,
 }
 
 /// Fuel needle: member 27 = empty, member 42 = full → 16 frames
@@ -37,6 +45,18 @@ const SPEEDO_MEMBER: u32 = 46;
 const SPEEDO_BASE_X: f32 = 100.0;
 const SPEEDO_TRAVEL: f32 = 140.0;
 
+/// Steering wheel: 7 frames, member 50 = centered
+const WHEEL_MEMBERS: [u32; 7] = [47, 48, 49, 50, 51, 52, 53];
+/// Steering wheel anchor (center of the dashboard)
+const WHEEL_ANCHOR: (i32, i32) = (320, 440);
+/// How far the wheel turns per frame towards the input (full lock in ~4 frames)
+const WHEEL_SPEED: f32 = 0.25;
+
+/// Pedals: brake up/down, gas up/down
+const PEDAL_MEMBERS: [u32; 4] = [54, 55, 56, 57];
+/// Pedal anchors: brake, gas
+const PEDAL_ANCHORS: [(i32, i32); 2] = [(392, 458), (424, 458)];
+
 impl Dashboard {
     /// Load all dashboard sprites from 05.DXR/.CXT.
     /// Returns `None` only if the DXR is completely missing.
@@ -61,14 +81,14 @@ impl Dashboard {
                     y: 447 - ry,
                     width: bmp.width,
                     height: bmp.height,
-                    pixels: bmp.pixels,
+                    pixels: bmp.pixels.into(),
                 });
             } else {
                 tracing::warn!("Dashboard: missing fuel needle frame #{}", mem);
                 // Push a 1×1 transparent stub so frame indexing stays valid
                 fuel_frames.push(FrameData {
                     x: 0, y: 0, width: 1, height: 1,
-                    pixels: vec![0, 0, 0, 0],
+                    pixels: vec![0, 0, 0, 0].into(),
                 });
             }
         }
@@ -77,25 +97,51 @@ impl Dashboard {
         let speedo = if let Some(bmp) = assets.decode_bitmap_transparent(file, SPEEDO_MEMBER) {
             // Speedometer anchor: (99, 446) per mulle.js
             let (rx, ry) = reg_point(assets, file, SPEEDO_MEMBER);
-            FrameData { x: 99 - rx, y: 446 - ry, width: bmp.width, height: bmp.height, pixels: bmp.pixels }
+            FrameData { x: 99 - rx, y: 446 - ry, width: bmp.width, height: bmp.height, pixels: bmp.pixels.into() }
         } else {
             tracing::warn!("Dashboard: missing speedometer #{}", SPEEDO_MEMBER);
-            FrameData { x: 0, y: 0, width: 1, height: 1, pixels: vec![0, 0, 0, 0] }
+            FrameData { x: 0, y: 0, width: 1, height: 1, pixels: vec![0, 0, 0, 0].into() }
         };
 
+        // Steering wheel + pedals
+        let wheel_frames = WHEEL_MEMBERS.iter()
+            .map(|&mem| load_frame(assets, file, mem, WHEEL_ANCHOR))
+            .collect();
+        let pedal_frames = PEDAL_MEMBERS.iter().enumerate()
+            .map(|(i, &mem)| load_frame(assets, file, mem, PEDAL_ANCHORS[i / 2]))
+            .collect();
+
         Some(Dashboard {
             fuel_frames,
             speedo,
+            wheel_frames,
+            pedal_frames,
+            wheel_pos: 0.0,
         })
     }
 
+    /// Ease the steering wheel towards the current input (call once per frame).
+    /// `steer` is -1 (left), 0 (straight) or 1 (right).
+    pub fn update_steering(&mut self, steer: i8) {
+        let target = steer.clamp(-1, 1) as f32;
+        let delta = (target - self.wheel_pos).clamp(-WHEEL_SPEED, WHEEL_SPEED);
+        self.wheel_pos += delta;
+    }
+
+    /// Wheel frame index for the current wheel position
+    fn wheel_frame(&self) -> usize {
+        let last = (WHEEL_MEMBERS.len() - 1) as f32;
+        (((self.wheel_pos + 1.0) / 2.0) * last).round().clamp(0.0, last) as usize
+    }
+
     /// Produce dashboard sprites for the current driving state.
     ///
     /// - `fuel_pct` — fuel as fraction [0.0, 1.0]
     /// - `speed`    — current speed (absolute)
     /// - `max_speed`— car's maximum speed
-    pub fn sprites(&self, fuel_pct: f32, speed: f32, max_speed: f32) -> Vec<Sprite> {
-        let mut out = Vec::with_capacity(2);
+    /// - `throttle` / `braking` — pedal input this frame
+    pub fn sprites(&self, fuel_pct: f32, speed: f32, max_speed: f32, throttle: bool, braking: bool) -> Vec<Sprite> {
+        let mut out = Vec::with_capacity(5);
 
         // ── Fuel needle ────────────────────────────────────────────────
         // frame_index = clamp(0, 15, round(fuel_pct * 16))
@@ -137,10 +183,59 @@ impl Dashboard {
             member_num: SPEEDO_MEMBER,
         });
 
+        // ── Steering wheel ─────────────────────────────────────────────
+        let wi = self.wheel_frame();
+        out.push(self.wheel_frames[wi].sprite("steering_wheel", WHEEL_MEMBERS[wi]));
+
+        // ── Pedals ─────────────────────────────────────────────────────
+        let brake = if braking { 1 } else { 0 };
+        let gas = if throttle { 3 } else { 2 };
+        out.push(self.pedal_frames[brake].sprite("pedal_brake", PEDAL_MEMBERS[brake]));
+        out.push(self.pedal_frames[gas].sprite("pedal_gas", PEDAL_MEMBERS[gas]));
+
         out
     }
 }
 
+impl FrameData {
+    /// Dashboard overlay sprite (above the dashboard bg)
+    fn sprite(&self, name: &str, member_num: u32) -> Sprite {
+        Sprite {
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+            pixels: self.pixels.clone(),
+            visible: true,
+            z_order: 51,
+            name: name.into(),
+            interactive: false,
+            member_num,
+        }
+    }
+}
+
+/// Load a frame anchored at `anchor` (via its registration point), or a
+/// 1×1 transparent stub if the member is missing
+fn load_frame(assets: &AssetStore, file: &str, mem: u32, anchor: (i32, i32)) -> FrameData {
+    match assets.decode_bitmap_transparent(file, mem) {
+        Some(bmp) => {
+            let (rx, ry) = reg_point(assets, file, mem);
+            FrameData {
+                x: anchor.0 - rx,
+                y: anchor.1 - ry,
+                width: bmp.width,
+                height: bmp.height,
+                pixels: bmp.pixels.into(),
+            }
+        }
+        None => {
+            tracing::warn!("Dashboard: missing member #{}", mem);
+            FrameData { x: 0, y: 0, width: 1, height: 1, pixels: vec![0, 0, 0, 0].into() }
+        }
+    }
+}
+
 fn reg_point(assets: &AssetStore, file: &str, num: u32) -> (i32, i32) {
     assets.files.get(file)
         .and_then(|df| df.cast_members.get(&num))
@@ -170,6 +265,42 @@ mod tests {
         }
     }
 
+    fn stub_dashboard() -> Dashboard {
+        let stub = || FrameData { x: 0, y: 0, width: 1, height: 1, pixels: vec![0; 4].into() };
+        Dashboard {
+            fuel_frames: (0..16).map(|_| stub()).collect(),
+            speedo: stub(),
+            wheel_frames: WHEEL_MEMBERS.iter().map(|_| stub()).collect(),
+            pedal_frames: PEDAL_MEMBERS.iter().map(|_| stub()).collect(),
+            wheel_pos: 0.0,
+        }
+    }
+
+    #[test]
+    fn wheel_eases_to_full_lock() {
+        let mut dash = stub_dashboard();
+        assert_eq!(dash.wheel_frame(), 3); // centered
+        dash.update_steering(1);
+        assert!(dash.wheel_frame() > 3 && dash.wheel_frame() < 6);
+        for _ in 0..10 {
+            dash.update_steering(1);
+        }
+        assert_eq!(dash.wheel_frame(), 6);
+        for _ in 0..20 {
+            dash.update_steering(-1);
+        }
+        assert_eq!(dash.wheel_frame(), 0);
+    }
+
+    #[test]
+    fn pedals_follow_input() {
+        let dash = stub_dashboard();
+        let sprites = dash.sprites(0.5, 0.0, 4.0, true, false);
+        let member = |name: &str| sprites.iter().find(|s| s.name == name).unwrap().member_num;
+        assert_eq!(member("pedal_brake"), 54);
+        assert_eq!(member("pedal_gas"), 57);
+    }
+
     #[test]
     fn speedo_x_range() {
         // speed=0 → x=100, speed=max → x=240
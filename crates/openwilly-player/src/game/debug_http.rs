@@ -0,0 +1,426 @@
+//! Debug HTTP endpoint — game state for external tools
+//!
+//! `OPENWILLY_DEBUG_HTTP=<port>` (or `=1` for port 7380) serves a small
+//! JSON API on 127.0.0.1 (connections served one after another on a
+//! background thread, the requests answered once per frame on the game
+//! thread), so editor UIs and test harnesses can look inside a running
+//! player:
+//!
+//! ```text
+//! GET  /state                    scene, profile, car parts, quest flags
+//! GET  /sprites                  scene sprites (name, position, size, z, member)
+//! GET  /log?lines=N              recent log lines (default 200)
+//! POST /goto?scene=garage        warp like the dev menu (names as for --scene)
+//! POST /flag?name=%23Dog&on=0    set / clear a quest flag (list=cache|permanent)
+//! ```
+//!
+//! Never listens on other interfaces; off unless the variable is set.
+//! Every request needs the session token in an `X-OpenWilly-Token` header
+//! (printed to stderr at startup — not logged, the log is served on /log —
+//! or fixed with `OPENWILLY_DEBUG_TOKEN`), and a `Host` / `Origin` other
+//! than localhost is refused, so web pages can't reach the endpoint
+//! (cross-site requests, DNS rebinding):
+//!
+//! ```text
+//! curl -H "X-OpenWilly-Token: $TOKEN" http://127.0.0.1:7380/state
+//! ```
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use serde::Serialize;
+
+use crate::game::dev_menu::DevAction;
+use crate::game::{autolaunch, bug_report, GameState, Scene};
+
+const DEFAULT_PORT: u16 = 7380;
+/// Requests answered per frame (keeps a flood from stalling the game)
+const MAX_PER_FRAME: usize = 4;
+const MAX_REQUEST_BYTES: usize = 8192;
+/// Time a client gets to send its request head (all reads together)
+const READ_DEADLINE: Duration = Duration::from_millis(500);
+/// Time the game thread gets to answer (loading screens, paused window)
+const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A parsed request line
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    /// Header names in lower case
+    pub headers: HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct State<'a> {
+    scene: Scene,
+    profile: Option<&'a str>,
+    car_parts: &'a [u32],
+    car_name: Option<&'a str>,
+    cache_flags: &'a [String],
+    permanent_flags: &'a [String],
+    busy: bool,
+}
+
+#[derive(Serialize)]
+struct SpriteInfo {
+    name: String,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    z: i32,
+    visible: bool,
+    interactive: bool,
+    member: u32,
+}
+
+/// A request for the game thread and where its answer goes
+type Job = (Request, mpsc::Sender<(u16, String)>);
+
+pub struct DebugServer {
+    jobs: mpsc::Receiver<Job>,
+}
+
+impl DebugServer {
+    pub fn from_env() -> Option<Self> {
+        let value = std::env::var("OPENWILLY_DEBUG_HTTP").ok()?;
+        let port = match value.parse::<u16>() {
+            Ok(0 | 1) => DEFAULT_PORT,
+            Ok(port) => port,
+            Err(_) => {
+                tracing::warn!("OPENWILLY_DEBUG_HTTP: '{}' is not a port", value);
+                return None;
+            }
+        };
+        let listener = match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                tracing::warn!("Debug HTTP endpoint unavailable on port {}: {}", port, e);
+                return None;
+            }
+        };
+        let token = std::env::var("OPENWILLY_DEBUG_TOKEN").ok()
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| format!("{:032x}", rand::thread_rng().gen::<u128>()));
+        tracing::info!("Debug HTTP endpoint: http://127.0.0.1:{}/state", port);
+        // Not through tracing: the log goes out on /log and in bug reports
+        eprintln!("Debug HTTP token (X-OpenWilly-Token): {}", token);
+        Self::start(listener, token)
+    }
+
+    fn start(listener: TcpListener, token: String) -> Option<Self> {
+        // Sockets are read and written on their own thread, the game thread
+        // only answers complete requests (a slow client can't stall a frame)
+        let (tx, jobs) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("debug-http".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    let result = stream.and_then(|stream| serve(stream, &token, &tx));
+                    if let Err(e) = result {
+                        tracing::debug!("Debug HTTP: {}", e);
+                    }
+                }
+            })
+            .map_err(|e| tracing::warn!("Debug HTTP endpoint unavailable: {}", e))
+            .ok()?;
+        Some(Self { jobs })
+    }
+
+    /// Answer waiting requests (call once per frame)
+    pub fn poll(&self, game: &mut GameState) {
+        for (request, reply) in self.jobs.try_iter().take(MAX_PER_FRAME) {
+            // The connection may have given up waiting
+            reply.send(respond(game, &request)).ok();
+        }
+    }
+}
+
+/// Read the request head with one overall deadline
+fn read_head(stream: &mut TcpStream) -> std::io::Result<Vec<u8>> {
+    let deadline = Instant::now() + READ_DEADLINE;
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") && head.len() < MAX_REQUEST_BYTES {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "request head too slow"));
+        }
+        stream.set_read_timeout(Some(left))?;
+        let n = stream.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    Ok(head)
+}
+
+/// One connection (on the server thread): read, check, hand the request
+/// to the game thread, write its answer
+fn serve(mut stream: TcpStream, token: &str, jobs: &mpsc::Sender<Job>) -> std::io::Result<()> {
+    let head = read_head(&mut stream)?;
+    let (status, body) = match parse_request(&String::from_utf8_lossy(&head)) {
+        Some(request) => match check_access(&request, token) {
+            Ok(()) => {
+                let (reply, answer) = mpsc::channel();
+                if jobs.send((request, reply)).is_err() {
+                    return Ok(()); // Game shutting down
+                }
+                answer.recv_timeout(REPLY_TIMEOUT)
+                    .unwrap_or_else(|_| (503, error("game not answering")))
+            }
+            Err(e) => (403, error(e)),
+        },
+        None => (400, error("bad request")),
+    };
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        503 => "Service Unavailable",
+        _ => "Method Not Allowed",
+    };
+    stream.set_write_timeout(Some(READ_DEADLINE))?;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, reason, body.len(), body,
+    )
+}
+
+/// Request line ("GET /log?lines=50 HTTP/1.1") and headers of a request head
+pub fn parse_request(head: &str) -> Option<Request> {
+    let mut lines = head.lines();
+    let mut parts = lines.next()?.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query.split('&')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let (k, v) = p.split_once('=').unwrap_or((p, ""));
+            (percent_decode(k), percent_decode(v))
+        })
+        .collect();
+    let headers = lines
+        .take_while(|l| !l.is_empty())
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim().to_string()))
+        .collect();
+    Some(Request { method, path: path.to_string(), query, headers })
+}
+
+/// "localhost" / "127.0.0.1" with an optional port
+fn is_local_host(host: &str) -> bool {
+    let name = match host.rsplit_once(':') {
+        Some((name, port)) if port.bytes().all(|b| b.is_ascii_digit()) => name,
+        _ => host,
+    };
+    name.eq_ignore_ascii_case("localhost") || name == "127.0.0.1"
+}
+
+/// Refuse requests a web page could have sent: foreign Host or Origin
+/// (DNS rebinding, cross-site requests) or no / a wrong session token
+pub fn check_access(request: &Request, token: &str) -> Result<(), &'static str> {
+    let header = |name: &str| request.headers.get(name).map(String::as_str);
+    if !header("host").is_some_and(is_local_host) {
+        return Err("host not allowed");
+    }
+    if let Some(origin) = header("origin") {
+        let host = origin.strip_prefix("http://").unwrap_or("");
+        if !is_local_host(host) {
+            return Err("origin not allowed");
+        }
+    }
+    if header("x-openwilly-token") != Some(token) {
+        return Err("token missing or wrong");
+    }
+    Ok(())
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 2;
+            }
+            (b'+', _) => out.push(b' '),
+            (b, _) => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn error(msg: &str) -> String {
+    serde_json::to_string(&HashMap::from([("error", msg)])).unwrap_or_default()
+}
+
+fn json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+/// Status code and JSON body for a request
+pub fn respond(game: &mut GameState, request: &Request) -> (u16, String) {
+    let param = |name: &str| request.query.get(name).map(String::as_str);
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/state") => {
+            let user = game.save_manager.active();
+            let state = State {
+                scene: game.current_scene,
+                profile: user.map(|u| u.user_id.as_str()),
+                car_parts: &game.car.parts,
+                car_name: user.map(|u| u.car.name.as_str()),
+                cache_flags: &game.quest.cache,
+                permanent_flags: &game.quest.permanent,
+                busy: game.is_busy(),
+            };
+            (200, json(&state))
+        }
+        ("GET", "/sprites") => {
+            let sprites: Vec<SpriteInfo> = game.get_all_sprites().into_iter()
+                .map(|s| SpriteInfo {
+                    name: s.name,
+                    x: s.x,
+                    y: s.y,
+                    width: s.width,
+                    height: s.height,
+                    z: s.z_order,
+                    visible: s.visible,
+                    interactive: s.interactive,
+                    member: s.member_num,
+                })
+                .collect();
+            (200, json(&sprites))
+        }
+        ("GET", "/log") => {
+            let lines = param("lines").and_then(|n| n.parse().ok()).unwrap_or(200);
+            (200, json(&bug_report::recent_log(lines)))
+        }
+        ("POST", "/goto") => match param("scene").map(autolaunch::parse_scene) {
+            Some(Ok(scene)) => {
+                game.handle_dev_action(DevAction::GotoScene(scene));
+                (200, json(&game.current_scene))
+            }
+            Some(Err(e)) => (400, error(&e.to_string())),
+            None => (400, error("scene missing")),
+        },
+        ("POST", "/flag") => {
+            let Some(name) = param("name").filter(|n| !n.is_empty()) else {
+                return (400, error("name missing"));
+            };
+            let on = param("on") != Some("0");
+            match (param("list").unwrap_or("permanent"), on) {
+                ("cache", true) => game.quest.add_cache(name),
+                ("cache", false) => game.quest.remove_cache(name),
+                ("permanent", true) => {
+                    game.quest.add_permanent(name);
+                    game.save_manager.add_stuff(name);
+                }
+                ("permanent", false) => {
+                    game.quest.permanent.retain(|f| f != name);
+                    game.save_manager.remove_stuff(name);
+                }
+                (other, _) => return (400, error(&format!("unknown list '{}'", other))),
+            }
+            tracing::info!("Debug HTTP: flag {} {}", name, if on { "set" } else { "cleared" });
+            (200, json(&(&game.quest.cache, &game.quest.permanent)))
+        }
+        (_, "/state" | "/sprites" | "/log" | "/goto" | "/flag") => (405, error("method not allowed")),
+        _ => (404, error("not found")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(line: &str) -> Request {
+        parse_request(&format!("{}\r\nHost: localhost\r\n\r\n", line)).unwrap()
+    }
+
+    #[test]
+    fn foreign_pages_and_missing_token_refused() {
+        let head = |headers: &str| parse_request(&format!("POST /goto?scene=garage HTTP/1.1\r\n{}\r\n", headers)).unwrap();
+        let ok = head("Host: 127.0.0.1:7380\r\nX-OpenWilly-Token: abc\r\n");
+        assert_eq!(ok.headers["host"], "127.0.0.1:7380");
+        assert_eq!(check_access(&ok, "abc"), Ok(()));
+        assert!(check_access(&ok, "xyz").is_err());
+        assert!(check_access(&head("Host: localhost\r\n"), "abc").is_err());
+        // DNS rebinding: attacker's name resolving to 127.0.0.1
+        assert!(check_access(&head("Host: evil.example:7380\r\nX-OpenWilly-Token: abc\r\n"), "abc").is_err());
+        let cross_site = head("Host: localhost:7380\r\nOrigin: https://evil.example\r\nX-OpenWilly-Token: abc\r\n");
+        assert!(check_access(&cross_site, "abc").is_err());
+        let local_page = head("Host: localhost:7380\r\nOrigin: http://localhost:8000\r\nX-OpenWilly-Token: abc\r\n");
+        assert_eq!(check_access(&local_page, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn slow_client_does_not_block_the_game_thread() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = DebugServer::start(listener, "abc".into()).unwrap();
+        // Connects and never sends a byte
+        let _slow = TcpStream::connect(addr).unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write!(stream, "GET /state HTTP/1.1\r\nHost: 127.0.0.1\r\nX-OpenWilly-Token: abc\r\n\r\n").unwrap();
+            let mut answer = String::new();
+            stream.read_to_string(&mut answer).unwrap();
+            answer
+        });
+
+        let dir = std::env::temp_dir().join(format!("openwilly_debug_slow_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut game = GameState::headless(&dir);
+        let start = Instant::now();
+        while !client.is_finished() && start.elapsed() < Duration::from_secs(5) {
+            let frame = Instant::now();
+            server.poll(&mut game);
+            assert!(frame.elapsed() < Duration::from_millis(50));
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(client.join().unwrap().starts_with("HTTP/1.1 200 OK"));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn state_goto_and_flags() {
+        let req = request("POST /flag?name=%23Dog&on=0 HTTP/1.1");
+        assert_eq!((req.method.as_str(), req.path.as_str()), ("POST", "/flag"));
+        assert_eq!(req.query["name"], "#Dog");
+
+        let dir = std::env::temp_dir().join(format!("openwilly_debug_http_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut game = GameState::headless(&dir);
+        let (status, body) = respond(&mut game, &request("GET /state HTTP/1.1"));
+        assert_eq!(status, 200);
+        assert!(body.contains("\"scene\": \"Menu\""));
+
+        assert_eq!(respond(&mut game, &request("POST /goto?scene=junkyard HTTP/1.1")).0, 200);
+        assert_eq!(game.current_scene, Scene::Junkyard);
+        assert_eq!(respond(&mut game, &request("GET /goto?scene=yard HTTP/1.1")).0, 405);
+        assert_eq!(respond(&mut game, &request("POST /goto?scene=moon HTTP/1.1")).0, 400);
+
+        respond(&mut game, &request("POST /flag?name=%23Dog&list=cache HTTP/1.1"));
+        assert!(game.quest.has_cache("#Dog"));
+        respond(&mut game, &request("POST /flag?name=%23Dog&list=cache&on=0 HTTP/1.1"));
+        assert!(!game.quest.has_cache("#Dog"));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
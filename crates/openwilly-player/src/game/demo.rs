@@ -0,0 +1,195 @@
+//! Input demos — record a session's inputs and play them back exactly
+//!
+//! The game logic runs one `update` per frame and draws its randomness from
+//! the seeded session RNG, so the RNG seed, the profiles at the start plus
+//! the per-frame inputs (mouse, clicks, keys and typed characters in their
+//! original order, arrow keys) reproduce a session. `OPENWILLY_RECORD=<file>`
+//! records the session into a JSON demo when the game exits,
+//! `OPENWILLY_PLAYBACK=<file>` replays one (live input is ignored until the
+//! demo ends; the player's own save stays untouched, see
+//! `GameState::start_playback`). Useful for bug repros and regression tests,
+//! see `GameState::apply_frame`.
+
+use std::path::Path;
+
+use anyhow::{ensure, Context, Result};
+use minifb::Key;
+use serde::{Deserialize, Serialize};
+
+use crate::game::save::UsersDB;
+
+/// Bumped when the recorded input, the frame order or the stored start
+/// state changes
+pub const DEMO_VERSION: u32 = 3;
+
+/// Keyboard input in the order the engine delivered it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyEvent {
+    /// Typed character (`on_char_input`)
+    Char(char),
+    /// Key press (`on_key_down`), by name
+    Key(String),
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+/// Everything the game sees in one frame
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameInput {
+    pub x: i32,
+    pub y: i32,
+    /// Left button held
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub down: bool,
+    /// Left button pressed this frame
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub click: bool,
+    /// Right button pressed this frame
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub right_click: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<KeyEvent>,
+    /// Held arrow keys: up, down, left, right
+    #[serde(default, skip_serializing_if = "is_default_arrows")]
+    pub arrows: [bool; 4],
+}
+
+fn is_default_arrows(a: &[bool; 4]) -> bool {
+    *a == [false; 4]
+}
+
+/// A recorded session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Demo {
+    pub version: u32,
+    /// Session RNG seed
+    pub seed: u64,
+    /// All profiles when the recording started
+    pub save: UsersDB,
+    pub frames: Vec<FrameInput>,
+}
+
+impl Demo {
+    pub fn new(seed: u64, save: UsersDB) -> Self {
+        Self { version: DEMO_VERSION, seed, save, frames: Vec::new() }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Reading demo {}", path.display()))?;
+        let demo: Demo = serde_json::from_str(&json)
+            .with_context(|| format!("Parsing demo {}", path.display()))?;
+        ensure!(demo.version == DEMO_VERSION,
+            "Demo version {} not supported (expected {})", demo.version, DEMO_VERSION);
+        Ok(demo)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)
+            .with_context(|| format!("Writing demo {}", path.display()))
+    }
+}
+
+/// Demo being played back, frame by frame
+pub struct Playback {
+    demo: Demo,
+    pos: usize,
+}
+
+impl Playback {
+    pub fn new(demo: Demo) -> Self {
+        Self { demo, pos: 0 }
+    }
+
+    /// Next frame's input, None once the demo is over
+    pub fn next_frame(&mut self) -> Option<&FrameInput> {
+        let frame = self.demo.frames.get(self.pos)?;
+        self.pos += 1;
+        Some(frame)
+    }
+}
+
+/// Keys the game reacts to (others are not recorded)
+const KEY_NAMES: &[(Key, &str)] = &[
+    (Key::Enter, "Enter"),
+    (Key::Escape, "Escape"),
+    (Key::Space, "Space"),
+    (Key::Backspace, "Backspace"),
+    (Key::Tab, "Tab"),
+    (Key::Up, "Up"),
+    (Key::Down, "Down"),
+    (Key::Left, "Left"),
+    (Key::Right, "Right"),
+    (Key::H, "H"),
+    (Key::F1, "F1"),
+    (Key::F2, "F2"),
+    (Key::F3, "F3"),
+    (Key::F4, "F4"),
+    (Key::F5, "F5"),
+    (Key::F6, "F6"),
+    (Key::F7, "F7"),
+    (Key::F8, "F8"),
+    (Key::F9, "F9"),
+    (Key::F10, "F10"),
+    (Key::F12, "F12"),
+];
+
+pub fn key_name(key: Key) -> Option<&'static str> {
+    KEY_NAMES.iter().find(|(k, _)| *k == key).map(|(_, name)| *name)
+}
+
+pub fn parse_key(name: &str) -> Option<Key> {
+    KEY_NAMES.iter().find(|(_, n)| *n == name).map(|(k, _)| *k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_names_round_trip() {
+        for (key, name) in KEY_NAMES {
+            assert_eq!(key_name(*key), Some(*name));
+            assert_eq!(parse_key(name), Some(*key));
+        }
+        assert_eq!(key_name(Key::Q), None);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = std::env::temp_dir().join(format!("openwilly_demo_{}", std::process::id()));
+        let path = dir.join("test.demo.json");
+        let mut demo = Demo::new(42, UsersDB::default());
+        demo.frames.push(FrameInput { x: 10, y: 20, ..Default::default() });
+        demo.frames.push(FrameInput {
+            x: 11,
+            y: 20,
+            down: true,
+            click: true,
+            keys: vec![KeyEvent::Char('a'), KeyEvent::Key("Enter".into())],
+            arrows: [true, false, false, true],
+            ..Default::default()
+        });
+        demo.save(&path).unwrap();
+
+        let loaded = Demo::load(&path).unwrap();
+        assert_eq!(loaded.seed, 42);
+        assert_eq!(loaded.frames, demo.frames);
+        let mut playback = Playback::new(loaded);
+        assert_eq!(playback.next_frame().map(|f| f.x), Some(10));
+        assert!(playback.next_frame().is_some());
+        assert!(playback.next_frame().is_none());
+
+        let mut old = demo.clone();
+        old.version = 0;
+        old.save(&path).unwrap();
+        assert!(Demo::load(&path).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,138 @@
+//! Developer mode — dev-menu cheats kept across launches
+//!
+//! With the "Entwicklermodus" switch in the dev menu on, the cheat toggles
+//! are written to `<game_dir>/openwilly_dev.json` whenever one changes and
+//! restored at the next start. The file is kept apart from the player's
+//! options, so copying someone's options never turns cheats on. While a
+//! cheat is active a badge in the top-right corner says so — it ends up in
+//! bug report screenshots and demo recordings.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::font;
+use crate::game::dev_menu::DevMenu;
+
+const FILE_NAME: &str = "openwilly_dev.json";
+
+/// Saved dev-menu state
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DevConfig {
+    /// Master switch: cheats are only saved and restored while it is on
+    pub developer_mode: bool,
+    pub infinite_fuel: bool,
+    pub noclip: bool,
+    pub show_hitboxes: bool,
+    pub skip_dialogs: bool,
+    pub meme_mode: bool,
+}
+
+impl DevConfig {
+    fn path(game_dir: &Path) -> PathBuf {
+        game_dir.join(FILE_NAME)
+    }
+
+    /// Saved state, everything off if there is none (or it can't be read)
+    pub fn load(game_dir: &Path) -> Self {
+        let path = Self::path(game_dir);
+        let Ok(json) = std::fs::read_to_string(&path) else { return Self::default() };
+        match serde_json::from_str(&json) {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Invalid {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self, game_dir: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(game_dir), json)?;
+        Ok(())
+    }
+
+    /// State to save — only the switch while developer mode is off
+    pub fn of(menu: &DevMenu) -> Self {
+        if !menu.developer_mode {
+            return Self::default();
+        }
+        Self {
+            developer_mode: true,
+            infinite_fuel: menu.infinite_fuel,
+            noclip: menu.noclip,
+            show_hitboxes: menu.show_hitboxes,
+            skip_dialogs: menu.skip_dialogs,
+            meme_mode: menu.meme_mode,
+        }
+    }
+
+    /// Restore the toggles (nothing unless developer mode was on)
+    pub fn apply(&self, menu: &mut DevMenu) {
+        if !self.developer_mode {
+            return;
+        }
+        menu.developer_mode = true;
+        menu.infinite_fuel = self.infinite_fuel;
+        menu.noclip = self.noclip;
+        menu.show_hitboxes = self.show_hitboxes;
+        menu.skip_dialogs = self.skip_dialogs;
+        menu.meme_mode = self.meme_mode;
+        let cheats = active_cheats(menu);
+        if !cheats.is_empty() {
+            tracing::info!("Developer mode: restored cheats {}", cheats.join(", "));
+        }
+    }
+}
+
+/// Short names of the cheats that change the game (hitboxes only draw)
+pub fn active_cheats(menu: &DevMenu) -> Vec<&'static str> {
+    [
+        (menu.infinite_fuel, "Benzin"),
+        (menu.noclip, "Noclip"),
+        (menu.skip_dialogs, "Dialoge"),
+        (menu.meme_mode, "Meme"),
+    ]
+    .into_iter()
+    .filter_map(|(on, name)| on.then_some(name))
+    .collect()
+}
+
+/// Badge in the top-right corner while a cheat is on
+pub fn draw_badge(fb: &mut [u32], cheats: &[&str]) {
+    if cheats.is_empty() {
+        return;
+    }
+    let text = format!("CHEATS: {}", cheats.join(" "));
+    let w = font::text_width(&text) + 10;
+    let x = 640 - w - 4;
+    font::draw_rect(fb, x, 4, w, 16, 0xCC330000);
+    font::draw_rect_outline(fb, x, 4, w, 16, 0xFFFF4444);
+    font::draw_text_shadow(fb, x + 5, 8, &text, 0xFFFF8888);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cheats_persist_only_in_developer_mode() {
+        let mut menu = DevMenu::new();
+        menu.noclip = true;
+        assert_eq!(DevConfig::of(&menu), DevConfig::default());
+
+        menu.developer_mode = true;
+        let dir = std::env::temp_dir().join(format!("openwilly_dev_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        DevConfig::of(&menu).save(&dir).unwrap();
+        let loaded = DevConfig::load(&dir);
+        std::fs::remove_dir_all(&dir).ok();
+
+        let mut restored = DevMenu::new();
+        loaded.apply(&mut restored);
+        assert!(restored.developer_mode && restored.noclip && !restored.infinite_fuel);
+        assert_eq!(active_cheats(&restored), vec!["Noclip"]);
+    }
+}
@@ -2,11 +2,17 @@
 //!
 //! Contains cheat toggles, dev triggers (scene warps, refuel), and the
 //! meme physics mode.  Opened/closed with 5× '#' within 2 seconds,
-//! confirmed by a quiet beep sound.
+//! confirmed by a quiet beep sound.  Keyboards where '#' is awkward to
+//! reach can use the hotkey instead (5× F12, or the key named in
+//! `OPENWILLY_DEV_KEY`, e.g. `F10`).  With developer mode on, the cheats
+//! are kept across launches (see `dev_config`).
 
 use std::time::Instant;
+use minifb::Key;
 use crate::engine::font;
 use crate::engine::{SCREEN_WIDTH, SCREEN_HEIGHT, DisplayMode};
+use crate::game::demo;
+use crate::game::parts_audit::PartsFix;
 use crate::game::Scene;
 
 // ─── Menu definition ────────────────────────────────────────────────────
@@ -40,16 +46,26 @@ const MENU: &[MenuItem] = &[
     MenuItem { label: "-> Schrottplatz",       kind: ItemKind::Trigger }, // 10
     MenuItem { label: "Tank auffuellen",       kind: ItemKind::Trigger }, // 11
     MenuItem { label: "Figge in Werkstatt",    kind: ItemKind::Trigger }, // 12
+    MenuItem { label: "Geburtstag = heute",    kind: ItemKind::Trigger }, // 13
+    MenuItem { label: "Fehler melden",         kind: ItemKind::Trigger }, // 14
+    // ── Parts report ──
+    MenuItem { label: "Teile-Bericht",         kind: ItemKind::Toggle },  // 15
+    MenuItem { label: "Teile: Doppelte weg",   kind: ItemKind::Trigger }, // 16
+    MenuItem { label: "Teile: Unbekannte -> Haufen 1", kind: ItemKind::Trigger }, // 17
+    // ── Developer mode ──
+    MenuItem { label: "Entwicklermodus (merken)", kind: ItemKind::Toggle }, // 18
     // ── Close ──
-    MenuItem { label: "Schliessen",            kind: ItemKind::Close },   // 13
+    MenuItem { label: "Schliessen",            kind: ItemKind::Close },   // 19
 ];
 
 // ─── Public types ───────────────────────────────────────────────────────
 
 /// Result from a dev-menu interaction
 pub enum DevAction {
-    /// Nothing happened / toggle was flipped in place
+    /// Nothing happened
     None,
+    /// A toggle was flipped in place
+    Toggled,
     /// Menu was closed
     Close,
     /// Warp to a different scene
@@ -58,6 +74,12 @@ pub enum DevAction {
     RefuelTank,
     /// Set #FiggeIsComing and go to Garage to trigger Figge cutscene
     TriggerFigge,
+    /// Set the profile's birthday to today (seasonal party hat)
+    BirthdayToday,
+    /// Bundle log, screenshot and save into a bug report zip
+    ReportIssue,
+    /// Clean up the active profile's parts
+    FixParts(PartsFix),
 }
 
 /// The dev menu state
@@ -76,12 +98,36 @@ pub struct DevMenu {
     pub detail_noise: bool,
     pub display_mode: DisplayMode,
 
+    // ── Debug overlays ──
+    /// Parts distribution of the active profile (top left)
+    pub show_parts_report: bool,
+
+    /// Save the cheat toggles and restore them at the next start
+    pub developer_mode: bool,
+
     // ── Activation detector ──
+    /// Fallback trigger key, counts like '#'
+    pub hotkey: Key,
     hash_times: Vec<Instant>,
 }
 
+/// Hotkey from `OPENWILLY_DEV_KEY` (a key name as in demos), F12 if unset
+fn hotkey_from_env() -> Key {
+    let Ok(name) = std::env::var("OPENWILLY_DEV_KEY") else { return Key::F12 };
+    demo::parse_key(&name).unwrap_or_else(|| {
+        tracing::warn!("OPENWILLY_DEV_KEY: unknown key '{}', using F12", name);
+        Key::F12
+    })
+}
+
 // ─── Implementation ─────────────────────────────────────────────────────
 
+impl Default for DevMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DevMenu {
     pub fn new() -> Self {
         Self {
@@ -94,11 +140,14 @@ impl DevMenu {
             meme_mode: false,
             detail_noise: false,
             display_mode: DisplayMode::Pillarbox,
+            show_parts_report: false,
+            developer_mode: false,
+            hotkey: hotkey_from_env(),
             hash_times: Vec::new(),
         }
     }
 
-    /// Record a '#' key press.  Returns `true` when 5 presses landed inside
+    /// Record a '#' (or hotkey) press.  Returns `true` when 5 presses landed inside
     /// a 2-second window → the menu should be toggled and the beep played.
     pub fn on_hash_press(&mut self) -> bool {
         let now = Instant::now();
@@ -116,6 +165,15 @@ impl DevMenu {
         }
     }
 
+    /// Close the menu and switch all cheats off (speedrun mode)
+    pub fn disable_cheats(&mut self) {
+        self.open = false;
+        self.infinite_fuel = false;
+        self.noclip = false;
+        self.skip_dialogs = false;
+        self.meme_mode = false;
+    }
+
     // ── Keyboard navigation ─────────────────────────────────────────────
 
     pub fn nav_up(&mut self) {
@@ -136,7 +194,7 @@ impl DevMenu {
         match item.kind {
             ItemKind::Toggle => {
                 self.flip_toggle(self.selected);
-                DevAction::None
+                DevAction::Toggled
             }
             ItemKind::Trigger => self.fire_trigger(self.selected),
             ItemKind::Close => {
@@ -148,26 +206,33 @@ impl DevMenu {
 
     /// Handle a mouse click while the menu is open.
     pub fn on_click(&mut self, mx: i32, my: i32) -> DevAction {
+        match Self::item_at(mx, my) {
+            Some(i) => {
+                self.selected = i;
+                self.activate()
+            }
+            None => DevAction::None,
+        }
+    }
+
+    /// Menu item under the mouse
+    pub fn item_at(mx: i32, my: i32) -> Option<usize> {
         let (box_x, box_y, box_w, _box_h, item_h) = Self::layout();
         let items_y = box_y + 40;
-        for i in 0..MENU.len() {
+        (0..MENU.len()).find(|&i| {
             let iy = items_y + i as i32 * item_h;
-            if mx >= box_x + 4
+            mx >= box_x + 4
                 && mx < box_x + box_w - 4
                 && my >= iy
                 && my < iy + item_h
-            {
-                self.selected = i;
-                return self.activate();
-            }
-        }
-        DevAction::None
+        })
     }
 
     // ── Rendering ───────────────────────────────────────────────────────
 
     /// Draw the dev-menu overlay onto the 640×480 framebuffer.
-    pub fn draw(&self, fb: &mut [u32]) {
+    /// `missing` = cast members replaced by placeholders this session.
+    pub fn draw(&self, fb: &mut [u32], missing: usize) {
         if !self.open {
             return;
         }
@@ -203,6 +268,10 @@ impl DevMenu {
             title,
             0xFF00FF88,
         );
+        if missing > 0 {
+            let text = format!("Fehlend: {}", missing);
+            font::draw_text(fb, box_x + box_w - 10 - font::text_width(&text), box_y + 14, &text, 0xFFFF6666);
+        }
 
         // Items
         let items_y = box_y + 40;
@@ -216,7 +285,7 @@ impl DevMenu {
             }
 
             // Section dividers
-            if i == 5 || i == 6 || i == MENU.len() - 1 {
+            if i == 5 || i == 6 || i == 15 || i == 18 || i == MENU.len() - 1 {
                 font::draw_rect(fb, box_x + 10, iy - 2, box_w - 20, 1, 0xFF336644);
             }
 
@@ -261,7 +330,8 @@ impl DevMenu {
     // ── Internals ───────────────────────────────────────────────────────
 
     fn layout() -> (i32, i32, i32, i32, i32) {
-        let item_h: i32 = 22;
+        // 21 px keeps all items inside the 480 px screen
+        let item_h: i32 = 21;
         let box_w: i32 = 320;
         let box_h: i32 = 50 + MENU.len() as i32 * item_h;
         let box_x = (SCREEN_WIDTH as i32 - box_w) / 2;
@@ -277,6 +347,8 @@ impl DevMenu {
             3 => Some(self.skip_dialogs),
             4 => Some(self.meme_mode),
             5 => Some(self.detail_noise),
+            15 => Some(self.show_parts_report),
+            18 => Some(self.developer_mode),
             _ => None,
         }
     }
@@ -289,6 +361,8 @@ impl DevMenu {
             3 => self.skip_dialogs = !self.skip_dialogs,
             4 => self.meme_mode = !self.meme_mode,
             5 => self.detail_noise = !self.detail_noise,
+            15 => self.show_parts_report = !self.show_parts_report,
+            18 => self.developer_mode = !self.developer_mode,
             _ => {}
         }
         let name = MENU.get(idx).map(|m| m.label).unwrap_or("?");
@@ -306,6 +380,10 @@ impl DevMenu {
             10 => DevAction::GotoScene(Scene::Junkyard),
             11 => DevAction::RefuelTank,
             12 => DevAction::TriggerFigge,
+            13 => DevAction::BirthdayToday,
+            14 => DevAction::ReportIssue,
+            16 => DevAction::FixParts(PartsFix::Dedupe),
+            17 => DevAction::FixParts(PartsFix::ReturnOrphans),
             _ => DevAction::None,
         }
     }
@@ -10,6 +10,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::assets::director::CuePoint;
 use crate::engine::sound_engine::PlaybackHandle;
+use crate::game::tow;
 
 // ---------------------------------------------------------------------------
 // Dialog system
@@ -176,6 +177,12 @@ pub struct DialogManager {
     cue_tracker: Option<CueTracker>,
 }
 
+impl Default for DialogManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DialogManager {
     pub fn new() -> Self {
         let mut mgr = Self {
@@ -373,6 +380,12 @@ impl DialogManager {
             SubtitleLine::new("- Danke, dass du {Salka} zurückgebracht hast!", "figge"),
             SubtitleLine::new("- Hier, nimm diesen Extra-Tank als Belohnung.", "figge"),
         ]);
+
+        // Tow truck call from the toolbox (no audio, see tow.rs)
+        self.set_lines(tow::CALL_DIALOG, vec![
+            SubtitleLine::new("- Hallo Figge? Ich komme nicht mehr weiter!", "mulle"),
+            SubtitleLine::new("- Bleib wo du bist, ich schlepp dich zur Tankstelle.", "figge"),
+        ]);
     }
 }
 
@@ -391,6 +404,12 @@ pub struct QuestState {
     pub permanent: Vec<String>,
 }
 
+impl Default for QuestState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl QuestState {
     pub fn new() -> Self {
         Self {
@@ -469,6 +488,15 @@ pub struct Mission {
     pub image: String,
     /// Audio member name for mission sound
     pub sound: String,
+    /// Part handed over at the completion ceremony in the yard
+    pub reward_part: Option<u32>,
+}
+
+impl Mission {
+    /// Permanent flag set when the mission is completed
+    pub fn done_flag(&self) -> String {
+        format!("#Mission{}Done", self.mission_id)
+    }
 }
 
 /// How a mission is delivered
@@ -491,22 +519,24 @@ impl MissionDB {
 
         let data = [
             // From missions.hash.json: (id, delivery_type, mail_image, sound)
-            (1, MissionDelivery::Telephone, "", "50d001v0"),
-            (2, MissionDelivery::Mail, "50b001v0", "50d016v0"),
-            (3, MissionDelivery::Mail, "50b002v0", "50d017v0"),
-            (4, MissionDelivery::Telephone, "", "50d018v0"),
-            (5, MissionDelivery::Telephone, "", "50d019v0"),
-            (6, MissionDelivery::Telephone, "", "50d020v0"),
-            (7, MissionDelivery::Mail, "50b003v0", "50d021v0"),
-            (8, MissionDelivery::Mail, "50b004v0", "50d022v0"),
+            // + reward part (Doris Digital: 306, Luddel Abb: 99)
+            (1, MissionDelivery::Telephone, "", "50d001v0", None),
+            (2, MissionDelivery::Mail, "50b001v0", "50d016v0", None),
+            (3, MissionDelivery::Mail, "50b002v0", "50d017v0", None),
+            (4, MissionDelivery::Telephone, "", "50d018v0", Some(306)),
+            (5, MissionDelivery::Telephone, "", "50d019v0", None),
+            (6, MissionDelivery::Telephone, "", "50d020v0", Some(99)),
+            (7, MissionDelivery::Mail, "50b003v0", "50d021v0", None),
+            (8, MissionDelivery::Mail, "50b004v0", "50d022v0", None),
         ];
 
-        for (id, delivery, image, sound) in data {
+        for (id, delivery, image, sound, reward_part) in data {
             missions.insert(id, Mission {
                 mission_id: id,
                 delivery,
                 image: image.to_string(),
                 sound: sound.to_string(),
+                reward_part,
             });
         }
 
@@ -514,7 +544,6 @@ impl MissionDB {
     }
 
     /// Get a mission by ID
-    #[allow(dead_code)] // Used by mission delivery system (upcoming)
     pub fn get(&self, id: u32) -> Option<&Mission> {
         self.missions.get(&id)
     }
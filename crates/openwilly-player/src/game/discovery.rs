@@ -0,0 +1,120 @@
+//! Destination discovery — "New place discovered" toasts while driving
+//!
+//! The first time the car enters a map tile with a destination on it, the
+//! destination counts as discovered for the profile (`ProfileStats::discovered`)
+//! and a short toast names it. Discovered places are marked on the minimap
+//! and counted on the statistics overlay.
+
+use crate::engine::font;
+use crate::engine::SCREEN_WIDTH;
+use crate::game::driving::{MapObjectType, MapTile};
+use crate::game::i18n::{self, Language};
+
+/// Destination scenes that can be discovered on the map
+pub const DESTINATIONS: std::ops::RangeInclusive<u8> = 82..=94;
+
+/// How long a toast stays up (frames at 30 fps)
+const TOAST_FRAMES: u32 = 105;
+/// Toasts fade out over the last frames
+const FADE_FRAMES: u32 = 15;
+const TOAST_Y: i32 = 120;
+
+/// Destination scene numbers placed on a tile (enabled objects only)
+pub fn tile_destinations(tile: &MapTile) -> Vec<u8> {
+    let mut out: Vec<u8> = tile.objects.iter()
+        .filter(|o| o.enabled)
+        .filter(|o| matches!(o.obj_type, MapObjectType::Destination | MapObjectType::RandomDestination))
+        .filter_map(|o| o.dir_resource.as_deref()?.parse::<u8>().ok())
+        .filter(|n| DESTINATIONS.contains(n))
+        .collect();
+    out.dedup();
+    out
+}
+
+/// i18n key for a destination's name
+pub fn name_key(destination: u8) -> &'static str {
+    match destination {
+        82 => "dest_82",
+        83 => "dest_83",
+        84 => "dest_84",
+        85 => "dest_85",
+        86 => "dest_86",
+        87 => "dest_87",
+        88 => "dest_88",
+        89 => "dest_89",
+        90 => "dest_90",
+        91 => "dest_91",
+        92 => "dest_92",
+        93 => "dest_93",
+        _ => "dest_94",
+    }
+}
+
+/// A timed message at the top of the screen
+pub struct Toast {
+    text: String,
+    frames: u32,
+}
+
+impl Toast {
+    /// Toast with a ready-made message
+    pub fn new(text: &str) -> Self {
+        Self { text: text.to_string(), frames: TOAST_FRAMES }
+    }
+
+    /// Toast for newly discovered destinations
+    pub fn discovered(destinations: &[u8], lang: Language) -> Self {
+        let names: Vec<&str> = destinations.iter().map(|&d| i18n::t(lang, name_key(d))).collect();
+        Self {
+            text: format!("{} {}!", i18n::t(lang, "discovered"), names.join(", ")),
+            frames: TOAST_FRAMES,
+        }
+    }
+
+    /// Draw and count down. Returns false once the toast has expired.
+    pub fn draw(&mut self, fb: &mut [u32]) -> bool {
+        if self.frames == 0 {
+            return false;
+        }
+        self.frames -= 1;
+        let alpha = (self.frames.min(FADE_FRAMES) * 0xCC / FADE_FRAMES) << 24;
+        let w = font::text_width(&self.text) + 24;
+        let x = (SCREEN_WIDTH as i32 - w) / 2;
+        font::draw_rect(fb, x, TOAST_Y, w, 24, alpha | 0x1A1A2E);
+        if self.frames > FADE_FRAMES / 2 {
+            font::draw_rect_outline(fb, x, TOAST_Y, w, 24, 0xFFFFD23F);
+            font::draw_text_shadow(fb, x + 12, TOAST_Y + 8, &self.text, 0xFFFFFFFF);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::driving::WorldMap;
+
+    #[test]
+    fn default_map_destinations() {
+        let wm = WorldMap::default_map();
+        let mut all: Vec<u8> = wm.tiles.values().flat_map(tile_destinations).collect();
+        all.sort();
+        all.dedup();
+        // The yard ("04") is home, not a destination
+        assert!(all.iter().all(|n| DESTINATIONS.contains(n)));
+        assert!(all.contains(&92));
+        let start = wm.tile_at(wm.start_tile.0, wm.start_tile.1).unwrap();
+        assert!(tile_destinations(wm.get_tile(start).unwrap()).contains(&92));
+    }
+
+    #[test]
+    fn toast_expires() {
+        let mut fb = vec![0u32; SCREEN_WIDTH * crate::engine::SCREEN_HEIGHT];
+        let mut toast = Toast::discovered(&[86, 92], Language::English);
+        assert_eq!(toast.text, "New place discovered: Mia's house, Figge Ferrum!");
+        for _ in 0..TOAST_FRAMES {
+            assert!(toast.draw(&mut fb));
+        }
+        assert!(!toast.draw(&mut fb));
+    }
+}
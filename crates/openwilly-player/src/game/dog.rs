@@ -0,0 +1,119 @@
+//! Salka the dog as a passenger
+//!
+//! After picking Salka up at the RoadDog destination (85, sets `#Dog`) she
+//! rides along in the car until she is delivered to Figge (92). While she is
+//! aboard she is drawn on top of the driving car and barks now and then —
+//! at cows on the road and when the horn is honked. At home she waits in the yard.
+
+use rand::Rng;
+
+use crate::game::driving::direction_vector;
+
+/// Cache flag set while Salka is with Mulle
+pub const DOG_FLAG: &str = "#Dog";
+
+/// Passenger bitmaps (05.CXT), facing right / left — resolved by name.
+/// The names follow the cast's `05b<nnn>v0` bitmap naming but are not
+/// confirmed against a cast listing; if they are missing Salka still rides
+/// along (flag, barks), she is just not drawn on the back seat.
+pub const DOG_SPRITE_RIGHT: &str = "05b060v0";
+pub const DOG_SPRITE_LEFT: &str = "05b061v0";
+
+/// Bark sounds, picked at random
+const BARK_SOUNDS: [&str; 2] = ["85e002v0", "85e003v0"];
+
+/// Minimum frames between two barks (~3 s at 30 fps)
+const BARK_COOLDOWN: u32 = 90;
+
+/// How far behind the car's center the dog sits (pixels along the heading)
+const SEAT_BACK: f32 = 6.0;
+/// Lift above the car's center so she peeks over the seat
+const SEAT_UP: i32 = 10;
+
+/// Things that can make Salka bark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarkTrigger {
+    /// Cows / goats blocking the road
+    Animals,
+    /// Mulle honks the horn
+    Horn,
+}
+
+impl BarkTrigger {
+    /// Chance (0.0–1.0) that the trigger makes her bark
+    fn chance(self) -> f64 {
+        match self {
+            BarkTrigger::Animals => 0.9,
+            BarkTrigger::Horn => 0.5,
+        }
+    }
+}
+
+/// Offset of the dog sprite center from the car center for a compass
+/// direction (1–16): she sits in the back, so the offset points against
+/// the driving direction.
+pub fn seat_offset(direction: u8) -> (i32, i32) {
+    let (dx, dy) = direction_vector(direction);
+    ((-dx * SEAT_BACK).round() as i32, (-dy * SEAT_BACK).round() as i32 - SEAT_UP)
+}
+
+/// Which sprite to use for a compass direction (1–8 east half → right)
+pub fn sprite_name(direction: u8) -> &'static str {
+    if (1..=8).contains(&direction) { DOG_SPRITE_RIGHT } else { DOG_SPRITE_LEFT }
+}
+
+/// Bark timing for the passenger dog
+#[derive(Debug, Default)]
+pub struct DogPassenger {
+    cooldown: u32,
+}
+
+impl DogPassenger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance one frame
+    pub fn tick(&mut self) {
+        self.cooldown = self.cooldown.saturating_sub(1);
+    }
+
+    /// React to a trigger. Returns the bark sound to play, if she barks.
+    pub fn on_trigger(&mut self, trigger: BarkTrigger, rng: &mut impl Rng) -> Option<&'static str> {
+        if self.cooldown > 0 || !rng.gen_bool(trigger.chance()) {
+            return None;
+        }
+        self.cooldown = BARK_COOLDOWN;
+        Some(BARK_SOUNDS[rng.gen_range(0..BARK_SOUNDS.len())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn seat_is_behind_the_driver() {
+        // Direction 4 (east): she sits further west
+        let (x, y) = seat_offset(4);
+        assert!(x < 0);
+        assert_eq!(y, -SEAT_UP);
+        // Direction 12 (west): further east
+        assert!(seat_offset(12).0 > 0);
+        assert_eq!(sprite_name(4), DOG_SPRITE_RIGHT);
+        assert_eq!(sprite_name(12), DOG_SPRITE_LEFT);
+    }
+
+    #[test]
+    fn bark_respects_cooldown() {
+        let mut dog = DogPassenger::new();
+        let mut rng = StepRng::new(0, 0); // gen_bool → always true
+        assert!(dog.on_trigger(BarkTrigger::Animals, &mut rng).is_some());
+        assert!(dog.on_trigger(BarkTrigger::Horn, &mut rng).is_none());
+        for _ in 0..BARK_COOLDOWN {
+            dog.tick();
+        }
+        assert!(dog.on_trigger(BarkTrigger::Horn, &mut rng).is_some());
+    }
+}
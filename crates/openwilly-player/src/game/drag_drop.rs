@@ -190,6 +190,14 @@ impl DraggableItem {
         self.junk_sprite.y = self.y;
     }
 
+    /// Lift the item to `y` and let gravity take it from there
+    pub fn drop_from(&mut self, y: i32) {
+        self.y = y;
+        self.velocity_y = 0.0;
+        self.on_ground = false;
+        self.sync_sprite_pos();
+    }
+
     /// Get the renderable sprite — UseView when snapped, junkView otherwise
     pub fn as_sprite(&self) -> Sprite {
         if self.can_attach {
@@ -713,7 +721,7 @@ mod tests {
         // Create a small test sprite
         let sprite = crate::engine::Sprite {
             x: 0, y: 0, width: 10, height: 10,
-            pixels: vec![255; 10 * 10 * 4],
+            pixels: vec![255; 10 * 10 * 4].into(),
             visible: true, z_order: 0,
             name: "test".to_string(),
             interactive: false, member_num: 0,
@@ -8,9 +8,12 @@
 //!   - Radius-based destination triggering
 //!   - Session state for saving position when entering destinations
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
 use rand::Rng;
 
+use crate::engine::sound_cues::SoundCue;
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
@@ -20,9 +23,7 @@ pub const DRIVE_FPS: u32 = 30;
 /// Number of discrete compass directions
 pub const NUM_DIRECTIONS: usize = 16;
 /// Visible map tile size
-#[allow(dead_code)]
 pub const MAP_WIDTH: i32 = 640;
-#[allow(dead_code)]
 pub const MAP_HEIGHT: i32 = 396;
 /// Topology bitmap resolution (half of visible, with offset)
 pub const TOPO_WIDTH: i32 = 316;
@@ -53,11 +54,20 @@ pub const COW_MOO_SOUND: &str = "31e001v0";
 /// "No horn" speech sound (Mulle says "we need a horn")
 pub const NO_HORN_SOUND: &str = "31d001v0";
 /// Mud blocking speech sound
-#[allow(dead_code)]
 pub const MUD_SOUND: &str = "05d003v0";
 /// Rocks/holes blocking speech sound
-#[allow(dead_code)]
 pub const HOLES_SOUND: &str = "05d002v0";
+/// Wall hit: the floor thuds (light/medium/heavy) as variations
+pub const WALL_THUD_CUE: SoundCue = SoundCue {
+    name: "wall",
+    samples: &["00e001v0", "00e002v0", "00e003v0"],
+    cooldown: Duration::from_millis(400),
+};
+/// Stuck in mud / holes: Mulle's remark, not every frame
+pub const MUD_CUE: SoundCue = SoundCue { name: "mud", samples: &[MUD_SOUND], cooldown: Duration::from_secs(8) };
+pub const HOLES_CUE: SoundCue = SoundCue { name: "holes", samples: &[HOLES_SOUND], cooldown: Duration::from_secs(8) };
+/// Impacts below this fraction of top speed stay silent
+pub const MIN_AUDIBLE_IMPACT: f32 = 0.05;
 
 /// Ferry drop-off positions (mulle.js Ferry.js), indexed by ferry_step (0 or 1)
 pub const FERRY_DROP_POSITIONS: [(f32, f32); 2] = [(254.0, 177.0), (369.0, 224.0)];
@@ -81,6 +91,20 @@ pub const WBRIDGE_CREAK_SOUND: &str = "31e003v0";
 pub const WBRIDGE_WARN_SOUND: &str = "31d010v0";
 /// CBridge sound (from objects.hash.json Sounds[0])
 pub const CBRIDGE_SOUND: &str = "31e004v0";
+/// Engine sounds: 9 engine types × 7 states: [startup, shutdown, idle, speed1, speed2, speed3, speed4]
+const ENGINE_SOUNDS: [[&str; 7]; 9] = [
+    ["05e073v0", "05e079v0", "05e074v0", "05e075v0", "05e076v0", "05e077v0", "05e078v0"], // type 1
+    ["05e067v0", "05e073v0", "05e068v0", "05e069v0", "05e070v0", "05e071v0", "05e072v0"], // type 2
+    ["05e025v0", "05e031v0", "05e026v0", "05e027v0", "05e028v0", "05e029v0", "05e030v0"], // type 3
+    ["05e004v0", "05e010v0", "05e005v0", "05e006v0", "05e007v0", "05e008v0", "05e009v0"], // type 4
+    ["05e011v0", "05e017v0", "05e012v0", "05e013v0", "05e014v0", "05e015v0", "05e016v0"], // type 5
+    ["05e053v0", "05e059v0", "05e054v0", "05e055v0", "05e056v0", "05e057v0", "05e058v0"], // type 6
+    ["05e018v0", "05e024v0", "05e019v0", "05e020v0", "05e021v0", "05e022v0", "05e023v0"], // type 7
+    ["05e060v0", "05e066v0", "05e061v0", "05e062v0", "05e063v0", "05e064v0", "05e065v0"], // type 8
+    ["05e032v0", "05e038v0", "05e033v0", "05e034v0", "05e035v0", "05e036v0", "05e037v0"], // type 9
+];
+/// Engine loop layers: idle state and speed2 (the adaptive loop pitches them)
+const ENGINE_LOOP_STATES: (usize, usize) = (2, 4);
 
 // ---------------------------------------------------------------------------
 // Types
@@ -183,6 +207,8 @@ pub enum MapObjectType {
     Picture,
     /// Sound trigger zone
     Sound,
+    /// Random event spawned for this session (see random_events.rs)
+    RandomEvent,
 }
 
 /// Racing mini-game state (mulle.js Racing.js)
@@ -265,29 +291,20 @@ impl MapObject {
     ///   → disable the object (invisible + no collision)
     ///   if CheckFor.Medals contains a medal the player has → same.
     pub fn do_check(&mut self, cache_list: &[String], medals: &[String]) {
-        // Check cache flags
-        for cache_val in &self.check_for_cache {
-            if cache_list.iter().any(|c| c == cache_val) {
-                if self.if_found.as_deref() == Some("#NoDisplay") {
-                    self.enabled = false;
-                    tracing::debug!("MapObject {} disabled by CheckFor {:?} (cache has {:?})",
-                        self.object_id, self.check_for_cache, cache_val);
-                    return;
-                }
-            }
+        if self.is_hidden(cache_list, medals) {
+            self.enabled = false;
+            tracing::debug!("MapObject {} disabled by CheckFor {:?} / Medals {:?}",
+                self.object_id, self.check_for_cache, self.check_for_medals);
         }
-        // Check medals
-        for &medal_id in &self.check_for_medals {
-            let medal_str = medal_id.to_string();
-            if medals.iter().any(|m| m == &medal_str) {
-                if self.if_found.as_deref() == Some("#NoDisplay") {
-                    self.enabled = false;
-                    tracing::debug!("MapObject {} disabled by CheckFor.Medals (has medal {})",
-                        self.object_id, medal_id);
-                    return;
-                }
-            }
+    }
+
+    /// Whether `do_check` would disable this object (without changing it)
+    pub fn is_hidden(&self, cache_list: &[String], medals: &[String]) -> bool {
+        if self.if_found.as_deref() != Some("#NoDisplay") {
+            return false;
         }
+        self.check_for_cache.iter().any(|flag| cache_list.contains(flag))
+            || self.check_for_medals.iter().any(|id| medals.iter().any(|m| *m == id.to_string()))
     }
 }
 
@@ -322,18 +339,21 @@ impl WorldMap {
     /// For each rdest object_id (8=TreeInRoad, 9=Dog, 10=MudCar), collect all
     /// tile positions, pick ONE random tile per type, and disable the rest.
     /// This ensures only one instance of each random event appears per session.
-    pub fn apply_random_destinations(&mut self) {
+    /// Candidates are visited in tile order, so a seeded `rng` (demos,
+    /// speedruns) always keeps the same tiles.
+    pub fn apply_random_destinations(&mut self, rng: &mut impl Rng) {
         // Collect: object_id → list of (tile_id, object_index)
-        let mut rdest_map: HashMap<u32, Vec<(u32, usize)>> = HashMap::new();
-        for (&tile_id, tile) in &self.tiles {
-            for (idx, obj) in tile.objects.iter().enumerate() {
+        let mut tile_ids: Vec<u32> = self.tiles.keys().copied().collect();
+        tile_ids.sort_unstable();
+        let mut rdest_map: BTreeMap<u32, Vec<(u32, usize)>> = BTreeMap::new();
+        for tile_id in tile_ids {
+            for (idx, obj) in self.tiles[&tile_id].objects.iter().enumerate() {
                 if obj.obj_type == MapObjectType::RandomDestination {
                     rdest_map.entry(obj.object_id).or_default().push((tile_id, idx));
                 }
             }
         }
 
-        let mut rng = rand::thread_rng();
         for (obj_id, positions) in &rdest_map {
             if positions.len() <= 1 { continue; }
             // Pick one random position to keep
@@ -800,8 +820,34 @@ impl WorldMap {
     }
 }
 
+/// Object closest to the car across the whole world grid (tiles laid out
+/// side by side) among those `filter` accepts: (tile col, tile row, object,
+/// offset from the car in world pixels)
+pub fn nearest_object(
+    wm: &WorldMap,
+    (col, row): (usize, usize),
+    (x, y): (f32, f32),
+    filter: impl Fn(&MapObject) -> bool,
+) -> Option<(usize, usize, &MapObject, (f32, f32))> {
+    let car = (col as f32 * MAP_WIDTH as f32 + x, row as f32 * MAP_HEIGHT as f32 + y);
+    wm.grid.iter().enumerate()
+        .flat_map(|(r, tiles)| tiles.iter().enumerate().map(move |(c, tile_id)| (c, r, tile_id)))
+        .filter_map(|(c, r, tile_id)| wm.get_tile(*tile_id).map(|tile| (c, r, tile)))
+        .flat_map(|(c, r, tile)| tile.objects.iter().map(move |obj| (c, r, obj)))
+        .filter(|(_, _, obj)| filter(obj))
+        .map(|(c, r, obj)| {
+            let dx = c as f32 * MAP_WIDTH as f32 + obj.x as f32 - car.0;
+            let dy = r as f32 * MAP_HEIGHT as f32 + obj.y as f32 - car.1;
+            (c, r, obj, (dx, dy))
+        })
+        .min_by(|a, b| {
+            let dist = |(dx, dy): (f32, f32)| dx * dx + dy * dy;
+            dist(a.3).total_cmp(&dist(b.3))
+        })
+}
+
 /// Session state — saved when entering a destination, restored when returning
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct DriveSession {
     /// Current tile grid position
     pub tile_col: usize,
@@ -942,6 +988,8 @@ pub struct DriveCar {
     pub ferry_step: u8,
     /// Racing mini-game state (persists across frames within a tile)
     pub racing: Option<RacingState>,
+    /// Last move went over holes (controller rumble)
+    pub over_holes: bool,
     /// Pending approach sounds to play (filled during update, drained by caller)
     pub pending_approach_sounds: Vec<String>,
     /// Set of object IDs whose outer radius we've already entered (for one-shot approach sounds)
@@ -968,6 +1016,8 @@ pub enum DriveEvent {
     /// Hit terrain obstacle
     TerrainBlocked {
         reason: &'static str,
+        /// Speed before the hit, as a fraction of top speed (0.0 – 1.0)
+        impact: f32,
     },
     /// Hit a gas station — game should start refueling
     GasStation,
@@ -994,6 +1044,8 @@ pub enum DriveEvent {
     FarAwayReached { object_id: u32 },
     /// Sound trigger zone entered
     SoundTrigger { sound_id: String },
+    /// Drove into a random event (hitchhiker, lost item, ...)
+    RandomEvent { object_id: u32 },
     /// Approach sound — played when entering outer radius of a map object
     #[allow(dead_code)] // TODO: implement approach sound triggers
     ApproachSound { sound_id: String },
@@ -1039,6 +1091,7 @@ impl DriveCar {
             forward_backward: 0,
             ferry_step: 0,
             racing: None,
+            over_holes: false,
             pending_approach_sounds: Vec::new(),
             entered_outer_set: std::collections::HashSet::new(),
         }
@@ -1223,11 +1276,16 @@ impl DriveCar {
         let topo_y = ((new_y as i32 - MAP_OFFSET_Y) / 2).clamp(0, TOPO_HEIGHT - 1);
         let terrain = get_terrain(topo_x, topo_y);
 
+        let impact = if self.props.max_speed > 0.0 {
+            (self.speed.abs() / self.props.max_speed).min(1.0)
+        } else {
+            0.0
+        };
         if terrain >= TERRAIN_WALL && !cheats.noclip {
             // Frontal wall hit — stop and increment OutOfBounds counter
             self.speed = 0.0;
             self.out_of_bounds += 1;
-            return DriveEvent::TerrainBlocked { reason: "wall" };
+            return DriveEvent::TerrainBlocked { reason: "wall", impact };
         }
 
         // Reset out_of_bounds on successful move
@@ -1237,19 +1295,19 @@ impl DriveCar {
         if !cheats.noclip {
             if altitude > 2 && self.props.strength <= BIG_HILL_STRENGTH_THRESHOLD {
                 self.speed = 0.0;
-                return DriveEvent::TerrainBlocked { reason: "big_hill" };
+                return DriveEvent::TerrainBlocked { reason: "big_hill", impact };
             }
             if altitude > 1 && self.props.strength <= SMALL_HILL_STRENGTH_THRESHOLD {
                 self.speed = 0.0;
-                return DriveEvent::TerrainBlocked { reason: "small_hill" };
+                return DriveEvent::TerrainBlocked { reason: "small_hill", impact };
             }
             if terrain == TERRAIN_MUD && self.props.grip <= MUD_GRIP_THRESHOLD {
                 self.speed = 0.0;
-                return DriveEvent::TerrainBlocked { reason: "mud" };
+                return DriveEvent::TerrainBlocked { reason: "mud", impact };
             }
             if terrain == TERRAIN_HOLES && self.props.durability <= HOLES_DURABILITY_THRESHOLD {
                 self.speed = 0.0;
-                return DriveEvent::TerrainBlocked { reason: "holes" };
+                return DriveEvent::TerrainBlocked { reason: "holes", impact };
             }
         }
 
@@ -1259,6 +1317,7 @@ impl DriveCar {
         // Apply movement
         self.x = new_x;
         self.y = new_y;
+        self.over_holes = terrain == TERRAIN_HOLES;
 
         // --- Fuel consumption ---
         if self.speed.abs() > 0.001 && !cheats.infinite_fuel {
@@ -1399,6 +1458,10 @@ impl DriveCar {
                             return DriveEvent::SoundTrigger { sound_id: sid.clone() };
                         }
                     }
+                    MapObjectType::RandomEvent => {
+                        self.speed = 0.0;
+                        return DriveEvent::RandomEvent { object_id: obj.object_id };
+                    }
                     MapObjectType::Custom | MapObjectType::Picture => {
                         tracing::trace!("Object {} at ({}, {})", obj.object_id, obj.x, obj.y);
                     }
@@ -1504,6 +1567,21 @@ impl DriveCar {
         self.fuel_empty = false;
     }
 
+    /// Tow truck drop-off: put the car down on another tile with at least
+    /// `fuel_fraction` of a tank, any race is called off
+    pub fn tow_to(&mut self, col: usize, row: usize, x: f32, y: f32, fuel_fraction: f32) {
+        self.tile_col = col;
+        self.tile_row = row;
+        self.x = x;
+        self.y = y;
+        self.speed = 0.0;
+        self.fuel = self.fuel.max(self.props.fuel_max * fuel_fraction);
+        self.fuel_empty = false;
+        self.refuel_ticks = 0;
+        self.racing = None;
+        self.position_history.clear();
+    }
+
     /// Step back N positions in the history (used when blocked by animals)
     pub fn stepback(&mut self, n: usize) {
         if let Some(&(px, py, pd)) = self.position_history.iter().rev().nth(n) {
@@ -1568,19 +1646,6 @@ impl DriveCar {
     /// Returns `Some(audio_id)` if a new sound should start (state changed),
     /// or `None` if the current sound continues unchanged.
     pub fn engine_sound_update(&mut self) -> Option<&'static str> {
-        // 9 engine types × 7 states: [startup, shutdown, idle, speed1, speed2, speed3, speed4]
-        const ENGINE_SOUNDS: [[&str; 7]; 9] = [
-            ["05e073v0", "05e079v0", "05e074v0", "05e075v0", "05e076v0", "05e077v0", "05e078v0"], // type 1
-            ["05e067v0", "05e073v0", "05e068v0", "05e069v0", "05e070v0", "05e071v0", "05e072v0"], // type 2
-            ["05e025v0", "05e031v0", "05e026v0", "05e027v0", "05e028v0", "05e029v0", "05e030v0"], // type 3
-            ["05e004v0", "05e010v0", "05e005v0", "05e006v0", "05e007v0", "05e008v0", "05e009v0"], // type 4
-            ["05e011v0", "05e017v0", "05e012v0", "05e013v0", "05e014v0", "05e015v0", "05e016v0"], // type 5
-            ["05e053v0", "05e059v0", "05e054v0", "05e055v0", "05e056v0", "05e057v0", "05e058v0"], // type 6
-            ["05e018v0", "05e024v0", "05e019v0", "05e020v0", "05e021v0", "05e022v0", "05e023v0"], // type 7
-            ["05e060v0", "05e066v0", "05e061v0", "05e062v0", "05e063v0", "05e064v0", "05e065v0"], // type 8
-            ["05e032v0", "05e038v0", "05e033v0", "05e034v0", "05e035v0", "05e036v0", "05e037v0"], // type 9
-        ];
-
         let et = self.props.engine_type;
         if et < 1 || et > 9 { return None; }
         let sounds = &ENGINE_SOUNDS[(et - 1) as usize];
@@ -1608,6 +1673,27 @@ impl DriveCar {
         Some(sounds[state as usize])
     }
 
+    /// Idle and driving samples for the adaptive engine loop
+    pub fn engine_loop_samples(&self) -> Option<(&'static str, &'static str)> {
+        let et = self.props.engine_type;
+        if !(1..=9).contains(&et) { return None; }
+        let sounds = &ENGINE_SOUNDS[(et - 1) as usize];
+        Some((sounds[ENGINE_LOOP_STATES.0], sounds[ENGINE_LOOP_STATES.1]))
+    }
+
+    /// Pitch and load for the adaptive engine loop: pitch rises smoothly with
+    /// speed, load leans towards the driving layer while on the throttle.
+    pub fn engine_tone(&self) -> (f32, f32) {
+        let ratio = if self.props.max_speed > 0.0 {
+            (self.speed.abs() / self.props.max_speed).min(1.0)
+        } else {
+            0.0
+        };
+        let pitch = 0.85 + 0.75 * ratio;
+        let load = if self.throttle { 0.4 + 0.6 * ratio } else { 0.6 * ratio };
+        (pitch, load)
+    }
+
     /// Apply mouse-based steering.
     ///
     /// When the mouse button is down, compute the angle from the car to the
@@ -1772,7 +1858,7 @@ mod tests {
         let mut car = DriveCar::new(320.0, 200.0, 1, test_props());
         car.speed = 2.0;
         let event = car.update(&[], |_, _| 250, DriveCheat::default()); // everything is wall
-        matches!(event, DriveEvent::TerrainBlocked { reason: "wall" });
+        assert!(matches!(event, DriveEvent::TerrainBlocked { reason: "wall", impact } if impact > 0.4));
     }
 
     #[test]
@@ -1941,4 +2027,24 @@ mod tests {
         assert!(matches!(event, DriveEvent::AnimalsBlocking { has_horn: false, horn_type: 0 }));
         assert_eq!(car.speed, 0.0);
     }
+
+    #[test]
+    fn random_destinations_follow_the_seed() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let kept = |seed: u64| {
+            let mut wm = WorldMap::default_map();
+            wm.apply_random_destinations(&mut StdRng::seed_from_u64(seed));
+            let mut kept: Vec<(u32, u32)> = wm.tiles.iter()
+                .flat_map(|(&tile, t)| t.objects.iter()
+                    .filter(|o| o.obj_type == MapObjectType::RandomDestination && o.enabled)
+                    .map(move |o| (o.object_id, tile)))
+                .collect();
+            kept.sort_unstable();
+            kept
+        };
+        assert!(!kept(3).is_empty());
+        assert_eq!(kept(3), kept(3));
+    }
 }
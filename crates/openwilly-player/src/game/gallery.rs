@@ -0,0 +1,313 @@
+//! Car Gallery cards and the diploma overlay
+//!
+//! Both show the car name chosen in the car-show naming dialog
+//! (see `car_naming`) together with its rating and medals.
+//! Cards can be exported as 800×600 PNGs into `<game dir>/screenshots`,
+//! or shared as `.willycar` exchange files (see `willycar`).
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::assets::AssetStore;
+use crate::engine::canvas::Canvas;
+use crate::engine::font;
+use crate::engine::{SCREEN_WIDTH, SCREEN_HEIGHT};
+use crate::game::build_car::BuildCar;
+use crate::game::i18n::{self, Language};
+use crate::game::parts_db::PartsDB;
+use crate::game::save::GalleryEntry;
+
+/// Number of medals that can be earned (BigHill, FarAway, Exhibition, Racing)
+pub const MEDAL_COUNT: usize = 4;
+/// Their IDs as stored in the save (`GameState::award_medal`)
+pub const MEDAL_IDS: [&str; MEDAL_COUNT] = ["1", "2", "4", "5"];
+/// Most thumbnails shown after an import
+const IMPORT_PREVIEW_MAX: usize = 3;
+
+// Card grid layout (Car Gallery scene)
+const CARD_W: i32 = 180;
+const CARD_H: i32 = 90;
+const CARD_GAP: i32 = 16;
+const CARD_COLS: i32 = 3;
+const GRID_Y: i32 = 60;
+const CARD_BTN_W: i32 = 60;
+const CARD_BTN_H: i32 = 16;
+const CARD_BTN_GAP: i32 = 8;
+
+/// "Import cars" button above the card grid
+pub const IMPORT_BUTTON: (i32, i32, i32, i32) = (510, 24, 110, 22);
+/// Top-left corner and height of the profile header (avatar + name)
+const PROFILE_POS: (i32, i32) = (20, 25);
+const PROFILE_H: i32 = crate::game::avatars::THUMB_HEIGHT as i32;
+
+// Exported card image
+pub const EXPORT_WIDTH: usize = 800;
+pub const EXPORT_HEIGHT: usize = 600;
+const EXPORT_BG: u32 = 0xFFF5E6C8;
+
+// Diploma layout
+const DIPLOMA_W: i32 = 400;
+const DIPLOMA_H: i32 = 240;
+
+/// Screen rectangle of the gallery card at `index`
+pub fn card_rect(index: usize) -> (i32, i32, i32, i32) {
+    let grid_w = CARD_COLS * CARD_W + (CARD_COLS - 1) * CARD_GAP;
+    let x0 = (SCREEN_WIDTH as i32 - grid_w) / 2;
+    let col = index as i32 % CARD_COLS;
+    let row = index as i32 / CARD_COLS;
+    (x0 + col * (CARD_W + CARD_GAP), GRID_Y + row * (CARD_H + CARD_GAP), CARD_W, CARD_H)
+}
+
+/// Buttons at the bottom of each gallery card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardButton {
+    /// Save the card as a PNG
+    Export,
+    /// Write a `.willycar` exchange file
+    Share,
+}
+
+impl CardButton {
+    const ALL: [CardButton; 2] = [CardButton::Export, CardButton::Share];
+
+    fn i18n_key(self) -> &'static str {
+        match self {
+            CardButton::Export => "gallery_export",
+            CardButton::Share => "gallery_share",
+        }
+    }
+}
+
+/// Screen rectangle of a button on the card at `index`
+fn card_button_rect(index: usize, button: CardButton) -> (i32, i32, i32, i32) {
+    let (x, y, w, h) = card_rect(index);
+    let row_w = 2 * CARD_BTN_W + CARD_BTN_GAP;
+    let slot = if button == CardButton::Export { 0 } else { 1 };
+    let bx = x + (w - row_w) / 2 + slot * (CARD_BTN_W + CARD_BTN_GAP);
+    (bx, y + h - CARD_BTN_H - 6, CARD_BTN_W, CARD_BTN_H)
+}
+
+/// Which card button (if any) is at (x, y)
+pub fn card_button_hit(count: usize, x: i32, y: i32) -> Option<(usize, CardButton)> {
+    (0..count)
+        .flat_map(|i| CardButton::ALL.into_iter().map(move |b| (i, b)))
+        .find(|&(i, b)| {
+            let (bx, by, bw, bh) = card_button_rect(i, b);
+            x >= bx && y >= by && x < bx + bw && y < by + bh
+        })
+}
+
+/// Whether (x, y) is on the import button
+pub fn import_button_hit(x: i32, y: i32) -> bool {
+    let (bx, by, bw, bh) = IMPORT_BUTTON;
+    x >= bx && y >= by && x < bx + bw && y < by + bh
+}
+
+/// Draw one gallery card: name, rating stars and medal count
+pub fn draw_card(fb: &mut [u32], x: i32, y: i32, entry: &GalleryEntry) {
+    font::draw_rect(fb, x, y, CARD_W, CARD_H, 0xDDF5E6C8);
+    font::draw_rect_outline(fb, x, y, CARD_W, CARD_H, 0xFF8B5A2B);
+    let tw = font::text_width(&entry.name);
+    font::draw_text(fb, x + (CARD_W - tw) / 2, y + 12, &entry.name, 0xFF000000);
+    let stars: String = (0..5).map(|i| if i < entry.rating { '*' } else { '.' }).collect();
+    let sw = font::text_width(&stars);
+    font::draw_text(fb, x + (CARD_W - sw) / 2, y + 32, &stars, 0xFFB8860B);
+    let medals = format!("{}/{}", entry.medals.len(), MEDAL_COUNT);
+    let mw = font::text_width(&medals);
+    font::draw_text(fb, x + (CARD_W - mw) / 2, y + 50, &medals, 0xFF555555);
+}
+
+fn draw_button(fb: &mut [u32], (x, y, w, h): (i32, i32, i32, i32), label: &str) {
+    font::draw_rect(fb, x, y, w, h, 0xFF333355);
+    font::draw_rect_outline(fb, x, y, w, h, 0xFF6666CC);
+    font::draw_text(fb, x + (w - font::text_width(label)) / 2, y + (h - 8) / 2, label, 0xFFFFFFFF);
+}
+
+/// Profile header: the current car's thumbnail and the player name
+pub fn draw_profile(fb: &mut [u32], name: &str, thumb: Option<&Canvas>) {
+    let (x, y) = PROFILE_POS;
+    let text_x = match thumb {
+        Some(thumb) => {
+            font::draw_rect_outline(fb, x - 1, y - 1, thumb.width as i32 + 2, thumb.height as i32 + 2, 0xFF8B5A2B);
+            thumb.draw_to(fb, x, y);
+            x + thumb.width as i32 + 8
+        }
+        None => x,
+    };
+    font::draw_text_shadow(fb, text_x, y + (PROFILE_H - 8) / 2, name, 0xFFFFFFFF);
+}
+
+/// Thumbnails of the cars just imported, in a row above the status line
+pub fn draw_import_preview(fb: &mut [u32], thumbs: &[Canvas]) {
+    const GAP: i32 = 12;
+    let thumbs = &thumbs[..thumbs.len().min(IMPORT_PREVIEW_MAX)];
+    let total: i32 = thumbs.iter().map(|t| t.width as i32 + GAP).sum::<i32>() - GAP;
+    let mut x = (SCREEN_WIDTH as i32 - total) / 2;
+    for thumb in thumbs {
+        let (w, h) = (thumb.width as i32, thumb.height as i32);
+        let y = 428 - h;
+        font::draw_rect_outline(fb, x - 1, y - 1, w + 2, h + 2, 0xFF8B5A2B);
+        thumb.draw_to(fb, x, y);
+        x += w + GAP;
+    }
+}
+
+/// Draw all gallery cards (or a hint if the gallery is empty)
+pub fn draw_gallery(fb: &mut [u32], entries: &[GalleryEntry], lang: Language) {
+    draw_button(fb, IMPORT_BUTTON, i18n::t(lang, "gallery_import"));
+    if entries.is_empty() {
+        let text = i18n::t(lang, "gallery_empty");
+        let tw = font::text_width(text);
+        font::draw_text_shadow(fb, (SCREEN_WIDTH as i32 - tw) / 2, GRID_Y, text, 0xFFFFFFFF);
+        return;
+    }
+    for (i, entry) in entries.iter().enumerate() {
+        let (x, y, _, h) = card_rect(i);
+        if y + h > SCREEN_HEIGHT as i32 { break; }
+        draw_card(fb, x, y, entry);
+        for button in CardButton::ALL {
+            draw_button(fb, card_button_rect(i, button), i18n::t(lang, button.i18n_key()));
+        }
+    }
+}
+
+/// Draw the diploma overlay with the car name and earned medals
+pub fn draw_diploma(fb: &mut [u32], car_name: &str, medals: &[String], lang: Language) {
+    let x = (SCREEN_WIDTH as i32 - DIPLOMA_W) / 2;
+    let y = (SCREEN_HEIGHT as i32 - DIPLOMA_H) / 2;
+    font::draw_rect(fb, x, y, DIPLOMA_W, DIPLOMA_H, 0xF0F5E6C8);
+    font::draw_rect_outline(fb, x, y, DIPLOMA_W, DIPLOMA_H, 0xFF8B5A2B);
+    font::draw_rect_outline(fb, x + 6, y + 6, DIPLOMA_W - 12, DIPLOMA_H - 12, 0xFFB8860B);
+
+    let title = i18n::t(lang, "diploma_title");
+    font::draw_text_shadow(fb, x + (DIPLOMA_W - font::text_width(title)) / 2, y + 30, title, 0xFFB8860B);
+    font::draw_text(fb, x + (DIPLOMA_W - font::text_width(car_name)) / 2, y + 90, car_name, 0xFF000000);
+
+    let medal_text = format!("{}: {}/{}", i18n::t(lang, "diploma_medals"), medals.len(), MEDAL_COUNT);
+    font::draw_text(fb, x + (DIPLOMA_W - font::text_width(&medal_text)) / 2, y + 150, &medal_text, 0xFF555555);
+}
+
+// ─── PNG export ─────────────────────────────────────────────────────────
+
+/// Render a gallery card off-screen (car sprite, name, stats, medals)
+pub fn render_card(entry: &GalleryEntry, parts_db: &PartsDB, assets: &AssetStore, lang: Language) -> Canvas {
+    let mut canvas = Canvas::new(EXPORT_WIDTH, EXPORT_HEIGHT, EXPORT_BG);
+    canvas.outline_rect(8, 8, EXPORT_WIDTH as i32 - 16, EXPORT_HEIGHT as i32 - 16, 0xFF8B5A2B);
+    canvas.outline_rect(14, 14, EXPORT_WIDTH as i32 - 28, EXPORT_HEIGHT as i32 - 28, 0xFFB8860B);
+
+    canvas.text_centered(40, &entry.name, 0xFF000000);
+
+    // Rebuild the car from the stored parts and center it in the upper half
+    let mut car = BuildCar::new(0, 0);
+    car.parts = entry.parts.clone();
+    car.refresh(parts_db, assets);
+    let mut sprites = car.all_sprites();
+    if let Some((min_x, min_y, max_x, max_y)) = sprites_bbox(&sprites) {
+        let dx = EXPORT_WIDTH as i32 / 2 - (min_x + max_x) / 2;
+        let dy = 240 - (min_y + max_y) / 2;
+        for sprite in &mut sprites {
+            sprite.x += dx;
+            sprite.y += dy;
+            canvas.blit(sprite);
+        }
+    }
+
+    // Stats
+    let props = car.properties();
+    let stars: String = (0..5).map(|i| if i < entry.rating { '*' } else { '.' }).collect();
+    let lines = [
+        format!("{}: {}", i18n::t(lang, "card_rating"), stars),
+        format!("{}: {}", i18n::t(lang, "card_speed"), props.speed),
+        format!("{}: {}", i18n::t(lang, "card_strength"), props.strength),
+        format!("{}: {}", i18n::t(lang, "card_comfort"), props.comfort),
+        format!("{}: {}", i18n::t(lang, "card_funny"), props.funny_factor),
+    ];
+    for (i, line) in lines.iter().enumerate() {
+        canvas.text(120, 420 + i as i32 * 14, line, 0xFF333333);
+    }
+
+    // Medals: one badge per medal slot, gold when earned
+    canvas.text(480, 420, i18n::t(lang, "diploma_medals"), 0xFF333333);
+    for slot in 0..MEDAL_COUNT {
+        let earned = slot < entry.medals.len();
+        let color = if earned { 0xFFDAA520 } else { 0xFFBBBBBB };
+        let bx = 480 + slot as i32 * 40;
+        canvas.fill_rect(bx, 440, 30, 30, color);
+        canvas.outline_rect(bx, 440, 30, 30, 0xFF8B5A2B);
+    }
+
+    canvas
+}
+
+/// Bounding box (min_x, min_y, max_x, max_y) of the visible sprites
+pub fn sprites_bbox(sprites: &[crate::engine::Sprite]) -> Option<(i32, i32, i32, i32)> {
+    sprites.iter()
+        .filter(|s| s.visible && s.width > 0 && s.height > 0)
+        .fold(None, |acc, s| {
+            let (x1, y1) = (s.x + s.width as i32, s.y + s.height as i32);
+            Some(match acc {
+                None => (s.x, s.y, x1, y1),
+                Some((a, b, c, d)) => (a.min(s.x), b.min(s.y), c.max(x1), d.max(y1)),
+            })
+        })
+}
+
+/// File name for an exported card: `card_<name>_<unix time>.png`
+fn export_file_name(name: &str, timestamp: u64) -> String {
+    let safe: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("card_{}_{}.png", safe, timestamp)
+}
+
+/// Render the card and write it to `<game_dir>/screenshots`. Returns the file path.
+pub fn export_card(entry: &GalleryEntry, parts_db: &PartsDB, assets: &AssetStore, lang: Language) -> Result<PathBuf> {
+    let dir = screenshots_dir(&assets.game_dir);
+    std::fs::create_dir_all(&dir)?;
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let path = dir.join(export_file_name(&entry.name, timestamp));
+    render_card(entry, parts_db, assets, lang).save_png(&path)?;
+    Ok(path)
+}
+
+/// Screenshot folder inside the game directory
+pub fn screenshots_dir(game_dir: &Path) -> PathBuf {
+    game_dir.join("screenshots")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cards_fit_on_screen_width() {
+        let (x0, _, _, _) = card_rect(0);
+        let (x2, y2, w, _) = card_rect(2);
+        assert!(x0 >= 0);
+        assert!(x2 + w <= SCREEN_WIDTH as i32);
+        // Fourth card wraps to the next row
+        let (x3, y3, _, _) = card_rect(3);
+        assert_eq!(x3, x0);
+        assert!(y3 > y2);
+    }
+
+    #[test]
+    fn card_buttons_inside_card() {
+        let (cx, cy, cw, ch) = card_rect(4);
+        for button in CardButton::ALL {
+            let (bx, by, bw, bh) = card_button_rect(4, button);
+            assert!(bx >= cx && by >= cy && bx + bw <= cx + cw && by + bh <= cy + ch);
+            assert_eq!(card_button_hit(5, bx + 1, by + 1), Some((4, button)));
+            assert_eq!(card_button_hit(4, bx + 1, by + 1), None);
+        }
+    }
+
+    #[test]
+    fn export_file_name_is_sanitized() {
+        assert_eq!(export_file_name("Blitz 2/../x", 42), "card_Blitz_2____x_42.png");
+    }
+}
@@ -0,0 +1,193 @@
+//! Horn selection — test the horns in the garage and pick one per profile
+//!
+//! By default the horn sound follows the car's parts (`horntype` 1-5). The
+//! garage horn panel lists the five original horns plus any WAV files the
+//! player drops into `<game_dir>/mods/horns/`; the choice is stored in the
+//! profile and used both for honking (H key) and for scaring cows off the road.
+//! A horn part is still required — the choice only changes how it sounds.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::font;
+use crate::game::driving::HORN_SOUNDS;
+use crate::game::i18n::{self, Language};
+
+/// Horn button in the garage (below the camera-view button)
+pub const HORN_BUTTON: (i32, i32, i32, i32) = (540, 34, 92, 22);
+
+/// Panel geometry: entries are listed below the horn button
+const PANEL_X: i32 = 460;
+const PANEL_Y: i32 = 60;
+const PANEL_W: i32 = 172;
+const ROW_H: i32 = 18;
+const TITLE_H: i32 = 20;
+/// Custom horns beyond this are not listed (the panel would leave the screen)
+const MAX_CUSTOM: usize = 12;
+
+/// Which horn sound the profile uses
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HornChoice {
+    /// Follow the car's horn part (original behaviour)
+    #[default]
+    Parts,
+    /// One of the five original horns (1-5)
+    Builtin(u8),
+    /// A WAV file from `mods/horns/` (file name only)
+    Custom(String),
+}
+
+/// A resolved horn sound, ready to play
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HornSound {
+    /// Director cast member name
+    Cast(&'static str),
+    /// WAV file on disk
+    File(PathBuf),
+}
+
+/// Directory for user-provided horn WAVs
+pub fn custom_horn_dir(game_dir: &Path) -> PathBuf {
+    game_dir.join("mods").join("horns")
+}
+
+/// All `*.wav` files in the custom horn directory, sorted by name
+pub fn list_custom_horns(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut names: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter(|n| n.to_ascii_lowercase().ends_with(".wav"))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Original horn sound for a horntype (1-5), clamped like mulle.js
+fn builtin_sound(horn_type: i32) -> &'static str {
+    HORN_SOUNDS[(horn_type.max(1) as usize - 1).min(HORN_SOUNDS.len() - 1)]
+}
+
+/// Resolve the profile's horn choice for a car with `horn_type` (0 = no horn).
+/// Returns None if the car has no horn. A missing custom file falls back to
+/// the car's own horn.
+pub fn resolve(choice: &HornChoice, horn_type: i32, game_dir: &Path) -> Option<HornSound> {
+    if horn_type <= 0 {
+        return None;
+    }
+    match choice {
+        HornChoice::Builtin(n) if (1..=HORN_SOUNDS.len() as u8).contains(n) => {
+            Some(HornSound::Cast(builtin_sound(*n as i32)))
+        }
+        HornChoice::Custom(file) => {
+            let path = custom_horn_dir(game_dir).join(file);
+            if path.is_file() {
+                Some(HornSound::File(path))
+            } else {
+                tracing::warn!("Custom horn '{}' not found, using the car's horn", file);
+                Some(HornSound::Cast(builtin_sound(horn_type)))
+            }
+        }
+        _ => Some(HornSound::Cast(builtin_sound(horn_type))),
+    }
+}
+
+/// Garage horn-test panel: click an entry to hear it and select it
+pub struct HornPanel {
+    entries: Vec<HornChoice>,
+}
+
+impl HornPanel {
+    /// Build the entry list (parts, the five originals, custom WAVs)
+    pub fn new(game_dir: &Path) -> Self {
+        let mut entries = vec![HornChoice::Parts];
+        entries.extend((1..=HORN_SOUNDS.len() as u8).map(HornChoice::Builtin));
+        entries.extend(
+            list_custom_horns(&custom_horn_dir(game_dir))
+                .into_iter()
+                .take(MAX_CUSTOM)
+                .map(HornChoice::Custom),
+        );
+        Self { entries }
+    }
+
+    fn height(&self) -> i32 {
+        TITLE_H + self.entries.len() as i32 * ROW_H + 4
+    }
+
+    /// Whether (x, y) lies inside the panel
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (PANEL_X..PANEL_X + PANEL_W).contains(&x) && (PANEL_Y..PANEL_Y + self.height()).contains(&y)
+    }
+
+    /// Entry under (x, y), if any
+    pub fn hit(&self, x: i32, y: i32) -> Option<&HornChoice> {
+        if !self.contains(x, y) || y < PANEL_Y + TITLE_H {
+            return None;
+        }
+        self.entries.get(((y - PANEL_Y - TITLE_H) / ROW_H) as usize)
+    }
+
+    pub fn draw(&self, fb: &mut [u32], selected: &HornChoice, lang: Language) {
+        let h = self.height();
+        font::draw_rect(fb, PANEL_X, PANEL_Y, PANEL_W, h, 0xDD1A1A2E);
+        font::draw_rect_outline(fb, PANEL_X, PANEL_Y, PANEL_W, h, 0xFF6666CC);
+        font::draw_text_shadow(fb, PANEL_X + 6, PANEL_Y + 6, i18n::t(lang, "horn_title"), 0xFFFFDD44);
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            let y = PANEL_Y + TITLE_H + i as i32 * ROW_H;
+            if entry == selected {
+                font::draw_rect(fb, PANEL_X + 2, y, PANEL_W - 4, ROW_H, 0xFF3A3A6E);
+            }
+            let label = match entry {
+                HornChoice::Parts => i18n::t(lang, "horn_parts").to_string(),
+                HornChoice::Builtin(n) => format!("{} {}", i18n::t(lang, "horn_builtin"), n),
+                HornChoice::Custom(file) => file.clone(),
+            };
+            font::draw_text_shadow(fb, PANEL_X + 8, y + 5, &label, 0xFFFFFFFF);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_follows_choice() {
+        let dir = Path::new("/nonexistent");
+        assert_eq!(resolve(&HornChoice::Parts, 0, dir), None);
+        assert_eq!(resolve(&HornChoice::Parts, 2, dir), Some(HornSound::Cast(HORN_SOUNDS[1])));
+        assert_eq!(resolve(&HornChoice::Builtin(5), 1, dir), Some(HornSound::Cast(HORN_SOUNDS[4])));
+        // Out-of-range builtin → car's horn
+        assert_eq!(resolve(&HornChoice::Builtin(9), 3, dir), Some(HornSound::Cast(HORN_SOUNDS[2])));
+        // Missing custom file → car's horn
+        assert_eq!(
+            resolve(&HornChoice::Custom("tut.wav".into()), 1, dir),
+            Some(HornSound::Cast(HORN_SOUNDS[0]))
+        );
+    }
+
+    #[test]
+    fn custom_horns_are_listed() {
+        let dir = std::env::temp_dir().join(format!("openwilly_horns_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.WAV"), b"").unwrap();
+        std::fs::write(dir.join("a.wav"), b"").unwrap();
+        std::fs::write(dir.join("readme.txt"), b"").unwrap();
+        assert_eq!(list_custom_horns(&dir), vec!["a.wav".to_string(), "b.WAV".to_string()]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn panel_hit_rows() {
+        let panel = HornPanel::new(Path::new("/nonexistent"));
+        assert_eq!(panel.entries.len(), 6);
+        assert_eq!(panel.hit(PANEL_X + 10, PANEL_Y + 2), None);
+        assert_eq!(panel.hit(PANEL_X + 10, PANEL_Y + TITLE_H + 1), Some(&HornChoice::Parts));
+        assert_eq!(panel.hit(PANEL_X + 10, PANEL_Y + TITLE_H + ROW_H + 1), Some(&HornChoice::Builtin(1)));
+    }
+}
@@ -4,8 +4,10 @@
 //! language; this module only handles engine-drawn UI text (menus, HUD labels,
 //! debug overlays, escape menu, dev menu).
 
+use serde::{Deserialize, Serialize};
+
 /// Supported UI languages
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Language {
     German,
     English,
@@ -44,14 +46,74 @@ pub fn t(lang: Language, key: &str) -> &'static str {
         (Language::English, "menu_detail_noise") => "Detail Noise",
         (Language::German, "menu_display_mode") => "Anzeigemodus",
         (Language::English, "menu_display_mode") => "Display Mode",
+        (Language::German, "menu_low_spec") => "Sparmodus (alte PCs)",
+        (Language::English, "menu_low_spec") => "Low-spec mode",
+        (Language::German, "menu_captions") => "Geraeusch-Untertitel",
+        (Language::English, "menu_captions") => "Sound captions",
+        (Language::German, "menu_rumble") => "Controller-Vibration",
+        (Language::English, "menu_rumble") => "Controller rumble",
+        (Language::German, "menu_options") => "Optionen",
+        (Language::English, "menu_options") => "Options",
         (Language::German, "menu_quit") => "Beenden",
         (Language::English, "menu_quit") => "Quit",
         (Language::German, "pause_hint") => "Pfeiltasten + Enter | Esc",
         (Language::English, "pause_hint") => "Arrow keys + Enter | Esc",
 
+        // ── World map hints ──
+        (Language::German, "prompt_gas") => "Reinfahren zum Tanken",
+        (Language::English, "prompt_gas") => "Drive in to refuel",
+        (Language::German, "prompt_ferry") => "Auf die Faehre fahren",
+        (Language::English, "prompt_ferry") => "Drive onto the ferry",
+        (Language::German, "prompt_honk") => "H: Hupen",
+        (Language::English, "prompt_honk") => "H: honk the horn",
+        (Language::German, "prompt_no_horn") => "Ohne Hupe kommst du nicht vorbei",
+        (Language::English, "prompt_no_horn") => "You need a horn to get past",
+        (Language::German, "prompt_hill") => "Zu steil - baue ein staerkeres Auto",
+        (Language::English, "prompt_hill") => "Too steep - build a stronger car",
+        (Language::German, "prompt_racing") => "Durchfahren startet das Rennen",
+        (Language::English, "prompt_racing") => "Drive through to start the race",
+        (Language::German, "prompt_visit") => "Reinfahren zum Besuchen",
+        (Language::English, "prompt_visit") => "Drive in to visit",
+
+        // ── Driving tutorial ──
+        (Language::German, "tut_steer") => "Lenke mit den Pfeiltasten und fahr ein Stueck",
+        (Language::English, "tut_steer") => "Steer with the arrow keys and drive a bit",
+        (Language::German, "tut_gas") => "Fahr zur markierten Tankstelle und tanke auf",
+        (Language::English, "tut_gas") => "Drive to the marked gas station to refuel",
+        (Language::German, "tut_destination") => "Folge dem Pfeil zu deinem ersten Ziel",
+        (Language::English, "tut_destination") => "Follow the arrow to your first destination",
+        (Language::German, "tut_skip") => "Tab: Uebung ueberspringen",
+        (Language::English, "tut_skip") => "Tab: skip the tutorial",
+        (Language::German, "tut_done") => "Gut gemacht! Jetzt kennst du die Strasse",
+        (Language::English, "tut_done") => "Well done! Now you know the road",
+        (Language::German, "window_title") => "Willy Werkel - Autos bauen",
+        (Language::English, "window_title") => "Willy Werkel - Building Cars",
+
+        // ── Options screen ──
+        (Language::German, "options_title") => "= OPTIONEN =",
+        (Language::English, "options_title") => "= OPTIONS =",
+        (Language::German, "opt_volume") => "Lautstaerke",
+        (Language::English, "opt_volume") => "Volume",
+        (Language::German, "opt_ui_volume") => "Menue-Geraeusche",
+        (Language::English, "opt_ui_volume") => "Menu sounds",
+        (Language::German, "opt_language") => "Sprache",
+        (Language::English, "opt_language") => "Language",
+        (Language::German, "opt_dev_key") => "Entwicklermenue-Taste",
+        (Language::English, "opt_dev_key") => "Dev menu key",
+        (Language::German, "opt_press_key") => "Taste druecken...",
+        (Language::English, "opt_press_key") => "Press a key...",
+        (Language::German, "opt_map_prompts") => "Hinweise auf der Karte",
+        (Language::English, "opt_map_prompts") => "Map hints",
+        (Language::German, "opt_back") => "Zurueck",
+        (Language::English, "opt_back") => "Back",
+        (Language::German, "options_hint") => "Pfeiltasten: waehlen/aendern | Esc",
+        (Language::English, "options_hint") => "Arrow keys: select/change | Esc",
+
         // ── Main menu ──
         (Language::German, "lang_label") => "Sprache: Deutsch",
         (Language::English, "lang_label") => "Language: English",
+        (Language::German, "menu_continue") => "Weiterspielen",
+        (Language::English, "menu_continue") => "Continue",
 
         // ── Garage / build ──
         (Language::German, "road_legal") => "Fahrtauglich!",
@@ -59,6 +121,22 @@ pub fn t(lang: Language, key: &str) -> &'static str {
         (Language::German, "not_road_legal") => "Noch nicht fahrtauglich",
         (Language::English, "not_road_legal") => "Not road legal yet",
 
+        // ── Garage camera views ──
+        (Language::German, "garage_view_side") => "Seite",
+        (Language::English, "garage_view_side") => "Side",
+        (Language::German, "garage_view_front") => "Vorne",
+        (Language::English, "garage_view_front") => "Front",
+        (Language::German, "garage_view_top") => "Oben",
+        (Language::English, "garage_view_top") => "Top",
+        (Language::German, "horn_button") => "Hupe",
+        (Language::English, "horn_button") => "Horn",
+        (Language::German, "horn_title") => "Hupen testen",
+        (Language::English, "horn_title") => "Test horns",
+        (Language::German, "horn_parts") => "Wie eingebaut",
+        (Language::English, "horn_parts") => "As installed",
+        (Language::German, "horn_builtin") => "Hupe",
+        (Language::English, "horn_builtin") => "Horn",
+
         // ── Dev menu ──
         (Language::German, "dev_title") => "~ DEV MENU ~",
         (Language::English, "dev_title") => "~ DEV MENU ~",
@@ -93,6 +171,206 @@ pub fn t(lang: Language, key: &str) -> &'static str {
         (Language::German, "dev_close") => "Schliessen",
         (Language::English, "dev_close") => "Close",
 
+        // ── Car show naming / diploma / gallery ──
+        (Language::German, "naming_title") => "Wie soll dein Auto heissen?",
+        (Language::English, "naming_title") => "What is your car called?",
+        (Language::German, "naming_ok") => "OK",
+        (Language::English, "naming_ok") => "OK",
+        (Language::German, "naming_err_empty") => "Bitte gib einen Namen ein",
+        (Language::English, "naming_err_empty") => "Please enter a name",
+        (Language::German, "naming_err_long") => "Der Name ist zu lang",
+        (Language::English, "naming_err_long") => "That name is too long",
+        (Language::German, "diploma_title") => "Diplom",
+        (Language::English, "diploma_title") => "Diploma",
+        (Language::German, "diploma_medals") => "Medaillen",
+        (Language::English, "diploma_medals") => "Medals",
+        (Language::German, "gallery_empty") => "Noch keine Autos ausgestellt",
+        (Language::English, "gallery_empty") => "No cars exhibited yet",
+        (Language::German, "gallery_export") => "Export",
+        (Language::English, "gallery_export") => "Export",
+        (Language::German, "gallery_exported") => "Karte gespeichert:",
+        (Language::English, "gallery_exported") => "Card saved:",
+        (Language::German, "gallery_share") => "Teilen",
+        (Language::English, "gallery_share") => "Share",
+        (Language::German, "gallery_shared") => "Auto gespeichert:",
+        (Language::English, "gallery_shared") => "Car saved:",
+        (Language::German, "gallery_import") => "Autos holen",
+        (Language::English, "gallery_import") => "Import cars",
+        (Language::German, "gallery_imported") => "Neue Autos:",
+        (Language::English, "gallery_imported") => "New cars:",
+        (Language::German, "gallery_import_none") => "Keine neuen Autos im Ordner 'exchange'",
+        (Language::English, "gallery_import_none") => "No new cars in the 'exchange' folder",
+        (Language::German, "card_rating") => "Bewertung",
+        (Language::English, "card_rating") => "Rating",
+        (Language::German, "card_speed") => "Tempo",
+        (Language::English, "card_speed") => "Speed",
+        (Language::German, "card_strength") => "Staerke",
+        (Language::English, "card_strength") => "Strength",
+        (Language::German, "card_comfort") => "Komfort",
+        (Language::English, "card_comfort") => "Comfort",
+        (Language::German, "card_funny") => "Witzigkeit",
+        (Language::English, "card_funny") => "Funny factor",
+
+        // ── Statistics overlay ──
+        (Language::German, "stats_title") => "Statistik",
+        (Language::English, "stats_title") => "Statistics",
+        (Language::German, "stats_km") => "Gefahren",
+        (Language::English, "stats_km") => "Distance driven",
+        (Language::German, "stats_fuel") => "Benzin verbraucht",
+        (Language::English, "stats_fuel") => "Fuel used",
+        (Language::German, "stats_parts") => "Teile gesammelt",
+        (Language::English, "stats_parts") => "Parts collected",
+        (Language::German, "stats_discovered") => "Orte entdeckt",
+        (Language::English, "stats_discovered") => "Places discovered",
+        (Language::German, "stats_destinations") => "Orte besucht",
+        (Language::English, "stats_destinations") => "Places visited",
+        (Language::German, "stats_races") => "Rennen gewonnen",
+        (Language::English, "stats_races") => "Races won",
+        (Language::German, "stats_time") => "Spielzeit",
+        (Language::English, "stats_time") => "Time played",
+        (Language::German, "stats_hint") => "F2 / Klick: schliessen",
+        (Language::English, "stats_hint") => "F2 / click: close",
+
+        // ── Speedrun ──
+        (Language::German, "speedrun_armed") => "Speedrun bereit - die Zeit laeuft ab dem Spielstart",
+        (Language::English, "speedrun_armed") => "Speedrun armed - the timer starts with the game",
+        (Language::German, "speedrun_saved") => "Lauf gespeichert:",
+        (Language::English, "speedrun_saved") => "Run saved:",
+        (Language::German, "speedrun_failed") => "Lauf konnte nicht gespeichert werden",
+        (Language::English, "speedrun_failed") => "Could not save the run",
+
+        // ── Closed captions ──
+        (Language::German, "cc_phone") => "Telefon klingelt",
+        (Language::English, "cc_phone") => "Phone ringing",
+        (Language::German, "cc_horn") => "Hupe",
+        (Language::English, "cc_horn") => "Horn honks",
+        (Language::German, "cc_engine_stall") => "Motor geht aus",
+        (Language::English, "cc_engine_stall") => "Engine stalls",
+        (Language::German, "cc_pump") => "Zapfsaeule pumpt",
+        (Language::English, "cc_pump") => "Fuel pump running",
+        (Language::German, "cc_cow") => "Kuh muht",
+        (Language::English, "cc_cow") => "Cow moos",
+        (Language::German, "cc_bridge_creak") => "Bruecke knarrt",
+        (Language::English, "cc_bridge_creak") => "Bridge creaks",
+        (Language::German, "cc_race_start") => "Startsignal",
+        (Language::English, "cc_race_start") => "Starting signal",
+        (Language::German, "cc_race_finish") => "Zielsignal",
+        (Language::English, "cc_race_finish") => "Finish signal",
+
+        // ── Part search ──
+        (Language::German, "search_button") => "Suchen",
+        (Language::English, "search_button") => "Search",
+        (Language::German, "search_title") => "Teile suchen",
+        (Language::English, "search_title") => "Find parts",
+        (Language::German, "search_piles") => "Gefunden auf:",
+        (Language::English, "search_piles") => "Found on:",
+        (Language::German, "search_pile") => "Haufen",
+        (Language::English, "search_pile") => "Pile",
+        (Language::German, "kind_wheels") => "Raeder",
+        (Language::English, "kind_wheels") => "Wheels",
+        (Language::German, "kind_engine") => "Motoren",
+        (Language::English, "kind_engine") => "Engines",
+        (Language::German, "kind_energy") => "Tanks & Batterien",
+        (Language::English, "kind_energy") => "Tanks & batteries",
+        (Language::German, "kind_electrics") => "Lampen & Hupen",
+        (Language::English, "kind_electrics") => "Lamps & horns",
+        (Language::German, "kind_controls") => "Lenkung & Bremsen",
+        (Language::English, "kind_controls") => "Steering & brakes",
+        (Language::German, "kind_body") => "Karosserie & Sitze",
+        (Language::English, "kind_body") => "Body & seats",
+        (Language::German, "kind_decoration") => "Deko & Sonstiges",
+        (Language::English, "kind_decoration") => "Decoration & misc",
+
+        // ── Missions ──
+        (Language::German, "mission_done") => "Auftrag erledigt! Deine Belohnung liegt im Hof.",
+        (Language::English, "mission_done") => "Mission complete! Your reward is in the yard.",
+
+        // ── Destination discovery ──
+        (Language::German, "discovered") => "Neuer Ort entdeckt:",
+        (Language::English, "discovered") => "New place discovered:",
+        (Language::German, "dest_82") => "Auto im Schlamm",
+        (Language::English, "dest_82") => "Car in the mud",
+        (Language::German, "dest_83") => "Baum auf der Strasse",
+        (Language::English, "dest_83") => "Tree on the road",
+        (Language::German, "dest_84") => "Ding auf der Strasse",
+        (Language::English, "dest_84") => "Thing on the road",
+        (Language::German, "dest_85") => "Salka",
+        (Language::English, "dest_85") => "Salka",
+        (Language::German, "dest_86") => "Mias Haus",
+        (Language::English, "dest_86") => "Mia's house",
+        (Language::German, "dest_87") => "Saftfabrik",
+        (Language::English, "dest_87") => "Juice factory",
+        (Language::German, "dest_88") => "Sture Stortand",
+        (Language::English, "dest_88") => "Sture Stortand",
+        (Language::German, "dest_89") => "Tankstelle",
+        (Language::English, "dest_89") => "Gas station",
+        (Language::German, "dest_90") => "Doris Digital",
+        (Language::English, "dest_90") => "Doris Digital",
+        (Language::German, "dest_91") => "Luddel Abb",
+        (Language::English, "dest_91") => "Luddel Abb",
+        (Language::German, "dest_92") => "Figge Ferrum",
+        (Language::English, "dest_92") => "Figge Ferrum",
+        (Language::German, "dest_93") => "Am Meer",
+        (Language::English, "dest_93") => "The sea",
+        (Language::German, "dest_94") => "Autoschau",
+        (Language::English, "dest_94") => "Car show",
+
+        // ── Driving minimap ──
+        (Language::German, "minimap_on") => "Karte: an",
+        (Language::English, "minimap_on") => "Map: on",
+        (Language::German, "minimap_off") => "Karte: aus",
+        (Language::English, "minimap_off") => "Map: off",
+        (Language::German, "tow_call") => "Figge anrufen",
+        (Language::English, "tow_call") => "Call Figge",
+
+        // ── Loading tips ──
+        (Language::German, "tip_drive_keys") => "Tipp: Mit den Pfeiltasten faehrst und lenkst du dein Auto.",
+        (Language::English, "tip_drive_keys") => "Tip: Use the arrow keys to drive and steer your car.",
+        (Language::German, "tip_road_legal") => "Tipp: Ohne Motor, Reifen, Bremsen, Tank, Batterie und Lenkrad geht es nicht los.",
+        (Language::English, "tip_road_legal") => "Tip: You need an engine, tires, brakes, tank, battery and steering wheel.",
+        (Language::German, "tip_fuel") => "Tipp: Wird der Sprit knapp, hilft die Tankstelle weiter.",
+        (Language::English, "tip_fuel") => "Tip: Running low on fuel? Visit the gas station.",
+        (Language::German, "tip_destinations") => "Tipp: Auf der Karte warten viele Leute, die Hilfe brauchen.",
+        (Language::English, "tip_destinations") => "Tip: Lots of people on the map are waiting for your help.",
+        (Language::German, "tip_junkyard") => "Tipp: Auf dem Schrottplatz findest du neue Teile fuer dein Auto.",
+        (Language::English, "tip_junkyard") => "Tip: The junkyard has new parts for your car.",
+        (Language::German, "tip_horn") => "Tipp: In der Werkstatt kannst du deine Hupe ausprobieren.",
+        (Language::English, "tip_horn") => "Tip: Try out your horn in the garage.",
+        (Language::German, "tip_gallery") => "Tipp: Fertige Autos kannst du in der Galerie als Karte speichern.",
+        (Language::English, "tip_gallery") => "Tip: Save finished cars as cards in the gallery.",
+        (Language::German, "tip_stats") => "Tipp: F2 zeigt, wie weit du schon gefahren bist.",
+        (Language::English, "tip_stats") => "Tip: Press F2 to see how far you have driven.",
+        (Language::German, "tip_escape") => "Tipp: Mit Escape pausierst du das Spiel.",
+        (Language::English, "tip_escape") => "Tip: Press Escape to pause the game.",
+
+        // ── Bug report ──
+        (Language::German, "report_title") => "Fehlerbericht",
+        (Language::English, "report_title") => "Bug report",
+        (Language::German, "report_saved") => "Bericht gespeichert:",
+        (Language::English, "report_saved") => "Report saved:",
+        (Language::German, "report_attach") => "Bitte die Datei an ein neues Issue anhaengen:",
+        (Language::English, "report_attach") => "Please attach the file to a new issue:",
+        (Language::German, "report_failed") => "Bericht konnte nicht gespeichert werden:",
+        (Language::English, "report_failed") => "Could not save the report:",
+        (Language::German, "report_hint") => "Klick oder Taste zum Schliessen",
+        (Language::English, "report_hint") => "Click or press a key to close",
+        (Language::German, "assets_damaged_title") => "Beschaedigte Spieldateien",
+        (Language::English, "assets_damaged_title") => "Damaged game files",
+        (Language::German, "assets_damaged_fix") => "Bitte diese Dateien erneut von der CD bzw. aus der ISO kopieren\n(oder den Ordner game_data loeschen und neu starten).",
+        (Language::English, "assets_damaged_fix") => "Please copy these files again from the CD or the ISO\n(or delete the game_data folder and restart).",
+        (Language::German, "damage_missing") => "fehlt",
+        (Language::English, "damage_missing") => "missing",
+        (Language::German, "damage_unreadable") => "nicht lesbar",
+        (Language::English, "damage_unreadable") => "unreadable",
+        (Language::German, "damage_truncated") => "unvollstaendig",
+        (Language::English, "damage_truncated") => "incomplete",
+        (Language::German, "damage_chunks") => "Daten fehlen",
+        (Language::English, "damage_chunks") => "data missing",
+        (Language::German, "damage_members") => "zu wenige Elemente",
+        (Language::English, "damage_members") => "members missing",
+        (Language::German, "damage_checksum") => "Pruefsumme falsch",
+        (Language::English, "damage_checksum") => "checksum mismatch",
+
         // ── Fallback ──
         _ => "???",
     }
@@ -106,12 +384,39 @@ mod tests {
     fn all_german_keys_have_english() {
         let keys = [
             "pause_title", "menu_resume", "menu_fullscreen", "menu_detail_noise",
-            "menu_display_mode", "menu_quit", "pause_hint", "lang_label", "road_legal", "not_road_legal",
+            "menu_display_mode", "menu_low_spec", "menu_quit", "pause_hint", "lang_label", "menu_continue", "road_legal", "not_road_legal",
+            "garage_view_side", "garage_view_front", "garage_view_top",
+            "horn_button", "horn_title", "horn_parts", "horn_builtin",
             "dev_title", "dev_hint", "dev_infinite_fuel", "dev_noclip",
             "dev_hitboxes", "dev_skip_dialog", "dev_meme", "dev_detail_noise",
             "dev_goto_garage", "dev_goto_yard", "dev_goto_world",
             "dev_goto_carshow", "dev_goto_junkyard", "dev_refuel",
             "dev_figge", "dev_close",
+            "naming_title", "naming_ok", "naming_err_empty", "naming_err_long",
+            "diploma_title", "diploma_medals", "gallery_empty",
+            "gallery_export", "gallery_exported", "gallery_share", "gallery_shared",
+            "gallery_import", "gallery_imported", "gallery_import_none",
+            "card_rating", "card_speed",
+            "card_strength", "card_comfort", "card_funny",
+            "stats_title", "stats_km", "stats_fuel", "stats_parts",
+            "stats_discovered", "stats_destinations", "stats_races", "stats_time", "stats_hint",
+            "speedrun_armed", "speedrun_saved", "speedrun_failed",
+            "menu_captions", "menu_rumble", "menu_options", "options_title", "opt_volume", "opt_ui_volume",
+            "opt_language", "opt_dev_key", "opt_press_key", "opt_back", "options_hint", "opt_map_prompts",
+            "prompt_gas", "prompt_ferry", "prompt_honk", "prompt_no_horn", "prompt_hill", "prompt_racing",
+            "prompt_visit", "tut_steer", "tut_gas", "tut_destination", "tut_skip", "tut_done", "window_title", "cc_phone", "cc_horn", "cc_engine_stall", "cc_pump", "cc_cow",
+            "cc_bridge_creak", "cc_race_start", "cc_race_finish",
+            "search_button", "search_title", "search_piles", "search_pile",
+            "kind_wheels", "kind_engine", "kind_energy", "kind_electrics",
+            "kind_controls", "kind_body", "kind_decoration",
+            "mission_done", "discovered", "dest_82", "dest_83", "dest_84", "dest_85", "dest_86", "dest_87",
+            "dest_88", "dest_89", "dest_90", "dest_91", "dest_92", "dest_93", "dest_94",
+            "minimap_on", "minimap_off", "tow_call",
+            "report_title", "report_saved", "report_attach", "report_failed", "report_hint",
+            "assets_damaged_title", "assets_damaged_fix", "damage_missing", "damage_unreadable",
+            "damage_truncated", "damage_chunks", "damage_members", "damage_checksum",
+            "tip_drive_keys", "tip_road_legal", "tip_fuel", "tip_destinations", "tip_junkyard",
+            "tip_horn", "tip_gallery", "tip_stats", "tip_escape",
         ];
         for key in &keys {
             let de = t(Language::German, key);
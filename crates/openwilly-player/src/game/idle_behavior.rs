@@ -0,0 +1,240 @@
+//! Idle behaviors — ambient animations for scene actors
+//!
+//! Actors otherwise hold their idle (or mouse-tracking) pose forever. A
+//! behavior list (data/idle_behaviors.json, overridable via
+//! `<game_dir>/mods/idle_behaviors.json`) gives actors, by name, a random
+//! pause range and weighted one-shot animations to play when the pause runs
+//! out (Mulle scratching his chin, the menu Mulle pointing), plus reactions
+//! to game events such as attaching a part. After a one-shot the actor goes
+//! back to the animation it had before.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::game::scenes::Actor;
+use crate::game::Scene;
+
+/// Game events actors can react to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    /// A part was attached to the car in the garage
+    PartAttached,
+    /// A part description starts playing
+    Description,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct WeightedAnim {
+    anim: String,
+    weight: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BehaviorDef {
+    /// Actor name (see `SceneHandler::load_*`)
+    actor: String,
+    /// Scenes where the behavior applies (empty = wherever the actor is)
+    #[serde(default)]
+    scenes: Vec<Scene>,
+    /// Random pause between idle animations (frames at 30 fps)
+    min_frames: u32,
+    max_frames: u32,
+    #[serde(default)]
+    idle: Vec<WeightedAnim>,
+    #[serde(default)]
+    reactions: HashMap<Trigger, Vec<WeightedAnim>>,
+}
+
+/// Per-actor runtime state
+#[derive(Debug, Default)]
+struct ActorState {
+    /// Frames until the next idle animation
+    countdown: u32,
+    /// One-shot playing: (its name, animation to return to)
+    playing: Option<(String, String)>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdleBehaviors {
+    behaviors: Vec<BehaviorDef>,
+    #[serde(skip)]
+    state: HashMap<String, ActorState>,
+}
+
+impl IdleBehaviors {
+    /// Built-in list, replaced by `<game_dir>/mods/idle_behaviors.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("idle_behaviors.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(behaviors) => {
+                    tracing::info!("Idle behaviors loaded from {}", path.display());
+                    return behaviors;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/idle_behaviors.json"))
+            .expect("Failed to parse idle_behaviors.json")
+    }
+
+    /// Forget timers and running one-shots (new scene, new actors)
+    pub fn reset(&mut self) {
+        self.state.clear();
+    }
+
+    #[cfg(test)]
+    fn def_for(&self, actor: &str, scene: Scene) -> Option<&BehaviorDef> {
+        find_def(&self.behaviors, actor, scene)
+    }
+
+    /// Advance timers and start idle animations. Call once per frame while
+    /// nothing scripted is going on.
+    pub fn update(&mut self, scene: Scene, actors: &mut [Actor], rng: &mut impl Rng) {
+        for actor in actors.iter_mut() {
+            let Some(def) = find_def(&self.behaviors, &actor.name, scene) else { continue };
+            let (min, max) = (def.min_frames, def.max_frames.max(def.min_frames));
+            let state = self.state.entry(actor.name.clone())
+                .or_insert_with(|| ActorState { countdown: rng.gen_range(min..=max), playing: None });
+
+            if let Some((anim, return_to)) = &state.playing {
+                let active = actor.animations.get(actor.active_anim);
+                if active.is_some_and(|a| &a.name == anim && a.playing) {
+                    continue;
+                }
+                // Finished (or replaced, e.g. by talking or mouse tracking)
+                if active.is_some_and(|a| &a.name == anim) && !actor.is_talking {
+                    actor.play(return_to);
+                }
+                state.playing = None;
+                state.countdown = rng.gen_range(min..=max);
+                continue;
+            }
+
+            if !actor.visible || actor.is_talking || !actor.is_looping() {
+                continue;
+            }
+            state.countdown = state.countdown.saturating_sub(1);
+            if state.countdown == 0 {
+                match start_one_shot(actor, &def.idle, rng) {
+                    Some(playing) => state.playing = Some(playing),
+                    None => state.countdown = rng.gen_range(min..=max),
+                }
+            }
+        }
+    }
+
+    /// Let actors react to a game event (if they are free to)
+    pub fn react(&mut self, trigger: Trigger, scene: Scene, actors: &mut [Actor], rng: &mut impl Rng) {
+        for actor in actors.iter_mut() {
+            let Some(def) = find_def(&self.behaviors, &actor.name, scene) else { continue };
+            let Some(anims) = def.reactions.get(&trigger) else { continue };
+            if !actor.visible || actor.is_talking {
+                continue;
+            }
+            let state = self.state.entry(actor.name.clone()).or_default();
+            // Interrupting a running one-shot keeps its return animation
+            let return_to = state.playing.take().map(|(_, r)| r);
+            if let Some((anim, previous)) = start_one_shot(actor, anims, rng) {
+                state.playing = Some((anim, return_to.unwrap_or(previous)));
+            }
+        }
+    }
+}
+
+fn find_def<'a>(behaviors: &'a [BehaviorDef], actor: &str, scene: Scene) -> Option<&'a BehaviorDef> {
+    behaviors.iter()
+        .find(|d| d.actor == actor && (d.scenes.is_empty() || d.scenes.contains(&scene)))
+}
+
+/// Play a weighted pick; returns (picked, previously active animation)
+fn start_one_shot(actor: &mut Actor, anims: &[WeightedAnim], rng: &mut impl Rng) -> Option<(String, String)> {
+    let total: u32 = anims.iter().map(|a| a.weight).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut roll = rng.gen_range(0..total);
+    let pick = anims.iter().find(|a| {
+        if roll < a.weight {
+            return true;
+        }
+        roll -= a.weight;
+        false
+    })?;
+    let previous = actor.animations.get(actor.active_anim)?.name.clone();
+    actor.play(&pick.anim).then(|| (pick.anim.clone(), previous))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::scenes::{AnimFrame, Animation, LOGIC_FPS};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn anim(name: &str, frames: usize, looping: bool) -> Animation {
+        let mut a = Animation::new(name, 10, looping);
+        a.frames = vec![AnimFrame { width: 1, height: 1, pixels: vec![0; 4].into(), reg_x: 0, reg_y: 0 }; frames];
+        a
+    }
+
+    fn mulle() -> Actor {
+        let mut actor = Actor::new("mulleDefault", 0, 0, 0);
+        actor.animations.push(anim("idle", 1, true));
+        actor.animations.push(anim("scratchChin", 6, false));
+        actor.animations.push(anim("scratchHead", 6, false));
+        actor.play("idle");
+        actor
+    }
+
+    fn active(actor: &Actor) -> &str {
+        &actor.animations[actor.active_anim].name
+    }
+
+    #[test]
+    fn idle_one_shot_then_back() {
+        let mut behaviors = IdleBehaviors::builtin();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut actors = [mulle()];
+        let (mut one_shot, mut back) = (false, false);
+        for _ in 0..1200 {
+            behaviors.update(Scene::Garage, &mut actors, &mut rng);
+            actors[0].tick(LOGIC_FPS);
+            if active(&actors[0]) != "idle" {
+                one_shot = true;
+            } else if one_shot {
+                back = true;
+            }
+        }
+        assert!(one_shot && back);
+    }
+
+    #[test]
+    fn reaction_plays_immediately_unless_talking() {
+        let mut behaviors = IdleBehaviors::builtin();
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut actors = [mulle()];
+        actors[0].is_talking = true;
+        behaviors.react(Trigger::PartAttached, Scene::Garage, &mut actors, &mut rng);
+        assert_eq!(active(&actors[0]), "idle");
+        actors[0].is_talking = false;
+        behaviors.react(Trigger::PartAttached, Scene::Garage, &mut actors, &mut rng);
+        assert_eq!(active(&actors[0]), "scratchHead");
+    }
+
+    #[test]
+    fn scene_filter() {
+        let behaviors = IdleBehaviors::builtin();
+        assert!(behaviors.def_for("mulleMenuHead", Scene::Menu).is_some());
+        assert!(behaviors.def_for("mulleMenuHead", Scene::Garage).is_none());
+        assert!(behaviors.def_for("mulleDefault", Scene::Destination(86)).is_some());
+    }
+}
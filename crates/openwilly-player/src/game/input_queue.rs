@@ -0,0 +1,106 @@
+//! Input queue — clicks that survive scene boundaries deterministically
+//!
+//! Clicks of a frame are queued and handed to the game one by one. A scene
+//! boundary (a transition cutscene starting, a scene being entered) drops
+//! whatever is still queued and starts a short lockout: clicks during the
+//! loading screen and the first `LOCKOUT_FRAMES` of the new scene are
+//! dropped, never delivered late. A button still held across the boundary
+//! counts as released until it is let go, so a double click on a door
+//! can't grab or detach a part on the garage's first frame.
+//!
+//! Everything is counted in frames, so demo playback drops the same clicks.
+
+use std::collections::VecDeque;
+
+use crate::game::demo::FrameInput;
+
+/// ~0.2 s at 30 fps
+pub const LOCKOUT_FRAMES: u32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pointer {
+    Click(i32, i32),
+    RightClick(i32, i32),
+}
+
+#[derive(Debug, Default)]
+pub struct InputQueue {
+    queue: VecDeque<Pointer>,
+    /// Frames left in which clicks are dropped
+    lockout: u32,
+    /// Left button held since before the last scene boundary
+    held_through: bool,
+    /// Clicks dropped since the last boundary (debug log)
+    dropped: u32,
+}
+
+impl InputQueue {
+    /// Start of a frame: queue its clicks, returns the left button state
+    /// the game should see
+    pub fn push_frame(&mut self, input: &FrameInput) -> bool {
+        self.lockout = self.lockout.saturating_sub(1);
+        if !input.down {
+            self.held_through = false;
+        }
+        if input.click {
+            self.queue.push_back(Pointer::Click(input.x, input.y));
+        }
+        if input.right_click {
+            self.queue.push_back(Pointer::RightClick(input.x, input.y));
+        }
+        input.down && !self.held_through
+    }
+
+    /// Next click to deliver; `blocked` (loading screen) drops the rest
+    pub fn next(&mut self, blocked: bool) -> Option<Pointer> {
+        if blocked || self.lockout > 0 {
+            self.dropped += self.queue.len() as u32;
+            self.queue.clear();
+            return None;
+        }
+        self.queue.pop_front()
+    }
+
+    /// Scene boundary: drop queued clicks, start the lockout
+    pub fn scene_changed(&mut self, button_down: bool) {
+        if self.dropped > 0 || !self.queue.is_empty() {
+            tracing::debug!("Input: {} clicks dropped at scene boundary", self.dropped + self.queue.len() as u32);
+        }
+        self.queue.clear();
+        self.dropped = 0;
+        self.lockout = LOCKOUT_FRAMES;
+        self.held_through = button_down;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn click(x: i32) -> FrameInput {
+        FrameInput { x, y: 100, down: true, click: true, ..Default::default() }
+    }
+
+    #[test]
+    fn clicks_after_a_boundary_are_dropped() {
+        let mut queue = InputQueue::default();
+        assert!(queue.push_frame(&FrameInput { right_click: true, ..click(10) }));
+        assert_eq!(queue.next(false), Some(Pointer::Click(10, 100)));
+
+        // First click switched the scene: the right click of the same frame goes
+        queue.scene_changed(true);
+        assert_eq!(queue.next(false), None);
+
+        // Held button reads as released, clicks in the lockout are dropped
+        for _ in 1..LOCKOUT_FRAMES {
+            assert!(!queue.push_frame(&click(20)));
+            assert_eq!(queue.next(false), None);
+        }
+        assert!(!queue.push_frame(&FrameInput::default()));
+        assert!(queue.push_frame(&click(30)));
+        assert_eq!(queue.next(true), None);
+        assert!(queue.push_frame(&click(40)));
+        assert_eq!(queue.next(false), Some(Pointer::Click(40, 100)));
+        assert_eq!(queue.next(false), None);
+    }
+}
@@ -0,0 +1,124 @@
+//! Interaction prompts on the world map
+//!
+//! Gas stations, the ferry, animals on the road and race tracks react when
+//! the car drives into them, but nothing tells a new player so. While the
+//! car is inside an object's outer radius a short hint is shown above the
+//! object ("Drive in to refuel", "H: honk the horn"). Switched off in the
+//! options screen.
+
+use crate::engine::font;
+use crate::game::driving::{
+    DriveProperties, HillType, MapObject, MapObjectType, BIG_HILL_STRENGTH_THRESHOLD,
+    SMALL_HILL_STRENGTH_THRESHOLD,
+};
+use crate::game::i18n::{self, Language};
+
+/// Bottom of the map area (the dashboard is below)
+const MAP_BOTTOM: i32 = 396;
+/// Gap between the prompt and the object center
+const LIFT: i32 = 28;
+
+/// i18n key of the hint for an object, None if it needs no explanation
+fn prompt_key(obj: &MapObject, props: &DriveProperties) -> Option<&'static str> {
+    match obj.obj_type {
+        MapObjectType::Gas => Some("prompt_gas"),
+        MapObjectType::Ferry => Some("prompt_ferry"),
+        MapObjectType::Cows | MapObjectType::Goats if props.horn_type > 0 => Some("prompt_honk"),
+        MapObjectType::Cows | MapObjectType::Goats => Some("prompt_no_horn"),
+        MapObjectType::Hill(hill) => {
+            let needed = match hill {
+                HillType::BigHill => BIG_HILL_STRENGTH_THRESHOLD,
+                HillType::SmallHill => SMALL_HILL_STRENGTH_THRESHOLD,
+            };
+            (props.strength < needed).then_some("prompt_hill")
+        }
+        MapObjectType::Racing { .. } => Some("prompt_racing"),
+        MapObjectType::Destination | MapObjectType::RandomDestination if obj.dir_resource.is_some() => {
+            Some("prompt_visit")
+        }
+        _ => None,
+    }
+}
+
+/// Hint for the nearest object whose outer radius the car is in:
+/// (i18n key, object x, object y)
+pub fn active_prompt(
+    (car_x, car_y): (f32, f32),
+    props: &DriveProperties,
+    objects: &[MapObject],
+    cache_list: &[String],
+    medals: &[String],
+) -> Option<(&'static str, i32, i32)> {
+    objects.iter()
+        .filter(|o| o.enabled && !o.is_hidden(cache_list, medals))
+        .filter_map(|o| {
+            let dist = ((car_x - o.x as f32).powi(2) + (car_y - o.y as f32).powi(2)).sqrt();
+            let key = prompt_key(o, props)?;
+            (dist <= o.outer_radius).then_some((dist, key, o.x, o.y))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, key, x, y)| (key, x, y))
+}
+
+/// Draw the hint centered above (x, y), kept on the map area
+pub fn draw(fb: &mut [u32], lang: Language, key: &str, x: i32, y: i32) {
+    let text = i18n::t(lang, key);
+    let w = font::text_width(text) + 10;
+    let h = 16;
+    let bx = (x - w / 2).clamp(2, 640 - w - 2);
+    let by = if y - LIFT - h >= 2 { y - LIFT - h } else { (y + LIFT).min(MAP_BOTTOM - h - 2) };
+    font::draw_rect(fb, bx, by, w, h, 0xCC1A1A2E);
+    font::draw_rect_outline(fb, bx, by, w, h, 0xFFFFD23F);
+    font::draw_text_shadow(fb, bx + 5, by + 4, text, 0xFFFFFFFF);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(id: u32, x: i32, obj_type: MapObjectType) -> MapObject {
+        MapObject {
+            object_id: id,
+            x,
+            y: 100,
+            obj_type,
+            inner_radius: 20.0,
+            outer_radius: 60.0,
+            dir_resource: None,
+            enabled: true,
+            check_for_cache: Vec::new(),
+            check_for_medals: Vec::new(),
+            if_found: None,
+            set_when_done: None,
+            sound_id: None,
+            approach_sound: None,
+            sprite_name: None,
+            z_under: false,
+        }
+    }
+
+    #[test]
+    fn nearest_object_in_range_wins() {
+        let props = DriveProperties { horn_type: 1, ..Default::default() };
+        let objects = [
+            object(1, 100, MapObjectType::Gas),
+            object(2, 150, MapObjectType::Cows),
+            object(3, 300, MapObjectType::Ferry),
+        ];
+        let prompt = |x| active_prompt((x, 100.0), &props, &objects, &[], &[]);
+        assert_eq!(prompt(110.0), Some(("prompt_gas", 100, 100)));
+        assert_eq!(prompt(140.0), Some(("prompt_honk", 150, 100)));
+        assert_eq!(prompt(220.0), None);
+    }
+
+    #[test]
+    fn hints_depend_on_the_car() {
+        let weak = DriveProperties { strength: 1, ..Default::default() };
+        let strong = DriveProperties { strength: 5, ..Default::default() };
+        let hill = object(1, 100, MapObjectType::Hill(HillType::BigHill));
+        assert_eq!(prompt_key(&hill, &weak), Some("prompt_hill"));
+        assert_eq!(prompt_key(&hill, &strong), None);
+        assert_eq!(prompt_key(&object(2, 0, MapObjectType::Goats), &weak), Some("prompt_no_horn"));
+        assert_eq!(prompt_key(&object(3, 0, MapObjectType::Correct), &weak), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! World-map object sprites, pre-transformed once per tile
+//!
+//! Looking up a map object's bitmap by name walks every cast of every
+//! Director file and decodes the BITD again, which used to happen for each
+//! object on every frame. Now the sprites of a tile are decoded when the car
+//! enters it and trimmed to their opaque bounds (most object bitmaps carry
+//! a wide transparent border). Per frame only the CheckFor/IfFound state
+//! is checked and the prepared sprites are handed out by index.
+
+use std::collections::HashMap;
+
+use crate::assets::bitmap::DecodedBitmap;
+use crate::assets::AssetStore;
+use crate::engine::{Sprite, SpritePixels};
+use crate::game::driving::{MapObject, MapTile};
+
+/// z-order of the first object under / over the car (the car is at 1000)
+const Z_UNDER: i32 = 500;
+const Z_OVER: i32 = 1500;
+
+/// A decoded bitmap cut down to its opaque pixels
+#[derive(Debug, Clone)]
+pub struct Trimmed {
+    /// Offset of the trimmed area inside the original bitmap
+    pub dx: i32,
+    pub dy: i32,
+    pub width: u32,
+    pub height: u32,
+    pub pixels: SpritePixels,
+}
+
+/// Trim a bitmap to the bounding box of its non-transparent pixels.
+/// None if nothing of it is visible.
+pub fn trim(bmp: &DecodedBitmap) -> Option<Trimmed> {
+    let (w, h) = (bmp.width as usize, bmp.height as usize);
+    let alpha = |x: usize, y: usize| bmp.pixels.get((y * w + x) * 4 + 3).copied().unwrap_or(0);
+    let rows: Vec<usize> = (0..h).filter(|&y| (0..w).any(|x| alpha(x, y) > 0)).collect();
+    let (&top, &bottom) = (rows.first()?, rows.last()?);
+    let left = (0..w).find(|&x| (top..=bottom).any(|y| alpha(x, y) > 0))?;
+    let right = (left..w).rev().find(|&x| (top..=bottom).any(|y| alpha(x, y) > 0))?;
+
+    let (tw, th) = (right - left + 1, bottom - top + 1);
+    let mut pixels = Vec::with_capacity(tw * th * 4);
+    for y in top..=bottom {
+        let row = (y * w + left) * 4;
+        pixels.extend_from_slice(&bmp.pixels[row..row + tw * 4]);
+    }
+    Some(Trimmed {
+        dx: left as i32,
+        dy: top as i32,
+        width: tw as u32,
+        height: th as u32,
+        pixels: pixels.into(),
+    })
+}
+
+/// Prepared sprite of one map object
+struct MapSprite {
+    /// Index into the tile's objects, and that object's id as a guard
+    index: usize,
+    object_id: u32,
+    z_under: bool,
+    sprite: Sprite,
+}
+
+/// Sprites of the tile the car is on
+#[derive(Default)]
+pub struct MapSprites {
+    tile: Option<u32>,
+    sprites: Vec<MapSprite>,
+}
+
+impl MapSprites {
+    /// Drop everything (new world map)
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Decode and trim the sprites of `tile`, unless they already are
+    pub fn prepare(&mut self, tile: &MapTile, assets: &AssetStore) {
+        if self.tile == Some(tile.id) {
+            return;
+        }
+        self.prepare_with(tile, |name| assets.find_bitmap_by_name(name));
+        tracing::debug!("Map tile {}: {} object sprites prepared", tile.id, self.sprites.len());
+    }
+
+    fn prepare_with<F>(&mut self, tile: &MapTile, mut decode: F)
+    where
+        F: FnMut(&str) -> Option<DecodedBitmap>,
+    {
+        // Objects sharing a bitmap (trees, signs) decode it once
+        let mut trimmed: HashMap<&str, Option<Trimmed>> = HashMap::new();
+        self.tile = Some(tile.id);
+        self.sprites.clear();
+        for (index, obj) in tile.objects.iter().enumerate() {
+            let Some(name) = obj.sprite_name.as_deref() else { continue };
+            let entry = trimmed.entry(name).or_insert_with(|| {
+                let bmp = decode(name)?;
+                // Offsets relative to the object, bitmaps are centered on it
+                trim(&bmp).map(|t| Trimmed { dx: t.dx - bmp.width as i32 / 2, dy: t.dy - bmp.height as i32 / 2, ..t })
+            });
+            let Some(t) = entry else { continue };
+            self.sprites.push(MapSprite {
+                index,
+                object_id: obj.object_id,
+                z_under: obj.z_under,
+                sprite: Sprite {
+                    x: obj.x + t.dx,
+                    y: obj.y + t.dy,
+                    width: t.width,
+                    height: t.height,
+                    pixels: t.pixels.clone(),
+                    visible: true,
+                    z_order: 0,
+                    name: format!("map_obj_{}", obj.object_id),
+                    interactive: false,
+                    member_num: 0,
+                },
+            });
+        }
+    }
+
+    /// Sprites of the visible objects, z-ordered under/over the car in
+    /// object order. `objects` is the current state of the prepared tile.
+    pub fn sprites<'a>(&'a self, objects: &'a [MapObject], cache_list: &'a [String], medals: &'a [String]) -> impl Iterator<Item = Sprite> + 'a {
+        let (mut z_under, mut z_over) = (Z_UNDER, Z_OVER);
+        self.sprites.iter()
+            .filter(move |s| objects.get(s.index).is_some_and(|o| {
+                o.object_id == s.object_id && o.enabled && !o.is_hidden(cache_list, medals)
+            }))
+            .map(move |s| {
+                let z = if s.z_under {
+                    z_under += 1;
+                    z_under
+                } else {
+                    z_over += 1;
+                    z_over
+                };
+                Sprite { z_order: z, ..s.sprite.clone() }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::driving::MapObjectType;
+
+    /// 6×4 bitmap with an opaque "L" at columns 1..4, rows 1..3
+    fn bitmap() -> DecodedBitmap {
+        let opaque = [(1, 1), (1, 2), (2, 2), (3, 2)];
+        let pixels = (0..24)
+            .flat_map(|i| {
+                let a = if opaque.contains(&(i % 6, i / 6)) { 255 } else { 0 };
+                [i as u8, 0, 0, a]
+            })
+            .collect();
+        DecodedBitmap { width: 6, height: 4, pixels }
+    }
+
+    fn object(id: u32, sprite: &str, z_under: bool) -> MapObject {
+        MapObject {
+            object_id: id,
+            x: 100,
+            y: 50,
+            obj_type: MapObjectType::Custom,
+            inner_radius: 0.0,
+            outer_radius: 0.0,
+            dir_resource: None,
+            enabled: true,
+            check_for_cache: vec!["#Flag".into()],
+            check_for_medals: Vec::new(),
+            if_found: Some("#NoDisplay".into()),
+            set_when_done: None,
+            sound_id: None,
+            approach_sound: None,
+            sprite_name: Some(sprite.into()),
+            z_under,
+        }
+    }
+
+    #[test]
+    fn trims_to_opaque_pixels() {
+        let t = trim(&bitmap()).unwrap();
+        assert_eq!((t.dx, t.dy, t.width, t.height), (1, 1, 3, 2));
+        // First pixel of the trimmed buffer is (1, 1) of the original
+        assert_eq!(t.pixels[0], 7);
+        let blank = DecodedBitmap { width: 2, height: 2, pixels: vec![0; 16] };
+        assert!(trim(&blank).is_none());
+    }
+
+    #[test]
+    fn decodes_once_and_orders_around_the_car() {
+        let tile = MapTile {
+            id: 7,
+            map_image: String::new(),
+            topology: String::new(),
+            objects: vec![object(1, "tree", false), object(2, "house", true), object(3, "tree", false)],
+        };
+        let mut decoded = Vec::new();
+        let mut cache = MapSprites::default();
+        cache.prepare_with(&tile, |name| {
+            decoded.push(name.to_string());
+            Some(bitmap())
+        });
+        assert_eq!(decoded, vec!["tree", "house"]);
+
+        let sprites: Vec<Sprite> = cache.sprites(&tile.objects, &[], &[]).collect();
+        let z: Vec<i32> = sprites.iter().map(|s| s.z_order).collect();
+        assert_eq!(z, vec![Z_OVER + 1, Z_UNDER + 1, Z_OVER + 2]);
+        // Trimmed sprite sits where the opaque pixels were: 100 - 6/2 + 1
+        assert_eq!((sprites[0].x, sprites[0].y, sprites[0].width), (98, 49, 3));
+
+        // CheckFor/IfFound hides objects without re-preparing
+        let hidden = cache.sprites(&tile.objects, &["#Flag".to_string()], &[]).count();
+        assert_eq!(hidden, 0);
+    }
+}
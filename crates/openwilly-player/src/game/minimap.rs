@@ -0,0 +1,183 @@
+//! Driving minimap — small roadmap of the current tile in the HUD corner
+//!
+//! The current tile's topology bitmap (316×198) is halved into a 158×99
+//! roadmap: drivable ground is drawn as road, walls as landscape, mud and
+//! holes in their own colors. The car is an arrow pointing in its driving
+//! direction, enabled destinations on the tile are markers (yellow when
+//! discovered, green once visited). The
+//! roadmap is only rebuilt when the topology changes (tile transition).
+
+use crate::engine::font;
+use crate::engine::SCREEN_WIDTH;
+use crate::game::driving::{
+    self, MapObject, MapObjectType, MAP_OFFSET_X, MAP_OFFSET_Y, TERRAIN_HOLES, TERRAIN_MUD,
+    TERRAIN_WALL, TOPO_HEIGHT, TOPO_WIDTH,
+};
+
+/// Roadmap size (half the topology resolution)
+const MAP_W: i32 = TOPO_WIDTH / 2;
+const MAP_H: i32 = TOPO_HEIGHT / 2;
+/// Top-right corner, clear of the debug line and the toolbox tab
+const MAP_X: i32 = SCREEN_WIDTH as i32 - MAP_W - 8;
+const MAP_Y: i32 = 8;
+
+const COLOR_LAND: u32 = 0xFF3E6B35;
+const COLOR_ROAD: u32 = 0xFFD9C9A0;
+const COLOR_HILL: u32 = 0xFFB39A6A;
+const COLOR_MUD: u32 = 0xFF7A5A30;
+const COLOR_HOLES: u32 = 0xFF8A8A8A;
+const COLOR_CAR: u32 = 0xFFE02020;
+const COLOR_DESTINATION: u32 = 0xFFFFD23F;
+const COLOR_VISITED: u32 = 0xFF4CC24C;
+
+/// Cached roadmap of one tile
+#[derive(Default)]
+pub struct Minimap {
+    /// Topology member the roadmap was built from
+    topology: String,
+    pixels: Vec<u32>,
+}
+
+impl Minimap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw the minimap. `topology` names the tile's topology bitmap, `topo`
+    /// is its data (see `GameState::topo_data`); the roadmap is rebuilt when
+    /// the name changes. `visited` lists destination scenes already visited.
+    pub fn draw(
+        &mut self,
+        fb: &mut [u32],
+        topology: &str,
+        topo: &[u8],
+        car: (f32, f32, u8),
+        objects: &[MapObject],
+        visited: &[u8],
+    ) {
+        if self.topology != topology || self.pixels.is_empty() {
+            self.pixels = roadmap(topo);
+            self.topology = topology.to_string();
+        }
+
+        font::draw_rect(fb, MAP_X - 2, MAP_Y - 2, MAP_W + 4, MAP_H + 4, 0xFF1A1A2E);
+        for y in 0..MAP_H {
+            let row = ((MAP_Y + y) as usize) * SCREEN_WIDTH;
+            let src = (y * MAP_W) as usize;
+            let dst = row + MAP_X as usize;
+            fb[dst..dst + MAP_W as usize].copy_from_slice(&self.pixels[src..src + MAP_W as usize]);
+        }
+
+        for obj in objects.iter().filter(|o| is_destination(o)) {
+            let (mx, my) = to_map(obj.x as f32, obj.y as f32);
+            let scene = obj.dir_resource.as_deref().and_then(|r| r.parse::<u8>().ok());
+            let color = if scene.is_some_and(|n| visited.contains(&n)) { COLOR_VISITED } else { COLOR_DESTINATION };
+            font::draw_rect(fb, MAP_X + mx - 3, MAP_Y + my - 3, 7, 7, 0xFF1A1A2E);
+            font::draw_rect(fb, MAP_X + mx - 2, MAP_Y + my - 2, 5, 5, color);
+        }
+
+        let (x, y, direction) = car;
+        draw_arrow(fb, to_map(x, y), direction);
+        font::draw_rect_outline(fb, MAP_X - 2, MAP_Y - 2, MAP_W + 4, MAP_H + 4, 0xFF6666CC);
+    }
+}
+
+fn is_destination(obj: &MapObject) -> bool {
+    obj.enabled
+        && matches!(obj.obj_type, MapObjectType::Destination | MapObjectType::RandomDestination)
+        && obj.dir_resource.is_some()
+}
+
+/// Tile pixel position (640×396) → roadmap position, clamped to the map
+fn to_map(x: f32, y: f32) -> (i32, i32) {
+    let mx = (x as i32 - MAP_OFFSET_X) / 4;
+    let my = (y as i32 - MAP_OFFSET_Y) / 4;
+    (mx.clamp(0, MAP_W - 1), my.clamp(0, MAP_H - 1))
+}
+
+/// Color for a topology value
+fn terrain_color(terrain: u8) -> u32 {
+    match terrain {
+        t if t >= TERRAIN_WALL => COLOR_LAND,
+        TERRAIN_MUD => COLOR_MUD,
+        TERRAIN_HOLES => COLOR_HOLES,
+        t if t % 16 > 1 => COLOR_HILL,
+        _ => COLOR_ROAD,
+    }
+}
+
+/// Halve the topology: a 2×2 block counts as road if any of it is drivable,
+/// so narrow roads don't disappear
+fn roadmap(topo: &[u8]) -> Vec<u32> {
+    let at = |x: i32, y: i32| topo.get((y * TOPO_WIDTH + x) as usize).copied().unwrap_or(TERRAIN_WALL);
+    let mut px = Vec::with_capacity((MAP_W * MAP_H) as usize);
+    for y in 0..MAP_H {
+        for x in 0..MAP_W {
+            let block = [at(2 * x, 2 * y), at(2 * x + 1, 2 * y), at(2 * x, 2 * y + 1), at(2 * x + 1, 2 * y + 1)];
+            let terrain = block.iter().copied().find(|&t| t < TERRAIN_WALL).unwrap_or(TERRAIN_WALL);
+            px.push(terrain_color(terrain));
+        }
+    }
+    px
+}
+
+/// Filled triangle pointing in the car's direction
+fn draw_arrow(fb: &mut [u32], (cx, cy): (i32, i32), direction: u8) {
+    let (dx, dy) = driving::direction_vector(direction);
+    let (cx, cy) = ((MAP_X + cx) as f32, (MAP_Y + cy) as f32);
+    let tip = (cx + dx * 6.0, cy + dy * 6.0);
+    let left = (cx - dx * 3.0 - dy * 4.0, cy - dy * 3.0 + dx * 4.0);
+    let right = (cx - dx * 3.0 + dy * 4.0, cy - dy * 3.0 - dx * 4.0);
+
+    let edge = |a: (f32, f32), b: (f32, f32), p: (f32, f32)| (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0);
+    let area = edge(tip, left, right);
+    for y in (cy as i32 - 7)..=(cy as i32 + 7) {
+        for x in (cx as i32 - 7)..=(cx as i32 + 7) {
+            let p = (x as f32 + 0.5, y as f32 + 0.5);
+            let (w0, w1, w2) = (edge(left, right, p), edge(right, tip, p), edge(tip, left, p));
+            let inside = if area >= 0.0 {
+                w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0
+            } else {
+                w0 <= 0.0 && w1 <= 0.0 && w2 <= 0.0
+            };
+            if inside && x >= 0 && y >= 0 && (x as usize) < SCREEN_WIDTH {
+                if let Some(pixel) = fb.get_mut(y as usize * SCREEN_WIDTH + x as usize) {
+                    *pixel = COLOR_CAR;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::SCREEN_HEIGHT;
+
+    #[test]
+    fn roadmap_keeps_narrow_roads() {
+        let mut topo = vec![TERRAIN_WALL; (TOPO_WIDTH * TOPO_HEIGHT) as usize];
+        // A one-pixel road along row 11 and a mud patch
+        for x in 0..TOPO_WIDTH {
+            topo[(11 * TOPO_WIDTH + x) as usize] = 0;
+        }
+        topo[(40 * TOPO_WIDTH + 40) as usize] = TERRAIN_MUD;
+        let px = roadmap(&topo);
+        assert_eq!(px.len(), (MAP_W * MAP_H) as usize);
+        assert_eq!(px[(5 * MAP_W + 30) as usize], COLOR_ROAD);
+        assert_eq!(px[(6 * MAP_W + 30) as usize], COLOR_LAND);
+        assert_eq!(px[(20 * MAP_W + 20) as usize], COLOR_MUD);
+    }
+
+    #[test]
+    fn arrow_points_along_direction() {
+        let mut fb = vec![0u32; SCREEN_WIDTH * SCREEN_HEIGHT];
+        // Direction 16 = north: the tip is above the center, nothing below the tail
+        draw_arrow(&mut fb, (50, 50), 16);
+        let at = |x: i32, y: i32| fb[((MAP_Y + y) as usize) * SCREEN_WIDTH + (MAP_X + x) as usize];
+        assert_eq!(at(50, 45), COLOR_CAR);
+        assert_eq!(at(50, 55), 0);
+        assert_eq!(to_map(4.0, 2.0), (0, 0));
+        assert_eq!(to_map(5000.0, 5000.0), (MAP_W - 1, MAP_H - 1));
+    }
+}
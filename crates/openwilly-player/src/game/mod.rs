@@ -14,25 +14,67 @@
 //!   18.DXR — Boot-up/Init
 //!   82-94  — Destinations (houses, shops, etc.)
 
+pub mod actors;
+pub mod ambience;
+pub mod asset_check;
+pub mod autolaunch;
+pub mod avatars;
+pub mod bug_report;
 pub mod build_car;
+pub mod captions;
+pub mod car_naming;
 pub mod cursor;
 pub mod dashboard;
+pub mod debug_http;
+pub mod demo;
+pub mod dev_config;
 pub mod dev_menu;
 pub mod dialog;
+pub mod discovery;
+pub mod dog;
 pub mod drag_drop;
 pub mod driving;
+pub mod gallery;
+pub mod horn;
 pub mod i18n;
+pub mod idle_behavior;
+pub mod input_queue;
+pub mod map_prompts;
+pub mod map_sprites;
+pub mod minimap;
+pub mod options;
+pub mod part_search;
+pub mod parts_audit;
 pub mod parts_db;
+pub mod quicksave;
+pub mod random_events;
 pub mod save;
+pub mod save_guard;
+#[cfg(test)]
+mod scenario;
 pub mod scene_script;
 pub mod scenes;
+pub mod seasonal;
+pub mod speedrun;
+pub mod stats;
+pub mod tempo;
+pub mod text_input;
+pub mod tips;
 pub mod toolbox;
+pub mod tow;
+pub mod tutorial;
+pub mod willycar;
 
 use minifb::Key;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use crate::assets::AssetStore;
 use crate::engine::Sprite;
 use crate::engine::font;
+use crate::engine::rumble::{Rumble, RumbleEvent};
 use crate::engine::sound_engine::SoundEngine;
+use crate::engine::sprite_cache::SpriteCache;
+use crate::engine::ui_sound::UiSound;
 use crate::game::build_car::BuildCar;
 use crate::game::dialog::{DialogManager, DialogEvent, QuestState, MissionDB};
 use crate::game::driving::{DriveCar, DriveSession, DriveProperties};
@@ -44,7 +86,7 @@ use crate::game::cursor::GameCursor;
 use crate::game::i18n::Language;
 
 /// Which scene is active
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Scene {
     Boot,
     Menu,
@@ -108,6 +150,19 @@ fn transition_cutscene(from: &Scene, to: &Scene, has_car: bool) -> Option<Cutsce
     }
 }
 
+/// Garage camera-view button (x, y, w, h)
+const VIEW_BUTTON: (i32, i32, i32, i32) = (540, 8, 92, 22);
+/// Main menu "Continue" button, bottom-right (x, y, w, h)
+const CONTINUE_BUTTON: (i32, i32, i32, i32) = (460, 440, 160, 26);
+
+/// Whether (x, y) is on the main menu's "Continue" button
+fn continue_button_hit(x: i32, y: i32) -> bool {
+    let (bx, by, bw, bh) = CONTINUE_BUTTON;
+    x >= bx && y >= by && x < bx + bw && y < by + bh
+}
+/// Turntable rotation length in frames (view switches at the midpoint)
+const TURN_FRAMES: u8 = 12;
+
 /// Central game state
 pub struct GameState {
     pub assets: AssetStore,
@@ -116,6 +171,8 @@ pub struct GameState {
     pub mouse_x: i32,
     pub mouse_y: i32,
     pub sound: Option<SoundEngine>,
+    /// Controller force feedback while driving
+    pub rumble: Rumble,
     pub parts_db: PartsDB,
     /// The car being built (persists across scenes)
     pub car: BuildCar,
@@ -136,6 +193,8 @@ pub struct GameState {
     pub mouse_down: bool,
     /// Active scene script (for destination dialog chains)
     pub active_script: Option<SceneScript>,
+    /// Driving tutorial hint and arrow (while its script runs)
+    pub tutorial: Option<tutorial::Tutorial>,
     /// Developer menu (hidden, activated by 5× '#')
     pub dev_menu: DevMenu,
     /// Dashboard HUD (fuel needle + speedometer), loaded once
@@ -144,6 +203,12 @@ pub struct GameState {
     pub toolbox: Option<toolbox::Toolbox>,
     /// Persistent world map (created once, with random destinations applied)
     pub world_map: Option<driving::WorldMap>,
+    /// Decoded object sprites of the tile the car is on
+    pub map_sprites: map_sprites::MapSprites,
+    /// Decoded driving car / Salka bitmaps
+    pub sprite_cache: SpriteCache,
+    /// Settings of the options screen as last saved
+    pub options: options::Options,
     /// Transition cutscene: bitmap + countdown frames + target scene
     pub transition: Option<TransitionCutscene>,
     /// Software-rendered cursor with stack-based type management
@@ -153,6 +218,73 @@ pub struct GameState {
     /// Topology bitmap red channel (316×198) for terrain collision.
     /// Loaded per map tile; indexed [y * 316 + x].
     pub topo_data: Vec<u8>,
+    /// Car naming dialog (open while entering the car show)
+    pub car_naming: Option<car_naming::CarNamingDialog>,
+    /// Diploma overlay (opened from the toolbox popup)
+    pub diploma_open: bool,
+    /// Car Gallery status line after an export: (text, frames left)
+    pub gallery_notice: Option<(String, u32)>,
+    /// Thumbnails of the cars the last import added (shown with its notice)
+    pub gallery_imports: Vec<crate::engine::canvas::Canvas>,
+    /// Salka riding along (bark timing)
+    pub dog: dog::DogPassenger,
+    /// Garage turntable rotation in progress: (target view, frame)
+    pub garage_turn: Option<(build_car::CarView, u8)>,
+    /// Garage horn-test panel (open while Some)
+    pub horn_panel: Option<horn::HornPanel>,
+    /// Junkyard part search (panel + highlighted kind)
+    pub part_search: part_search::PartSearch,
+    /// Captions for important non-speech sounds (accessibility)
+    pub captions: captions::Captions,
+    /// Speedrun mode (F10 on the main menu), see `toggle_speedrun`
+    pub speedrun: Option<speedrun::Speedrun>,
+    /// Date-based easter eggs (birthday hat, holiday decorations)
+    pub seasonal: seasonal::SeasonalCalendar,
+    /// Statistics overlay (F2)
+    pub stats_open: bool,
+    /// Frames since the last full second of play time
+    play_frames: u32,
+    /// Low-spec mode (older Atom/Celeron machines), see `set_low_spec`
+    pub low_spec: bool,
+    /// Driving minimap (toggled from the toolbox)
+    pub minimap: minimap::Minimap,
+    /// "New place discovered" message while driving
+    pub toast: Option<discovery::Toast>,
+    /// Tow truck called from the toolbox
+    pub tow: tow::Tow,
+    /// Background loop and mix per scene
+    pub ambience: ambience::Ambience,
+    /// Animation tempo per scene (score tempo or data/tempo.json)
+    pub tempi: tempo::TempoTable,
+    /// Car thumbnails per profile (menu name list, gallery header)
+    pub avatars: avatars::Avatars,
+    /// Random event definitions (hitchhiker, lost item, ...)
+    pub random_events: random_events::RandomEvents,
+    /// Events spawned on the world map this session, not yet collected
+    pub active_events: Vec<random_events::ActiveEvent>,
+    /// Snapshot from the last escape-menu quit ("Continue" on the main menu)
+    pub quick_save: Option<quicksave::QuickSave>,
+    /// Set while restoring the quick-save: no cutscene, no arrival dialogs
+    resuming: bool,
+    /// Overlay button under the mouse (hover sound on change), see `ui_hover_target`
+    ui_hover: Option<usize>,
+    /// Game randomness (random events, dog barks, part drops), seeded per
+    /// session — `OPENWILLY_SEED` replays a seed from a bug report
+    pub rng: StdRng,
+    pub rng_seed: u64,
+    /// Bug report requested from the dev menu; written by the engine once
+    /// the next scene frame is rendered (`write_bug_report`)
+    pub report_requested: bool,
+    /// Bug report result dialog (modal)
+    pub report_dialog: Option<bug_report::ReportDialog>,
+    /// Damaged game files found while loading (notice on top)
+    pub asset_check: asset_check::AssetCheck,
+    /// Tip rotation for transition screens
+    pub tips: tips::Tips,
+    /// Ambient actor animations (Mulle scratching his chin, ...)
+    pub idle_behaviors: idle_behavior::IdleBehaviors,
+    /// Clicks per frame, dropped at scene boundaries (`apply_frame`)
+    input_queue: input_queue::InputQueue,
 }
 
 /// A brief cutscene image shown during scene transitions
@@ -169,16 +301,48 @@ pub struct TransitionCutscene {
     pub target: Scene,
     /// Progress bar position (0.0 = start, 1.0 = done)
     pub progress: f32,
+    /// Tip shown under the progress bar (i18n key)
+    pub tip: &'static str,
 }
 
 impl GameState {
     pub fn new(assets: AssetStore) -> Self {
+        let mut state = Self::with_sound(assets, SoundEngine::new());
+        state.rumble = Rumble::new();
+        state.options = options::Options::load(&state.assets.game_dir);
+        state.apply_options();
+        dev_config::DevConfig::load(&state.assets.game_dir).apply(&mut state.dev_menu);
+        state
+    }
+
+    /// Game state without audio output or game data — saves go to `game_dir`.
+    /// For front-ends without a window and the scenario tests (see `scenario`).
+    pub fn headless(game_dir: &std::path::Path) -> Self {
+        let assets = AssetStore {
+            files: std::collections::BTreeMap::new(),
+            game_dir: game_dir.to_path_buf(),
+            failed: Vec::new(),
+            pending: None,
+            missing: Default::default(),
+            edition: None,
+        };
+        Self::with_sound(assets, None)
+    }
+
+    fn with_sound(assets: AssetStore, sound: Option<SoundEngine>) -> Self {
         let current_scene = Scene::Boot;
         let scene_handler = scenes::SceneHandler::new(current_scene, &assets, false);
-        let sound = SoundEngine::new();
         let parts_db = PartsDB::load();
         // Save manager — uses game directory for save file
         let save_manager = SaveManager::new(&assets.game_dir);
+        let seasonal = seasonal::SeasonalCalendar::load(&assets.game_dir);
+        let random_events = random_events::RandomEvents::load(&assets.game_dir);
+        let idle_behaviors = idle_behavior::IdleBehaviors::load(&assets.game_dir);
+        let asset_check = asset_check::AssetCheck::new(&assets.game_dir);
+        let ambience = ambience::Ambience::load(&assets.game_dir);
+        let tempi = tempo::TempoTable::load(&assets.game_dir);
+        let avatars = avatars::Avatars::new(&assets.game_dir);
+        let quick_save = quicksave::QuickSave::load(&assets.game_dir);
         // Dialog, quest, and mission systems
         let dialog = DialogManager::new();
         let quest = QuestState::new();
@@ -192,6 +356,11 @@ impl GameState {
 
         let cursor = GameCursor::new(&assets);
 
+        let rng_seed = std::env::var("OPENWILLY_SEED").ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(rand::random);
+        tracing::info!("RNG seed: {}", rng_seed);
+
         let mut state = Self {
             assets,
             current_scene,
@@ -199,6 +368,7 @@ impl GameState {
             mouse_x: 0,
             mouse_y: 0,
             sound,
+            rumble: Rumble::disabled(),
             parts_db,
             car,
             save_manager,
@@ -209,14 +379,51 @@ impl GameState {
             drive_session: DriveSession::default(),
             mouse_down: false,
             active_script: None,
+            tutorial: None,
             dev_menu: DevMenu::new(),
             dashboard: None,
             toolbox: None,
             world_map: None,
+            map_sprites: map_sprites::MapSprites::default(),
+            sprite_cache: SpriteCache::default(),
+            options: options::Options::default(),
             transition: None,
             cursor,
             language: Language::German,
             topo_data: vec![0u8; (driving::TOPO_WIDTH * driving::TOPO_HEIGHT) as usize],
+            car_naming: None,
+            diploma_open: false,
+            gallery_notice: None,
+            gallery_imports: Vec::new(),
+            dog: dog::DogPassenger::new(),
+            garage_turn: None,
+            horn_panel: None,
+            part_search: part_search::PartSearch::default(),
+            captions: captions::Captions::default(),
+            speedrun: None,
+            seasonal,
+            stats_open: false,
+            play_frames: 0,
+            low_spec: false,
+            minimap: minimap::Minimap::new(),
+            toast: None,
+            tow: tow::Tow::default(),
+            ambience,
+            tempi,
+            avatars,
+            random_events,
+            active_events: Vec::new(),
+            quick_save,
+            resuming: false,
+            ui_hover: None,
+            rng: StdRng::seed_from_u64(rng_seed),
+            rng_seed,
+            report_requested: false,
+            report_dialog: None,
+            asset_check,
+            tips: tips::Tips::new(),
+            idle_behaviors,
+            input_queue: input_queue::InputQueue::default(),
         };
 
         // Boot → Menu transition
@@ -225,11 +432,30 @@ impl GameState {
     }
 
     pub fn update(&mut self) {
+        // Pick up Director files streamed from the ISO in the background
+        self.assets.poll_background();
+        self.asset_check.update(&self.assets);
+        self.rumble.update();
+        self.cursor.tick();
+        self.cursor.set_busy(self.is_busy());
+
+        // Play time (30 fps)
+        self.play_frames += 1;
+        if self.play_frames >= 30 {
+            self.play_frames = 0;
+            if let Some(stats) = self.save_manager.stats_mut() {
+                stats.play_seconds += 1;
+            }
+        }
+
         // Transition cutscene: count down frames, then switch scene
         if let Some(trans) = &mut self.transition {
             trans.frames_left = trans.frames_left.saturating_sub(1);
             trans.progress = 1.0 - (trans.frames_left as f32 / 15.0);
-            if trans.frames_left == 0 {
+            // Streaming from the ISO: keep the loading screen up until done
+            if self.assets.is_loading() {
+                trans.progress = trans.progress.min(0.9);
+            } else if trans.frames_left == 0 {
                 let target = trans.target.clone();
                 self.transition = None;
                 self.switch_scene(target);
@@ -238,11 +464,22 @@ impl GameState {
         }
 
         // Tick scene actors, collect animation events
+        self.scene_handler.skip_idle_ticks = self.low_spec;
         let scene_events = self.scene_handler.update(&self.assets, self.mouse_x, self.mouse_y);
+        if self.active_script.is_none() && !self.dialog.is_talking() {
+            self.idle_behaviors.update(self.current_scene, &mut self.scene_handler.actors, &mut self.rng);
+        }
         for event in &scene_events {
             self.handle_scene_event(event);
         }
 
+        // Sounds started last frame → captions
+        if let Some(snd) = &mut self.sound {
+            for name in snd.take_played() {
+                self.captions.on_sound(&name);
+            }
+        }
+
         // Advance dialog/subtitles (~33ms per frame at 30fps)
         let dialog_events = self.dialog.update(33);
         for event in &dialog_events {
@@ -273,14 +510,15 @@ impl GameState {
             }
         }
 
-        // Driving physics when on the World map
-        if self.current_scene == Scene::World {
+        // Tow truck: phone call, fade, drop-off at the nearest pump
+        if self.tow.update(self.dialog.is_talking()) {
+            self.finish_tow();
+        }
+
+        // Driving physics when on the World map (the car waits while being towed)
+        if self.current_scene == Scene::World && !self.tow.is_active() {
             // Ensure persistent world map is initialized (with random destinations)
-            if self.world_map.is_none() {
-                let mut wm = driving::WorldMap::default_map();
-                wm.apply_random_destinations();
-                self.world_map = Some(wm);
-            }
+            self.ensure_world_map();
             // Borrow topo_data separately so the closure can read it while car is &mut
             let topo = &self.topo_data;
             let topo_w = driving::TOPO_WIDTH as usize;
@@ -317,10 +555,18 @@ impl GameState {
                     noclip: self.dev_menu.noclip,
                     meme_mode: self.dev_menu.meme_mode,
                 };
+                let fuel_before = car.fuel;
                 let event = car.update(&tile_objects, |tx, ty| {
                     let idx = ty as usize * topo_w + tx as usize;
                     if idx < topo.len() { topo[idx] } else { 0 }
                 }, drive_cheats);
+                if let Some(stats) = self.save_manager.stats_mut() {
+                    stats.add_drive_frame(car.speed, fuel_before - car.fuel);
+                }
+                if car.over_holes && car.speed != 0.0 {
+                    let impact = (car.speed.abs() / car.max_speed()).min(1.0);
+                    self.rumble.play(RumbleEvent::Holes { impact });
+                }
                 let saved = match &event {
                     driving::DriveEvent::ReachedDestination { .. } => Some(car.save_session()),
                     _ => None,
@@ -331,12 +577,19 @@ impl GameState {
                 } else {
                     None
                 };
-                let sound = car.engine_sound_update().map(|s| s.to_string());
+                // (sound, is the startup one-shot)
+                let sound = car.engine_sound_update().map(|s| (s.to_string(), car.engine_sound_state == Some(0)));
                 (Some(event), sound, saved, tile_pos)
             } else {
                 (None, None, None, None)
             };
 
+            // Tutorial: steering practice counts the distance driven
+            let speed = self.drive_car.as_ref().map(|c| c.speed).unwrap_or(0.0);
+            if self.tutorial.as_mut().is_some_and(|t| t.add_distance(speed)) {
+                self.tutorial_event("steered");
+            }
+
             // Load new topology after tile transition (outside car borrow)
             if let Some((col, row)) = new_tile_pos {
                 let wm = self.world_map.as_ref()
@@ -347,6 +600,7 @@ impl GameState {
                 if let Some(topo_name) = topo_name {
                     self.load_topology(&topo_name);
                 }
+                self.discover_tile(col, row);
             }
 
             // Play approach sounds from map objects (outside car borrow)
@@ -362,9 +616,6 @@ impl GameState {
             // Process events outside the car borrow
             if let Some(event) = drive_event {
                 match event {
-                    driving::DriveEvent::FuelEmpty => {
-                        self.play_dialog("05d011v0"); // "Tank ist leer!"
-                    }
                     driving::DriveEvent::ReachedDestination { object_id, dir_resource } => {
                         tracing::info!("Reached destination object {} → {}", object_id, dir_resource);
                         if let Some(session) = saved_session {
@@ -384,7 +635,7 @@ impl GameState {
                             for &part_id in &swd.parts {
                                 let actual_id = if part_id == 0 {
                                     // #Random — get a random part not yet owned
-                                    self.save_manager.random_unowned_part()
+                                    self.save_manager.random_unowned_part(&mut self.rng)
                                         .unwrap_or(287) // fallback
                                 } else {
                                     part_id
@@ -398,118 +649,104 @@ impl GameState {
                             }
                         }
 
+                        // The tutorial finishes before the destination takes over
+                        if self.tutorial.is_some() {
+                            self.tutorial_event("destination");
+                            self.advance_script();
+                        }
+
                         if let Ok(n) = dir_resource.parse::<u8>() {
                             self.switch_scene(Scene::Destination(n));
                         }
                     }
-                    driving::DriveEvent::TerrainBlocked { reason } => {
-                        tracing::debug!("Terrain blocked: {}", reason);
-                    }
-                    driving::DriveEvent::GasStation => {
-                        if let Some(snd) = &mut self.sound {
-                            snd.play_by_name("31e006v0", &self.assets);
-                        }
-                        tracing::info!("Refueling at gas station");
-                    }
-                    driving::DriveEvent::AnimalsBlocking { has_horn, horn_type } => {
-                        if has_horn {
-                            // Honk horn sound by horntype, then play cow moo
-                            let idx = ((horn_type - 1) as usize).min(driving::HORN_SOUNDS.len() - 1);
-                            if let Some(snd) = &mut self.sound {
-                                snd.play_by_name(driving::HORN_SOUNDS[idx], &self.assets);
-                                snd.play_by_name(driving::COW_MOO_SOUND, &self.assets);
-                            }
-                        } else {
-                            // No horn — blocked sound
-                            if let Some(snd) = &mut self.sound {
-                                snd.play_by_name(driving::NO_HORN_SOUND, &self.assets);
-                            }
-                        }
-                    }
-                    driving::DriveEvent::HillSound { big } => {
-                        // Hill feedback sounds (from objects.hash Sounds array)
-                        let sound = if big { "31d005v0" } else { "31d004v0" };
-                        if let Some(snd) = &mut self.sound {
-                            snd.play_by_name(sound, &self.assets);
-                        }
-                        if big {
-                            self.award_medal(1); // BigHill medal
-                        }
-                    }
-                    driving::DriveEvent::FerryBoard => {
-                        // Ferry crossing — requires #FerryTicket (from Mia/Solhem dest 86)
-                        if self.quest.has_permanent("#FerryTicket") {
-                            // Teleport car to other shore
-                            if let Some(car) = &mut self.drive_car {
-                                car.ferry_teleport();
-                            }
-                            if let Some(snd) = &mut self.sound {
-                                snd.play_by_name(driving::FERRY_SOUND, &self.assets);
-                            }
-                            tracing::info!("Ferry crossing!");
-                        } else {
-                            // No ticket — Mulle says he needs a ticket
-                            self.play_dialog("05d014v0"); // "Ich brauche ein Fährticket"
-                        }
-                    }
-                    driving::DriveEvent::RaceStarted => {
-                        if let Some(snd) = &mut self.sound {
-                            snd.play_by_name(driving::RACING_START_SOUND, &self.assets);
-                        }
-                        tracing::info!("Race started!");
-                    }
-                    driving::DriveEvent::RaceFinished { time_secs } => {
-                        if let Some(snd) = &mut self.sound {
-                            snd.play_by_name(driving::RACING_FINISH_SOUND, &self.assets);
-                        }
-                        // Award racing medal
-                        self.award_medal(5);
-                        tracing::info!("Race finished in {:.2}s — medal 5 awarded!", time_secs);
-                    }
-                    driving::DriveEvent::BridgeSound { wooden } => {
-                        if let Some(snd) = &mut self.sound {
-                            if wooden {
-                                snd.play_by_name(driving::WBRIDGE_CREAK_SOUND, &self.assets);
-                            } else {
-                                snd.play_by_name(driving::CBRIDGE_SOUND, &self.assets);
-                            }
-                        }
-                    }
-                    driving::DriveEvent::FarAwayReached { object_id } => {
-                        self.award_medal(2);
-                        tracing::info!("FarAway object {} reached — medal 2 awarded!", object_id);
+                    other => self.on_drive_event(other),
+                }
+            }
+
+            // Engine sound: the adaptive loop follows speed and throttle; if its
+            // samples are unavailable, fall back to switching samples by state
+            if let (Some(snd), Some(car)) = (&mut self.sound, &self.drive_car) {
+                if !snd.engine_running() {
+                    if let Some((idle, driving)) = car.engine_loop_samples() {
+                        snd.start_engine(idle, driving, &self.assets);
                     }
-                    driving::DriveEvent::SoundTrigger { sound_id } => {
-                        if let Some(snd) = &mut self.sound {
-                            snd.play_by_name(&sound_id, &self.assets);
-                        }
+                }
+                let (pitch, load) = car.engine_tone();
+                snd.set_engine_tone(pitch, load);
+                if let Some((sound_id, startup)) = engine_sound {
+                    if startup || !snd.engine_running() {
+                        snd.play_by_name(&sound_id, &self.assets);
                     }
-                    _ => {}
                 }
             }
+        }
 
-            // Play engine sound if state changed
-            if let Some(sound_id) = engine_sound {
-                if let Some(snd) = &mut self.sound {
-                    snd.play_by_name(&sound_id, &self.assets);
+        // Object sprites of the car's tile (also after a tow moved the car),
+        // the car's and Salka's bitmaps for this frame
+        if self.current_scene == Scene::World {
+            if let (Some(wm), Some(car)) = (&self.world_map, &self.drive_car) {
+                if let Some(tile) = wm.tile_at(car.tile_col, car.tile_row).and_then(|tid| wm.get_tile(tid)) {
+                    self.map_sprites.prepare(tile, &self.assets);
                 }
             }
+            if let Some(car) = &self.drive_car {
+                self.sprite_cache.load_member(&self.assets, "05.DXR", car.sprite_member());
+                if self.quest.has_cache(dog::DOG_FLAG) {
+                    self.sprite_cache.load_named(&self.assets, dog::sprite_name(car.direction));
+                }
+            }
+        }
+
+        self.dog.tick();
+        if let (Some(dash), Some(car)) = (&mut self.dashboard, &self.drive_car) {
+            dash.update_steering(car.steer_right as i8 - car.steer_left as i8);
         }
+        self.update_garage_turn();
 
-        // Clean up finished sound effects
+        // Clean up finished sound effects, background fades + ducking under dialog
         if let Some(snd) = &mut self.sound {
             snd.gc();
+            snd.set_ducked(self.dialog.is_talking());
+            snd.update_background();
         }
     }
 
     pub fn on_click(&mut self, x: i32, y: i32) {
         // Dev menu intercepts clicks
         if self.dev_menu.open {
+            if DevMenu::item_at(x, y).is_some() {
+                self.play_ui_sound(UiSound::Click);
+            }
             let action = self.dev_menu.on_click(x, y);
             self.handle_dev_action(action);
             return;
         }
 
+        // Bug report dialog / asset notice / diploma / statistics overlay: any click closes it
+        if self.report_dialog.take().is_some() {
+            return;
+        }
+        if self.asset_check.open {
+            self.asset_check.open = false;
+            return;
+        }
+        if self.diploma_open {
+            self.diploma_open = false;
+            return;
+        }
+        if self.stats_open {
+            self.stats_open = false;
+            return;
+        }
+
+        // Car naming dialog is modal
+        if let Some(dialog) = &mut self.car_naming {
+            if let car_naming::NamingResult::Confirmed(name) = dialog.on_click(x, y) {
+                self.confirm_car_name(name);
+            }
+            return;
+        }
+
         // Toolbox / popup menu in World scene
         if self.current_scene == Scene::World {
             if let Some(tb) = &mut self.toolbox {
@@ -545,20 +782,26 @@ impl GameState {
                                 tb.popup_open = false;
                             }
                             toolbox::PopupAction::Diploma => {
-                                // Show earned medals info
-                                let medal_count = self
-                                    .save_manager
-                                    .active()
-                                    .map(|u| u.car.medals.len())
-                                    .unwrap_or(0);
-                                tracing::info!(
-                                    "Diploma: {}/4 medals earned",
-                                    medal_count
-                                );
+                                // Show the diploma with car name + earned medals
                                 if let Some(snd) = &mut self.sound {
                                     snd.play_by_name("09d002v0", &self.assets);
                                 }
                                 tb.popup_open = false;
+                                self.diploma_open = true;
+                            }
+                            toolbox::PopupAction::Minimap => {
+                                tb.minimap = !tb.minimap;
+                                tracing::info!("Minimap {}", if tb.minimap { "on" } else { "off" });
+                            }
+                            toolbox::PopupAction::Tow => {
+                                if self.drive_car.is_some() && self.tow.call() {
+                                    tb.popup_open = false;
+                                    self.captions.show("cc_phone");
+                                    self.dialog.talk(tow::CALL_DIALOG);
+                                    tracing::info!("Tow truck called");
+                                } else {
+                                    tracing::debug!("Tow truck not available ({} s)", self.tow.cooldown_secs());
+                                }
                             }
                         }
                     }
@@ -573,6 +816,9 @@ impl GameState {
                         if let Some(car) = &mut self.drive_car {
                             car.engine_sound_state = None;
                         }
+                        if let Some(snd) = &mut self.sound {
+                            snd.stop_engine();
+                        }
                     }
                     return;
                 }
@@ -582,10 +828,17 @@ impl GameState {
         // Language button on menu screen (bottom-left corner, 20,440 to 180,470)
         if self.current_scene == Scene::Menu {
             if x >= 20 && x < 180 && y >= 440 && y < 470 {
+                self.play_ui_sound(UiSound::Click);
                 self.language = self.language.next();
                 tracing::info!("Language switched to {}", self.language.code());
                 return;
             }
+            // Continue button (bottom-right corner)
+            if self.quick_save.is_some() && continue_button_hit(x, y) {
+                self.play_ui_sound(UiSound::Click);
+                self.continue_quick_save();
+                return;
+            }
         }
 
         // Play button click sound if applicable
@@ -600,6 +853,71 @@ impl GameState {
             }
         }
 
+        // Garage horn panel: pick + preview a horn, any other click closes it
+        if let Some(panel) = &self.horn_panel {
+            if let Some(choice) = panel.hit(x, y).cloned() {
+                self.preview_horn(&choice);
+                self.save_manager.save_horn(choice);
+            } else if !panel.contains(x, y) {
+                self.horn_panel = None;
+            }
+            return;
+        }
+        if self.current_scene == Scene::Garage {
+            let (bx, by, bw, bh) = horn::HORN_BUTTON;
+            if x >= bx && y >= by && x < bx + bw && y < by + bh {
+                self.horn_panel = Some(horn::HornPanel::new(&self.assets.game_dir));
+                return;
+            }
+        }
+
+        // Junkyard part search button / panel
+        if self.current_scene == Scene::Junkyard && self.speedrun.is_none() && self.part_search.on_click(x, y) {
+            self.play_ui_sound(UiSound::Click);
+            return;
+        }
+
+        // Garage camera-view button → rotate the turntable
+        if self.current_scene == Scene::Garage && self.garage_turn.is_none() {
+            let (bx, by, bw, bh) = VIEW_BUTTON;
+            if x >= bx && y >= by && x < bx + bw && y < by + bh {
+                self.garage_turn = Some((self.car.view.next(), 0));
+                return;
+            }
+        }
+
+        // Scene script waiting for a hotspot click (e.g. Saftfabrik machine)
+        if let Some(script) = &mut self.active_script {
+            if script.on_click(x, y) {
+                return;
+            }
+        }
+
+        // Car Gallery: card buttons (PNG export / .willycar share) + import
+        if self.current_scene == Scene::CarGallery {
+            let hit = self.save_manager.active()
+                .and_then(|u| gallery::card_button_hit(u.gallery.len(), x, y)
+                    .map(|(i, button)| (u.gallery[i].clone(), button)));
+            if hit.is_some() || gallery::import_button_hit(x, y) {
+                self.play_ui_sound(UiSound::Click);
+            }
+            match hit {
+                Some((entry, gallery::CardButton::Export)) => {
+                    self.export_gallery_card(&entry);
+                    return;
+                }
+                Some((entry, gallery::CardButton::Share)) => {
+                    self.share_gallery_car(&entry);
+                    return;
+                }
+                None => {}
+            }
+            if gallery::import_button_hit(x, y) {
+                self.import_gallery_cars();
+                return;
+            }
+        }
+
         // Capture current pile items BEFORE click processing (pile switch clears them)
         let pre_click_pile_items = if self.current_scene == Scene::Junkyard {
             Some((self.current_pile_index(), self.scene_handler.drag_drop.item_positions()))
@@ -705,14 +1023,18 @@ impl GameState {
         // Update toolbox hover state
         if self.current_scene == Scene::World {
             if let Some(tb) = &mut self.toolbox {
-                let just_hovered = tb.update_hover(x, y);
-                if just_hovered {
-                    if let Some(snd) = &mut self.sound {
-                        snd.play_by_name(toolbox::TOOLBOX_HOVER_SOUND, &self.assets);
-                    }
+                if tb.update_hover(x, y) {
+                    self.play_ui_sound(UiSound::Hover);
                 }
             }
         }
+        let hover = self.ui_hover_target(x, y);
+        if hover != self.ui_hover {
+            self.ui_hover = hover;
+            if hover.is_some() {
+                self.play_ui_sound(UiSound::Hover);
+            }
+        }
 
         // Garage: left-click on an attached car part → detach it and start dragging
         if just_pressed && self.current_scene == Scene::Garage {
@@ -758,6 +1080,13 @@ impl GameState {
             }
         }
 
+        // Junkyard: no drag from under the search button / panel
+        if just_pressed && self.current_scene == Scene::Junkyard && self.speedrun.is_none()
+            && self.part_search.contains(x, y)
+        {
+            self.scene_handler.drag_drop.prev_mouse_down = true;
+        }
+
         // Forward drag processing to scene handler
         let result = self.scene_handler.process_drag(x, y, down);
         self.handle_drop_result(result);
@@ -798,15 +1127,25 @@ impl GameState {
         self.update_cursor(x, y);
     }
 
+    /// Loading screen or a cutscene script the player only watches
+    /// (menu intro and the driving tutorial leave the player in control)
+    fn is_busy(&self) -> bool {
+        let cutscene = self.active_script.as_ref()
+            .is_some_and(|s| s.click_target().is_none())
+            && self.tutorial.is_none()
+            && self.current_scene != Scene::Menu;
+        self.transition.is_some() || cutscene
+    }
+
     /// Determine the correct cursor type for the current mouse position.
     fn update_cursor(&mut self, x: i32, y: i32) {
         use crate::game::cursor::CursorType;
 
         self.cursor.reset();
 
-        // Dragging a part → Grab
+        // Dragging a part → closed hand
         if self.scene_handler.drag_drop.is_dragging() {
-            self.cursor.set(CursorType::Grab);
+            self.cursor.set(CursorType::GrabClosed);
             return;
         }
 
@@ -863,6 +1202,14 @@ impl GameState {
             return;
         }
 
+        // Hotspot the active scene script is waiting on
+        if let Some((rx, ry, rw, rh)) = self.active_script.as_ref().and_then(|s| s.click_target()) {
+            if x >= rx && y >= ry && x < rx + rw && y < ry + rh {
+                self.cursor.set(CursorType::Click);
+                return;
+            }
+        }
+
         // Hotspots
         for hs in &self.scene_handler.hotspots {
             if x >= hs.x && x < hs.x + hs.width as i32
@@ -921,6 +1268,10 @@ impl GameState {
                             };
                             snd.play_by_name(sound_id, &self.assets);
                         }
+                        self.idle_behaviors.react(
+                            idle_behavior::Trigger::PartAttached,
+                            self.current_scene, &mut self.scene_handler.actors, &mut self.rng,
+                        );
                         // Rebuild snap targets so remaining parts see updated availability
                         self.rebuild_snap_targets();
                     }
@@ -994,6 +1345,10 @@ impl GameState {
                     None
                 };
                 if let Some(desc_id) = desc {
+                    self.idle_behaviors.react(
+                        idle_behavior::Trigger::Description,
+                        self.current_scene, &mut self.scene_handler.actors, &mut self.rng,
+                    );
                     self.play_dialog(&desc_id);
                     return; // description replaces floor-drop sound
                 }
@@ -1017,18 +1372,69 @@ impl GameState {
     }
 
     pub fn on_key_down(&mut self, key: Key) {
+        if key == self.dev_menu.hotkey {
+            self.on_dev_menu_trigger();
+            return;
+        }
         // ── Dev menu navigation (eats all input while open) ──
         if self.dev_menu.open {
+            let selected = self.dev_menu.selected;
             match key {
                 Key::Up => self.dev_menu.nav_up(),
                 Key::Down => self.dev_menu.nav_down(),
                 Key::Enter => {
+                    self.play_ui_sound(UiSound::Click);
                     let action = self.dev_menu.activate();
                     self.handle_dev_action(action);
                 }
                 Key::Escape => self.dev_menu.open = false,
                 _ => {}
             }
+            if self.dev_menu.selected != selected {
+                self.play_ui_sound(UiSound::Hover);
+            }
+            return;
+        }
+
+        if self.report_dialog.take().is_some() {
+            return;
+        }
+        if self.asset_check.open {
+            self.asset_check.open = false;
+            return;
+        }
+
+        // Car naming dialog eats Enter/Backspace/Escape (typing arrives via on_char_input)
+        if let Some(dialog) = &mut self.car_naming {
+            match key {
+                Key::Enter => {
+                    if let car_naming::NamingResult::Confirmed(name) = dialog.confirm() {
+                        self.confirm_car_name(name);
+                    }
+                }
+                Key::Backspace => dialog.on_char('\x08'),
+                _ => {}
+            }
+            return;
+        }
+        if self.diploma_open && key == Key::Escape {
+            self.diploma_open = false;
+            return;
+        }
+
+        // F10 → arm / end a speedrun
+        if key == Key::F10 {
+            self.toggle_speedrun();
+            return;
+        }
+
+        // F2 → statistics overlay (once a profile is loaded)
+        if key == Key::F2 && self.save_manager.active().is_some() {
+            self.stats_open = !self.stats_open;
+            return;
+        }
+        if self.stats_open && key == Key::Escape {
+            self.stats_open = false;
             return;
         }
 
@@ -1037,6 +1443,12 @@ impl GameState {
             self.dialog.skip_current();
         }
 
+        // Tab → skip the driving tutorial
+        if key == Key::Tab && self.tutorial.is_some() {
+            self.skip_tutorial();
+            return;
+        }
+
         // H → horn (while driving on world map)
         if key == Key::H && self.current_scene == Scene::World {
             self.play_horn();
@@ -1052,13 +1464,62 @@ impl GameState {
                 return;
             }
         }
+        // No scene warps during a speedrun
+        let warp_key = matches!(key, Key::F1 | Key::F3 | Key::F4 | Key::F5 | Key::F6
+            | Key::F7 | Key::F8 | Key::F9);
+        if warp_key && self.speedrun.is_some() {
+            return;
+        }
         if let Some(next) = self.scene_handler.on_key_down(key, &self.assets) {
             self.switch_scene(next);
         }
     }
 
+    /// Restart the session RNG with `seed` (demo playback)
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng_seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        tracing::info!("RNG reseeded: {}", seed);
+    }
+
+    /// Put the game into a demo's start state: its RNG seed and the
+    /// profiles it was recorded with (call on the menu, before the first frame)
+    pub fn start_playback(&mut self, demo: &demo::Demo) {
+        self.reseed(demo.seed);
+        self.save_manager.load_for_playback(demo.save.clone());
+    }
+
+    /// Feed one frame of input and advance the game by one frame — the
+    /// engine loop's input order, shared by live play and demo playback
+    pub fn apply_frame(&mut self, input: &demo::FrameInput) {
+        let down = self.input_queue.push_frame(input);
+        self.on_mouse_state(input.x, input.y, down);
+        // A click that switches the scene drops the rest (see `input_queue`)
+        while let Some(pointer) = self.input_queue.next(self.transition.is_some()) {
+            match pointer {
+                input_queue::Pointer::Click(x, y) => self.on_click(x, y),
+                input_queue::Pointer::RightClick(x, y) => self.on_right_click(x, y),
+            }
+        }
+        for event in &input.keys {
+            match event {
+                demo::KeyEvent::Char(ch) => self.on_char_input(*ch),
+                demo::KeyEvent::Key(name) => match demo::parse_key(name) {
+                    Some(key) => self.on_key_down(key),
+                    None => tracing::warn!("Demo: unknown key '{}'", name),
+                },
+            }
+        }
+        let [up, down, left, right] = input.arrows;
+        self.update_drive_keys(up, down, left, right);
+        self.update();
+    }
+
     /// Update driving input from polled key state (call each frame from engine)
     pub fn update_drive_keys(&mut self, up: bool, down: bool, left: bool, right: bool) {
+        if let Some(run) = &mut self.speedrun {
+            run.inputs = speedrun::Inputs { up, down, left, right, mouse: self.mouse_down };
+        }
         // Don't process driving input when popup menu is open
         if let Some(tb) = &self.toolbox {
             if tb.popup_open {
@@ -1090,21 +1551,32 @@ impl GameState {
         }
     }
 
+    /// Dev-menu activation: 5× '#' (or the hotkey) within 2 seconds
+    fn on_dev_menu_trigger(&mut self) {
+        if self.speedrun.is_some() {
+            return;
+        }
+        if self.dev_menu.on_hash_press() {
+            self.play_ui_sound(UiSound::Click);
+            tracing::info!("Dev menu {}", if self.dev_menu.open { "opened" } else { "closed" });
+        }
+    }
+
     /// Forward character input (typing) to the scene handler
     pub fn on_char_input(&mut self, ch: char) {
-        // Dev-menu activation: 5× '#' within 2 seconds
         if ch == '#' {
-            if self.dev_menu.on_hash_press() {
-                // Play a quiet beep to confirm
-                if let Some(snd) = &mut self.sound {
-                    snd.play_by_name("00e004v0", &self.assets);
-                }
-                tracing::info!("Dev menu {}", if self.dev_menu.open { "opened" } else { "closed" });
-            }
+            self.on_dev_menu_trigger();
             return;
         }
         // Don't forward input while dev menu is open
         if self.dev_menu.open { return; }
+        if let Some(dialog) = &mut self.car_naming {
+            // Backspace comes through on_key_down
+            if ch != '\x08' {
+                dialog.on_char(ch);
+            }
+            return;
+        }
         self.scene_handler.on_char_input(ch);
     }
 
@@ -1140,6 +1612,7 @@ impl GameState {
             if bar_w > 0 {
                 font::draw_rect(fb, 170, 400, bar_w, 32, 0xFF65C265);
             }
+            tips::draw_tip(fb, 444, trans.tip, self.language);
             return; // Don't draw normal UI during transition
         }
 
@@ -1155,6 +1628,25 @@ impl GameState {
             let tw = font::text_width(lang_text);
             let tx = 20 + (160 - tw) / 2;
             font::draw_text_shadow(fb, tx, 446, lang_text, 0xFFFFFFFF);
+
+            if self.quick_save.is_some() {
+                let (bx, by, bw, bh) = CONTINUE_BUTTON;
+                let text = i18n::t(self.language, "menu_continue");
+                font::draw_rect(fb, bx, by, bw, bh, 0xAA1A1A2E);
+                font::draw_rect_outline(fb, bx, by, bw, bh, 0xFFFFD23F);
+                let tw = font::text_width(text);
+                font::draw_text_shadow(fb, bx + (bw - tw) / 2, by + 6, text, 0xFFFFFFFF);
+            }
+        }
+
+        self.captions.draw(fb, self.language);
+        if self.dev_menu.show_parts_report && !self.dev_menu.open {
+            if let Some(user) = self.save_manager.active() {
+                parts_audit::PartsAudit::of(user, &self.parts_db).draw(fb);
+            }
+        }
+        if let Some(run) = &self.speedrun {
+            run.draw(fb, std::time::Instant::now());
         }
 
         // Subtitle rendering at screen bottom
@@ -1190,10 +1682,38 @@ impl GameState {
                     car.speed * 30.0, car.fuel_percent() * 100.0);
                 font::draw_text(fb, 10, 10, &debug_text, 0xFF888888);
             }
+            self.draw_map_prompt(fb);
+            self.draw_tutorial(fb);
+            self.draw_minimap(fb);
+            self.tow.draw(fb);
+        }
+
+        if let Some(toast) = &mut self.toast {
+            if !toast.draw(fb) {
+                self.toast = None;
+            }
         }
 
-        // Road legality indicator in Garage
+        // Road legality indicator + camera-view button in Garage
         if self.current_scene == Scene::Garage {
+            let (bx, by, bw, bh) = VIEW_BUTTON;
+            let label = i18n::t(self.language, self.car.view.i18n_key());
+            font::draw_rect(fb, bx, by, bw, bh, 0xAA1A1A2E);
+            font::draw_rect_outline(fb, bx, by, bw, bh, 0xFF6666CC);
+            font::draw_text_shadow(fb, bx + (bw - font::text_width(label)) / 2, by + 7, label, 0xFFFFFFFF);
+
+            let (bx, by, bw, bh) = horn::HORN_BUTTON;
+            let label = i18n::t(self.language, "horn_button");
+            font::draw_rect(fb, bx, by, bw, bh, 0xAA1A1A2E);
+            font::draw_rect_outline(fb, bx, by, bw, bh, 0xFF6666CC);
+            font::draw_text_shadow(fb, bx + (bw - font::text_width(label)) / 2, by + 7, label, 0xFFFFFFFF);
+            if let Some(panel) = &self.horn_panel {
+                let selected = self.save_manager.active()
+                    .map(|u| u.horn.clone())
+                    .unwrap_or_default();
+                panel.draw(fb, &selected, self.language);
+            }
+
             if self.car.is_road_legal() {
                 font::draw_text_shadow(fb, 10, 460, i18n::t(self.language, "road_legal"), 0xFF00FF00);
             } else {
@@ -1204,14 +1724,89 @@ impl GameState {
             }
         }
 
-        // Dev menu overlay (drawn last — on top of everything)
-        self.dev_menu.draw(fb);
+        // Junkyard part search: highlights, button, panel with pile counts
+        if self.current_scene == Scene::Junkyard && self.speedrun.is_none() {
+            self.part_search.draw_highlights(fb, &self.parts_db, &self.scene_handler.drag_drop.items);
+            part_search::PartSearch::draw_button(fb, self.language);
+            if self.part_search.open {
+                let current = self.current_pile_index();
+                let piles: [Vec<u32>; 6] = std::array::from_fn(|i| {
+                    let pile = i as u8 + 1;
+                    if pile == current {
+                        self.scene_handler.drag_drop.items.iter().map(|it| it.part_id).collect()
+                    } else {
+                        self.save_manager.active()
+                            .map(|u| u.junk.pile(pile).keys().copied().collect())
+                            .unwrap_or_default()
+                    }
+                });
+                let counts = self.part_search.pile_counts(&self.parts_db, piles);
+                self.part_search.draw_panel(fb, &counts, current, self.language);
+            }
+        }
+
+        // Car Gallery: one card per exhibited car
+        if self.current_scene == Scene::CarGallery {
+            if let Some(user) = self.save_manager.active() {
+                let thumb = self.avatars.get(&user.user_id, &self.car.parts, &self.parts_db, &self.assets);
+                gallery::draw_profile(fb, &user.user_id, thumb);
+                gallery::draw_gallery(fb, &user.gallery, self.language);
+            }
+            if let Some((text, frames)) = &mut self.gallery_notice {
+                gallery::draw_import_preview(fb, &self.gallery_imports);
+                let tw = font::text_width(text);
+                font::draw_rect(fb, 0, 436, 640, 20, 0xCC000000);
+                font::draw_text_shadow(fb, (640 - tw) / 2, 442, text, 0xFFFFFFFF);
+                *frames = frames.saturating_sub(1);
+                if *frames == 0 {
+                    self.gallery_notice = None;
+                    self.gallery_imports.clear();
+                }
+            }
+        }
+
+        // Diploma overlay
+        if self.diploma_open {
+            let (name, medals) = self.save_manager.active()
+                .map(|u| (u.car.name.clone(), u.car.medals.clone()))
+                .unwrap_or_default();
+            gallery::draw_diploma(fb, &name, &medals, self.language);
+        }
+
+        // Statistics overlay
+        if self.stats_open {
+            if let Some(user) = self.save_manager.active() {
+                stats::draw_stats(fb, &user.user_id, &user.stats, self.language);
+            }
+        }
+
+        // Car naming dialog (modal)
+        if let Some(dialog) = &mut self.car_naming {
+            dialog.draw(fb, self.language);
+        }
+
+        // Bug report dialog (modal)
+        if let Some(dialog) = &self.report_dialog {
+            dialog.draw(fb, self.language);
+        }
+        self.asset_check.draw(fb, self.language);
+
+        // Cheat badge, then the dev menu overlay (drawn last — on top of everything)
+        if !self.dev_menu.open {
+            dev_config::draw_badge(fb, &dev_config::active_cheats(&self.dev_menu));
+        }
+        self.dev_menu.draw(fb, self.assets.missing.count());
     }
 
     /// Handle an action returned by the dev menu
     fn handle_dev_action(&mut self, action: DevAction) {
         match action {
             DevAction::None | DevAction::Close => {}
+            DevAction::Toggled => {
+                if let Err(e) = dev_config::DevConfig::of(&self.dev_menu).save(&self.assets.game_dir) {
+                    tracing::warn!("Dev config not saved: {:#}", e);
+                }
+            }
             DevAction::GotoScene(scene) => {
                 tracing::info!("Dev warp → {:?}", scene);
                 self.switch_scene(scene);
@@ -1229,16 +1824,92 @@ impl GameState {
                 tracing::info!("Dev: set #FiggeIsComing, switching to Garage");
                 self.switch_scene(Scene::Garage);
             }
+            DevAction::BirthdayToday => {
+                self.save_manager.save_birthday(seasonal::Date::today());
+                tracing::info!("Dev: birthday set to today, reloading scene");
+                self.switch_scene(self.current_scene);
+            }
+            DevAction::ReportIssue => {
+                self.report_requested = true;
+            }
+            DevAction::FixParts(fix) => self.fix_parts(fix),
+        }
+    }
+
+    /// Dev-menu parts fix: bring the save up to date, fix it, reload the scene
+    fn fix_parts(&mut self, fix: parts_audit::PartsFix) {
+        self.save_scene_state(self.current_scene);
+        let pile = self.current_pile_index();
+        let Some(user) = self.save_manager.active_mut() else {
+            tracing::warn!("Dev: no profile to fix");
+            return;
+        };
+        let changed = fix.apply(user, &self.parts_db);
+        // The scene writes its parts back when it is left: give it the fixed set
+        let scene_parts = match self.current_scene {
+            Scene::Garage => Some(user.junk.shop_floor.clone()),
+            Scene::Junkyard => Some(user.junk.pile(pile).clone()),
+            Scene::Yard => Some(user.junk.yard.clone()),
+            _ => None,
+        };
+        self.car.parts = user.car.parts.clone();
+        self.save_manager.save();
+        tracing::info!("Dev: {:?} changed {} parts, reloading scene", fix, changed);
+        self.car.refresh(&self.parts_db, &self.assets);
+        if let Some(parts) = scene_parts {
+            self.scene_handler.drag_drop.items.clear();
+            self.spawn_parts_from_map(&parts, false);
         }
+        self.switch_scene(self.current_scene);
+    }
+
+    /// Write the bug report zip (`screen` = scene frame without overlays)
+    /// and open the dialog pointing to it
+    pub fn write_bug_report(&mut self, screen: &[u32]) {
+        self.report_requested = false;
+        let save_json = self.save_manager.active()
+            .and_then(|u| serde_json::to_string_pretty(u).ok());
+        let report = bug_report::BugReport {
+            screen,
+            save_json,
+            rng_seed: self.rng_seed,
+            game_info: vec![
+                format!("Scene: {:?}", self.current_scene),
+                format!("Language: {}", self.language.code()),
+                format!("Low-spec mode: {}", self.low_spec),
+                format!("Display mode: {:?}", self.dev_menu.display_mode),
+                format!("Driving: {}", self.drive_session.active),
+                format!("Director files loaded: {}", self.assets.total_files()),
+                format!("Game dir: {}", self.assets.game_dir.display()),
+            ].into_iter()
+                .chain(self.assets.missing.report().into_iter().map(|line| format!("Missing {}", line)))
+                .collect(),
+        };
+        self.report_dialog = Some(match report.write(&self.assets.game_dir) {
+            Ok(path) => {
+                tracing::info!("Bug report written: {}", path.display());
+                bug_report::ReportDialog::saved(&path, self.language)
+            }
+            Err(e) => {
+                tracing::error!("Bug report failed: {:#}", e);
+                bug_report::ReportDialog::failed(&e, self.language)
+            }
+        });
     }
 
     pub fn get_all_sprites(&self) -> Vec<Sprite> {
         let mut sprites = self.scene_handler.all_sprites();
 
-        // In Garage, render the car as well
+        // In Garage, render the car as well (squashed while the turntable rotates)
         if self.current_scene == Scene::Garage {
-            for car_sprite in self.car.all_sprites() {
-                sprites.push(car_sprite);
+            let car_sprites = self.car.all_sprites();
+            match self.garage_turn {
+                Some((_, frame)) => {
+                    let t = frame as f32 / TURN_FRAMES as f32;
+                    let scale = (t * std::f32::consts::PI).cos().abs();
+                    sprites.extend(build_car::squash_sprites(&car_sprites, self.car.x, scale));
+                }
+                None => sprites.extend(car_sprites),
             }
             sprites.sort_by_key(|s| s.z_order);
         }
@@ -1247,72 +1918,52 @@ impl GameState {
         if self.current_scene == Scene::World {
             if let Some(car) = &self.drive_car {
                 // --- Map object sprites (behind / in front of car) ---
-                let cache_list: Vec<String> = self.quest.cache_list().to_vec();
-                let medals: Vec<String> = self.save_manager.active()
-                    .map(|u| u.car.medals.clone())
+                // Decoded on tile entry (see map_sprites), only CheckFor is live
+                let medals = self.save_manager.active()
+                    .map(|u| u.car.medals.as_slice())
                     .unwrap_or_default();
-                if let Some(wm) = &self.world_map {
-                    let tile_objects: Vec<driving::MapObject> = wm
-                        .tile_at(car.tile_col, car.tile_row)
-                        .and_then(|tid| wm.get_tile(tid))
-                        .map(|t| t.objects.clone())
-                        .unwrap_or_default();
-                    let mut z_under_idx = 500i32;
-                    let mut z_over_idx = 1500i32;
-                    for mut obj in tile_objects {
-                        obj.do_check(&cache_list, &medals);
-                        if !obj.enabled { continue; }
-                        if let Some(ref sname) = obj.sprite_name {
-                            if let Some(bmp) = self.assets.find_bitmap_by_name(sname) {
-                                let z = if obj.z_under {
-                                    z_under_idx += 1;
-                                    z_under_idx
-                                } else {
-                                    z_over_idx += 1;
-                                    z_over_idx
-                                };
-                                sprites.push(Sprite {
-                                    x: obj.x - bmp.width as i32 / 2,
-                                    y: obj.y - bmp.height as i32 / 2,
-                                    width: bmp.width,
-                                    height: bmp.height,
-                                    pixels: bmp.pixels,
-                                    visible: true,
-                                    z_order: z,
-                                    name: format!("map_obj_{}", obj.object_id),
-                                    interactive: false,
-                                    member_num: 0,
-                                });
-                            }
-                        }
-                    }
+                if let Some(tile) = self.world_map.as_ref()
+                    .and_then(|wm| wm.tile_at(car.tile_col, car.tile_row).and_then(|tid| wm.get_tile(tid)))
+                {
+                    sprites.extend(self.map_sprites.sprites(&tile.objects, self.quest.cache_list(), medals));
                 }
 
-                // --- Driving car sprite ---
-                let member = car.sprite_member();
-                // Lookup sprite bitmap from 05.DXR cast member
-                if let Some(bmp) = self.assets.decode_bitmap_transparent("05.DXR", member) {
-                    let car_sprite = Sprite {
-                        x: car.x as i32 - bmp.width as i32 / 2,
-                        y: car.y as i32 - bmp.height as i32 / 2,
-                        width: bmp.width,
-                        height: bmp.height,
-                        pixels: bmp.pixels,
-                        visible: true,
+                // --- Random events on this tile ---
+                if let Some(tile) = self.world_map.as_ref().and_then(|wm| wm.tile_at(car.tile_col, car.tile_row)) {
+                    sprites.extend(self.active_events.iter()
+                        .filter(|e| e.tile == tile)
+                        .map(|e| e.sprite.clone()));
+                }
+
+                // --- Driving car sprite (05.DXR cast member, decoded in update) ---
+                if let Some(car_sprite) = self.sprite_cache.member("05.DXR", car.sprite_member(), car.x as i32, car.y as i32) {
+                    sprites.push(Sprite {
                         z_order: 1000, // car between under/over objects
                         name: format!("drive_car_d{}", car.direction),
                         interactive: true,
-                        member_num: member,
-                    };
-                    sprites.push(car_sprite);
+                        ..car_sprite
+                    });
+                }
+
+                // --- Salka on the back seat ---
+                if self.quest.has_cache(dog::DOG_FLAG) {
+                    let (ox, oy) = dog::seat_offset(car.direction);
+                    let (x, y) = (car.x as i32 + ox, car.y as i32 + oy);
+                    if let Some(dog_sprite) = self.sprite_cache.named(dog::sprite_name(car.direction), x, y) {
+                        sprites.push(Sprite {
+                            z_order: 1001, // just above the car
+                            name: "drive_dog".into(),
+                            ..dog_sprite
+                        });
+                    }
                 }
 
-                // Dashboard HUD: fuel needle + speedometer
+                // Dashboard HUD: fuel needle, speedometer, steering wheel + pedals
                 if let Some(dash) = &self.dashboard {
                     let fuel_pct = car.fuel_percent();
                     let speed = car.speed;
                     let max_speed = car.max_speed();
-                    for ds in dash.sprites(fuel_pct, speed, max_speed) {
+                    for ds in dash.sprites(fuel_pct, speed, max_speed, car.throttle, car.braking) {
                         sprites.push(ds);
                     }
                 }
@@ -1423,7 +2074,94 @@ impl GameState {
         self.play_dialog_with_actor(audio_id, None);
     }
 
-    /// Award a medal to the current car (from objects.hash.json SetWhenDone.Medals).
+    /// Naming dialog confirmed: store the name, put the car into the gallery
+    /// and let the judge start rating.
+    fn confirm_car_name(&mut self, name: String) {
+        self.car_naming = None;
+        self.save_manager.save_car_name(&name);
+
+        let ff = self.car.properties().funny_factor;
+        let entry = save::GalleryEntry {
+            name: name.clone(),
+            parts: self.car.parts.clone(),
+            rating: scene_script::carshow_rating(ff),
+            medals: self.save_manager.active()
+                .map(|u| u.car.medals.clone())
+                .unwrap_or_default(),
+        };
+        self.save_manager.save_gallery_entry(entry);
+        tracing::info!("Car show: car named '{}'", name);
+
+        self.active_script = Some(scene_script::build_carshow_script(ff));
+    }
+
+    /// Write a gallery card PNG to the screenshots folder and show the result
+    fn export_gallery_card(&mut self, entry: &save::GalleryEntry) {
+        let text = match gallery::export_card(entry, &self.parts_db, &self.assets, self.language) {
+            Ok(path) => {
+                tracing::info!("Gallery card exported: {}", path.display());
+                let file = path.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
+                format!("{} {}", i18n::t(self.language, "gallery_exported"), file)
+            }
+            Err(e) => {
+                tracing::warn!("Gallery card export failed: {}", e);
+                format!("{}", e)
+            }
+        };
+        self.gallery_notice = Some((text, 90));
+        self.gallery_imports.clear();
+    }
+
+    /// Write a `.willycar` exchange file for a gallery car
+    fn share_gallery_car(&mut self, entry: &save::GalleryEntry) {
+        let text = match willycar::export(entry, &self.parts_db, &self.assets, self.language) {
+            Ok(path) => {
+                tracing::info!("Car exported for exchange: {}", path.display());
+                let file = path.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
+                format!("{} {}", i18n::t(self.language, "gallery_shared"), file)
+            }
+            Err(e) => {
+                tracing::warn!("Car exchange export failed: {}", e);
+                format!("{}", e)
+            }
+        };
+        self.gallery_notice = Some((text, 90));
+        self.gallery_imports.clear();
+    }
+
+    /// Import all valid `.willycar` files from the exchange folder into the
+    /// gallery. Cars already in the gallery are skipped; name clashes get a
+    /// number appended.
+    fn import_gallery_cars(&mut self) {
+        let mut imported = 0;
+        self.gallery_imports.clear();
+        for car in willycar::scan(&self.assets.game_dir, &self.parts_db) {
+            let thumb = car.thumbnail_canvas();
+            let mut entry = car.entry;
+            let duplicate = self.save_manager.active()
+                .is_some_and(|u| u.gallery.iter().any(|e| e.name == entry.name && e.parts == entry.parts));
+            if duplicate {
+                continue;
+            }
+            match thumb {
+                Ok(thumb) => self.gallery_imports.push(thumb),
+                Err(e) => tracing::warn!("Thumbnail of '{}' not shown: {}", entry.name, e),
+            }
+            let taken = self.save_manager.gallery_names_except("");
+            entry.name = car_naming::unique_name(&entry.name, &taken);
+            tracing::info!("Imported car '{}' ({} parts)", entry.name, entry.parts.len());
+            self.save_manager.save_gallery_entry(entry);
+            imported += 1;
+        }
+        let text = if imported > 0 {
+            format!("{} {}", i18n::t(self.language, "gallery_imported"), imported)
+        } else {
+            i18n::t(self.language, "gallery_import_none").to_string()
+        };
+        self.gallery_notice = Some((text, 90));
+    }
+
+    /// Award a medal to the current car (from objects.hash.json SetWhenDone.Medals).
     ///
     /// Medal IDs (from objects.hash.json):
     ///   1 = BigHill (conquered a steep hill)
@@ -1441,21 +2179,570 @@ impl GameState {
             }
         }
         self.save_manager.save();
+        if let Some(run) = &mut self.speedrun {
+            run.split(medal_id, std::time::Instant::now());
+        }
     }
 
-    /// Play the horn sound based on the car's horn_type (1-5).
-    /// Sound IDs from mulle.js: ["05e050v0", "05e049v0", "05e044v0", "05e042v0", "05d013v0"]
+    /// Handle a driving event (everything except reaching a destination,
+    /// which needs the drive session and is handled in `update`)
+    fn on_drive_event(&mut self, event: driving::DriveEvent) {
+        match event {
+            driving::DriveEvent::FuelEmpty => {
+                self.captions.show("cc_engine_stall");
+                self.play_dialog("05d011v0"); // "Tank ist leer!"
+            }
+            driving::DriveEvent::TerrainBlocked { reason, impact } => {
+                tracing::debug!("Terrain blocked: {} (impact {:.2})", reason, impact);
+                let cue = match reason {
+                    "mud" => &driving::MUD_CUE,
+                    "holes" => &driving::HOLES_CUE,
+                    "wall" if impact >= driving::MIN_AUDIBLE_IMPACT => &driving::WALL_THUD_CUE,
+                    _ => return,
+                };
+                match reason {
+                    "wall" if impact >= driving::MIN_AUDIBLE_IMPACT => {
+                        self.rumble.play(RumbleEvent::WallHit { impact });
+                    }
+                    "holes" => self.rumble.play(RumbleEvent::Holes { impact: impact.max(0.3) }),
+                    _ => {}
+                }
+                // Thuds get louder with the speed of the hit
+                let gain = if reason == "wall" { 0.3 + 0.7 * impact } else { 1.0 };
+                if let Some(snd) = &mut self.sound {
+                    snd.play_cue(cue, gain, &self.assets);
+                }
+            }
+            driving::DriveEvent::GasStation => {
+                if let Some(snd) = &mut self.sound {
+                    snd.play_by_name("31e006v0", &self.assets);
+                }
+                tracing::info!("Refueling at gas station");
+                self.tutorial_event("refueled");
+            }
+            driving::DriveEvent::AnimalsBlocking { has_horn, horn_type } => {
+                self.dog_bark(dog::BarkTrigger::Animals);
+                if has_horn {
+                    // Honk the selected horn, then play cow moo
+                    if let Some(sound) = self.horn_sound(horn_type) {
+                        self.play_horn_sound(&sound);
+                    }
+                    if let Some(snd) = &mut self.sound {
+                        snd.play_by_name(driving::COW_MOO_SOUND, &self.assets);
+                    }
+                } else {
+                    // No horn — blocked sound
+                    if let Some(snd) = &mut self.sound {
+                        snd.play_by_name(driving::NO_HORN_SOUND, &self.assets);
+                    }
+                }
+            }
+            driving::DriveEvent::HillSound { big } => {
+                // Hill feedback sounds (from objects.hash Sounds array)
+                let sound = if big { "31d005v0" } else { "31d004v0" };
+                if let Some(snd) = &mut self.sound {
+                    snd.play_by_name(sound, &self.assets);
+                }
+                if big {
+                    self.award_medal(1); // BigHill medal
+                }
+            }
+            driving::DriveEvent::FerryBoard => {
+                // Ferry crossing — requires #FerryTicket (from Mia/Solhem dest 86)
+                if self.quest.has_permanent("#FerryTicket") {
+                    // Teleport car to other shore
+                    if let Some(car) = &mut self.drive_car {
+                        car.ferry_teleport();
+                    }
+                    self.rumble.play(RumbleEvent::FerryDock);
+                    if let Some(snd) = &mut self.sound {
+                        snd.play_by_name(driving::FERRY_SOUND, &self.assets);
+                    }
+                    tracing::info!("Ferry crossing!");
+                } else {
+                    // No ticket — Mulle says he needs a ticket
+                    self.play_dialog("05d014v0"); // "Ich brauche ein Fährticket"
+                }
+            }
+            driving::DriveEvent::RaceStarted => {
+                self.rumble.play(RumbleEvent::RaceStart);
+                if let Some(snd) = &mut self.sound {
+                    snd.play_by_name(driving::RACING_START_SOUND, &self.assets);
+                }
+                tracing::info!("Race started!");
+            }
+            driving::DriveEvent::RaceFinished { time_secs } => {
+                self.rumble.play(RumbleEvent::RaceFinish);
+                if let Some(snd) = &mut self.sound {
+                    snd.play_by_name(driving::RACING_FINISH_SOUND, &self.assets);
+                }
+                // Award racing medal
+                if let Some(stats) = self.save_manager.stats_mut() {
+                    stats.races_won += 1;
+                }
+                self.award_medal(5);
+                tracing::info!("Race finished in {:.2}s — medal 5 awarded!", time_secs);
+            }
+            driving::DriveEvent::BridgeSound { wooden } => {
+                if let Some(snd) = &mut self.sound {
+                    if wooden {
+                        snd.play_by_name(driving::WBRIDGE_CREAK_SOUND, &self.assets);
+                    } else {
+                        snd.play_by_name(driving::CBRIDGE_SOUND, &self.assets);
+                    }
+                }
+            }
+            driving::DriveEvent::FarAwayReached { object_id } => {
+                self.award_medal(2);
+                tracing::info!("FarAway object {} reached — medal 2 awarded!", object_id);
+            }
+            driving::DriveEvent::SoundTrigger { sound_id } => {
+                if let Some(snd) = &mut self.sound {
+                    snd.play_by_name(&sound_id, &self.assets);
+                }
+            }
+            driving::DriveEvent::RandomEvent { object_id } => {
+                self.collect_random_event(object_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Persistent world map: random destinations and this session's random
+    /// events are placed once, on first use. They get an RNG of their own
+    /// from the session seed, so idle animations drawing from `rng` before
+    /// the first drive don't move them.
+    fn ensure_world_map(&mut self) {
+        if self.world_map.is_some() {
+            return;
+        }
+        let mut map_rng = StdRng::seed_from_u64(self.rng_seed);
+        let mut wm = driving::WorldMap::default_map();
+        wm.apply_random_destinations(&mut map_rng);
+        let quest = &self.quest;
+        self.active_events = self.random_events.spawn(
+            &mut wm,
+            |flag| quest.has_cache(flag) || quest.has_permanent(flag),
+            &mut map_rng,
+        );
+        self.map_sprites.clear();
+        self.world_map = Some(wm);
+    }
+
+    /// Hand out a random event's reward and take it off the map
+    fn collect_random_event(&mut self, object_id: u32) {
+        let Some(idx) = self.active_events.iter().position(|e| e.object_id == object_id) else { return };
+        let event = self.active_events.remove(idx);
+        if let Some(obj) = self.world_map.as_mut()
+            .and_then(|wm| wm.tiles.get_mut(&event.tile))
+            .and_then(|t| t.objects.iter_mut().find(|o| o.object_id == object_id))
+        {
+            obj.enabled = false;
+        }
+
+        let reward = event.reward;
+        if let Some(part_id) = reward.part {
+            let part_id = if part_id == 0 {
+                self.save_manager.random_unowned_part(&mut self.rng)
+            } else {
+                Some(part_id)
+            };
+            if let Some(part_id) = part_id {
+                self.save_manager.add_yard_part(part_id);
+                tracing::info!("Random event: gave part {} to yard", part_id);
+            }
+        }
+        if let Some(flag) = &reward.cache {
+            self.quest.add_cache(flag);
+        }
+        if let Some(flag) = &reward.stuff {
+            self.quest.add_permanent(flag);
+        }
+        if let Some(sound) = &reward.sound {
+            if let Some(snd) = &mut self.sound {
+                snd.play_by_name(sound, &self.assets);
+            }
+        }
+        let text = reward.text.get(self.language.code())
+            .or_else(|| reward.text.values().next());
+        if let Some(text) = text {
+            self.toast = Some(discovery::Toast::new(text));
+        }
+        self.save_quest_state();
+    }
+
+    /// Menu hover/click feedback (escape menu, dev menu, menu buttons, gallery)
+    pub fn play_ui_sound(&mut self, sound: UiSound) {
+        if let Some(snd) = &mut self.sound {
+            snd.play_ui(sound, &self.assets);
+        }
+    }
+
+    /// Overlay button under the mouse: language/continue on the main menu,
+    /// card and import buttons in the gallery
+    fn ui_hover_target(&self, x: i32, y: i32) -> Option<usize> {
+        match self.current_scene {
+            Scene::Menu if (20..180).contains(&x) && (440..470).contains(&y) => Some(0),
+            Scene::Menu if self.quick_save.is_some() && continue_button_hit(x, y) => Some(1),
+            Scene::CarGallery if gallery::import_button_hit(x, y) => Some(0),
+            Scene::CarGallery => {
+                let count = self.save_manager.active().map_or(0, |u| u.gallery.len());
+                gallery::card_button_hit(count, x, y)
+                    .map(|(i, button)| 1 + 2 * i + (button == gallery::CardButton::Share) as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Play the horn: the profile's selected horn, or the car's horn_type (1-5).
     fn play_horn(&mut self) {
-        const HORN_SOUNDS: [&str; 5] = [
-            "05e050v0", "05e049v0", "05e044v0", "05e042v0", "05d013v0",
-        ];
         let horn_type = self.drive_car.as_ref()
             .map(|c| c.props.horn_type)
             .unwrap_or(0);
-        if horn_type >= 1 && horn_type <= 5 {
-            let sound_id = HORN_SOUNDS[(horn_type - 1) as usize];
+        if let Some(sound) = self.horn_sound(horn_type) {
+            self.play_horn_sound(&sound);
+            self.dog_bark(dog::BarkTrigger::Horn);
+        }
+    }
+
+    /// First world-map visit of a profile: run the driving tutorial
+    fn start_tutorial(&mut self) {
+        let Some(user) = self.save_manager.active() else { return };
+        if self.save_manager.has_stuff(tutorial::TUTORIAL_DONE) || !user.stats.destinations.is_empty() {
+            return;
+        }
+        self.tutorial = Some(tutorial::Tutorial::default());
+        self.active_script = Some(scene_script::build_tutorial_script());
+        tracing::info!("Driving tutorial started");
+    }
+
+    /// Tab during the tutorial: end it for good
+    fn skip_tutorial(&mut self) {
+        self.tutorial = None;
+        self.active_script = None;
+        self.quest.add_permanent(tutorial::TUTORIAL_DONE);
+        self.save_manager.add_stuff(tutorial::TUTORIAL_DONE);
+        self.save_manager.save();
+        tracing::info!("Driving tutorial skipped");
+    }
+
+    /// Report a game event to the running tutorial script
+    fn tutorial_event(&mut self, name: &str) {
+        if self.tutorial.is_none() {
+            return;
+        }
+        if let Some(script) = &mut self.active_script {
+            script.on_event(name);
+        }
+    }
+
+    /// Tutorial banner and the arrow towards its target (World scene)
+    fn draw_tutorial(&self, fb: &mut [u32]) {
+        let (Some(tut), Some(car)) = (&self.tutorial, &self.drive_car) else { return };
+        let medals = self.save_manager.active().map(|u| u.car.medals.as_slice()).unwrap_or_default();
+        let offset = tut.target.zip(self.world_map.as_ref()).and_then(|(target, wm)| {
+            tutorial::target_offset(
+                wm, target, (car.tile_col, car.tile_row), (car.x, car.y), self.quest.cache_list(), medals,
+            )
+        });
+        tut.draw(fb, self.language, (car.x, car.y), offset);
+    }
+
+    /// Hint for the map object the car is approaching (World scene)
+    fn draw_map_prompt(&self, fb: &mut [u32]) {
+        if !self.options.map_prompts {
+            return;
+        }
+        let (Some(car), Some(wm)) = (&self.drive_car, &self.world_map) else { return };
+        let Some(tile) = wm.tile_at(car.tile_col, car.tile_row).and_then(|id| wm.get_tile(id)) else {
+            return;
+        };
+        let medals = self.save_manager.active().map(|u| u.car.medals.as_slice()).unwrap_or_default();
+        let prompt = map_prompts::active_prompt(
+            (car.x, car.y), &car.props, &tile.objects, self.quest.cache_list(), medals,
+        );
+        if let Some((key, x, y)) = prompt {
+            map_prompts::draw(fb, self.language, key, x, y);
+        }
+    }
+
+    /// Minimap of the current tile (World scene, unless hidden via the toolbox)
+    fn draw_minimap(&mut self, fb: &mut [u32]) {
+        let Some(tb) = &self.toolbox else { return };
+        tb.draw_minimap_button(fb, self.language);
+        tb.draw_tow_button(fb, self.language, self.tow.cooldown_secs());
+        if !tb.minimap || tb.popup_open {
+            return;
+        }
+        let (Some(car), Some(wm)) = (&self.drive_car, &self.world_map) else { return };
+        let Some(tile) = wm.tile_at(car.tile_col, car.tile_row).and_then(|id| wm.get_tile(id)) else {
+            return;
+        };
+        let visited = self.save_manager.active().map(|u| u.stats.destinations.as_slice()).unwrap_or(&[]);
+        self.minimap.draw(fb, &tile.topology, &self.topo_data, (car.x, car.y, car.direction), &tile.objects, visited);
+    }
+
+    /// Figge drops the car off next to the nearest pump (black frame of the tow fade)
+    fn finish_tow(&mut self) {
+        if self.current_scene != Scene::World {
+            return;
+        }
+        let (Some(car), Some(wm)) = (&self.drive_car, &self.world_map) else { return };
+        let Some((col, row, px, py)) = tow::nearest_gas_station(wm, car.tile_col, car.tile_row, car.x, car.y) else {
+            tracing::warn!("Tow: no gas station on the world map");
+            return;
+        };
+        let topo_name = wm.tile_at(col, row)
+            .and_then(|tid| wm.get_tile(tid))
+            .map(|t| t.topology.clone());
+        if let Some(topo_name) = topo_name {
+            self.load_topology(&topo_name);
+        }
+        let topo = &self.topo_data;
+        let (x, y) = tow::drop_point((px, py), |x, y| {
+            let tx = ((x as i32 - driving::MAP_OFFSET_X) / 2).clamp(0, driving::TOPO_WIDTH - 1) as usize;
+            let ty = ((y as i32 - driving::MAP_OFFSET_Y) / 2).clamp(0, driving::TOPO_HEIGHT - 1) as usize;
+            topo.get(ty * driving::TOPO_WIDTH as usize + tx).copied().unwrap_or(0)
+        });
+        if let Some(car) = &mut self.drive_car {
+            car.tow_to(col, row, x, y, tow::TOW_FUEL_FRACTION);
+        }
+        self.discover_tile(col, row);
+        tracing::info!("Towed to the gas station on tile ({}, {}) at ({:.0}, {:.0})", col, row, x, y);
+    }
+
+    /// Mark the destinations on a map tile as discovered and announce new ones
+    fn discover_tile(&mut self, col: usize, row: usize) {
+        let Some(wm) = &self.world_map else { return };
+        let Some(tile) = wm.tile_at(col, row).and_then(|id| wm.get_tile(id)) else { return };
+        let on_tile = discovery::tile_destinations(tile);
+        let Some(stats) = self.save_manager.stats_mut() else { return };
+        let new: Vec<u8> = on_tile.into_iter().filter(|&d| stats.discover(d)).collect();
+        if new.is_empty() {
+            return;
+        }
+        tracing::info!("Discovered destinations {:?}", new);
+        self.toast = Some(discovery::Toast::discovered(&new, self.language));
+        if let Some(snd) = &mut self.sound {
+            snd.play_by_name("00e004v0", &self.assets);
+        }
+        self.save_manager.save();
+    }
+
+    /// F10: arm a speedrun on the main menu (fixed seed, assists off), end
+    /// and export a running one, or dismiss a finished/armed one
+    fn toggle_speedrun(&mut self) {
+        let now = std::time::Instant::now();
+        match &mut self.speedrun {
+            None if self.current_scene == Scene::Menu => {
+                self.rng_seed = speedrun::SPEEDRUN_SEED;
+                self.rng = StdRng::seed_from_u64(self.rng_seed);
+                // Placed again from the run's seed on the first drive
+                self.world_map = None;
+                self.dev_menu.disable_cheats();
+                self.part_search = part_search::PartSearch::default();
+                self.speedrun = Some(speedrun::Speedrun::default());
+                self.toast = Some(discovery::Toast::new(i18n::t(self.language, "speedrun_armed")));
+                tracing::info!("Speedrun armed (seed {})", self.rng_seed);
+            }
+            None => {}
+            Some(run) if run.is_running() => {
+                run.finish(now);
+                let text = match run.export(&self.assets.game_dir, self.rng_seed, now) {
+                    Ok(path) => {
+                        tracing::info!("Speedrun saved to {}", path.display());
+                        format!("{} {}", i18n::t(self.language, "speedrun_saved"),
+                            speedrun::format_time(run.elapsed(now)))
+                    }
+                    Err(e) => {
+                        tracing::warn!("Speedrun export failed: {:#}", e);
+                        i18n::t(self.language, "speedrun_failed").to_string()
+                    }
+                };
+                self.toast = Some(discovery::Toast::new(&text));
+            }
+            Some(_) => self.speedrun = None,
+        }
+    }
+
+    /// Low-spec mode: no detail noise, fewer simultaneous sound effects and
+    /// no ticking of idle actors. The game already runs capped at 30 fps.
+    pub fn set_low_spec(&mut self, on: bool) {
+        const LOW_SPEC_CHANNELS: usize = 4;
+        self.low_spec = on;
+        if on {
+            self.dev_menu.detail_noise = false;
+        }
+        if let Some(snd) = &mut self.sound {
+            snd.set_max_channels(on.then_some(LOW_SPEC_CHANNELS));
+        }
+        tracing::info!("Low-spec mode → {}", on);
+    }
+
+    /// Push the saved options into the running game
+    fn apply_options(&mut self) {
+        let o = self.options.clone();
+        self.dev_menu.display_mode = o.display_mode;
+        self.set_low_spec(o.low_spec);
+        self.dev_menu.detail_noise = o.detail_noise && !o.low_spec;
+        self.language = o.language;
+        self.captions.enabled = o.captions;
+        self.rumble.level = o.rumble;
+        if std::env::var_os("OPENWILLY_DEV_KEY").is_none() {
+            if let Some(key) = demo::parse_key(&o.dev_key) {
+                self.dev_menu.hotkey = key;
+            }
+        }
+        self.apply_volume();
+    }
+
+    /// Collect the current settings and save them if anything changed
+    pub fn store_options(&mut self, fullscreen: bool) {
+        let options = options::Options {
+            fullscreen,
+            display_mode: self.dev_menu.display_mode,
+            detail_noise: self.dev_menu.detail_noise,
+            low_spec: self.low_spec,
+            volume: self.options.volume,
+            ui_volume: self.options.ui_volume,
+            language: self.language,
+            captions: self.captions.enabled,
+            map_prompts: self.options.map_prompts,
+            rumble: self.rumble.level,
+            dev_key: self.options.dev_key.clone(),
+        };
+        if options == self.options {
+            return;
+        }
+        match options.save(&self.assets.game_dir) {
+            Ok(()) => tracing::info!("Options saved"),
+            Err(e) => tracing::warn!("Options not saved: {:#}", e),
+        }
+        self.options = options;
+    }
+
+    /// Current value of an options row ("" for rows without one)
+    pub fn option_value(&self, item: options::OptionItem) -> String {
+        use options::OptionItem;
+        let on_off = |on: bool| if on { "ON" } else { "OFF" }.to_string();
+        match item {
+            OptionItem::DisplayMode => self.dev_menu.display_mode.label().to_string(),
+            OptionItem::DetailNoise => on_off(self.dev_menu.detail_noise),
+            OptionItem::LowSpec => on_off(self.low_spec),
+            OptionItem::Volume => format!("{}%", self.options.volume),
+            OptionItem::UiVolume => format!("{}%", self.options.ui_volume),
+            OptionItem::Language => self.language.code().to_string(),
+            OptionItem::Captions => on_off(self.captions.enabled),
+            OptionItem::MapPrompts => on_off(self.options.map_prompts),
+            OptionItem::Rumble => self.rumble.level.label().to_string(),
+            OptionItem::DevKey => self.options.dev_key.clone(),
+            OptionItem::Back => String::new(),
+        }
+    }
+
+    /// Change an options row: `forward` for Enter/click/Right, false for Left.
+    /// DevKey and Back are handled by the options screen itself.
+    pub fn change_option(&mut self, item: options::OptionItem, forward: bool) {
+        use options::OptionItem;
+        match item {
+            OptionItem::DisplayMode => {
+                let mode = self.dev_menu.display_mode.next();
+                self.dev_menu.display_mode = if forward { mode } else { mode.next() };
+            }
+            OptionItem::DetailNoise => self.dev_menu.detail_noise = !self.dev_menu.detail_noise,
+            OptionItem::LowSpec => self.set_low_spec(!self.low_spec),
+            OptionItem::Volume => {
+                self.options.volume = options::step_volume(self.options.volume, forward);
+                self.apply_volume();
+            }
+            OptionItem::UiVolume => {
+                self.options.ui_volume = options::step_volume(self.options.ui_volume, forward);
+                self.apply_volume();
+            }
+            OptionItem::Language => self.language = self.language.next(),
+            OptionItem::MapPrompts => self.options.map_prompts = !self.options.map_prompts,
+            OptionItem::Captions => {
+                self.captions.enabled = !self.captions.enabled;
+                self.captions.clear();
+            }
+            OptionItem::Rumble => {
+                let level = self.rumble.level.next();
+                self.rumble.level = if forward { level } else { level.next() };
+            }
+            OptionItem::DevKey | OptionItem::Back => return,
+        }
+        tracing::info!("Option {:?} → {}", item, self.option_value(item));
+    }
+
+    /// New dev-menu hotkey from the options screen (`OPENWILLY_DEV_KEY` still wins)
+    pub fn set_dev_key(&mut self, key: Key) {
+        let Some(name) = demo::key_name(key) else { return };
+        self.options.dev_key = name.to_string();
+        if std::env::var_os("OPENWILLY_DEV_KEY").is_none() {
+            self.dev_menu.hotkey = key;
+        }
+        tracing::info!("Dev-menu key → {}", name);
+    }
+
+    /// Resolve the profile's horn choice for a car with `horn_type` (0 = no horn)
+    fn horn_sound(&self, horn_type: i32) -> Option<horn::HornSound> {
+        let choice = self.save_manager.active()
+            .map(|u| u.horn.clone())
+            .unwrap_or_default();
+        horn::resolve(&choice, horn_type, &self.assets.game_dir)
+    }
+
+    fn play_horn_sound(&mut self, sound: &horn::HornSound) {
+        if let Some(snd) = &mut self.sound {
+            match sound {
+                horn::HornSound::Cast(name) => { snd.play_by_name(name, &self.assets); }
+                horn::HornSound::File(path) => {
+                    snd.play_file(path);
+                    self.captions.show("cc_horn");
+                }
+            }
+        }
+    }
+
+    /// Garage horn test: play a horn choice. "As installed" without a horn
+    /// part plays Mulle's "we need a horn" line instead.
+    fn preview_horn(&mut self, choice: &horn::HornChoice) {
+        let horn_type = self.car.properties().horn_type;
+        let test_type = if *choice == horn::HornChoice::Parts { horn_type } else { horn_type.max(1) };
+        match horn::resolve(choice, test_type, &self.assets.game_dir) {
+            Some(sound) => self.play_horn_sound(&sound),
+            None => {
+                if let Some(snd) = &mut self.sound {
+                    snd.play_by_name(driving::NO_HORN_SOUND, &self.assets);
+                }
+            }
+        }
+    }
+
+    /// Advance the garage turntable; swaps the car view at the midpoint
+    fn update_garage_turn(&mut self) {
+        let Some((target, frame)) = self.garage_turn else { return };
+        if self.current_scene != Scene::Garage {
+            self.garage_turn = None;
+            return;
+        }
+        let frame = frame + 1;
+        if frame == TURN_FRAMES / 2 {
+            self.car.set_view(target, &self.parts_db, &self.assets);
+            self.rebuild_snap_targets();
+            tracing::debug!("Garage view → {:?}", target);
+        }
+        self.garage_turn = if frame >= TURN_FRAMES { None } else { Some((target, frame)) };
+    }
+
+    /// Let Salka bark (sometimes) if she's riding along
+    fn dog_bark(&mut self, trigger: dog::BarkTrigger) {
+        if !self.quest.has_cache(dog::DOG_FLAG) {
+            return;
+        }
+        if let Some(bark) = self.dog.on_trigger(trigger, &mut self.rng) {
             if let Some(snd) = &mut self.sound {
-                snd.play_by_name(sound_id, &self.assets);
+                snd.play_by_name(bark, &self.assets);
             }
         }
     }
@@ -1594,10 +2881,12 @@ impl GameState {
 
         // Build context for condition evaluation
         let car_parts: Vec<u32> = self.car.parts.clone();
+        let actors: Vec<&str> = self.scene_handler.actors.iter().map(|a| a.name.as_str()).collect();
         let ctx = ScriptContext {
             cache: self.quest.cache_list(),
             permanent: self.quest.permanent_list(),
             car_parts: &car_parts,
+            actors: &actors,
         };
 
         // Advance and collect requests
@@ -1615,7 +2904,12 @@ impl GameState {
                     self.play_dialog_with_actor(&audio_id, actor_name.as_deref());
                 }
                 ScriptRequest::PlayAnim { actor_name, anim_name } => {
-                    self.scene_handler.play_actor_anim(&actor_name, &anim_name);
+                    if !self.scene_handler.play_actor_anim(&actor_name, &anim_name) {
+                        // Missing actor/anim would block the script forever
+                        if let Some(script) = &mut self.active_script {
+                            script.on_anim_finished(&actor_name);
+                        }
+                    }
                 }
                 ScriptRequest::SetCache(flag) => {
                     self.quest.add_cache(&flag);
@@ -1631,6 +2925,10 @@ impl GameState {
                     self.save_manager.add_yard_part(part_id);
                     tracing::info!("Script gave part {} to yard", part_id);
                 }
+                ScriptRequest::CompleteMission(mission_id) => {
+                    // Reward is handed over in the yard (see mission_ceremonies)
+                    self.save_manager.complete_mission(mission_id);
+                }
                 ScriptRequest::Refuel => {
                     if let Some(car) = &mut self.drive_car {
                         car.refuel();
@@ -1648,6 +2946,16 @@ impl GameState {
                         snd.play_by_name(&sound_id, &self.assets);
                     }
                 }
+                ScriptRequest::ShowHint(key) => {
+                    if let Some(tut) = &mut self.tutorial {
+                        tut.hint = (!key.is_empty()).then_some(key);
+                    }
+                }
+                ScriptRequest::PointAt(target) => {
+                    if let Some(tut) = &mut self.tutorial {
+                        tut.target = tutorial::Target::from_name(&target);
+                    }
+                }
                 ScriptRequest::LeaveToWorld => {
                     leave = true;
                 }
@@ -1658,6 +2966,11 @@ impl GameState {
         if let Some(script) = &self.active_script {
             if script.finished {
                 self.active_script = None;
+                if self.tutorial.take().is_some() {
+                    self.save_manager.save();
+                    self.toast = Some(discovery::Toast::new(i18n::t(self.language, "tut_done")));
+                    tracing::info!("Driving tutorial completed");
+                }
             }
         }
 
@@ -1669,8 +2982,112 @@ impl GameState {
         }
     }
 
+    /// Persist the state of a scene that is being left (or quit from)
+    fn save_scene_state(&mut self, scene: Scene) {
+        match scene {
+            Scene::World => {
+                // Save driving session when leaving the world map
+                if let Some(car) = &self.drive_car {
+                    self.drive_session = car.save_session();
+                    tracing::info!("Drive session saved at tile ({},{})",
+                        self.drive_session.tile_col, self.drive_session.tile_row);
+                }
+            }
+            Scene::Garage => {
+                // Save car state and shop floor when leaving the garage
+                self.save_manager.save_car_parts(&self.car.parts);
+                // Persist shop-floor part positions
+                let floor_parts = self.scene_handler.drag_drop.item_positions();
+                self.save_manager.save_shop_floor(&floor_parts);
+                self.save_quest_state();
+            }
+            Scene::Junkyard => {
+                // Save junk pile contents and last-visited pile index
+                let pile_parts = self.scene_handler.drag_drop.item_positions();
+                let pile_idx = self.current_pile_index();
+                self.save_manager.save_pile(pile_idx, &pile_parts);
+                self.save_manager.save_last_pile(pile_idx);
+                tracing::debug!("Saved junkyard pile {} ({} parts)", pile_idx, pile_parts.len());
+            }
+            Scene::Yard => {
+                // Persist yard part positions when leaving
+                let yard_parts = self.scene_handler.drag_drop.item_positions();
+                self.save_manager.save_yard(&yard_parts);
+                tracing::debug!("Saved yard state ({} parts)", yard_parts.len());
+            }
+            _ => {}
+        }
+    }
+
+    /// Write the "continue where I left off" snapshot (escape menu → Quit)
+    pub fn quick_save(&mut self) {
+        let Some(profile) = self.save_manager.active_user.clone() else { return };
+        if matches!(self.current_scene, Scene::Boot | Scene::Menu) {
+            return;
+        }
+        self.save_scene_state(self.current_scene);
+        self.save_quest_state();
+        let qs = quicksave::QuickSave {
+            profile,
+            scene: self.current_scene,
+            drive_session: self.drive_session.clone(),
+        };
+        qs.write(&self.assets.game_dir);
+        self.quick_save = Some(qs);
+    }
+
+    /// Main menu "Continue": log the quick-saved profile in and jump to its
+    /// scene, skipping the transition cutscene and arrival dialogs
+    fn continue_quick_save(&mut self) {
+        let Some(qs) = self.quick_save.take() else { return };
+        quicksave::QuickSave::remove(&self.assets.game_dir);
+        self.login_user(&qs.profile);
+        self.drive_session = qs.drive_session.clone();
+        let scene = qs.resume_scene();
+        tracing::info!("Continuing '{}' in {:?}", qs.profile, scene);
+
+        self.resuming = true;
+        self.switch_scene(scene);
+        if scene == Scene::World && self.drive_car.is_none() {
+            // World refused (car no longer road legal) — fall back to the garage
+            self.switch_scene(Scene::Garage);
+        }
+        self.resuming = false;
+        self.dialog.clear();
+    }
+
+    /// Command-line start (`--scene`, `--profile`, `--car`): log in, load
+    /// the car and enter the scene without menu and cutscene
+    pub fn autolaunch(&mut self, launch: &autolaunch::LaunchArgs) {
+        let profile = launch.profile.as_deref().unwrap_or("default");
+        self.login_user(profile);
+        if let Some(path) = &launch.car {
+            match autolaunch::load_car(path, &self.parts_db) {
+                Ok(parts) => {
+                    tracing::info!("Car from {}: {} parts", path.display(), parts.len());
+                    self.car.parts = parts;
+                    self.car.refresh(&self.parts_db, &self.assets);
+                    self.save_manager.save_car_parts(&self.car.parts);
+                }
+                Err(e) => tracing::warn!("Car not loaded: {:#}", e),
+            }
+        }
+        let scene = launch.scene.unwrap_or(Scene::Menu);
+        tracing::info!("Autolaunch: '{}' in {:?}", profile, scene);
+
+        self.resuming = true;
+        self.switch_scene(scene);
+        if scene == Scene::World && self.drive_car.is_none() {
+            // World refused (car not road legal) — the garage instead
+            self.switch_scene(Scene::Garage);
+        }
+        self.resuming = false;
+        self.dialog.clear();
+    }
+
     fn switch_scene(&mut self, scene: Scene) {
         let prev_scene = self.current_scene;
+        self.input_queue.scene_changed(self.mouse_down);
 
         // Skip redundant transition (e.g. cutscene already set current_scene)
         if prev_scene == scene {
@@ -1682,9 +3099,21 @@ impl GameState {
             // Update current_scene early so the cutscene lookup won't re-match
             // on the next call after the cutscene finishes (prevents infinite loop).
             self.current_scene = scene;
+            if let Some(run) = &mut self.speedrun {
+                let now = std::time::Instant::now();
+                if scene != Scene::Menu {
+                    run.start(now);
+                }
+                run.enter_scene(scene, now);
+            }
+            self.horn_panel = None;
+            self.toast = None;
+            if scene != Scene::Junkyard {
+                self.part_search = part_search::PartSearch::default();
+            }
 
             // Check for transition cutscene (only if we're not already resuming from one)
-            if self.transition.is_none() {
+            if self.transition.is_none() && !self.resuming {
                 let has_car = self.car.properties().is_road_legal();
                 if let Some(cutscene_spec) = transition_cutscene(&prev_scene, &scene, has_car) {
                 // Resolve cutscene member to (file, member_num)
@@ -1711,6 +3140,7 @@ impl GameState {
                             frames_left: 15, // ~0.5 seconds at 30fps
                             target: scene,
                             progress: 0.0,
+                            tip: self.tips.next(&mut self.rng),
                         });
                         return; // Don't switch yet — cutscene plays first
                     }
@@ -1718,6 +3148,13 @@ impl GameState {
             }
         }
 
+        // Boot and menu are parsed up front, everything past the menu
+        // needs the rest of the ISO (no-op for extracted game data; the
+        // transition cutscene above already waited for it)
+        if !matches!(scene, Scene::Boot | Scene::Menu) {
+            self.assets.finish_loading();
+        }
+
         // Log the active dialog audio_id if still talking when switching
         if self.dialog.is_talking() {
             if let Some(d) = &self.dialog.active_dialog {
@@ -1731,45 +3168,17 @@ impl GameState {
         // Stop all sounds from the previous scene
         if let Some(snd) = &mut self.sound {
             snd.stop_all();
+            snd.enter_scene(&format!("{:?}", scene));
         }
 
         // Reset cursor stack on scene switch (like mulle.js MulleState.create)
         self.cursor.reset();
+        self.cursor.set_scene(scene.director_file(), &self.assets);
 
         // --- Scene exit logic ---
-        match prev_scene {
-            Scene::World => {
-                // Save driving session when leaving the world map
-                if let Some(car) = &self.drive_car {
-                    self.drive_session = car.save_session();
-                    tracing::info!("Drive session saved at tile ({},{})",
-                        self.drive_session.tile_col, self.drive_session.tile_row);
-                }
-                self.drive_car = None;
-            }
-            Scene::Garage => {
-                // Save car state and shop floor when leaving the garage
-                self.save_manager.save_car_parts(&self.car.parts);
-                // Persist shop-floor part positions
-                let floor_parts = self.scene_handler.drag_drop.item_positions();
-                self.save_manager.save_shop_floor(&floor_parts);
-                self.save_quest_state();
-            }
-            Scene::Junkyard => {
-                // Save junk pile contents and last-visited pile index
-                let pile_parts = self.scene_handler.drag_drop.item_positions();
-                let pile_idx = self.current_pile_index();
-                self.save_manager.save_pile(pile_idx, &pile_parts);
-                self.save_manager.save_last_pile(pile_idx);
-                tracing::debug!("Saved junkyard pile {} ({} parts)", pile_idx, pile_parts.len());
-            }
-            Scene::Yard => {
-                // Persist yard part positions when leaving
-                let yard_parts = self.scene_handler.drag_drop.item_positions();
-                self.save_manager.save_yard(&yard_parts);
-                tracing::debug!("Saved yard state ({} parts)", yard_parts.len());
-            }
-            _ => {}
+        self.save_scene_state(prev_scene);
+        if prev_scene == Scene::World {
+            self.drive_car = None;
         }
 
         // --- Scene entry gate checks ---
@@ -1792,11 +3201,7 @@ impl GameState {
             let mut drive_car = DriveCar::new(320.0, 200.0, 1, drive_props);
 
             // Ensure persistent world map is initialized (with random destinations)
-            if self.world_map.is_none() {
-                let mut wm = driving::WorldMap::default_map();
-                wm.apply_random_destinations();
-                self.world_map = Some(wm);
-            }
+            self.ensure_world_map();
 
             // Extract start data from world map (clone to avoid borrow conflicts)
             let (start_topo, start_info) = {
@@ -1845,7 +3250,9 @@ impl GameState {
                     self.drive_session.tile_col, self.drive_session.tile_row);
             }
 
+            let (col, row) = (drive_car.tile_col, drive_car.tile_row);
             self.drive_car = Some(drive_car);
+            self.discover_tile(col, row);
 
             // Load dashboard HUD (once)
             if self.dashboard.is_none() {
@@ -1862,7 +3269,7 @@ impl GameState {
         }
 
         // --- Login on menu → garage transition ---
-        if prev_scene == Scene::Menu && scene == Scene::Garage {
+        if prev_scene == Scene::Menu && scene == Scene::Garage && !self.resuming {
             // Login with the name entered in the menu
             let name = self.scene_handler.effective_name();
             let login_name = if name.is_empty() { "default".to_string() } else { name };
@@ -1875,25 +3282,39 @@ impl GameState {
             self.save_quest_state();
         }
 
-        // Reset quest cache when leaving yard (as per mulle.js behavior)
+        // Reset quest cache when leaving yard (as per mulle.js behavior).
+        // Salka stays aboard until she's delivered to Figge.
         if prev_scene == Scene::Yard && scene != Scene::Garage {
+            let has_dog = self.quest.has_cache(dog::DOG_FLAG);
             self.quest.reset_cache();
+            if has_dog {
+                self.quest.add_cache(dog::DOG_FLAG);
+            }
         }
 
         } // end of `if prev_scene != scene` block
 
         // (current_scene already set above)
         let has_car = self.car.is_road_legal();
+        self.idle_behaviors.reset();
 
         // For CarShow, compute rating and pass it to the scene handler
         if scene == Scene::CarShow {
             let ff = self.car.properties().funny_factor;
             let rating = scene_script::carshow_rating(ff);
             self.scene_handler = scenes::SceneHandler::new_with_rating(scene, &self.assets, has_car, rating);
-            self.active_script = Some(scene_script::build_carshow_script(ff));
             tracing::info!("CarShow: funny_factor={}, rating={}", ff, rating);
             self.award_medal(4); // Exhibition medal
+
+            // Ask for the car's name first — the judge script starts on confirm
+            let current_name = self.save_manager.active()
+                .map(|u| u.car.name.clone())
+                .unwrap_or_default();
+            let taken = self.save_manager.gallery_names_except(&current_name);
+            let seed = self.save_manager.active().map(|u| u.gallery.len()).unwrap_or(0);
+            self.car_naming = Some(car_naming::CarNamingDialog::new(&current_name, taken, seed, self.language));
         } else {
+            self.car_naming = None;
             self.scene_handler = scenes::SceneHandler::new(scene, &self.assets, has_car);
 
             // Activate scene script for destinations
@@ -1906,6 +3327,12 @@ impl GameState {
                 // Load saved profile names into menu UI
                 let names: Vec<String> = self.save_manager.profile_names()
                     .iter().map(|s| s.to_string()).collect();
+                for name in &names {
+                    let Some(user) = self.save_manager.users_db.users.get(name) else { continue };
+                    if let Some(thumb) = self.avatars.get(name, &user.car.parts, &self.parts_db, &self.assets) {
+                        self.scene_handler.thumbnails.insert(name.clone(), thumb.clone());
+                    }
+                }
                 self.scene_handler.saved_names = names;
                 // Menu intro script: jingle → ambient → Mulle greeting
                 let jingle_ms = self.assets.sound_duration_ms("10e001v0");
@@ -1917,10 +3344,30 @@ impl GameState {
         }
 
         // --- Scene entry setup ---
+        let score_tempo = self.assets.files.get(scene.director_file()).and_then(|df| df.tempo);
+        self.scene_handler.tempo = self.tempi.tempo(scene, score_tempo);
+        tracing::debug!("Scene tempo: {} fps", self.scene_handler.tempo);
+        self.tutorial = None;
+        if scene == Scene::World {
+            self.start_tutorial();
+        }
+        if let Scene::Destination(n) = scene {
+            if let Some(stats) = self.save_manager.stats_mut() {
+                stats.visit(n);
+            }
+            self.save_manager.save();
+        }
+        if scene == Scene::Yard && self.quest.has_cache(dog::DOG_FLAG) {
+            self.scene_handler.add_yard_dog(&self.assets);
+        }
+        let birthday = self.save_manager.active().and_then(|u| u.birthday);
+        for deco in self.seasonal.decorations(scene, seasonal::Date::today(), birthday, &self.assets) {
+            self.scene_handler.add_decoration(deco);
+        }
         if scene == Scene::Garage {
             // Check for Figge delivery cutscene
             // Trigger: has #FiggeIsComing flag (set when leaving dest 92 with #ExtraTank)
-            if self.save_manager.has_stuff("#FiggeIsComing") && self.active_script.is_none() {
+            if self.save_manager.has_stuff("#FiggeIsComing") && self.active_script.is_none() && !self.resuming {
                 self.save_manager.remove_stuff("#FiggeIsComing");
                 self.active_script = Some(scene_script::build_figge_script());
                 // Give up to 3 junkman parts to the yard
@@ -1944,17 +3391,27 @@ impl GameState {
         }
 
         if scene == Scene::Yard {
+            // Completed missions: reward parts are added to the yard here
+            // and dropped in separately below
+            let rewards = if self.resuming { Vec::new() } else { self.mission_ceremonies() };
+
             // Spawn yard parts (quest rewards / parts dragged here)
-            let yard_parts = self.save_manager.active()
+            let mut yard_parts = self.save_manager.active()
                 .map(|u| u.junk.yard.clone())
                 .unwrap_or_default();
+            let reward_parts: std::collections::HashMap<u32, (i32, i32)> = rewards.iter()
+                .filter_map(|pid| yard_parts.remove_entry(pid))
+                .collect();
+            if !reward_parts.is_empty() {
+                self.drop_in_parts(&reward_parts);
+            }
             if !yard_parts.is_empty() {
                 tracing::debug!("Yard: spawning {} parts", yard_parts.len());
                 self.spawn_parts_from_map(&yard_parts, true);
             }
 
             // Deliver pending missions (telephone ring or mail)
-            if self.save_manager.has_pending_missions() {
+            if self.save_manager.has_pending_missions() && !self.resuming {
                 if let Some(mid) = self.save_manager.pop_pending_mission() {
                     let missions = dialog::MissionDB::load();
                     if let Some(mission) = missions.get(mid) {
@@ -1966,21 +3423,20 @@ impl GameState {
                         }
                         // For mail missions, show the mail image as an overlay
                         if mission.delivery == dialog::MissionDelivery::Mail && !mission.image.is_empty() {
-                            if let Some(bmp) = self.assets.find_bitmap_by_name(&mission.image) {
-                                let sprite = Sprite {
-                                    x: 320 - bmp.width as i32 / 2,
-                                    y: 240 - bmp.height as i32 / 2,
-                                    width: bmp.width,
-                                    height: bmp.height,
-                                    pixels: bmp.pixels,
-                                    visible: true,
-                                    z_order: 9000, // on top of everything
-                                    name: format!("mail_mission_{}", mid),
-                                    interactive: true,
-                                    member_num: 0,
-                                };
-                                self.scene_handler.sprites.push(sprite);
-                            }
+                            let bmp = self.assets.require_bitmap_by_name(&mission.image);
+                            let sprite = Sprite {
+                                x: 320 - bmp.width as i32 / 2,
+                                y: 240 - bmp.height as i32 / 2,
+                                width: bmp.width,
+                                height: bmp.height,
+                                pixels: bmp.pixels.into(),
+                                visible: true,
+                                z_order: 9000, // on top of everything
+                                name: format!("mail_mission_{}", mid),
+                                interactive: true,
+                                member_num: 0,
+                            };
+                            self.scene_handler.sprites.push(sprite);
                         }
                     }
                 }
@@ -2017,6 +3473,13 @@ impl GameState {
     }
 
     /// Get the current junkyard pile index (1-6) from the scene handler
+    /// Zoom lens (Ctrl) only in the drag-and-drop scenes, not while typing
+    pub fn zoom_allowed(&self) -> bool {
+        matches!(self.current_scene, Scene::Garage | Scene::Yard | Scene::Junkyard)
+            && self.car_naming.is_none()
+            && !self.dev_menu.open
+    }
+
     fn current_pile_index(&self) -> u8 {
         self.scene_handler.junk_pile
     }
@@ -2036,7 +3499,7 @@ impl GameState {
                     y,
                     width: bmp.width,
                     height: bmp.height,
-                    pixels: bmp.pixels,
+                    pixels: bmp.pixels.into(),
                     visible: true,
                     z_order: z,
                     name: format!("part_{}", part_id),
@@ -2064,7 +3527,7 @@ impl GameState {
             x, y,
             width: sz,
             height: sz,
-            pixels,
+            pixels: pixels.into(),
             visible: true,
             z_order: z,
             name: format!("part_{}", part_id),
@@ -2108,7 +3571,7 @@ impl GameState {
                 y: snap_y - reg_y,
                 width: bmp.width,
                 height: bmp.height,
-                pixels: bmp.pixels,
+                pixels: bmp.pixels.into(),
                 visible: true,
                 z_order: 50,
                 name: format!("morph:{}#{}", morph.use_view, morph.part_id),
@@ -2124,6 +3587,47 @@ impl GameState {
         }
     }
 
+    /// Hand out the rewards of missions completed since the last yard visit:
+    /// reward part into the yard, `#Mission<N>Done` flag, fanfare and toast.
+    /// Returns the reward part IDs (to be dropped into the scene).
+    fn mission_ceremonies(&mut self) -> Vec<u32> {
+        let ceremonies = self.save_manager.take_mission_ceremonies();
+        if ceremonies.is_empty() {
+            return Vec::new();
+        }
+        let missions = dialog::MissionDB::load();
+        let mut rewards = Vec::new();
+        for mid in ceremonies {
+            let Some(mission) = missions.get(mid) else {
+                tracing::warn!("Ceremony for unknown mission {}", mid);
+                continue;
+            };
+            tracing::info!("Mission {} ceremony, reward part {:?}", mid, mission.reward_part);
+            if let Some(part_id) = mission.reward_part {
+                self.save_manager.add_yard_part(part_id);
+                rewards.push(part_id);
+            }
+            self.quest.add_permanent(&mission.done_flag());
+        }
+        self.save_quest_state();
+
+        if let Some(snd) = &mut self.sound {
+            snd.play_by_name("00e004v0", &self.assets);
+        }
+        self.toast = Some(discovery::Toast::new(i18n::t(self.language, "mission_done")));
+        rewards
+    }
+
+    /// Spawn parts above the screen so they fall into the yard
+    fn drop_in_parts(&mut self, parts: &std::collections::HashMap<u32, (i32, i32)>) {
+        let first = self.scene_handler.drag_drop.items.len();
+        self.spawn_parts_from_map(parts, true);
+        for item in &mut self.scene_handler.drag_drop.items[first..] {
+            let height = item.junk_sprite.height as i32;
+            item.drop_from(-height);
+        }
+    }
+
     /// Spawn a set of parts (from save data HashMap) as DraggableItems
     /// into the scene's drag_drop system. Used for junkyard piles,
     /// shop floor, and yard.
@@ -2192,7 +3696,7 @@ impl GameState {
                     y: snap_y - reg_y,
                     width: bmp.width,
                     height: bmp.height,
-                    pixels: bmp.pixels,
+                    pixels: bmp.pixels.into(),
                     visible: true,
                     z_order: 50,
                     name: format!("morph:{}#{}", morph.use_view, morph.part_id),
@@ -2253,59 +3757,22 @@ impl GameState {
             None => return,
         };
 
-        // Stop previous scene's background audio
-        snd.stop_background();
+        // Crossfade to the scene's ambient loop (data/ambience.json). Menu
+        // audio is handled by the menu script (jingle → ambient → greeting),
+        // the Garage has no BG loop per mulle.js, driving sounds come from
+        // the driving system.
+        let (loop_name, mix) = self.ambience.mix(self.current_scene);
+        snd.play_background(loop_name, mix, &self.assets);
 
-        match self.current_scene {
-            Scene::Menu => {
-                // Menu audio is handled by the menu script (jingle → ambient → greeting)
-                // See build_menu_script() in scene_script.rs
-            }
-            Scene::Garage => {
-                // Garage has no dedicated BG loop per mulle.js
-                // One-shot greeting sounds played by scene script
-            }
-            Scene::Junkyard => {
-                // Junkyard ambient BG loop (shared with Yard)
-                snd.play_background("02e010v0", &self.assets);
-            }
-            Scene::Yard => {
-                // Outdoor ambient BG loop (shared with Junkyard)
-                snd.play_background("02e010v0", &self.assets);
-            }
-            Scene::World => {
-                // Driving engine sounds handled by driving system
-            }
-            Scene::CarShow => {
-                // Car show crowd ambient loop
-                snd.play_background("94e001v0", &self.assets);
-                // Save the car's name when entering the car show
-                let car_name = self.save_manager.active()
-                    .map(|u| u.car.name.clone())
-                    .unwrap_or_default();
-                let display_name = if car_name.is_empty() { "Unbenannt".to_string() } else { car_name };
-                self.save_manager.save_car_name(&display_name);
-                tracing::info!("Car show: saved car name '{}'", display_name);
-            }
-            Scene::Destination(n) => {
-                // Destination-specific ambient loops
-                match n {
-                    85 => { snd.play_background("85e001v0", &self.assets); } // Roaddog (Salka)
-                    86 => { snd.play_background("86e005v0", &self.assets); } // Solhem (Mia)
-                    88 => { snd.play_background("88e001v0", &self.assets); } // Sture Stortand
-                    92 => { snd.play_background("92e002v0", &self.assets); } // Figge Ferrum
-                    // 87 (Saftfabrik), 84 (Roadthing) — no BG loop per mulle.js
-                    _ => {}
-                }
-            }
-            _ => {}
-        }
+        self.apply_volume();
+    }
 
-        // Adjust volume for driving scenes (slightly quieter ambient)
-        if self.current_scene == Scene::World {
-            snd.set_volume(0.7);
-        } else {
-            snd.set_volume(1.0);
+    /// Master volume from the options, slightly quieter ambient while driving
+    fn apply_volume(&mut self) {
+        let scene_gain = if self.current_scene == Scene::World { 0.7 } else { 1.0 };
+        if let Some(snd) = &mut self.sound {
+            snd.set_volume(scene_gain * self.options.volume as f32 / 100.0);
+            snd.set_ui_volume(self.options.ui_volume as f32 / 100.0);
         }
     }
 }
@@ -0,0 +1,172 @@
+//! Options screen — display, sound, language, hints, rumble, dev-menu key
+//!
+//! Opened from the pause menu. Replaces the settings that used to be spread
+//! over the pause menu and the dev menu; the choices are saved to
+//! `<game_dir>/openwilly_options.json` when the screen is closed (and on
+//! exit) and applied again at the next start.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::rumble::RumbleLevel;
+use crate::engine::DisplayMode;
+use crate::game::i18n::Language;
+
+const FILE_NAME: &str = "openwilly_options.json";
+/// Volume steps in percent
+const VOLUME_STEP: u8 = 10;
+
+/// Saved settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Options {
+    pub fullscreen: bool,
+    pub display_mode: DisplayMode,
+    pub detail_noise: bool,
+    pub low_spec: bool,
+    /// Master volume in percent
+    pub volume: u8,
+    /// Menu hover/click sounds in percent of the master volume
+    pub ui_volume: u8,
+    pub language: Language,
+    pub captions: bool,
+    /// Interaction hints on the world map
+    pub map_prompts: bool,
+    pub rumble: RumbleLevel,
+    /// Dev-menu hotkey (key name as in demos), `OPENWILLY_DEV_KEY` wins
+    pub dev_key: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            fullscreen: false,
+            display_mode: DisplayMode::Pillarbox,
+            detail_noise: false,
+            low_spec: false,
+            volume: 100,
+            ui_volume: 80,
+            language: Language::German,
+            captions: false,
+            map_prompts: true,
+            rumble: RumbleLevel::Full,
+            dev_key: "F12".into(),
+        }
+    }
+}
+
+impl Options {
+    fn path(game_dir: &Path) -> PathBuf {
+        game_dir.join(FILE_NAME)
+    }
+
+    /// Saved options, defaults if there are none (or they can't be read)
+    pub fn load(game_dir: &Path) -> Self {
+        let path = Self::path(game_dir);
+        let Ok(json) = std::fs::read_to_string(&path) else { return Self::default() };
+        match serde_json::from_str(&json) {
+            Ok(options) => options,
+            Err(e) => {
+                tracing::warn!("Invalid {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self, game_dir: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(game_dir), json)?;
+        Ok(())
+    }
+}
+
+/// Step a volume percentage up or down, clamped to 0 – 100
+pub fn step_volume(percent: u8, up: bool) -> u8 {
+    if up {
+        percent.saturating_add(VOLUME_STEP).min(100)
+    } else {
+        percent.saturating_sub(VOLUME_STEP)
+    }
+}
+
+/// Rows of the options screen, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionItem {
+    DisplayMode,
+    DetailNoise,
+    LowSpec,
+    Volume,
+    UiVolume,
+    Language,
+    Captions,
+    MapPrompts,
+    Rumble,
+    DevKey,
+    Back,
+}
+
+impl OptionItem {
+    pub const ALL: [OptionItem; 11] = [
+        OptionItem::DisplayMode,
+        OptionItem::DetailNoise,
+        OptionItem::LowSpec,
+        OptionItem::Volume,
+        OptionItem::UiVolume,
+        OptionItem::Language,
+        OptionItem::Captions,
+        OptionItem::MapPrompts,
+        OptionItem::Rumble,
+        OptionItem::DevKey,
+        OptionItem::Back,
+    ];
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            OptionItem::DisplayMode => "menu_display_mode",
+            OptionItem::DetailNoise => "menu_detail_noise",
+            OptionItem::LowSpec => "menu_low_spec",
+            OptionItem::Volume => "opt_volume",
+            OptionItem::UiVolume => "opt_ui_volume",
+            OptionItem::Language => "opt_language",
+            OptionItem::Captions => "menu_captions",
+            OptionItem::MapPrompts => "opt_map_prompts",
+            OptionItem::Rumble => "menu_rumble",
+            OptionItem::DevKey => "opt_dev_key",
+            OptionItem::Back => "opt_back",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load() {
+        let dir = std::env::temp_dir().join(format!("openwilly_options_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(Options::load(&dir), Options::default());
+        let options = Options {
+            display_mode: DisplayMode::PixelPerfect,
+            volume: 40,
+            language: Language::English,
+            rumble: RumbleLevel::Off,
+            ..Default::default()
+        };
+        options.save(&dir).unwrap();
+        let loaded = Options::load(&dir);
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(loaded, options);
+    }
+
+    #[test]
+    fn older_files_keep_defaults() {
+        let options: Options = serde_json::from_str(r#"{ "captions": true }"#).unwrap();
+        assert!(options.captions);
+        assert_eq!(options.volume, 100);
+        assert_eq!(step_volume(95, true), 100);
+        assert_eq!(step_volume(5, false), 0);
+    }
+}
@@ -0,0 +1,173 @@
+//! Junkyard part search — find a wheel among 300 parts
+//!
+//! A "Suchen" button in the junkyard opens a panel with one row per part
+//! kind (see `PartKind`). Picking a kind outlines the matching parts on the
+//! current pile and lists how many are lying on each of the six piles, so
+//! the player knows where to go. The highlight stays on while switching
+//! piles and is cleared when leaving the junkyard.
+
+use crate::engine::font;
+use crate::game::drag_drop::DraggableItem;
+use crate::game::i18n::{self, Language};
+use crate::game::parts_db::{PartKind, PartsDB};
+
+/// Search button in the junkyard (top right)
+pub const SEARCH_BUTTON: (i32, i32, i32, i32) = (540, 8, 92, 22);
+
+/// Panel geometry: kinds first, pile counts below
+const PANEL_X: i32 = 460;
+const PANEL_Y: i32 = 34;
+const PANEL_W: i32 = 172;
+const ROW_H: i32 = 18;
+const TITLE_H: i32 = 20;
+const HIGHLIGHT: u32 = 0xFFFFDD44;
+
+#[derive(Debug, Default)]
+pub struct PartSearch {
+    /// Panel visible
+    pub open: bool,
+    /// Kind being searched for (highlight stays while the panel is closed)
+    pub kind: Option<PartKind>,
+}
+
+impl PartSearch {
+    pub fn button_hit(x: i32, y: i32) -> bool {
+        let (bx, by, bw, bh) = SEARCH_BUTTON;
+        x >= bx && y >= by && x < bx + bw && y < by + bh
+    }
+
+    fn height(&self) -> i32 {
+        let piles = if self.kind.is_some() { 7 } else { 0 };
+        TITLE_H + (PartKind::ALL.len() as i32 + piles) * ROW_H + 4
+    }
+
+    /// Whether (x, y) lies on the button or the open panel
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        Self::button_hit(x, y)
+            || (self.open
+                && (PANEL_X..PANEL_X + PANEL_W).contains(&x)
+                && (PANEL_Y..PANEL_Y + self.height()).contains(&y))
+    }
+
+    /// Handle a click on the button or panel. Returns false if the click
+    /// wasn't meant for the search (closes the panel though).
+    pub fn on_click(&mut self, x: i32, y: i32) -> bool {
+        if Self::button_hit(x, y) {
+            self.open = !self.open;
+            return true;
+        }
+        if !self.open {
+            return false;
+        }
+        if !self.contains(x, y) {
+            self.open = false;
+            return false;
+        }
+        let row = (y - PANEL_Y - TITLE_H).div_euclid(ROW_H);
+        if let Some(&kind) = usize::try_from(row).ok().and_then(|r| PartKind::ALL.get(r)) {
+            // Clicking the selected kind again clears the search
+            self.kind = (self.kind != Some(kind)).then_some(kind);
+        }
+        true
+    }
+
+    /// Does this part match the current search?
+    pub fn matches(&self, db: &PartsDB, part_id: u32) -> bool {
+        self.kind.is_some() && db.part_kind(part_id) == self.kind
+    }
+
+    /// Number of matching parts per pile (index 0 = pile 1)
+    pub fn pile_counts(&self, db: &PartsDB, piles: [Vec<u32>; 6]) -> [usize; 6] {
+        piles.map(|ids| ids.iter().filter(|&&id| self.matches(db, id)).count())
+    }
+
+    pub fn draw_button(fb: &mut [u32], lang: Language) {
+        let (bx, by, bw, bh) = SEARCH_BUTTON;
+        let label = i18n::t(lang, "search_button");
+        font::draw_rect(fb, bx, by, bw, bh, 0xAA1A1A2E);
+        font::draw_rect_outline(fb, bx, by, bw, bh, 0xFF6666CC);
+        font::draw_text_shadow(fb, bx + (bw - font::text_width(label)) / 2, by + 7, label, 0xFFFFFFFF);
+    }
+
+    /// Outline matching parts on the current pile
+    pub fn draw_highlights(&self, fb: &mut [u32], db: &PartsDB, items: &[DraggableItem]) {
+        for item in items.iter().filter(|i| !i.dragging && self.matches(db, i.part_id)) {
+            let (w, h) = (item.junk_sprite.width as i32, item.junk_sprite.height as i32);
+            font::draw_rect_outline(fb, item.x - 2, item.y - 2, w + 4, h + 4, HIGHLIGHT);
+            font::draw_rect_outline(fb, item.x - 3, item.y - 3, w + 6, h + 6, HIGHLIGHT);
+        }
+    }
+
+    pub fn draw_panel(&self, fb: &mut [u32], counts: &[usize; 6], current_pile: u8, lang: Language) {
+        if !self.open {
+            return;
+        }
+        let h = self.height();
+        font::draw_rect(fb, PANEL_X, PANEL_Y, PANEL_W, h, 0xDD1A1A2E);
+        font::draw_rect_outline(fb, PANEL_X, PANEL_Y, PANEL_W, h, 0xFF6666CC);
+        font::draw_text_shadow(fb, PANEL_X + 6, PANEL_Y + 6, i18n::t(lang, "search_title"), 0xFFFFDD44);
+
+        for (i, kind) in PartKind::ALL.iter().enumerate() {
+            let y = PANEL_Y + TITLE_H + i as i32 * ROW_H;
+            if Some(*kind) == self.kind {
+                font::draw_rect(fb, PANEL_X + 2, y, PANEL_W - 4, ROW_H, 0xFF3A3A6E);
+            }
+            font::draw_text_shadow(fb, PANEL_X + 8, y + 5, i18n::t(lang, kind.i18n_key()), 0xFFFFFFFF);
+        }
+
+        if self.kind.is_none() {
+            return;
+        }
+        let top = PANEL_Y + TITLE_H + PartKind::ALL.len() as i32 * ROW_H;
+        font::draw_text_shadow(fb, PANEL_X + 6, top + 5, i18n::t(lang, "search_piles"), 0xFFFFDD44);
+        for (i, &count) in counts.iter().enumerate() {
+            let pile = i as u8 + 1;
+            let y = top + (i as i32 + 1) * ROW_H;
+            let mut text = format!("{} {}: {}", i18n::t(lang, "search_pile"), pile, count);
+            if pile == current_pile {
+                text.push_str(" <");
+            }
+            let color = if count > 0 { 0xFFFFFFFF } else { 0xFF777777 };
+            font::draw_text_shadow(fb, PANEL_X + 8, y + 5, &text, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_y(row: i32) -> i32 {
+        PANEL_Y + TITLE_H + row * ROW_H + 5
+    }
+
+    #[test]
+    fn pick_and_clear_kind() {
+        let mut search = PartSearch::default();
+        assert!(!search.on_click(PANEL_X + 10, row_y(0)));
+        assert!(search.on_click(SEARCH_BUTTON.0 + 5, SEARCH_BUTTON.1 + 5));
+        assert!(search.open);
+        assert!(search.on_click(PANEL_X + 10, row_y(0)));
+        assert_eq!(search.kind, Some(PartKind::Wheels));
+        assert!(search.on_click(PANEL_X + 10, row_y(0)));
+        assert_eq!(search.kind, None);
+
+        // Outside click closes the panel, highlight stays
+        search.on_click(PANEL_X + 10, row_y(1));
+        assert!(!search.on_click(10, 400));
+        assert!(!search.open);
+        assert_eq!(search.kind, Some(PartKind::Engine));
+    }
+
+    #[test]
+    fn counts_per_pile() {
+        let db = PartsDB::load();
+        let wheel = db.iter()
+            .map(|(&id, _)| id)
+            .find(|&id| db.part_kind(id) == Some(PartKind::Wheels))
+            .unwrap();
+        let search = PartSearch { open: true, kind: Some(PartKind::Wheels) };
+        let piles = [vec![wheel, 1], vec![], vec![1], vec![wheel], vec![], vec![]];
+        assert_eq!(search.pile_counts(&db, piles), [1, 0, 0, 1, 0, 0]);
+    }
+}
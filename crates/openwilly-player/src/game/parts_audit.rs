@@ -0,0 +1,241 @@
+//! Parts report (dev menu) — where the active profile's parts are
+//!
+//! Save issues users report are usually about parts: one that shows up on
+//! the car and in a pile at the same time, or an ID the data files don't
+//! know (old saves, edited `parts.hash.json`). The report counts the parts
+//! per location of the saved profile (the scene being played writes its
+//! parts back when it is left), lists duplicates and unknown IDs, and two
+//! dev-menu fixes clean them up.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::engine::font;
+use crate::game::drag_drop::DropRect;
+use crate::game::parts_db::PartsDB;
+use crate::game::save::UserSave;
+
+/// Listed duplicates / unknown IDs (the log has all of them)
+const MAX_LISTED: usize = 8;
+
+/// Where a part is kept, in the order a duplicate keeps its first copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Location {
+    Car,
+    ShopFloor,
+    Yard,
+    Pile(u8),
+}
+
+impl Location {
+    const ALL: [Location; 9] = [
+        Location::Car,
+        Location::ShopFloor,
+        Location::Yard,
+        Location::Pile(1),
+        Location::Pile(2),
+        Location::Pile(3),
+        Location::Pile(4),
+        Location::Pile(5),
+        Location::Pile(6),
+    ];
+
+    pub fn label(self) -> String {
+        match self {
+            Location::Car => "Auto".into(),
+            Location::ShopFloor => "Werkstatt".into(),
+            Location::Yard => "Hof".into(),
+            Location::Pile(n) => format!("Haufen {}", n),
+        }
+    }
+
+    /// Part IDs at this location (the car may list one twice)
+    fn parts(self, user: &UserSave) -> Vec<u32> {
+        let mut ids: Vec<u32> = match self {
+            Location::Car => return user.car.parts.clone(),
+            Location::ShopFloor => user.junk.shop_floor.keys().copied().collect(),
+            Location::Yard => user.junk.yard.keys().copied().collect(),
+            Location::Pile(n) => user.junk.pile(n).keys().copied().collect(),
+        };
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Drop a part from this location (all copies)
+    fn remove(self, user: &mut UserSave, part_id: u32) {
+        match self {
+            Location::Car => user.car.parts.retain(|&p| p != part_id),
+            Location::ShopFloor => {
+                user.junk.shop_floor.remove(&part_id);
+            }
+            Location::Yard => {
+                user.junk.yard.remove(&part_id);
+            }
+            Location::Pile(n) => {
+                user.junk.pile_mut(n).remove(&part_id);
+            }
+        }
+    }
+}
+
+/// Part distribution of one profile
+#[derive(Debug, Default)]
+pub struct PartsAudit {
+    pub counts: Vec<(Location, usize)>,
+    /// Part ID → every location holding it (more than one)
+    pub duplicates: BTreeMap<u32, Vec<Location>>,
+    /// IDs missing from PartsDB and where they are
+    pub orphans: Vec<(u32, Location)>,
+}
+
+impl PartsAudit {
+    pub fn of(user: &UserSave, db: &PartsDB) -> Self {
+        let mut audit = Self::default();
+        let mut seen: BTreeMap<u32, Vec<Location>> = BTreeMap::new();
+        for loc in Location::ALL {
+            let parts = loc.parts(user);
+            audit.counts.push((loc, parts.len()));
+            for id in parts {
+                seen.entry(id).or_default().push(loc);
+                if db.get(id).is_none() {
+                    audit.orphans.push((id, loc));
+                }
+            }
+        }
+        audit.duplicates = seen.into_iter().filter(|(_, locs)| locs.len() > 1).collect();
+        audit
+    }
+
+    /// Report text, one line each
+    pub fn lines(&self) -> Vec<String> {
+        let total: usize = self.counts.iter().map(|(_, n)| n).sum();
+        let mut lines = vec![format!("Teile gesamt: {}", total)];
+        lines.extend(self.counts.iter().map(|(loc, n)| format!("  {}: {}", loc.label(), n)));
+        lines.push(format!("Doppelt: {}", self.duplicates.len()));
+        for (id, locs) in self.duplicates.iter().take(MAX_LISTED) {
+            let places: Vec<String> = locs.iter().map(|l| l.label()).collect();
+            lines.push(format!("  #{}: {}", id, places.join(", ")));
+        }
+        lines.push(format!("Unbekannt: {}", self.orphans.len()));
+        for (id, loc) in self.orphans.iter().take(MAX_LISTED) {
+            lines.push(format!("  #{}: {}", id, loc.label()));
+        }
+        lines
+    }
+
+    /// Panel in the top-left corner
+    pub fn draw(&self, fb: &mut [u32]) {
+        let lines = self.lines();
+        let w = lines.iter().map(|l| font::text_width(l)).max().unwrap_or(0) + 12;
+        let h = lines.len() as i32 * 11 + 10;
+        font::draw_rect(fb, 4, 4, w, h, 0xCC000000);
+        font::draw_rect_outline(fb, 4, 4, w, h, 0xFF00CC66);
+        for (i, line) in lines.iter().enumerate() {
+            let color = if line.starts_with(' ') { 0xFFBBBBBB } else { 0xFF00FF88 };
+            font::draw_text(fb, 10, 9 + i as i32 * 11, line, color);
+        }
+    }
+}
+
+/// One-click fixes from the dev menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartsFix {
+    /// Keep each part only at its first location (car, shop, yard, piles)
+    Dedupe,
+    /// Move IDs unknown to PartsDB to junk pile 1
+    ReturnOrphans,
+}
+
+impl PartsFix {
+    /// Apply to a profile, returns the number of parts moved or removed
+    pub fn apply(self, user: &mut UserSave, db: &PartsDB) -> usize {
+        match self {
+            PartsFix::Dedupe => dedupe(user).values().sum(),
+            PartsFix::ReturnOrphans => return_orphans(user, db),
+        }
+    }
+}
+
+/// Keep each part only at its first location in `Location` order (also
+/// used by the save guard), returns the copies each location lost
+pub fn dedupe(user: &mut UserSave) -> BTreeMap<Location, usize> {
+    let mut kept = HashSet::new();
+    let mut removed = BTreeMap::new();
+    // Car first: a doubled entry on the car itself goes as well
+    let car = user.car.parts.len();
+    user.car.parts.retain(|&id| kept.insert(id));
+    if user.car.parts.len() < car {
+        removed.insert(Location::Car, car - user.car.parts.len());
+    }
+    for loc in &Location::ALL[1..] {
+        for id in loc.parts(user) {
+            if !kept.insert(id) {
+                loc.remove(user, id);
+                *removed.entry(*loc).or_insert(0) += 1;
+                tracing::info!("Parts fix: #{} removed from {}", id, loc.label());
+            }
+        }
+    }
+    removed
+}
+
+fn return_orphans(user: &mut UserSave, db: &PartsDB) -> usize {
+    let pile_rects = DropRect::pile_rects(1);
+    let pile_area = &pile_rects[0];
+    let mut moved = 0;
+    for loc in Location::ALL.into_iter().filter(|&l| l != Location::Pile(1)) {
+        for id in loc.parts(user) {
+            if db.get(id).is_some() {
+                continue;
+            }
+            loc.remove(user, id);
+            user.junk.pile1.entry(id).or_insert_with(|| pile_area.random_point(id));
+            moved += 1;
+            tracing::info!("Parts fix: unknown #{} moved from {} to pile 1", id, loc.label());
+        }
+    }
+    moved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ID no parts database has
+    const UNKNOWN: u32 = 99_999;
+
+    fn profile() -> UserSave {
+        let mut user = UserSave::new("Test");
+        user.car.parts = vec![1, 66, 66, UNKNOWN];
+        user.junk.yard.insert(29, (0, 0)); // also on pile 1
+        user.junk.shop_floor.insert(UNKNOWN + 1, (0, 0));
+        user
+    }
+
+    #[test]
+    fn finds_duplicates_and_unknown_ids() {
+        let db = PartsDB::load();
+        let audit = PartsAudit::of(&profile(), &db);
+        assert_eq!(audit.counts[0], (Location::Car, 4));
+        assert_eq!(audit.duplicates[&66], vec![Location::Car, Location::Car, Location::Pile(1)]);
+        assert_eq!(audit.duplicates[&29], vec![Location::Yard, Location::Pile(1)]);
+        assert_eq!(audit.orphans, vec![(UNKNOWN, Location::Car), (UNKNOWN + 1, Location::ShopFloor)]);
+        assert!(audit.lines()[0].starts_with("Teile gesamt:"));
+    }
+
+    #[test]
+    fn fixes_leave_a_clean_profile() {
+        let db = PartsDB::load();
+        let mut user = profile();
+        assert_eq!(PartsFix::Dedupe.apply(&mut user, &db), 3);
+        assert_eq!(user.car.parts, vec![1, 66, UNKNOWN]);
+        assert!(user.junk.yard.contains_key(&29) && !user.junk.pile1.contains_key(&29));
+
+        assert_eq!(PartsFix::ReturnOrphans.apply(&mut user, &db), 2);
+        assert_eq!(user.car.parts, vec![1, 66]);
+        assert!(user.junk.pile1.contains_key(&UNKNOWN) && user.junk.pile1.contains_key(&(UNKNOWN + 1)));
+
+        let audit = PartsAudit::of(&user, &db);
+        assert!(audit.duplicates.is_empty());
+        assert!(audit.orphans.iter().all(|&(_, loc)| loc == Location::Pile(1)));
+    }
+}
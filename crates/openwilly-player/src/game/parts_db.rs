@@ -117,6 +117,65 @@ pub enum PartCategory {
     Random,
 }
 
+/// What a part does, derived from its properties (junkyard part search)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartKind {
+    Wheels,
+    Engine,
+    /// Fuel tanks and batteries
+    Energy,
+    /// Lamps and horns
+    Electrics,
+    /// Steering wheels, brakes, pedals
+    Controls,
+    /// Frames, bodywork, seats, load space
+    Body,
+    Decoration,
+}
+
+impl PartKind {
+    pub const ALL: [PartKind; 7] = [
+        PartKind::Wheels,
+        PartKind::Engine,
+        PartKind::Energy,
+        PartKind::Electrics,
+        PartKind::Controls,
+        PartKind::Body,
+        PartKind::Decoration,
+    ];
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            PartKind::Wheels => "kind_wheels",
+            PartKind::Engine => "kind_engine",
+            PartKind::Energy => "kind_energy",
+            PartKind::Electrics => "kind_electrics",
+            PartKind::Controls => "kind_controls",
+            PartKind::Body => "kind_body",
+            PartKind::Decoration => "kind_decoration",
+        }
+    }
+
+    /// Classify by the most defining property (a wheel with weight is a wheel)
+    fn of(p: &PartProperties) -> Self {
+        if p.grip > 0 {
+            PartKind::Wheels
+        } else if p.engine_type > 0 || p.speed > 0 || p.acceleration > 0 {
+            PartKind::Engine
+        } else if p.fuel_volume > 0 || p.electric_volume > 0 {
+            PartKind::Energy
+        } else if p.lamps > 0 || p.horn > 0 || p.horn_type > 0 {
+            PartKind::Electrics
+        } else if p.steering > 0 || p.brake > 0 || p.pedals > 0 {
+            PartKind::Controls
+        } else if p.strength > 0 || p.durability > 0 || p.load_capacity > 0 || p.comfort > 0 {
+            PartKind::Body
+        } else {
+            PartKind::Decoration
+        }
+    }
+}
+
 impl PartsDB {
     /// Load the embedded parts database (parsed once at startup)
     pub fn load() -> Self {
@@ -152,6 +211,10 @@ impl PartsDB {
         self.parts.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
     /// Iterate over all parts
     pub fn iter(&self) -> impl Iterator<Item = (&u32, &PartData)> {
         self.parts.iter()
@@ -275,6 +338,17 @@ impl PartsDB {
         }
     }
 
+    /// What kind of part this is. Morph parents (no properties of their own)
+    /// take the kind of their first variant.
+    pub fn part_kind(&self, part_id: u32) -> Option<PartKind> {
+        let part = self.parts.get(&part_id)?;
+        let props = match part.morphs_to.first().and_then(|m| self.parts.get(m)) {
+            Some(morph) if part.is_morph_parent() => &morph.properties,
+            _ => &part.properties,
+        };
+        Some(PartKind::of(props))
+    }
+
     // -----------------------------------------------------------------------
     // Car property aggregation (from mulle.js getCarProperties)
     // -----------------------------------------------------------------------
@@ -586,6 +660,19 @@ mod tests {
         assert!(chassis.attachment_points.len() >= 20);
     }
 
+    #[test]
+    fn part_kinds() {
+        let db = PartsDB::load();
+        let wheels = db.iter().filter(|(&id, _)| db.part_kind(id) == Some(PartKind::Wheels)).count();
+        assert!(wheels > 10, "Expected plenty of wheels, got {}", wheels);
+        for kind in PartKind::ALL {
+            assert!(db.iter().any(|(&id, _)| db.part_kind(id) == Some(kind)), "{:?}", kind);
+        }
+        // Morph parent 2 has no properties of its own
+        assert_eq!(db.part_kind(2), db.part_kind(3));
+        assert_eq!(db.part_kind(9999), None);
+    }
+
     #[test]
     fn morph_parent_child() {
         let db = PartsDB::load();
@@ -0,0 +1,104 @@
+//! Quick-save — "continue where I left off"
+//!
+//! Quitting from the escape menu writes a small snapshot next to the profile
+//! save: which profile was playing, the scene and the driving session. The
+//! main menu then offers "Continue", which logs the profile back in and
+//! jumps straight to that scene. The snapshot is separate from the profile
+//! save (which keeps being written on every change) and is used up once
+//! restored.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::driving::DriveSession;
+use crate::game::Scene;
+
+const FILE_NAME: &str = "openwilly_quicksave.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickSave {
+    /// Profile that was playing
+    pub profile: String,
+    pub scene: Scene,
+    /// Car position on the world map (also kept while in a destination)
+    #[serde(default)]
+    pub drive_session: DriveSession,
+}
+
+impl QuickSave {
+    /// Scene to resume in. Destinations and the car show run scripted
+    /// sequences, so those resume on the map or in the garage instead.
+    pub fn resume_scene(&self) -> Scene {
+        match self.scene {
+            Scene::Garage | Scene::Junkyard | Scene::Yard => self.scene,
+            Scene::World | Scene::Destination(_) if self.drive_session.active => Scene::World,
+            _ => Scene::Garage,
+        }
+    }
+
+    pub fn path(save_dir: &Path) -> PathBuf {
+        save_dir.join(FILE_NAME)
+    }
+
+    pub fn load(save_dir: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(Self::path(save_dir)).ok()?;
+        match serde_json::from_str(&json) {
+            Ok(qs) => Some(qs),
+            Err(e) => {
+                tracing::warn!("Ignoring invalid quick-save: {}", e);
+                None
+            }
+        }
+    }
+
+    pub fn write(&self, save_dir: &Path) {
+        let path = Self::path(save_dir);
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => match std::fs::write(&path, json) {
+                Ok(_) => tracing::info!("Quick-save written: {:?} for '{}'", self.scene, self.profile),
+                Err(e) => tracing::error!("Failed to write quick-save {}: {}", path.display(), e),
+            },
+            Err(e) => tracing::error!("Failed to serialize quick-save: {}", e),
+        }
+    }
+
+    pub fn remove(save_dir: &Path) {
+        let _ = std::fs::remove_file(Self::path(save_dir));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quick_save(scene: Scene, driving: bool) -> QuickSave {
+        QuickSave {
+            profile: "Testkind".into(),
+            scene,
+            drive_session: DriveSession { tile_col: 2, tile_row: 3, x: 100.0, y: 80.0, direction: 5, fuel: 40.0, active: driving },
+        }
+    }
+
+    #[test]
+    fn write_load_remove() {
+        let dir = std::env::temp_dir().join("openwilly_test_quicksave");
+        std::fs::create_dir_all(&dir).unwrap();
+        quick_save(Scene::Destination(86), true).write(&dir);
+        let qs = QuickSave::load(&dir).unwrap();
+        assert_eq!(qs.profile, "Testkind");
+        assert_eq!(qs.scene, Scene::Destination(86));
+        assert_eq!((qs.drive_session.tile_col, qs.drive_session.direction), (2, 5));
+        QuickSave::remove(&dir);
+        assert!(QuickSave::load(&dir).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn resume_scene() {
+        assert_eq!(quick_save(Scene::Junkyard, false).resume_scene(), Scene::Junkyard);
+        assert_eq!(quick_save(Scene::Destination(92), true).resume_scene(), Scene::World);
+        assert_eq!(quick_save(Scene::World, false).resume_scene(), Scene::Garage);
+        assert_eq!(quick_save(Scene::CarShow, true).resume_scene(), Scene::Garage);
+    }
+}
@@ -0,0 +1,298 @@
+//! Random events while driving — hitchhiker, lost item, rainbow photo op
+//!
+//! The event list (data/random_events.json, overridable via
+//! `<game_dir>/mods/random_events.json`) gives each event a spawn weight,
+//! quest flags that must (not) be set, candidate map spots and a reward.
+//! When the world map is built, a few events are picked by weight and added
+//! to their tiles as `MapObjectType::RandomEvent` objects, so the driving
+//! code detects them like any other map object. Driving into one stops the
+//! car and hands out the reward; the object then disappears.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::engine::Sprite;
+use crate::game::driving::{MapObject, MapObjectType, WorldMap};
+use crate::game::seasonal::argb_to_rgba;
+
+/// Object IDs for spawned events (clear of the objects.hash.json IDs)
+const OBJECT_ID_BASE: u32 = 900;
+/// Events sit between the under-car objects and the car
+const EVENT_Z: i32 = 999;
+
+#[derive(Debug, Clone, Deserialize)]
+struct EventDef {
+    id: String,
+    #[serde(default = "default_weight")]
+    weight: u32,
+    /// Quest flags (cache or permanent) that must be set
+    #[serde(default)]
+    requires: Vec<String>,
+    /// Quest flags that must not be set (e.g. the one the reward sets)
+    #[serde(default)]
+    excludes: Vec<String>,
+    shape: Shape,
+    #[serde(default)]
+    colors: Vec<String>,
+    /// Candidate positions: [tile id, x, y]
+    spots: Vec<(u32, i32, i32)>,
+    reward: Reward,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Shape {
+    Figure,
+    Crate,
+    Rainbow,
+}
+
+/// What the player gets
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Reward {
+    /// Part for the yard (0 = random part not owned yet)
+    pub part: Option<u32>,
+    /// Cache flag to set (cleared when leaving the yard)
+    pub cache: Option<String>,
+    /// Permanent flag to set
+    pub stuff: Option<String>,
+    pub sound: Option<String>,
+    /// Message by language code ("DE", "EN")
+    #[serde(default)]
+    pub text: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RandomEvents {
+    /// Events spawned per driving session
+    per_session: usize,
+    events: Vec<EventDef>,
+}
+
+/// An event placed on the map this session
+pub struct ActiveEvent {
+    pub object_id: u32,
+    pub tile: u32,
+    pub reward: Reward,
+    pub sprite: Sprite,
+}
+
+impl RandomEvents {
+    /// Built-in list, replaced by `<game_dir>/mods/random_events.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("random_events.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(events) => {
+                    tracing::info!("Random events loaded from {}", path.display());
+                    return events;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/random_events.json"))
+            .expect("Failed to parse random_events.json")
+    }
+
+    /// Pick this session's events (weighted, without repeats) and add them
+    /// to the world map. `has_flag` checks cache and permanent quest flags.
+    pub fn spawn(
+        &self,
+        world: &mut WorldMap,
+        has_flag: impl Fn(&str) -> bool,
+        rng: &mut impl Rng,
+    ) -> Vec<ActiveEvent> {
+        let mut candidates: Vec<&EventDef> = self.events.iter()
+            .filter(|e| e.weight > 0 && !e.spots.is_empty())
+            .filter(|e| e.requires.iter().all(|f| has_flag(f)))
+            .filter(|e| !e.excludes.iter().any(|f| has_flag(f)))
+            .filter(|e| e.spots.iter().any(|s| world.tiles.contains_key(&s.0)))
+            .collect();
+
+        let mut active = Vec::new();
+        while active.len() < self.per_session && !candidates.is_empty() {
+            let total: u32 = candidates.iter().map(|e| e.weight).sum();
+            let mut roll = rng.gen_range(0..total);
+            let idx = candidates.iter()
+                .position(|e| {
+                    if roll < e.weight {
+                        return true;
+                    }
+                    roll -= e.weight;
+                    false
+                })
+                .unwrap_or(0);
+            let def = candidates.swap_remove(idx);
+
+            let spots: Vec<_> = def.spots.iter().filter(|s| world.tiles.contains_key(&s.0)).collect();
+            let &(tile, x, y) = spots[rng.gen_range(0..spots.len())];
+            let object_id = OBJECT_ID_BASE + active.len() as u32;
+            if let Some(t) = world.tiles.get_mut(&tile) {
+                t.objects.push(event_object(object_id, x, y));
+            }
+            tracing::info!("Random event '{}' on tile {} at ({}, {})", def.id, tile, x, y);
+            active.push(ActiveEvent {
+                object_id,
+                tile,
+                reward: def.reward.clone(),
+                sprite: def.sprite(x, y),
+            });
+        }
+        active
+    }
+}
+
+fn event_object(object_id: u32, x: i32, y: i32) -> MapObject {
+    MapObject {
+        object_id,
+        x,
+        y,
+        obj_type: MapObjectType::RandomEvent,
+        inner_radius: 30.0,
+        outer_radius: 45.0,
+        dir_resource: None,
+        enabled: true,
+        check_for_cache: Vec::new(),
+        check_for_medals: Vec::new(),
+        if_found: None,
+        set_when_done: None,
+        sound_id: None,
+        approach_sound: None,
+        sprite_name: None,
+        z_under: true,
+    }
+}
+
+impl EventDef {
+    fn sprite(&self, x: i32, y: i32) -> Sprite {
+        let colors: Vec<u32> = self.colors.iter().filter_map(|c| parse_color(c)).collect();
+        let color = |i: usize, fallback: u32| colors.get(i).copied().unwrap_or(fallback);
+        let (w, h, px) = match self.shape {
+            Shape::Figure => figure(color(0, 0xFF3A86FF), color(1, 0xFFF2C49B)),
+            Shape::Crate => crate_box(color(0, 0xFF8B5A2B), color(1, 0xFF5C3A1A)),
+            Shape::Rainbow => rainbow(),
+        };
+        Sprite {
+            x: x - w as i32 / 2,
+            y: y - h as i32 / 2,
+            width: w,
+            height: h,
+            pixels: argb_to_rgba(&px).into(),
+            visible: true,
+            z_order: EVENT_Z,
+            name: format!("event:{}", self.id),
+            interactive: false,
+            member_num: 0,
+        }
+    }
+}
+
+/// "#RRGGBB" → opaque ARGB
+fn parse_color(s: &str) -> Option<u32> {
+    let hex = s.strip_prefix('#').filter(|h| h.len() == 6)?;
+    u32::from_str_radix(hex, 16).ok().map(|rgb| 0xFF00_0000 | rgb)
+}
+
+// ─── Shapes ─────────────────────────────────────────────────────────────
+
+/// Hitchhiker: head, body, legs and a raised thumb arm
+fn figure(body: u32, skin: u32) -> (u32, u32, Vec<u32>) {
+    let (w, h) = (16u32, 30u32);
+    let mut px = vec![0u32; (w * h) as usize];
+    let mut fill = |x0: u32, y0: u32, x1: u32, y1: u32, c: u32| {
+        for y in y0..y1 {
+            for x in x0..x1 {
+                px[(y * w + x) as usize] = c;
+            }
+        }
+    };
+    fill(5, 0, 10, 6, skin); // head
+    fill(4, 6, 11, 18, body); // body
+    fill(11, 4, 13, 10, body); // raised arm
+    fill(11, 2, 14, 4, skin); // thumb
+    fill(4, 18, 7, 30, 0xFF2A2A3A); // legs
+    fill(8, 18, 11, 30, 0xFF2A2A3A);
+    (w, h, px)
+}
+
+/// Lost item: a wooden crate with planks
+fn crate_box(wood: u32, dark: u32) -> (u32, u32, Vec<u32>) {
+    let (w, h) = (20u32, 16u32);
+    let px = (0..w * h)
+        .map(|i| {
+            let (x, y) = (i % w, i / w);
+            let edge = x == 0 || y == 0 || x == w - 1 || y == h - 1;
+            if edge || y == h / 2 || x == y + 2 { dark } else { wood }
+        })
+        .collect();
+    (w, h, px)
+}
+
+/// Rainbow: concentric half rings
+fn rainbow() -> (u32, u32, Vec<u32>) {
+    const BANDS: [u32; 6] = [0xFFE53935, 0xFFFB8C00, 0xFFFDD835, 0xFF43A047, 0xFF1E88E5, 0xFF8E24AA];
+    let (w, h) = (56u32, 28u32);
+    let mut px = vec![0u32; (w * h) as usize];
+    let (cx, cy) = (w as f32 / 2.0, h as f32);
+    for y in 0..h {
+        for x in 0..w {
+            let d = ((x as f32 + 0.5 - cx).powi(2) + (y as f32 + 0.5 - cy).powi(2)).sqrt();
+            let band = (28.0 - d) / 3.0;
+            if (0.0..BANDS.len() as f32).contains(&band) {
+                px[(y * w + x) as usize] = BANDS[band as usize];
+            }
+        }
+    }
+    (w, h, px)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn spawn_respects_flags_and_session_size() {
+        let events = RandomEvents::builtin();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let mut world = WorldMap::default_map();
+        let active = events.spawn(&mut world, |_| false, &mut rng);
+        assert_eq!(active.len(), 2);
+        assert_ne!(active[0].sprite.name, active[1].sprite.name);
+        for ev in &active {
+            let tile = &world.tiles[&ev.tile];
+            let obj = tile.objects.iter().find(|o| o.object_id == ev.object_id).unwrap();
+            assert_eq!(obj.obj_type, MapObjectType::RandomEvent);
+        }
+
+        // Salka on the back seat: no hitchhiker; everything done: nothing spawns
+        let mut world = WorldMap::default_map();
+        for _ in 0..20 {
+            let active = events.spawn(&mut world, |f| f == "#Dog", &mut rng);
+            assert!(active.iter().all(|e| e.sprite.name != "event:hitchhiker"));
+        }
+        let done = ["#Hitchhiker", "#LostItemFound", "#RainbowPhoto"];
+        assert!(events.spawn(&mut world, |f| done.contains(&f), &mut rng).is_empty());
+    }
+
+    #[test]
+    fn shapes_match_their_size() {
+        for (w, h, px) in [figure(1, 2), crate_box(1, 2), rainbow()] {
+            assert_eq!(px.len(), (w * h) as usize);
+            assert!(px.iter().any(|&p| p != 0));
+        }
+    }
+}
@@ -13,6 +13,11 @@ use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::game::horn::HornChoice;
+use crate::game::save_guard;
+use crate::game::seasonal::Date;
+use crate::game::stats::ProfileStats;
+
 // ---------------------------------------------------------------------------
 // Save data structures
 // ---------------------------------------------------------------------------
@@ -41,9 +46,27 @@ pub struct UserSave {
     /// Active/given missions
     #[serde(default)]
     pub given_missions: Vec<String>,
+    /// Missions delivered (phone call / mail) but not completed yet
+    #[serde(default)]
+    pub active_missions: Vec<String>,
+    /// Completed missions whose ceremony in the yard hasn't played yet
+    #[serde(default)]
+    pub mission_ceremonies: Vec<u32>,
     /// Last visited junk pile (1-6)
     #[serde(default = "default_pile")]
     pub my_last_pile: u8,
+    /// Cars shown at the car show (Car Gallery)
+    #[serde(default)]
+    pub gallery: Vec<GalleryEntry>,
+    /// Selected horn sound (garage horn panel)
+    #[serde(default)]
+    pub horn: HornChoice,
+    /// Player's birthday (month/day) for the seasonal party hat
+    #[serde(default)]
+    pub birthday: Option<Date>,
+    /// Play statistics (km driven, parts collected, …)
+    #[serde(default)]
+    pub stats: ProfileStats,
 }
 
 fn default_pile() -> u8 {
@@ -77,6 +100,21 @@ impl Default for CarSave {
     }
 }
 
+/// A car entered into the Car Gallery after a car show visit
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GalleryEntry {
+    /// Car name (a car shown again after rebuilding gets another card with
+    /// the same name)
+    pub name: String,
+    /// Part IDs at the time of the show
+    pub parts: Vec<u32>,
+    /// Car show rating (1–5)
+    pub rating: u8,
+    /// Medals earned at the time of the show
+    #[serde(default)]
+    pub medals: Vec<String>,
+}
+
 /// Junk pile saved positions: pile_name → { part_id → (x, y) }
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct JunkSave {
@@ -193,7 +231,13 @@ impl UserSave {
             completed_missions: Vec::new(),
             own_stuff: Vec::new(),
             given_missions: Vec::new(),
+            active_missions: Vec::new(),
+            mission_ceremonies: Vec::new(),
             my_last_pile: 1,
+            gallery: Vec::new(),
+            horn: HornChoice::Parts,
+            birthday: None,
+            stats: ProfileStats::default(),
         }
     }
 }
@@ -216,16 +260,25 @@ impl SaveManager {
     /// Create a new SaveManager, loading from disk if the file exists
     pub fn new(save_dir: &Path) -> Self {
         let save_path = save_dir.join("openwilly_save.json");
+        let mut repaired = false;
 
         let users_db = if save_path.exists() {
             match std::fs::read_to_string(&save_path) {
                 Ok(json) => match serde_json::from_str::<UsersDB>(&json) {
-                    Ok(db) => {
+                    Ok(mut db) => {
                         tracing::info!(
                             "Loaded {} user profile(s) from {}",
                             db.users.len(),
                             save_path.display()
                         );
+                        repaired = Self::check_loaded(&json, &mut db);
+                        if repaired {
+                            let backup = save_dir.join("openwilly_save.bak.json");
+                            match std::fs::copy(&save_path, &backup) {
+                                Ok(_) => tracing::info!("Save repaired, original kept as {}", backup.display()),
+                                Err(e) => tracing::warn!("Failed to back up save file: {}", e),
+                            }
+                        }
                         db
                     }
                     Err(e) => {
@@ -243,15 +296,61 @@ impl SaveManager {
             UsersDB::default()
         };
 
-        Self {
+        let mut manager = Self {
             save_path,
             users_db,
             active_user: None,
+        };
+        if repaired {
+            manager.save();
         }
+        manager
     }
 
-    /// Write all profiles to disk
-    pub fn save(&self) {
+    /// Demo playback: continue from the profiles the demo was recorded
+    /// with. Saves go to `demo_playback/` next to the save file, the
+    /// player's own profiles stay untouched.
+    pub fn load_for_playback(&mut self, users_db: UsersDB) {
+        if let Some(dir) = self.save_path.parent() {
+            self.save_path = dir.join("demo_playback").join("openwilly_save.json");
+        }
+        self.users_db = users_db;
+        self.active_user = None;
+        tracing::info!("Demo profiles loaded, saving to {}", self.save_path.display());
+    }
+
+    /// Guard rails on a freshly loaded save (see `save_guard`), returns
+    /// whether the file on disk needs rewriting
+    fn check_loaded(json: &str, db: &mut UsersDB) -> bool {
+        let mut repaired = false;
+        let raw: serde_json::Value = serde_json::from_str(json).unwrap_or_default();
+        if let Ok(typed) = serde_json::to_value(&*db) {
+            let unknown = save_guard::unknown_fields(&raw, &typed);
+            if !unknown.is_empty() {
+                tracing::warn!("Save file: unknown fields dropped: {}", unknown.join(", "));
+                repaired = true;
+            }
+        }
+        for (name, user) in &mut db.users {
+            for fix in save_guard::sanitize(user) {
+                tracing::warn!("Profile '{}': {}", name, fix);
+                repaired = true;
+            }
+            let size = save_guard::profile_size(user);
+            if size > save_guard::MAX_PROFILE_BYTES {
+                tracing::warn!("Profile '{}' is unusually large ({} KB)", name, size / 1024);
+            }
+        }
+        repaired
+    }
+
+    /// Write all profiles to disk (the active one checked first)
+    pub fn save(&mut self) {
+        if let Some(user) = self.active_mut() {
+            for fix in save_guard::sanitize(user) {
+                tracing::warn!("Profile '{}': {}", user.user_id, fix);
+            }
+        }
         match serde_json::to_string_pretty(&self.users_db) {
             Ok(json) => {
                 if let Some(parent) = self.save_path.parent() {
@@ -336,6 +435,50 @@ impl SaveManager {
         self.save();
     }
 
+    /// Store the selected horn sound
+    pub fn save_horn(&mut self, horn: HornChoice) {
+        if let Some(user) = self.active_mut() {
+            user.horn = horn;
+        }
+        self.save();
+    }
+
+    /// Statistics of the active profile (written with the next save)
+    pub fn stats_mut(&mut self) -> Option<&mut ProfileStats> {
+        self.active_mut().map(|u| &mut u.stats)
+    }
+
+    /// Store the player's birthday
+    pub fn save_birthday(&mut self, birthday: Date) {
+        if let Some(user) = self.active_mut() {
+            user.birthday = Some(birthday);
+        }
+        self.save();
+    }
+
+    /// Names of the gallery cars in the active profile, except `exclude`
+    /// (the car currently being renamed).
+    pub fn gallery_names_except(&self, exclude: &str) -> Vec<String> {
+        self.active()
+            .map(|u| u.gallery.iter()
+                .filter(|e| e.name != exclude)
+                .map(|e| e.name.clone())
+                .collect())
+            .unwrap_or_default()
+    }
+
+    /// Add a car show card to the gallery. Showing the same car unchanged
+    /// (same name and parts) again updates its card instead.
+    pub fn save_gallery_entry(&mut self, entry: GalleryEntry) {
+        if let Some(user) = self.active_mut() {
+            match user.gallery.iter_mut().find(|e| e.name == entry.name && e.parts == entry.parts) {
+                Some(existing) => *existing = entry,
+                None => user.gallery.push(entry),
+            }
+        }
+        self.save();
+    }
+
     /// Save a junk pile's contents
     pub fn save_pile(&mut self, pile_index: u8, parts: &HashMap<u32, (i32, i32)>) {
         if let Some(user) = self.active_mut() {
@@ -360,22 +503,42 @@ impl SaveManager {
         self.save();
     }
 
-    /// Mark a mission as completed
-    #[allow(dead_code)] // Used by mission delivery system (upcoming)
-    pub fn complete_mission(&mut self, mission_id: &str) {
-        if let Some(user) = self.active_mut() {
-            if !user.completed_missions.contains(&mission_id.to_string()) {
-                user.completed_missions.push(mission_id.to_string());
-            }
+    /// Mark a mission as completed and queue its ceremony in the yard.
+    /// Returns false if it was already completed.
+    pub fn complete_mission(&mut self, mission_id: u32) -> bool {
+        let mid = mission_id.to_string();
+        let Some(user) = self.active_mut() else { return false };
+        if user.completed_missions.contains(&mid) {
+            return false;
         }
+        user.given_missions.retain(|m| m != &mid);
+        user.active_missions.retain(|m| m != &mid);
+        user.completed_missions.push(mid);
+        user.mission_ceremonies.push(mission_id);
+        tracing::info!("Mission {} completed", mission_id);
         self.save();
+        true
+    }
+
+    /// Take the completed missions waiting for their yard ceremony
+    pub fn take_mission_ceremonies(&mut self) -> Vec<u32> {
+        let ceremonies = self.active_mut()
+            .map(|u| std::mem::take(&mut u.mission_ceremonies))
+            .unwrap_or_default();
+        if !ceremonies.is_empty() {
+            self.save();
+        }
+        ceremonies
     }
 
     /// Give a mission (add to given_missions if not already given or completed)
     pub fn give_mission(&mut self, mission_id: u32) {
         let mid = mission_id.to_string();
         if let Some(user) = self.active_mut() {
-            if !user.given_missions.contains(&mid) && !user.completed_missions.contains(&mid) {
+            if !user.given_missions.contains(&mid)
+                && !user.active_missions.contains(&mid)
+                && !user.completed_missions.contains(&mid)
+            {
                 user.given_missions.push(mid.clone());
                 tracing::info!("Mission {} added to given_missions", mid);
             }
@@ -388,17 +551,17 @@ impl SaveManager {
         self.active().map(|u| !u.given_missions.is_empty()).unwrap_or(false)
     }
 
-    /// Get a pending mission ID and remove it from given_missions
+    /// Get a pending mission ID and move it from given_missions to the
+    /// active (delivered, not yet completed) missions
     pub fn pop_pending_mission(&mut self) -> Option<u32> {
         let mid = self.active_mut().and_then(|u| {
             if u.given_missions.is_empty() { None }
             else { Some(u.given_missions.remove(0)) }
         });
         if let Some(ref m) = mid {
-            // Move to completed
             if let Some(user) = self.active_mut() {
-                if !user.completed_missions.contains(m) {
-                    user.completed_missions.push(m.clone());
+                if !user.active_missions.contains(m) {
+                    user.active_missions.push(m.clone());
                 }
             }
             self.save();
@@ -445,6 +608,7 @@ impl SaveManager {
             let x = 100 + (user.junk.yard.len() as i32 % 5) * 80;
             let y = 200 + (user.junk.yard.len() as i32 / 5) * 60;
             user.junk.yard.insert(part_id, (x, y));
+            user.stats.parts_collected += 1;
             tracing::info!("Added part {} to yard inventory", part_id);
         }
         self.save();
@@ -457,7 +621,7 @@ impl SaveManager {
 
     /// Get a random part that isn't already owned (in piles, yard, car, or shop).
     /// Used by SetWhenDone #Random rewards (mulle.js savedata.js getRandomPart).
-    pub fn random_unowned_part(&self) -> Option<u32> {
+    pub fn random_unowned_part(&self, rng: &mut impl rand::Rng) -> Option<u32> {
         use crate::game::parts_db::PartsDB;
         use rand::seq::SliceRandom;
 
@@ -485,8 +649,7 @@ impl SaveManager {
             .filter(|&&id| !owned.contains(&id))
             .copied()
             .collect();
-        let mut rng = rand::thread_rng();
-        available.choose(&mut rng).copied()
+        available.choose(rng).copied()
     }
 }
 
@@ -529,7 +692,7 @@ mod tests {
             mgr.login("Alice");
             mgr.save_car_parts(&[1, 82, 133, 152, 60, 61]);
             mgr.save_car_name("Rusty");
-            mgr.complete_mission("mission_01");
+            mgr.complete_mission(1);
             mgr.add_stuff("#GotDogOnce");
         }
 
@@ -540,7 +703,8 @@ mod tests {
             let alice = mgr.users_db.users.get("Alice").unwrap();
             assert_eq!(alice.car.parts, vec![1, 82, 133, 152, 60, 61]);
             assert_eq!(alice.car.name, "Rusty");
-            assert!(alice.completed_missions.contains(&"mission_01".to_string()));
+            assert!(alice.completed_missions.contains(&"1".to_string()));
+            assert_eq!(alice.mission_ceremonies, vec![1]);
             assert!(alice.own_stuff.contains(&"#GotDogOnce".to_string()));
         }
 
@@ -575,6 +739,60 @@ mod tests {
         cleanup(&dir);
     }
 
+    #[test]
+    fn mission_lifecycle() {
+        let dir = temp_save_dir().join("missions");
+        cleanup(&dir);
+
+        let mut mgr = SaveManager::new(&dir);
+        mgr.login("Alice");
+        mgr.give_mission(4);
+        assert_eq!(mgr.pop_pending_mission(), Some(4));
+        let alice = mgr.active().unwrap();
+        assert_eq!(alice.active_missions, vec!["4".to_string()]);
+        assert!(alice.completed_missions.is_empty());
+
+        // Delivered missions aren't handed out again
+        mgr.give_mission(4);
+        assert!(!mgr.has_pending_missions());
+
+        assert!(mgr.complete_mission(4));
+        assert!(!mgr.complete_mission(4));
+        assert!(mgr.active().unwrap().active_missions.is_empty());
+        assert_eq!(mgr.take_mission_ceremonies(), vec![4]);
+        assert!(mgr.take_mission_ceremonies().is_empty());
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn gallery_card_per_show() {
+        let dir = temp_save_dir().join("gallery");
+        cleanup(&dir);
+
+        let mut mgr = SaveManager::new(&dir);
+        mgr.login("Alice");
+        let entry = |name: &str, parts: &[u32], rating: u8| GalleryEntry {
+            name: name.to_string(),
+            parts: parts.to_vec(),
+            rating,
+            medals: Vec::new(),
+        };
+        mgr.save_gallery_entry(entry("Blitz", &[1, 82], 3));
+        mgr.save_gallery_entry(entry("Hummel", &[1, 82], 2));
+        // Rebuilt and renamed: the earlier cards stay
+        mgr.save_gallery_entry(entry("Blitz", &[1, 82, 133], 4));
+        mgr.save_gallery_entry(entry("Sausewind", &[1, 82, 133], 4));
+        // Shown again unchanged: the card is updated
+        mgr.save_gallery_entry(entry("Blitz", &[1, 82], 5));
+
+        assert_eq!(mgr.gallery_names_except(""), vec!["Blitz", "Hummel", "Blitz", "Sausewind"]);
+        assert_eq!(mgr.gallery_names_except("Blitz"), vec!["Hummel", "Sausewind"]);
+        assert_eq!(mgr.active().unwrap().gallery[0].rating, 5);
+
+        cleanup(&dir);
+    }
+
     #[test]
     fn car_save_default() {
         let car = CarSave::default();
@@ -0,0 +1,150 @@
+//! Save guard rails — keep long-running profiles small and consistent
+//!
+//! A profile played for years must not grow without bound: a part sitting
+//! in two places at once (on the car and in a pile, in two piles) gets
+//! duplicated on every visit, flags and mission lists collect repeats.
+//! `sanitize` runs on every profile at load and on the active one at every
+//! save: it removes duplicates (parts in the dev menu's parts report order:
+//! car, shop floor, yard, piles 1–6) and caps each collection.
+//!
+//! Fields the current version doesn't know (an older or newer OpenWilly,
+//! a hand-edited file) are dropped with the next save; before a save drops
+//! anything the original file is kept as `openwilly_save.bak.json`.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use serde_json::Value;
+
+use crate::game::parts_audit;
+use crate::game::save::UserSave;
+
+/// Far more than any car can carry
+pub const MAX_CAR_PARTS: usize = 64;
+/// Parts per location (pile, shop floor, yard) — the game has ~300 parts
+pub const MAX_LOCATION_PARTS: usize = 320;
+/// Story flags and medals per list
+pub const MAX_FLAGS: usize = 512;
+pub const MAX_MISSIONS: usize = 256;
+pub const MAX_GALLERY: usize = 500;
+/// A profile this large (serialized) is reported at load
+pub const MAX_PROFILE_BYTES: usize = 256 * 1024;
+
+/// Remove repeats, keep the first occurrence
+fn dedupe<T: Eq + Hash + Clone>(list: &mut Vec<T>) -> usize {
+    let before = list.len();
+    let mut seen = HashSet::new();
+    list.retain(|item| seen.insert(item.clone()));
+    before - list.len()
+}
+
+/// Deduplicate and cap a list, noting what was done
+fn tidy<T: Eq + Hash + Clone>(list: &mut Vec<T>, max: usize, what: &str, fixes: &mut Vec<String>) {
+    let repeats = dedupe(list);
+    if repeats > 0 {
+        fixes.push(format!("{}: {} duplicates removed", what, repeats));
+    }
+    if list.len() > max {
+        fixes.push(format!("{}: {} entries over the limit of {} dropped", what, list.len() - max, max));
+        list.truncate(max);
+    }
+}
+
+/// Fix duplicates and oversized collections, returns what was changed
+pub fn sanitize(user: &mut UserSave) -> Vec<String> {
+    let mut fixes = Vec::new();
+    tidy(&mut user.car.parts, MAX_CAR_PARTS, "car parts", &mut fixes);
+    tidy(&mut user.car.medals, MAX_FLAGS, "medals", &mut fixes);
+    tidy(&mut user.car.cache_list, MAX_FLAGS, "car flags", &mut fixes);
+    tidy(&mut user.own_stuff, MAX_FLAGS, "items", &mut fixes);
+    tidy(&mut user.completed_missions, MAX_MISSIONS, "completed missions", &mut fixes);
+    tidy(&mut user.given_missions, MAX_MISSIONS, "given missions", &mut fixes);
+    tidy(&mut user.active_missions, MAX_MISSIONS, "active missions", &mut fixes);
+    tidy(&mut user.mission_ceremonies, MAX_MISSIONS, "mission ceremonies", &mut fixes);
+
+    // One card per show; only the very same car (name and parts) twice is a repeat
+    let mut cards = HashSet::new();
+    let cars = user.gallery.len();
+    user.gallery.retain(|entry| cards.insert((entry.name.clone(), entry.parts.clone())));
+    if user.gallery.len() < cars {
+        fixes.push(format!("gallery: {} repeated cars removed", cars - user.gallery.len()));
+    }
+    if user.gallery.len() > MAX_GALLERY {
+        fixes.push(format!("gallery: {} cars over the limit of {} dropped", user.gallery.len() - MAX_GALLERY, MAX_GALLERY));
+        user.gallery.truncate(MAX_GALLERY);
+    }
+
+    // Every part in one place, the same way the parts report's fix does it
+    for (loc, removed) in parts_audit::dedupe(user) {
+        fixes.push(format!("{}: {} parts that are elsewhere removed", loc.label(), removed));
+    }
+    let junk = &mut user.junk;
+    let locations = [
+        ("shop floor", &mut junk.shop_floor), ("yard", &mut junk.yard),
+        ("pile 1", &mut junk.pile1), ("pile 2", &mut junk.pile2), ("pile 3", &mut junk.pile3),
+        ("pile 4", &mut junk.pile4), ("pile 5", &mut junk.pile5), ("pile 6", &mut junk.pile6),
+    ];
+    for (what, parts) in locations {
+        if parts.len() > MAX_LOCATION_PARTS {
+            let mut ids: Vec<u32> = parts.keys().copied().collect();
+            ids.sort_unstable();
+            for id in &ids[MAX_LOCATION_PARTS..] {
+                parts.remove(id);
+            }
+            fixes.push(format!("{}: {} parts over the limit of {} dropped", what, ids.len() - MAX_LOCATION_PARTS, MAX_LOCATION_PARTS));
+        }
+    }
+    fixes
+}
+
+/// Serialized size of a profile in bytes
+pub fn profile_size(user: &UserSave) -> usize {
+    serde_json::to_vec(user).map(|json| json.len()).unwrap_or(0)
+}
+
+/// Keys in the file that the typed data doesn't have (dotted paths)
+pub fn unknown_fields(raw: &Value, typed: &Value) -> Vec<String> {
+    let mut unknown = Vec::new();
+    collect_unknown(raw, typed, "", &mut unknown);
+    unknown
+}
+
+fn collect_unknown(raw: &Value, typed: &Value, path: &str, unknown: &mut Vec<String>) {
+    let (Value::Object(raw), Value::Object(typed)) = (raw, typed) else { return };
+    for (key, value) in raw {
+        let key_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+        match typed.get(key) {
+            Some(known) => collect_unknown(value, known, &key_path, unknown),
+            None => unknown.push(key_path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates_removed_and_unknown_fields_found() {
+        let mut user = UserSave::new("Kim");
+        user.car.parts.extend([82, 66, 66]);
+        user.own_stuff = vec!["#Dog".into(), "#Dog".into()];
+        user.junk.pile2.insert(66, (1, 2)); // also pile 1 and now on the car
+        user.junk.yard.insert(215, (3, 4)); // also pile 2
+
+        let fixes = sanitize(&mut user);
+        assert_eq!(user.car.parts, vec![1, 82, 133, 152, 66]);
+        assert_eq!(user.own_stuff, vec!["#Dog".to_string()]);
+        assert!(!user.junk.pile1.contains_key(&66) && !user.junk.pile2.contains_key(&66));
+        // The yard comes before the piles
+        assert!(!user.junk.pile2.contains_key(&215) && user.junk.yard.contains_key(&215));
+        assert_eq!(fixes.len(), 4, "{:?}", fixes);
+        assert!(sanitize(&mut user).is_empty());
+
+        let typed = serde_json::to_value(&user).unwrap();
+        let mut raw = typed.clone();
+        raw["turbo"] = Value::Bool(true);
+        raw["car"]["paint"] = Value::from("red");
+        assert_eq!(unknown_fields(&raw, &typed), vec!["car.paint".to_string(), "turbo".to_string()]);
+    }
+}
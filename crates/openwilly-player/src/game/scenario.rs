@@ -0,0 +1,472 @@
+//! Scenario tests — recorded input scripts replayed against a headless game
+//!
+//! Each `tests/scenarios/*.scn` file is a list of steps, one per line
+//! (lines starting with `#` are comments). Input steps go through the same entry points the
+//! engine loop uses (on_click, on_char_input, on_key_down, update); `place`
+//! and `hold` drive the car through the real driving update. Where a step
+//! would need the real game data (dragging a part onto the car, a long trip
+//! across the map) the script triggers the resulting game event instead:
+//!
+//! ```text
+//! goto Garage | goto Destination 85   warp like the dev menu does
+//! click X Y | rclick X Y              mouse click (press + release)
+//! type TEXT                           characters (name field, car name dialog)
+//! key NAME                            Enter, Escape, Space, Backspace, F2, H
+//! frames N                            run N engine frames (update + draw)
+//! place COL ROW X Y                   put the driving car at X Y on map tile COL ROW
+//! hold KEYS N                         N frames with arrow keys held (up,left …)
+//! attach PART [MORPH]                 a drag & drop that attached PART (as MORPH)
+//! event big_hill|far_away|race_finished
+//! save | reload                       flush the save / restart the game
+//! expect scene S | road_legal BOOL | part N | medals N… | stat NAME N | flag F
+//! expect dialog AUDIO_ID              the dialog playing right now
+//! ```
+//!
+//! Every run starts from an empty game directory without sound or cast
+//! data, so the scripts check game logic and save state, not graphics.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use minifb::Key;
+
+use crate::game::dev_menu::DevAction;
+use crate::game::{drag_drop, driving, GameState, Scene};
+
+/// One parsed script line
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Goto(Scene),
+    Click { x: i32, y: i32, right: bool },
+    Type(String),
+    Key(Key),
+    Frames(u32),
+    Place { col: usize, row: usize, x: f32, y: f32 },
+    Hold { keys: DriveKeys, frames: u32 },
+    Attach { part_id: u32, morph_id: Option<u32> },
+    Event(GameEvent),
+    Save,
+    Reload,
+    Expect(Expect),
+}
+
+/// Arrow keys held while driving
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct DriveKeys {
+    up: bool,
+    down: bool,
+    left: bool,
+    right: bool,
+}
+
+fn parse_drive_keys(list: &str) -> Result<DriveKeys> {
+    let mut keys = DriveKeys::default();
+    for name in list.split(',') {
+        match name {
+            "up" => keys.up = true,
+            "down" => keys.down = true,
+            "left" => keys.left = true,
+            "right" => keys.right = true,
+            other => bail!("unknown drive key '{}'", other),
+        }
+    }
+    Ok(keys)
+}
+
+/// Driving events a script can trigger
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GameEvent {
+    BigHill,
+    FarAway,
+    RaceFinished,
+}
+
+impl GameEvent {
+    fn drive_event(self) -> driving::DriveEvent {
+        match self {
+            GameEvent::BigHill => driving::DriveEvent::HillSound { big: true },
+            GameEvent::FarAway => driving::DriveEvent::FarAwayReached { object_id: 0 },
+            GameEvent::RaceFinished => driving::DriveEvent::RaceFinished { time_secs: 60.0 },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expect {
+    Scene(String),
+    RoadLegal(bool),
+    Part(u32),
+    Medals(Vec<String>),
+    Stat(String, u64),
+    Flag(String),
+    Dialog(String),
+}
+
+fn parse_scene(name: &str, arg: Option<&str>) -> Result<Scene> {
+    Ok(match name {
+        "Menu" => Scene::Menu,
+        "Garage" => Scene::Garage,
+        "Junkyard" => Scene::Junkyard,
+        "Yard" => Scene::Yard,
+        "World" => Scene::World,
+        "CarGallery" => Scene::CarGallery,
+        "CarShow" => Scene::CarShow,
+        "Destination" => {
+            let n: u8 = arg.context("Destination needs a number")?.parse()?;
+            ensure!((82..=94).contains(&n), "no destination {}", n);
+            Scene::Destination(n)
+        }
+        other => bail!("unknown scene '{}'", other),
+    })
+}
+
+fn parse_key(name: &str) -> Result<Key> {
+    Ok(match name {
+        "Enter" => Key::Enter,
+        "Escape" => Key::Escape,
+        "Space" => Key::Space,
+        "Backspace" => Key::Backspace,
+        "F2" => Key::F2,
+        "H" => Key::H,
+        other => bail!("unknown key '{}'", other),
+    })
+}
+
+fn parse_event(name: &str) -> Result<GameEvent> {
+    Ok(match name {
+        "big_hill" => GameEvent::BigHill,
+        "far_away" => GameEvent::FarAway,
+        "race_finished" => GameEvent::RaceFinished,
+        other => bail!("unknown event '{}'", other),
+    })
+}
+
+fn parse_line(line: &str) -> Result<Option<Step>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    let (cmd, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let args: Vec<&str> = rest.split_whitespace().collect();
+    let num = |i: usize| -> Result<i64> {
+        args.get(i).with_context(|| format!("'{}' needs argument {}", cmd, i + 1))?
+            .parse::<i64>()
+            .map_err(Into::into)
+    };
+    let step = match cmd {
+        "goto" => Step::Goto(parse_scene(args.first().copied().unwrap_or(""), args.get(1).copied())?),
+        "click" | "rclick" => Step::Click { x: num(0)? as i32, y: num(1)? as i32, right: cmd == "rclick" },
+        "type" => Step::Type(rest.to_string()),
+        "key" => Step::Key(parse_key(rest)?),
+        "frames" => Step::Frames(num(0)? as u32),
+        "place" => Step::Place { col: num(0)? as usize, row: num(1)? as usize, x: num(2)? as f32, y: num(3)? as f32 },
+        "hold" => Step::Hold {
+            keys: parse_drive_keys(args.first().copied().unwrap_or(""))?,
+            frames: num(1)? as u32,
+        },
+        "attach" => Step::Attach {
+            part_id: num(0)? as u32,
+            morph_id: if args.len() > 1 { Some(num(1)? as u32) } else { None },
+        },
+        "event" => Step::Event(parse_event(rest)?),
+        "save" => Step::Save,
+        "reload" => Step::Reload,
+        "expect" => Step::Expect(match args.first().copied() {
+            Some("scene") => Expect::Scene(args[1..].join(" ")),
+            Some("road_legal") => Expect::RoadLegal(args.get(1).context("expected true/false")?.parse()?),
+            Some("part") => Expect::Part(num(1)? as u32),
+            Some("medals") => Expect::Medals(args[1..].iter().map(|s| s.to_string()).collect()),
+            Some("stat") => Expect::Stat(
+                args.get(1).context("stat needs a name")?.to_string(),
+                num(2)? as u64,
+            ),
+            Some("flag") => Expect::Flag(args.get(1).context("flag needs a name")?.to_string()),
+            Some("dialog") => Expect::Dialog(args.get(1).context("dialog needs an audio id")?.to_string()),
+            other => bail!("unknown expectation {:?}", other),
+        }),
+        other => bail!("unknown command '{}'", other),
+    };
+    Ok(Some(step))
+}
+
+/// Parse a whole script; errors carry the line number
+fn parse(script: &str) -> Result<Vec<(usize, Step)>> {
+    let mut steps = Vec::new();
+    for (i, line) in script.lines().enumerate() {
+        if let Some(step) = parse_line(line).with_context(|| format!("line {}", i + 1))? {
+            steps.push((i + 1, step));
+        }
+    }
+    Ok(steps)
+}
+
+// ─── Runner ─────────────────────────────────────────────────────────────
+
+struct Runner {
+    game: GameState,
+    dir: PathBuf,
+    fb: Vec<u32>,
+}
+
+impl Runner {
+    fn new(dir: &Path) -> Self {
+        Self {
+            game: GameState::headless(dir),
+            dir: dir.to_path_buf(),
+            fb: vec![0u32; crate::engine::SCREEN_WIDTH * crate::engine::SCREEN_HEIGHT],
+        }
+    }
+
+    /// One engine frame, as in the main loop
+    fn frame(&mut self, keys: DriveKeys) {
+        let (mx, my) = (self.game.mouse_x, self.game.mouse_y);
+        self.game.on_mouse_state(mx, my, false);
+        self.game.update_drive_keys(keys.up, keys.down, keys.left, keys.right);
+        self.game.update();
+        let _ = self.game.get_all_sprites();
+        self.game.draw_ui(&mut self.fb);
+    }
+
+    fn run(&mut self, step: &Step) -> Result<()> {
+        match step {
+            Step::Goto(scene) => self.game.handle_dev_action(DevAction::GotoScene(*scene)),
+            Step::Click { x, y, right } => {
+                self.game.on_mouse_state(*x, *y, !right);
+                if *right {
+                    self.game.on_right_click(*x, *y);
+                } else {
+                    self.game.on_click(*x, *y);
+                }
+                self.game.on_mouse_state(*x, *y, false);
+            }
+            Step::Type(text) => text.chars().for_each(|ch| self.game.on_char_input(ch)),
+            Step::Key(key) => self.game.on_key_down(*key),
+            Step::Frames(n) => (0..*n).for_each(|_| self.frame(DriveKeys::default())),
+            Step::Place { col, row, x, y } => {
+                let car = self.game.drive_car.as_mut().context("not driving")?;
+                car.tile_col = *col;
+                car.tile_row = *row;
+                car.x = *x;
+                car.y = *y;
+            }
+            Step::Hold { keys, frames } => (0..*frames).for_each(|_| self.frame(*keys)),
+            Step::Attach { part_id, morph_id } => self.game.handle_drop_result(drag_drop::DropResult::Attached {
+                part_id: *part_id,
+                morph_id: *morph_id,
+                point_id: String::new(),
+            }),
+            Step::Event(event) => self.game.on_drive_event(event.drive_event()),
+            Step::Save => {
+                let parts = self.game.car.parts.clone();
+                self.game.save_manager.save_car_parts(&parts);
+                self.game.save_quest_state();
+                self.game.save_manager.save();
+            }
+            Step::Reload => self.game = GameState::headless(&self.dir),
+            Step::Expect(expect) => self.check(expect)?,
+        }
+        Ok(())
+    }
+
+    fn check(&self, expect: &Expect) -> Result<()> {
+        let game = &self.game;
+        let user = game.save_manager.active();
+        match expect {
+            Expect::Scene(name) => {
+                let scene = format!("{:?}", game.current_scene);
+                ensure!(&scene == name, "scene is {}, expected {}", scene, name);
+            }
+            Expect::RoadLegal(want) => {
+                let legal = game.car.is_road_legal();
+                ensure!(legal == *want, "road legal = {} ({:?})",
+                    legal, game.car.properties().road_legal_failures());
+            }
+            Expect::Part(id) => {
+                ensure!(game.car.parts.contains(id), "part {} missing: {:?}", id, game.car.parts);
+            }
+            Expect::Medals(want) => {
+                let mut medals = user.context("no profile")?.car.medals.clone();
+                medals.sort();
+                ensure!(&medals == want, "medals {:?}, expected {:?}", medals, want);
+            }
+            Expect::Stat(name, want) => {
+                let stats = &user.context("no profile")?.stats;
+                let value = match name.as_str() {
+                    "destinations" => stats.destinations.len() as u64,
+                    "parts_collected" => stats.parts_collected as u64,
+                    "races_won" => stats.races_won as u64,
+                    other => bail!("unknown stat '{}'", other),
+                };
+                ensure!(value == *want, "stat {} = {}, expected {}", name, value, want);
+            }
+            Expect::Flag(flag) => {
+                ensure!(game.quest.has_cache(flag) || game.quest.has_permanent(flag),
+                    "flag {} not set", flag);
+            }
+            Expect::Dialog(audio_id) => {
+                let playing = game.dialog.active_dialog.as_ref().map(|d| d.audio_id.as_str());
+                ensure!(playing == Some(audio_id.as_str()), "dialog {:?}, expected {}", playing, audio_id);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run `tests/scenarios/<name>` in a fresh game directory
+fn run_scenario(name: &str) -> Result<()> {
+    let path = scenario_dir().join(name);
+    let script = std::fs::read_to_string(&path)
+        .with_context(|| format!("Cannot read {}", path.display()))?;
+    let steps = parse(&script).with_context(|| name.to_string())?;
+
+    let dir = std::env::temp_dir()
+        .join(format!("openwilly_scn_{}_{}", std::process::id(), name.trim_end_matches(".scn")));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let mut runner = Runner::new(&dir);
+    let result = steps.iter().try_for_each(|(line, step)| {
+        runner.run(step).with_context(|| format!("{}:{}: {:?}", name, line, step))
+    });
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+fn scenario_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("scenarios")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_steps() {
+        let steps = parse("# comment\ngoto Destination 85\n\nclick 10 20\nexpect medals 1 5\n").unwrap();
+        assert_eq!(steps, vec![
+            (2, Step::Goto(Scene::Destination(85))),
+            (4, Step::Click { x: 10, y: 20, right: false }),
+            (5, Step::Expect(Expect::Medals(vec!["1".into(), "5".into()]))),
+        ]);
+        assert!(parse("goto Destination 12").is_err());
+        assert!(parse("jump 1 2").is_err());
+    }
+
+    #[test]
+    fn all_scenarios_parse() {
+        let mut count = 0;
+        for entry in std::fs::read_dir(scenario_dir()).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|e| e == "scn") {
+                parse(&std::fs::read_to_string(&path).unwrap())
+                    .unwrap_or_else(|e| panic!("{}: {:#}", path.display(), e));
+                count += 1;
+            }
+        }
+        assert!(count >= 5);
+    }
+
+    #[test]
+    fn build_road_legal_car() {
+        run_scenario("build_car.scn").unwrap();
+    }
+
+    #[test]
+    fn drive_into_destination() {
+        run_scenario("drive.scn").unwrap();
+    }
+
+    #[test]
+    fn drive_to_every_destination() {
+        run_scenario("destinations.scn").unwrap();
+    }
+
+    #[test]
+    fn finish_race() {
+        run_scenario("race.scn").unwrap();
+    }
+
+    #[test]
+    fn earn_all_medals() {
+        run_scenario("medals.scn").unwrap();
+    }
+
+    #[test]
+    fn save_load_round_trip() {
+        run_scenario("save_load.scn").unwrap();
+    }
+
+    /// Same seed + same inputs → same game (demo playback relies on it)
+    #[test]
+    fn demo_playback_is_deterministic() {
+        use crate::game::demo::{Demo, FrameInput, KeyEvent};
+        use rand::Rng;
+
+        let mut demo = Demo::new(7, Default::default());
+        let key = |name: &str| KeyEvent::Key(name.to_string());
+        let mut login: Vec<KeyEvent> = "Testkind".chars().map(KeyEvent::Char).collect();
+        login.push(key("Enter"));
+        demo.frames.push(FrameInput { x: 320, y: 240, keys: login, ..Default::default() });
+        for (warp, x) in [("F3", 200), ("F4", 400), ("F3", 300)] {
+            demo.frames.extend((0..20).map(|_| FrameInput { x, y: 300, ..Default::default() }));
+            demo.frames.push(FrameInput { x, y: 300, down: true, click: true, ..Default::default() });
+            demo.frames.push(FrameInput { x, y: 300, keys: vec![key(warp)], ..Default::default() });
+        }
+
+        let play = |tag: &str| {
+            let dir = std::env::temp_dir().join(format!("openwilly_demo_{}_{}", std::process::id(), tag));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            let mut game = GameState::headless(&dir);
+            game.start_playback(&demo);
+            for frame in &demo.frames {
+                game.apply_frame(frame);
+            }
+            let junk = game.save_manager.active().map(|u| u.junk.pile(1).len());
+            let state = (game.current_scene, game.car.parts.clone(), junk, game.rng.gen::<u64>());
+            let _ = std::fs::remove_dir_all(&dir);
+            state
+        };
+        let first = play("a");
+        assert_eq!(first.0, Scene::Junkyard);
+        assert_eq!(first, play("b"));
+    }
+
+    /// A demo starts from its recorded profiles, not from the player's save
+    #[test]
+    fn demo_playback_over_existing_save() {
+        use crate::game::demo::{Demo, FrameInput, KeyEvent};
+        use crate::game::save::SaveManager;
+
+        let dir = std::env::temp_dir().join(format!("openwilly_demo_{}_save", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let profile = |dir: &std::path::Path, parts: &[u32]| {
+            let mut saves = SaveManager::new(dir);
+            saves.login("Testkind");
+            saves.active_mut().unwrap().car.parts = parts.to_vec();
+            saves.save();
+            saves.users_db
+        };
+        let recorded_parts = [1, 82, 133, 152, 3];
+        let mut demo = Demo::new(7, profile(&dir.join("recorded"), &recorded_parts));
+        let mut login: Vec<KeyEvent> = "Testkind".chars().map(KeyEvent::Char).collect();
+        login.push(KeyEvent::Key("Enter".into()));
+        demo.frames.push(FrameInput { x: 320, y: 240, keys: login, ..Default::default() });
+        demo.frames.extend((0..5).map(|_| FrameInput { x: 320, y: 240, ..Default::default() }));
+
+        // The player's own Testkind has another car
+        let own = dir.join("own");
+        profile(&own, &[1, 82, 133, 152, 41, 57]);
+        let own_save = std::fs::read(own.join("openwilly_save.json")).unwrap();
+        let mut game = GameState::headless(&own);
+        game.start_playback(&demo);
+        for frame in &demo.frames {
+            game.apply_frame(frame);
+        }
+        game.save_manager.save();
+
+        assert_eq!(game.car.parts, recorded_parts);
+        assert_eq!(std::fs::read(own.join("openwilly_save.json")).unwrap(), own_save);
+        assert!(own.join("demo_playback").join("openwilly_save.json").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
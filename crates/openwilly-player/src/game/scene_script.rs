@@ -9,6 +9,8 @@
 
 use std::collections::HashMap;
 
+use crate::game::tutorial::TUTORIAL_DONE;
+
 /// A condition that can gate a script step
 #[derive(Debug, Clone)]
 pub enum Condition {
@@ -23,6 +25,8 @@ pub enum Condition {
     HasPart(u32),
     /// Check if a part is NOT on the car
     NotPart(u32),
+    /// Check if the scene has an actor (optional actors may be missing)
+    HasActor(String),
     /// Always true
     Always,
 }
@@ -46,6 +50,8 @@ pub enum Action {
     SetStuff(String),
     /// Give a part to the player (placed in yard)
     GivePart(u32),
+    /// Complete a mission (reward handed over in the yard)
+    CompleteMission(u32),
     /// Refuel the car to maximum
     Refuel,
     /// Show/hide an actor
@@ -55,8 +61,19 @@ pub enum Action {
     },
     /// Wait for a fixed duration (ms) before proceeding
     Delay(u32),
+    /// Wait until the player clicks inside a screen rectangle (x, y, w, h)
+    WaitForClick {
+        hotspot: String,
+        rect: (i32, i32, i32, i32),
+    },
     /// Play a sound effect (non-blocking, fire-and-forget)
     PlaySound(String),
+    /// Wait until the game reports a named event (e.g. "refueled")
+    WaitForEvent(String),
+    /// Show an on-screen hint (i18n key), an empty key hides it
+    ShowHint(String),
+    /// Point the arrow at a map target ("gas", "destination"), empty hides it
+    PointAt(String),
     /// Leave the scene (go back to world map)
     LeaveToWorld,
     /// Change an actor's talk/silence animation pair mid-script
@@ -164,6 +181,17 @@ impl ScriptStep {
         }
     }
 
+    /// Create a mission-completion step
+    pub fn complete_mission(mission_id: u32) -> Self {
+        Self {
+            condition: Condition::Always,
+            action: Action::CompleteMission(mission_id),
+            blocking: false,
+            label: None,
+            jump_to: None,
+        }
+    }
+
     /// Create a refuel step
     pub fn refuel() -> Self {
         Self {
@@ -225,6 +253,18 @@ impl ScriptStep {
         self
     }
 
+    /// Builder: don't wait for this step (e.g. switching back to a looping idle anim)
+    pub fn no_wait(mut self) -> Self {
+        self.blocking = false;
+        self
+    }
+
+    /// Builder: only run this step if `condition` holds
+    pub fn when(mut self, condition: Condition) -> Self {
+        self.condition = condition;
+        self
+    }
+
     /// Create an actor-animation step (blocking)
     pub fn play_anim(actor: &str, anim: &str) -> Self {
         Self {
@@ -253,6 +293,55 @@ impl ScriptStep {
         }
     }
 
+    /// Create a click-wait step (blocking) — the script pauses until the
+    /// player clicks the named hotspot rectangle
+    pub fn wait_click(hotspot: &str, x: i32, y: i32, w: i32, h: i32) -> Self {
+        Self {
+            condition: Condition::Always,
+            action: Action::WaitForClick {
+                hotspot: hotspot.to_string(),
+                rect: (x, y, w, h),
+            },
+            blocking: true,
+            label: None,
+            jump_to: None,
+        }
+    }
+
+    /// Create an event-wait step (blocking) — the script pauses until the
+    /// game calls `SceneScript::on_event` with that name
+    pub fn wait_event(name: &str) -> Self {
+        Self {
+            condition: Condition::Always,
+            action: Action::WaitForEvent(name.to_string()),
+            blocking: true,
+            label: None,
+            jump_to: None,
+        }
+    }
+
+    /// Create a hint step (instant)
+    pub fn hint(key: &str) -> Self {
+        Self {
+            condition: Condition::Always,
+            action: Action::ShowHint(key.to_string()),
+            blocking: false,
+            label: None,
+            jump_to: None,
+        }
+    }
+
+    /// Create a pointer step (instant)
+    pub fn point_at(target: &str) -> Self {
+        Self {
+            condition: Condition::Always,
+            action: Action::PointAt(target.to_string()),
+            blocking: false,
+            label: None,
+            jump_to: None,
+        }
+    }
+
     /// Create set-talk-anims step (instant) — changes an actor's lip-sync animations
     pub fn set_talk_anims(actor: &str, talk: &str, silence: &str) -> Self {
         Self {
@@ -277,6 +366,10 @@ pub struct SceneScript {
     pub waiting_for_dialog: Option<String>,
     /// Waiting for an animation to finish (keyed by actor_name)
     pub waiting_for_anim: Option<String>,
+    /// Waiting for the player to click a hotspot: (name, x, y, w, h)
+    pub waiting_for_click: Option<(String, (i32, i32, i32, i32))>,
+    /// Waiting for a game event (keyed by event name)
+    pub waiting_for_event: Option<String>,
     /// Delay timer remaining (ms)
     pub delay_remaining: u32,
     /// Whether the script has completed
@@ -294,10 +387,13 @@ pub enum ScriptRequest {
     RemoveCache(String),
     SetStuff(String),
     GivePart(u32),
+    CompleteMission(u32),
     Refuel,
     SetActorVisible { actor_name: String, visible: bool },
     SetTalkAnims { actor_name: String, talk_anim: String, silence_anim: String },
     PlaySound(String),
+    ShowHint(String),
+    PointAt(String),
     LeaveToWorld,
 }
 
@@ -306,6 +402,8 @@ pub struct ScriptContext<'a> {
     pub cache: &'a [String],
     pub permanent: &'a [String],
     pub car_parts: &'a [u32],
+    /// Names of the actors in the scene
+    pub actors: &'a [&'a str],
 }
 
 impl SceneScript {
@@ -321,6 +419,8 @@ impl SceneScript {
             current_step: 0,
             waiting_for_dialog: None,
             waiting_for_anim: None,
+            waiting_for_click: None,
+            waiting_for_event: None,
             delay_remaining: 0,
             finished: false,
             label_map,
@@ -331,6 +431,8 @@ impl SceneScript {
     pub fn is_waiting(&self) -> bool {
         self.waiting_for_dialog.is_some()
             || self.waiting_for_anim.is_some()
+            || self.waiting_for_click.is_some()
+            || self.waiting_for_event.is_some()
             || self.delay_remaining > 0
     }
 
@@ -352,6 +454,32 @@ impl SceneScript {
         }
     }
 
+    /// Notify the script of a game event (driving tutorial)
+    pub fn on_event(&mut self, name: &str) {
+        if self.waiting_for_event.as_deref() == Some(name) {
+            self.waiting_for_event = None;
+        }
+    }
+
+    /// Notify the script of a mouse click. Returns `true` if the click hit
+    /// the hotspot the script is waiting for (the click is consumed).
+    pub fn on_click(&mut self, x: i32, y: i32) -> bool {
+        let Some((_, (rx, ry, rw, rh))) = self.waiting_for_click else {
+            return false;
+        };
+        if x >= rx && y >= ry && x < rx + rw && y < ry + rh {
+            self.waiting_for_click = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Hotspot rectangle the script is currently waiting on (for the cursor)
+    pub fn click_target(&self) -> Option<(i32, i32, i32, i32)> {
+        self.waiting_for_click.as_ref().map(|(_, rect)| *rect)
+    }
+
     /// Advance time for delay steps
     pub fn tick(&mut self, dt_ms: u32) {
         if self.delay_remaining > 0 {
@@ -425,6 +553,9 @@ impl SceneScript {
                 Action::GivePart(part_id) => {
                     requests.push(ScriptRequest::GivePart(*part_id));
                 }
+                Action::CompleteMission(mission_id) => {
+                    requests.push(ScriptRequest::CompleteMission(*mission_id));
+                }
                 Action::Refuel => {
                     requests.push(ScriptRequest::Refuel);
                 }
@@ -444,9 +575,21 @@ impl SceneScript {
                 Action::Delay(ms) => {
                     self.delay_remaining = *ms;
                 }
+                Action::WaitForClick { hotspot, rect } => {
+                    self.waiting_for_click = Some((hotspot.clone(), *rect));
+                }
                 Action::PlaySound(sound_id) => {
                     requests.push(ScriptRequest::PlaySound(sound_id.clone()));
                 }
+                Action::WaitForEvent(name) => {
+                    self.waiting_for_event = Some(name.clone());
+                }
+                Action::ShowHint(key) => {
+                    requests.push(ScriptRequest::ShowHint(key.clone()));
+                }
+                Action::PointAt(target) => {
+                    requests.push(ScriptRequest::PointAt(target.clone()));
+                }
                 Action::LeaveToWorld => {
                     requests.push(ScriptRequest::LeaveToWorld);
                 }
@@ -473,6 +616,7 @@ fn evaluate_condition(cond: &Condition, ctx: &ScriptContext<'_>) -> bool {
         Condition::NotStuff(flag) => !ctx.permanent.iter().any(|f| f == flag),
         Condition::HasPart(id) => ctx.car_parts.contains(id),
         Condition::NotPart(id) => !ctx.car_parts.contains(id),
+        Condition::HasActor(name) => ctx.actors.contains(&name.as_str()),
     }
 }
 
@@ -514,6 +658,34 @@ pub fn build_menu_script(jingle_duration_ms: u32) -> SceneScript {
     ])
 }
 
+// ---------------------------------------------------------------------------
+// Driving tutorial script
+// ---------------------------------------------------------------------------
+
+/// Build the driving tutorial for a profile's first world-map visit.
+///
+/// The game reports the events the steps wait for:
+/// 1. "steered" — the car has been driven a short distance
+/// 2. "refueled" — the car drove into a gas station (arrow points there)
+/// 3. "destination" — a destination was reached (arrow points to the nearest)
+///
+/// Finishing sets `#TutorialDone`, so the tutorial runs only once.
+pub fn build_tutorial_script() -> SceneScript {
+    SceneScript::new(vec![
+        ScriptStep::hint("tut_steer"),
+        ScriptStep::wait_event("steered"),
+        ScriptStep::hint("tut_gas"),
+        ScriptStep::point_at("gas"),
+        ScriptStep::wait_event("refueled"),
+        ScriptStep::hint("tut_destination"),
+        ScriptStep::point_at("destination"),
+        ScriptStep::wait_event("destination"),
+        ScriptStep::hint(""),
+        ScriptStep::point_at(""),
+        ScriptStep::set_stuff(TUTORIAL_DONE),
+    ])
+}
+
 // ---------------------------------------------------------------------------
 // Car show script
 // ---------------------------------------------------------------------------
@@ -696,8 +868,24 @@ fn script_figge() -> Vec<ScriptStep> {
     ]
 }
 
+/// Saftfabrik conveyor start button (screen rect). Not taken from the
+/// original: placed around the "conveyor" actor (data/actors.json), whose
+/// frames are unconfirmed member names, see `actors`.
+pub const SAFT_BUTTON_RECT: (i32, i32, i32, i32) = (180, 250, 60, 50);
+/// Saftfabrik filling tap above the car's tank (screen rect), placed the
+/// same way around the "tap" actor
+pub const SAFT_TAP_RECT: (i32, i32, i32, i32) = (380, 180, 70, 90);
+
 /// Destination 87 — Saftfabrik (branching on Part 172 = Tank)
+///
+/// With a tank on the car, Garson explains the machine and the player runs
+/// it: click the button → bottles roll down the conveyor, click the tap →
+/// the tank is filled with lemonade (#Lemonade, delivered to Sture at 88).
+/// Without the conveyor/tap actors (optional, see data/actors.json) there
+/// is nothing to click: the machine runs by itself.
 fn script_saftfabrik() -> Vec<ScriptStep> {
+    let (bx, by, bw, bh) = SAFT_BUTTON_RECT;
+    let (tx, ty, tw, th) = SAFT_TAP_RECT;
     vec![
         ScriptStep::talk("87d002v0"),                // Garson: "Wir haben kein Saft mehr"
 
@@ -712,9 +900,21 @@ fn script_saftfabrik() -> Vec<ScriptStep> {
         // Has tank → fill with lemonade
         ScriptStep::label("has_tank"),
         ScriptStep::talk("87d004v0"),                // Mulle: "Na klar!"
-        // (splash animation + sound would play here)
         ScriptStep::talk("87d005v0"),                // Garson: instructions
         ScriptStep::talk("87d006v0"),                // Mulle: "Verstanden"
+
+        // 1. Start the conveyor
+        ScriptStep::wait_click("conveyorButton", bx, by, bw, bh).when(Condition::HasActor("conveyor".into())),
+        ScriptStep::play_sound("87e002v0"),          // Machine start
+        ScriptStep::play_anim("conveyor", "run"),
+        ScriptStep::play_anim("conveyor", "idle").no_wait(),
+
+        // 2. Open the tap over the tank
+        ScriptStep::wait_click("tap", tx, ty, tw, th).when(Condition::HasActor("tap".into())),
+        ScriptStep::play_sound("87e003v0"),          // Splash
+        ScriptStep::play_anim("tap", "fill"),
+        ScriptStep::play_anim("tap", "idle").no_wait(),
+
         ScriptStep::set_cache("#Lemonade"),
         ScriptStep::delay(1000),
         ScriptStep::leave(),
@@ -827,7 +1027,7 @@ fn script_tree_in_road() -> Vec<ScriptStep> {
     ]
 }
 
-/// Destination 90 — Doris Digital (NPC, gives part 306, mission 4)
+/// Destination 90 — Doris Digital (NPC, completes mission 4 → part 306)
 /// Doris runs a computer shop. Part 306 = keyboard? as reward.
 fn script_doris_digital() -> Vec<ScriptStep> {
     vec![
@@ -837,9 +1037,8 @@ fn script_doris_digital() -> Vec<ScriptStep> {
         // First visit
         ScriptStep::talk("90d002v0"),                // Doris: greeting
         ScriptStep::talk("90d003v0"),                // Doris: "Hier, die kannst du haben"
-        ScriptStep::give_part(306),                  // Part 306 → yard
+        ScriptStep::complete_mission(4),             // Part 306 → yard ceremony
         ScriptStep::set_cache("#DorisVisited"),
-        // TODO: Complete mission 4 when mission system is integrated
         ScriptStep::delay(500),
         ScriptStep::leave(),
 
@@ -851,7 +1050,7 @@ fn script_doris_digital() -> Vec<ScriptStep> {
     ]
 }
 
-/// Destination 91 — Luddel Abb (NPC blacksmith, gives part 99, mission 6)
+/// Destination 91 — Luddel Abb (NPC blacksmith, completes mission 6 → part 99)
 /// Luddel runs a forge. Part 99 = metal part as reward.
 fn script_luddel_abb() -> Vec<ScriptStep> {
     vec![
@@ -861,9 +1060,8 @@ fn script_luddel_abb() -> Vec<ScriptStep> {
         // First visit
         ScriptStep::talk("91d002v0"),                // Luddel: greeting
         ScriptStep::talk("91d003v0"),                // Luddel: "Hier, das habe ich für dich"
-        ScriptStep::give_part(99),                   // Part 99 → yard
+        ScriptStep::complete_mission(6),             // Part 99 → yard ceremony
         ScriptStep::set_cache("#LuddelVisited"),
-        // TODO: Complete mission 6 when mission system is integrated
         ScriptStep::delay(500),
         ScriptStep::leave(),
 
@@ -908,6 +1106,7 @@ mod tests {
             cache: &[],
             permanent: &[],
             car_parts: &[],
+            actors: &[],
         }
     }
 
@@ -962,6 +1161,7 @@ mod tests {
             cache: &cache,
             permanent: &[],
             car_parts: &[],
+            actors: &[],
         };
 
         let reqs = script.advance(&ctx);
@@ -982,6 +1182,7 @@ mod tests {
             cache: &cache,
             permanent: &[],
             car_parts: &[],
+            actors: &[],
         };
 
         let reqs = script.advance(&ctx);
@@ -1010,6 +1211,7 @@ mod tests {
             cache: &[],
             permanent: &[],
             car_parts: &[172],
+            actors: &[],
         };
 
         let reqs = script.advance(&ctx);
@@ -1021,6 +1223,56 @@ mod tests {
         assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::Talk { audio_id, .. } if audio_id == "87d004v0")));
     }
 
+    #[test]
+    fn saftfabrik_waits_for_clicks() {
+        let mut script = build_destination_script(87).unwrap();
+        let cache: Vec<String> = Vec::new();
+        let ctx = ScriptContext { cache: &cache, permanent: &[], car_parts: &[172], actors: &["conveyor", "tap"] };
+
+        script.advance(&ctx);
+        for id in ["87d002v0", "87d004v0", "87d005v0", "87d006v0"] {
+            script.on_dialog_finished(id);
+            script.advance(&ctx);
+        }
+        // Blocked on the conveyor button; a click elsewhere is ignored
+        assert_eq!(script.click_target(), Some(SAFT_BUTTON_RECT));
+        assert!(!script.on_click(0, 0));
+        assert!(script.on_click(SAFT_BUTTON_RECT.0 + 1, SAFT_BUTTON_RECT.1 + 1));
+
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::PlayAnim { anim_name, .. } if anim_name == "run")));
+        script.on_anim_finished("conveyor");
+        script.advance(&ctx);
+
+        // Tap click fills the tank and sets #Lemonade
+        assert_eq!(script.click_target(), Some(SAFT_TAP_RECT));
+        assert!(script.on_click(SAFT_TAP_RECT.0 + 1, SAFT_TAP_RECT.1 + 1));
+        script.advance(&ctx);
+        script.on_anim_finished("tap");
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::SetCache(f) if f == "#Lemonade")));
+    }
+
+    #[test]
+    fn saftfabrik_without_machine_actors() {
+        // Cast without the conveyor/tap frames: no click to wait for
+        let mut script = build_destination_script(87).unwrap();
+        let ctx = ScriptContext { cache: &[], permanent: &[], car_parts: &[172], actors: &["garson"] };
+        script.advance(&ctx);
+        for id in ["87d002v0", "87d004v0", "87d005v0", "87d006v0"] {
+            script.on_dialog_finished(id);
+            script.advance(&ctx);
+        }
+        assert_eq!(script.click_target(), None);
+        // The animation steps are skipped by GameState when the actor is missing
+        script.on_anim_finished("conveyor");
+        script.advance(&ctx);
+        assert_eq!(script.click_target(), None);
+        script.on_anim_finished("tap");
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::SetCache(f) if f == "#Lemonade")));
+    }
+
     #[test]
     fn sture_with_lemonade() {
         let mut script = build_destination_script(88).unwrap();
@@ -1029,6 +1281,7 @@ mod tests {
             cache: &cache,
             permanent: &[],
             car_parts: &[],
+            actors: &[],
         };
 
         let reqs = script.advance(&ctx);
@@ -1044,6 +1297,7 @@ mod tests {
             cache: &[],
             permanent: &perm,
             car_parts: &[],
+            actors: &[],
         };
 
         let reqs = script.advance(&ctx);
@@ -1125,4 +1379,33 @@ mod tests {
             Action::Talk { audio_id, .. } if audio_id == "94d004v0"));
         assert!(has_five_star);
     }
+
+    #[test]
+    fn tutorial_waits_for_game_events() {
+        let mut script = build_tutorial_script();
+        let ctx = empty_ctx();
+
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::ShowHint(k) if k == "tut_steer")));
+        assert!(script.is_waiting());
+
+        // Events out of order are ignored
+        script.on_event("refueled");
+        assert!(script.advance(&ctx).is_empty());
+
+        script.on_event("steered");
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::PointAt(t) if t == "gas")));
+
+        script.on_event("refueled");
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::PointAt(t) if t == "destination")));
+        assert!(!script.finished);
+
+        script.on_event("destination");
+        let reqs = script.advance(&ctx);
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::SetStuff(f) if f == TUTORIAL_DONE)));
+        assert!(reqs.iter().any(|r| matches!(r, ScriptRequest::ShowHint(k) if k.is_empty())));
+        assert!(script.finished);
+    }
 }
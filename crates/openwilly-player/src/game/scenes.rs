@@ -8,14 +8,21 @@
 //!  - MulleButtons (default/hover state with scene transition target)
 //!  - Hotspots (fallback clickable rectangles)
 
+use std::collections::HashMap;
+
 use minifb::Key;
 
 use crate::assets::AssetStore;
 use crate::assets::director::CastType;
-use crate::engine::Sprite;
+use crate::engine::{Sprite, SpritePixels};
+use crate::engine::canvas::Canvas;
 use crate::engine::font;
 use crate::game::Scene;
+use crate::game::actors::{ActorDef, ActorTable, FrameRef};
+use crate::game::avatars;
 use crate::game::drag_drop::{DragDropState, DropResult};
+use crate::game::seasonal::Decoration;
+use crate::game::text_input::TextInput;
 
 // ─── Animation system ─────────────────────────────────────────────────────
 
@@ -24,14 +31,18 @@ use crate::game::drag_drop::{DragDropState, DropResult};
 pub struct AnimFrame {
     pub width: u32,
     pub height: u32,
-    pub pixels: Vec<u8>, // RGBA
+    pub pixels: SpritePixels, // RGBA
     /// Registration point X (origin offset for positioning)
     pub reg_x: i32,
     /// Registration point Y
     pub reg_y: i32,
 }
 
+/// Game logic rate (ticks per second)
+pub const LOGIC_FPS: u32 = 30;
+
 /// A named animation — a sequence of frames, played at a given fps
+/// (0 = one frame per score frame, i.e. at the scene tempo)
 #[derive(Debug, Clone)]
 pub struct Animation {
     pub name: String,
@@ -39,7 +50,7 @@ pub struct Animation {
     pub fps: u32,
     pub looping: bool,
     pub current_frame: usize,
-    pub ticks_per_frame: u32,
+    /// Progress towards the next frame: `fps` per tick, a frame every `LOGIC_FPS`
     pub tick: u32,
     pub playing: bool,
     pub finished: bool,
@@ -47,14 +58,12 @@ pub struct Animation {
 
 impl Animation {
     pub fn new(name: &str, fps: u32, looping: bool) -> Self {
-        let tpf = if fps > 0 { 30 / fps.max(1) } else { 1 };
         Self {
             name: name.to_string(),
             frames: Vec::new(),
             fps,
             looping,
             current_frame: 0,
-            ticks_per_frame: tpf.max(1),
             tick: 0,
             playing: false,
             finished: false,
@@ -64,20 +73,26 @@ impl Animation {
     pub fn play(&mut self) {
         self.current_frame = 0;
         self.tick = 0;
-        // Recompute ticks_per_frame from fps in case it was changed
-        self.ticks_per_frame = if self.fps > 0 { (30 / self.fps.max(1)).max(1) } else { 1 };
         self.playing = true;
         self.finished = false;
     }
 
-    /// Advance one game tick (30 fps). Returns true if frame changed.
-    pub fn tick(&mut self) -> bool {
+    /// Frames per second at a scene tempo: a cel changes at most once per
+    /// score frame, so the authored rate is capped by the tempo
+    fn rate(&self, tempo: u32) -> u32 {
+        let tempo = tempo.clamp(1, LOGIC_FPS);
+        if self.fps == 0 { tempo } else { self.fps.min(tempo) }
+    }
+
+    /// Advance one game tick (30 fps) at the scene tempo. Returns true if
+    /// the frame changed.
+    pub fn tick(&mut self, tempo: u32) -> bool {
         if !self.playing || self.frames.is_empty() {
             return false;
         }
-        self.tick += 1;
-        if self.tick >= self.ticks_per_frame {
-            self.tick = 0;
+        self.tick += self.rate(tempo);
+        if self.tick >= LOGIC_FPS {
+            self.tick -= LOGIC_FPS;
             self.current_frame += 1;
             if self.current_frame >= self.frames.len() {
                 if self.looping {
@@ -198,39 +213,32 @@ impl Actor {
     ) {
         let mut anim = Animation::new(name, fps, looping);
         for &(file, num) in member_refs {
-            if let Some(bmp) = assets.decode_bitmap_transparent(file, num) {
-                // Get registration point from BitmapInfo
-                let (rx, ry) = assets.files.get(file)
-                    .and_then(|df| df.cast_members.get(&num))
-                    .and_then(|m| m.bitmap_info.as_ref())
-                    .map(|bi| (bi.reg_x as i32, bi.reg_y as i32))
-                    .unwrap_or((0, 0));
-                anim.frames.push(AnimFrame {
-                    width: bmp.width,
-                    height: bmp.height,
-                    pixels: bmp.pixels,
-                    reg_x: rx,
-                    reg_y: ry,
-                });
-            } else {
-                tracing::warn!(
-                    "Actor '{}' anim '{}': failed to load {}/{}",
-                    self.name, name, file, num
-                );
-            }
+            // Missing frames show a placeholder (logged by the asset store)
+            let bmp = assets.require_bitmap_transparent(file, num);
+            let (rx, ry) = SceneHandler::reg_point(file, num, assets);
+            anim.frames.push(AnimFrame {
+                width: bmp.width,
+                height: bmp.height,
+                pixels: bmp.pixels.into(),
+                reg_x: rx,
+                reg_y: ry,
+            });
         }
         anim.playing = true;
         self.animations.push(anim);
     }
 
-    pub fn play(&mut self, name: &str) {
+    /// Start the named animation. Returns `false` if the actor has no such
+    /// animation (or it has no frames, so it would never finish).
+    pub fn play(&mut self, name: &str) -> bool {
         for (i, a) in self.animations.iter_mut().enumerate() {
             if a.name == name {
                 a.play();
                 self.active_anim = i;
-                return;
+                return !a.frames.is_empty();
             }
         }
+        false
     }
 
     /// Update the idle animation based on mouse position (MulleShopBH behavior).
@@ -272,11 +280,24 @@ impl Actor {
         self.play(target_anim);
     }
 
-    /// Tick the active animation. Returns an event if a non-looping animation finished.
-    pub fn tick(&mut self) -> Option<ActorEvent> {
+    /// Whether the active animation loops (no one-shot in progress)
+    pub fn is_looping(&self) -> bool {
+        self.animations.get(self.active_anim).is_some_and(|a| a.looping)
+    }
+
+    /// Whether ticking would change nothing visible: a looping animation that
+    /// has a single frame or belongs to a hidden actor
+    pub fn is_idle(&self) -> bool {
+        self.animations.get(self.active_anim)
+            .map_or(true, |a| a.looping && (a.frames.len() <= 1 || !self.visible))
+    }
+
+    /// Tick the active animation at the scene tempo. Returns an event if a
+    /// non-looping animation finished.
+    pub fn tick(&mut self, tempo: u32) -> Option<ActorEvent> {
         if let Some(anim) = self.animations.get_mut(self.active_anim) {
             let was_playing = anim.playing;
-            anim.tick();
+            anim.tick(tempo);
             // Fire event only on the exact frame the animation becomes finished
             if was_playing && !anim.playing && anim.finished {
                 return Some(ActorEvent::AnimationFinished {
@@ -316,8 +337,8 @@ pub struct MulleButton {
     pub y: i32,
     pub width: u32,
     pub height: u32,
-    pub default_pixels: Vec<u8>,
-    pub hover_pixels: Option<Vec<u8>>,
+    pub default_pixels: SpritePixels,
+    pub hover_pixels: Option<SpritePixels>,
     pub hover_width: u32,
     pub hover_height: u32,
     pub hovered: bool,
@@ -333,7 +354,8 @@ pub struct MulleButton {
 impl MulleButton {
     /// Create from Director member references.
     /// `ax, ay` = anchor point (mulle.js always uses 320, 240).
-    /// Position = anchor − regPoint.
+    /// Position = anchor − regPoint. None if `file` isn't loaded at all;
+    /// a missing member within it shows a placeholder.
     pub fn new(
         name: &str,
         file: &str,
@@ -345,13 +367,13 @@ impl MulleButton {
         z_order: i32,
         assets: &AssetStore,
     ) -> Option<Self> {
-        let def = assets.decode_bitmap_transparent(file, default_num)?;
-        let df = assets.files.get(file)?;
-        let bi = df.cast_members.get(&default_num)?.bitmap_info.as_ref()?;
+        assets.files.get(file)?;
+        let def = assets.require_bitmap_transparent(file, default_num);
+        let (reg_x, reg_y) = SceneHandler::reg_point(file, default_num, assets);
 
         let (hover_px, hw, hh) = if let Some(hnum) = hover_num {
             if let Some(hbmp) = assets.decode_bitmap_transparent(file, hnum) {
-                (Some(hbmp.pixels), hbmp.width, hbmp.height)
+                (Some(hbmp.pixels.into()), hbmp.width, hbmp.height)
             } else {
                 (None, 0, 0)
             }
@@ -361,11 +383,11 @@ impl MulleButton {
 
         Some(Self {
             name: name.to_string(),
-            x: ax - bi.reg_x as i32,
-            y: ay - bi.reg_y as i32,
+            x: ax - reg_x,
+            y: ay - reg_y,
             width: def.width,
             height: def.height,
-            default_pixels: def.pixels,
+            default_pixels: def.pixels.into(),
             hover_pixels: hover_px,
             hover_width: hw,
             hover_height: hh,
@@ -429,10 +451,10 @@ pub struct SceneHandler {
     pub actors: Vec<Actor>,
     pub hotspots: Vec<Hotspot>,
     // Menu UI state
-    input_text: String,
-    cursor_visible: bool,
-    frame_counter: u32,
+    input_text: TextInput,
     pub saved_names: Vec<String>,
+    /// Car thumbnail per saved name
+    pub thumbnails: HashMap<String, Canvas>,
     selected_name: Option<usize>,
     // Junkyard sub-state
     pub(crate) junk_pile: u8,
@@ -446,6 +468,12 @@ pub struct SceneHandler {
     carshow_rating: u8,
     /// Set when junkyard pile navigation occurs: (old_pile, new_pile)
     pub pile_switched: Option<(u8, u8)>,
+    /// Seasonal easter-egg sprites (party hat, bunting, …)
+    decorations: Vec<Decoration>,
+    /// Low-spec mode: don't tick actors whose animation can't change
+    pub skip_idle_ticks: bool,
+    /// Score tempo in fps (caps the actor animations), see `game::tempo`
+    pub tempo: u32,
 }
 
 impl SceneHandler {
@@ -461,10 +489,9 @@ impl SceneHandler {
             buttons: Vec::new(),
             actors: Vec::new(),
             hotspots: Vec::new(),
-            input_text: String::new(),
-            cursor_visible: true,
-            frame_counter: 0,
+            input_text: TextInput::new(20),
             saved_names: Vec::new(),
+            thumbnails: HashMap::new(),
             selected_name: None,
             junk_pile: 1,
             drag_drop: DragDropState::new(),
@@ -472,6 +499,9 @@ impl SceneHandler {
             has_car,
             carshow_rating,
             pile_switched: None,
+            decorations: Vec::new(),
+            skip_idle_ticks: false,
+            tempo: LOGIC_FPS,
         };
 
         handler.load_scene(assets);
@@ -507,47 +537,43 @@ impl SceneHandler {
     // ─── Helper: load a single member as sprite ─────────────────────────
 
     fn load_bg(&mut self, file: &str, num: u32, assets: &AssetStore) {
-        if let Some(bmp) = assets.decode_bitmap(file, num) {
-            // Position: center anchor (320,240) minus regPoint → gives top-left
-            let (rx, ry) = Self::reg_point(file, num, assets);
-            self.sprites.push(Sprite {
-                x: 320 - rx,
-                y: 240 - ry,
-                width: bmp.width, height: bmp.height,
-                pixels: bmp.pixels,
-                visible: true,
-                z_order: 0,
-                name: format!("bg#{}", num),
-                interactive: false,
-                member_num: num,
-            });
-        } else {
-            tracing::warn!("Failed to load bg {}#{}", file, num);
-        }
+        let bmp = assets.require_bitmap(file, num);
+        // Position: center anchor (320,240) minus regPoint → gives top-left
+        let (rx, ry) = Self::reg_point(file, num, assets);
+        self.sprites.push(Sprite {
+            x: 320 - rx,
+            y: 240 - ry,
+            width: bmp.width, height: bmp.height,
+            pixels: bmp.pixels.into(),
+            visible: true,
+            z_order: 0,
+            name: format!("bg#{}", num),
+            interactive: false,
+            member_num: num,
+        });
     }
 
     /// Load a sprite overlay positioned at anchor (ax, ay) minus regPoint.
     /// In mulle.js every overlay/button uses an anchor (usually 320,240)
     /// and the Director regPoint determines the top-left corner.
     fn load_overlay_at(&mut self, file: &str, num: u32, ax: i32, ay: i32, z: i32, visible: bool, assets: &AssetStore) {
-        if let Some(bmp) = assets.decode_bitmap_transparent(file, num) {
-            let (rx, ry) = Self::reg_point(file, num, assets);
-            let name = assets.files.get(file)
-                .and_then(|df| df.cast_members.get(&num))
-                .map(|m| m.name.clone())
-                .unwrap_or_default();
-            self.sprites.push(Sprite {
-                x: ax - rx,
-                y: ay - ry,
-                width: bmp.width, height: bmp.height,
-                pixels: bmp.pixels,
-                visible,
-                z_order: z,
-                name: format!("#{} {}", num, name),
-                interactive: true,
-                member_num: num,
-            });
-        }
+        let bmp = assets.require_bitmap_transparent(file, num);
+        let (rx, ry) = Self::reg_point(file, num, assets);
+        let name = assets.files.get(file)
+            .and_then(|df| df.cast_members.get(&num))
+            .map(|m| m.name.clone())
+            .unwrap_or_default();
+        self.sprites.push(Sprite {
+            x: ax - rx,
+            y: ay - ry,
+            width: bmp.width, height: bmp.height,
+            pixels: bmp.pixels.into(),
+            visible,
+            z_order: z,
+            name: format!("#{} {}", num, name),
+            interactive: true,
+            member_num: num,
+        });
     }
 
     /// Convenience: load overlay anchored at screen center (320, 240)
@@ -574,26 +600,8 @@ impl SceneHandler {
         // Mulle body: member #125 (static sprite, anchored at same point as head/mouth)
         self.load_overlay_at(f, 125, 139, 296, 10, true, assets);
 
-        // Mulle head animation: #126 (idle), #136-#137 (point)
-        let mut head = Actor::new("mulleMenuHead", 139, 296, 15);
-        head.add_animation("idle", &[(f, 126)], 0, true, assets);
-        head.add_animation("point", &[
-            (f, 136), (f, 137), (f, 137), (f, 137), (f, 137),
-            (f, 137), (f, 137), (f, 137), (f, 137), (f, 136), (f, 126),
-        ], 10, false, assets);
-        head.play("idle");
-        self.actors.push(head);
-
-        // Mulle mouth animation: #115 (idle), #115-#122 (talk)
-        let mut mouth = Actor::new("mulleMenuMouth", 139, 296, 16);
-        mouth.add_animation("idle", &[(f, 115)], 5, true, assets);
-        mouth.add_animation("talkPlayer", &[
-            (f, 115), (f, 116), (f, 117), (f, 118),
-            (f, 119), (f, 120), (f, 121), (f, 122),
-        ], 10, true, assets);
-        mouth.set_talk_anims("talkPlayer", "idle");
-        mouth.play("idle");
-        self.actors.push(mouth);
+        // Mulle head (idle, point) and mouth (lip-sync), see data/actors.json
+        self.spawn_actors("Menu", assets);
     }
 
     // ─── Garage (03.DXR) ───────────────────────────────────────────────
@@ -645,45 +653,9 @@ impl SceneHandler {
             self.buttons.push(btn);
         }
 
-        // Mulle actor at (118, 188) — from 00.CXT shared cast
-        let b = "00.CXT";
-        let mut mulle = Actor::new("mulleDefault", 118, 188, 20);
-        mulle.add_animation("idle", &[(b, 271)], 10, true, assets);
-        mulle.add_animation("lookPlayer", &[(b, 287), (b, 288)], 10, true, assets);
-        mulle.add_animation("talkPlayer", &[
-            (b, 289), (b, 290), (b, 291), (b, 292),
-            (b, 293), (b, 294), (b, 295),
-        ], 10, true, assets);
-        mulle.add_animation("scratchChin", &[
-            (b, 271), (b, 272), (b, 273), (b, 274), (b, 275), (b, 276),
-        ], 10, false, assets);
-        mulle.add_animation("scratchHead", &[
-            (b, 277), (b, 278), (b, 279), (b, 280), (b, 281), (b, 282),
-        ], 10, false, assets);
-        mulle.add_animation("lookLeft", &[(b, 283)], 10, true, assets);
-        mulle.add_animation("lookRight", &[(b, 286)], 10, true, assets);
-        mulle.add_animation("turnBack", &[(b, 285)], 10, true, assets);
-        mulle.mouse_track = true;
-        mulle.play("idle");
-        self.actors.push(mulle);
-
-        // Figge actor at the side door (hidden until triggered)
-        // Sprites from 03.DXR members 81-93
-        let mut figge = Actor::new("figge", 320, 240, 18);
-        figge.add_animation("enter", &[
-            (f, 81), (f, 82), (f, 83), (f, 84), (f, 85),
-        ], 10, false, assets);
-        figge.add_animation("entered", &[(f, 86)], 10, true, assets);
-        figge.add_animation("exit", &[
-            (f, 85), (f, 84), (f, 83), (f, 82), (f, 81),
-        ], 10, false, assets);
-        figge.add_animation("talk", &[
-            (f, 86), (f, 87), (f, 88), (f, 89),
-            (f, 90), (f, 91), (f, 92), (f, 93),
-        ], 10, true, assets);
-        figge.set_talk_anims("talk", "entered");
-        figge.visible = false;
-        self.actors.push(figge);
+        // Mulle (tracks the mouse) and Figge at the side door (hidden until
+        // triggered), see data/actors.json
+        self.spawn_actors("Garage", assets);
     }
 
     // ─── Junkyard (02.DXR / 02.CXT) ────────────────────────────────────
@@ -882,25 +854,8 @@ impl SceneHandler {
         // Background: member #200
         self.load_bg(&f, 200, assets);
 
-        // Judge actor at (155, 210) — full animation set
-        let mut judge = Actor::new("judge", 155, 210, 20);
-        judge.add_animation("idle", &[(&f, 31)], 10, true, assets);
-        judge.add_animation("talk", &[
-            (&f, 43), (&f, 44), (&f, 45), (&f, 46), (&f, 47),
-        ], 10, true, assets);
-        judge.add_animation("raiseScore", &[
-            (&f, 32), (&f, 33), (&f, 34), (&f, 35),
-        ], 5, false, assets);
-        judge.add_animation("idleScore", &[(&f, 36)], 10, true, assets);
-        judge.add_animation("talkScore", &[
-            (&f, 37), (&f, 38), (&f, 39), (&f, 41), (&f, 42),
-        ], 10, true, assets);
-        judge.add_animation("lowerScore", &[
-            (&f, 35), (&f, 34), (&f, 33), (&f, 32),
-        ], 5, false, assets);
-        judge.set_talk_anims("talk", "idle");
-        judge.play("idle");
-        self.actors.push(judge);
+        // Judge (full animation set) and Mulle looking left, see data/actors.json
+        self.spawn_actors("CarShow", assets);
 
         // Score sprite at (177, 93) — 94.DXR members 17–21 (rating 1→17 … 5→21)
         // Use the pre-computed rating to select the right member
@@ -911,19 +866,6 @@ impl SceneHandler {
         score.visible = false; // Hidden until script reveals it
         self.actors.push(score);
 
-        // Mulle at (89, 337) looking left
-        let b = "00.CXT";
-        let mut mulle = Actor::new("mulleDefault", 89, 337, 15);
-        mulle.add_animation("lookLeft", &[(b, 283)], 10, true, assets);
-        mulle.add_animation("idle", &[(b, 271)], 10, true, assets);
-        mulle.add_animation("talkRegular", &[
-            (b, 296), (b, 297), (b, 298), (b, 299),
-            (b, 300), (b, 301), (b, 302),
-        ], 10, true, assets);
-        mulle.set_talk_anims("talkRegular", "idle");
-        mulle.play("lookLeft");
-        self.actors.push(mulle);
-
         // Return to world
         self.hotspots.push(Hotspot {
             x: 0, y: 400, width: 640, height: 80,
@@ -935,197 +877,41 @@ impl SceneHandler {
     // ─── Destinations (82-94) ──────────────────────────────────────────
 
     fn load_destination(&mut self, num: u8, assets: &AssetStore) {
+        if num == 94 {
+            self.load_carshow(assets);
+            return;
+        }
         let f = self.resolve_file(&format!("{:02}", num), assets);
-        let b = "00.CXT";
-
-        // Per-destination Mulle position and setup
-        let (mulle_x, mulle_y) = match num {
-            85 => (95, 300),   // RoadDog — default position
-            86 => (350, 398),  // Solhem — near Mia's house
-            87 => (496, 332),  // Saftfabrik — right side
-            88 => (351, 234),  // StureStortand — center
-            92 => (95, 300),   // FiggeFerrum — left side
-            _ => (95, 300),    // Generic default
-        };
 
         match num {
-            85 => {
-                // ─── RoadDog — Salka on the road ────────────────────────
-                self.load_bg(&f, 25, assets);
-
-                let mut salka = Actor::new("salkaRight", 480, 386, 20);
-                salka.add_animation("idle", &[
-                    (&f, 26), (&f, 27), (&f, 28), (&f, 29),
-                    (&f, 30), (&f, 29), (&f, 28), (&f, 27),
-                ], 15, true, assets);
-                salka.play("idle");
-                self.actors.push(salka);
-            }
-            86 => {
-                // ─── Solhem — Mia and the cat ───────────────────────────
-                self.load_bg(&f, 1, assets);
-
-                // Mia body at (277, 246)
-                let mut mia_body = Actor::new("miaBody", 277, 246, 18);
-                mia_body.add_animation("idle", &[(&f, 55)], 10, true, assets);
-                mia_body.add_animation("catchIntro", &[
-                    (&f, 55), (&f, 56), (&f, 57), (&f, 58),
-                ], 10, false, assets);
-                mia_body.add_animation("catchEnd", &[
-                    (&f, 47), (&f, 48), (&f, 49), (&f, 50),
-                ], 10, false, assets);
-                mia_body.play("idle");
-                self.actors.push(mia_body);
-
-                // Mia head at (535, 336) — talks about the cat
-                let mut mia_head = Actor::new("miaHead", 535, 336, 19);
-                mia_head.add_animation("idle", &[(&f, 62)], 10, true, assets);
-                mia_head.add_animation("talk", &[
-                    (&f, 63), (&f, 64), (&f, 65), (&f, 66), (&f, 67),
-                ], 10, true, assets);
-                mia_head.add_animation("idleCat", &[(&f, 69)], 10, true, assets);
-                mia_head.add_animation("talkCat", &[
-                    (&f, 69), (&f, 70), (&f, 71), (&f, 72), (&f, 73), (&f, 74),
-                ], 10, true, assets);
-                mia_head.set_talk_anims("talk", "idle");
-                mia_head.play("idle");
-                self.actors.push(mia_head);
-
-                // Cat at (278, 240)
-                let mut cat = Actor::new("cat", 278, 240, 20);
-                cat.add_animation("idle", &[(&f, 30)], 10, true, assets);
-                // jump1: members 31–42 (12 frames)
-                let jump1_refs: Vec<(&str, u32)> = (31..=42).map(|n| (f.as_str(), n)).collect();
-                cat.add_animation("jump1", &jump1_refs, 10, false, assets);
-                // jump2: members 42–45 (4 frames)
-                cat.add_animation("jump2", &[
-                    (&f, 42), (&f, 43), (&f, 44), (&f, 45),
-                ], 10, false, assets);
-                cat.play("idle");
-                self.actors.push(cat);
-            }
-            87 => {
-                // ─── Saftfabrik — Lemonade factory ──────────────────────
-                self.load_bg(&f, 208, assets);
-
-                let mut garson = Actor::new("garson", 537, 218, 20);
-                garson.add_animation("idle", &[(&f, 15)], 10, true, assets);
-                garson.add_animation("talk", &[
-                    (&f, 16), (&f, 17), (&f, 18),
-                ], 8, true, assets);
-                garson.set_talk_anims("talk", "idle");
-                garson.play("idle");
-                self.actors.push(garson);
-            }
-            88 => {
-                // ─── StureStortand — Sture's party ──────────────────────
-                // BG 32 (with lemonade) or 40 (without) — selected later
-                // For now, use 32 as default (the scene_script will handle logic)
-                self.load_bg(&f, 32, assets);
-
-                // Sture sad at (285, 162)
-                let mut sture_sad = Actor::new("stureSad", 285, 162, 20);
-                sture_sad.add_animation("idle", &[(&f, 42)], 10, true, assets);
-                sture_sad.add_animation("talk", &[
-                    (&f, 43), (&f, 44), (&f, 45), (&f, 46), (&f, 47),
-                ], 10, true, assets);
-                sture_sad.set_talk_anims("talk", "idle");
-                sture_sad.play("idle");
-                self.actors.push(sture_sad);
-
-                // Sture happy at (285, 162) — initially hidden
-                let mut sture_happy = Actor::new("stureHappy", 285, 162, 20);
-                sture_happy.add_animation("idle", &[(&f, 34)], 10, true, assets);
-                sture_happy.add_animation("talk", &[
-                    (&f, 35), (&f, 36), (&f, 37), (&f, 38), (&f, 39),
-                ], 8, true, assets);
-                sture_happy.set_talk_anims("talk", "idle");
-                sture_happy.visible = false;
-                sture_happy.play("idle");
-                self.actors.push(sture_happy);
-            }
+            // RoadDog — Salka on the road
+            85 => self.load_bg(&f, 25, assets),
+            // Solhem — Mia and the cat
+            86 => self.load_bg(&f, 1, assets),
+            // Saftfabrik — lemonade factory (bottle conveyor + filling tap for
+            // scene_script::script_saftfabrik)
+            87 => self.load_bg(&f, 208, assets),
+            // StureStortand — BG 32 (with lemonade) or 40 (without), the
+            // scene script handles the switch
+            88 => self.load_bg(&f, 32, assets),
             92 => {
-                // ─── FiggeFerrum — Figge and his dog ────────────────────
+                // FiggeFerrum — Figge, his dog and Salka
                 self.load_bg(&f, 1, assets);
-
-                // Buffa (dog) from 00.CXT
-                let mut buffa = Actor::new("buffa", 271, 347, 15);
-                buffa.add_animation("idle", &[(b, 214)], 10, true, assets);
-                buffa.add_animation("scratch1", &[(b, 214), (b, 215)], 10, true, assets);
-                buffa.add_animation("sleep_intro", &[
-                    (b, 214), (b, 216), (b, 217), (b, 218),
-                ], 10, false, assets);
-                buffa.add_animation("sleep_loop", &[(b, 219), (b, 220)], 1, false, assets);
-                buffa.add_animation("bark", &[(b, 222), (b, 223)], 10, true, assets);
-                buffa.play("idle");
-                self.actors.push(buffa);
-
                 // Figge body (static overlay, anchored at 102, 292 like the head)
                 self.load_overlay_at(&f, 16, 102, 292, 18, true, assets);
-
-                // Figge head (animated) at (102, 292)
-                let mut figge = Actor::new("figge", 102, 292, 20);
-                figge.add_animation("idle", &[(&f, 17)], 10, true, assets);
-                figge.add_animation("talkPlayer", &[
-                    (&f, 17), (&f, 18), (&f, 19), (&f, 20),
-                    (&f, 21), (&f, 22), (&f, 23), (&f, 24), (&f, 25),
-                ], 10, true, assets);
-                figge.set_talk_anims("talkPlayer", "idle");
-                figge.play("idle");
-                self.actors.push(figge);
-
-                // SalkaLeft — appears when dog is returned (initially hidden)
-                let mut salka_l = Actor::new("salkaLeft", 200, 363, 18);
-                salka_l.add_animation("idle", &[
-                    (&f, 40), (&f, 41), (&f, 42), (&f, 43),
-                    (&f, 44), (&f, 43), (&f, 42), (&f, 41),
-                ], 15, true, assets);
-                salka_l.visible = false;
-                salka_l.play("idle");
-                self.actors.push(salka_l);
-            }
-            94 => {
-                self.load_carshow(assets);
-                return;
-            }
-            _ => {
-                // ─── Generic destination (82, 83, 84, 89, 90, 91, 93) ──
-                // Load the largest bitmap as background
-                self.load_largest_bg(&f, assets);
             }
+            // Generic destination (82, 83, 84, 89, 90, 91, 93): largest bitmap
+            _ => self.load_largest_bg(&f, assets),
         }
 
-        // ─── Common: Mulle actor + back-to-world hotspot ────────────────
-        if num != 94 {
-            let mut mulle = Actor::new("mulleDefault", mulle_x, mulle_y, 25);
-            mulle.add_animation("idle", &[(b, 271)], 10, true, assets);
-            mulle.add_animation("lookPlayer", &[(b, 287), (b, 288)], 10, true, assets);
-            mulle.add_animation("talkPlayer", &[
-                (b, 289), (b, 290), (b, 291), (b, 292),
-                (b, 293), (b, 294), (b, 295),
-            ], 10, true, assets);
-            mulle.add_animation("talkRegular", &[
-                (b, 296), (b, 297), (b, 298), (b, 299),
-                (b, 300), (b, 301), (b, 302),
-            ], 10, true, assets);
-            mulle.add_animation("lookLeft", &[(b, 283)], 10, true, assets);
-            mulle.add_animation("turnBack", &[(b, 285)], 10, true, assets);
-            mulle.add_animation("scratchChin", &[
-                (b, 271), (b, 272), (b, 273), (b, 274), (b, 275), (b, 276),
-            ], 10, false, assets);
-            mulle.add_animation("scratchHead", &[
-                (b, 277), (b, 278), (b, 279), (b, 280), (b, 281), (b, 282),
-            ], 10, false, assets);
-            mulle.set_talk_anims("talkRegular", "idle");
-            mulle.play("idle");
-            self.actors.push(mulle);
+        // Characters and Mulle (position per destination), see data/actors.json
+        self.spawn_actors(&format!("Destination({})", num), assets);
 
-            self.hotspots.push(Hotspot {
-                x: 0, y: 400, width: 640, height: 80,
-                name: "← Weltkarte".into(),
-                target: Some(Scene::World),
-            });
-        }
+        self.hotspots.push(Hotspot {
+            x: 0, y: 400, width: 640, height: 80,
+            name: "← Weltkarte".into(),
+            target: Some(Scene::World),
+        });
     }
 
     // ─── Generic fallback ──────────────────────────────────────────────
@@ -1170,6 +956,54 @@ impl SceneHandler {
         else { dxr } // fallback
     }
 
+    /// Add the actors of a scene entry in the actor table (`game::actors`)
+    fn spawn_actors(&mut self, key: &str, assets: &AssetStore) {
+        for def in ActorTable::load(&assets.game_dir).actors(key) {
+            if let Some(actor) = self.build_actor(&def, assets) {
+                self.actors.push(actor);
+            }
+        }
+    }
+
+    fn build_actor(&self, def: &ActorDef, assets: &AssetStore) -> Option<Actor> {
+        let file = def.file.as_deref().unwrap_or_default();
+        let file = if file.contains('.') { file.to_string() } else { self.resolve_file(file, assets) };
+        let optional = def.optional.unwrap_or(false);
+        if !assets.files.contains_key(&file) && !optional {
+            tracing::warn!("Actor '{}': {} not loaded", def.name(), file);
+        }
+        let mut actor = Actor::new(def.name(), def.x.unwrap_or(0), def.y.unwrap_or(0), def.z.unwrap_or(20));
+        for anim in &def.animations {
+            let members: Vec<u32> = anim.frames.iter()
+                .filter_map(|frame| match frame {
+                    FrameRef::Member(num) => Some(*num),
+                    FrameRef::Name(name) => {
+                        let found = self.find_member_by_name(&file, name, assets);
+                        if found.is_none() && !optional {
+                            tracing::warn!("Actor '{}': no member '{}' in {}", def.name(), name, file);
+                        }
+                        found
+                    }
+                })
+                .collect();
+            if members.is_empty() && optional {
+                tracing::debug!("Actor '{}' skipped: no frames for '{}'", def.name(), anim.name);
+                return None;
+            }
+            let refs: Vec<(&str, u32)> = members.iter().map(|&num| (file.as_str(), num)).collect();
+            actor.add_animation(&anim.name, &refs, anim.fps, anim.looping, assets);
+        }
+        if let (Some(talk), Some(silence)) = (&def.talk, &def.silence) {
+            actor.set_talk_anims(talk, silence);
+        }
+        actor.visible = def.visible.unwrap_or(true);
+        actor.mouse_track = def.mouse_track.unwrap_or(false);
+        if let Some(anim) = def.start_anim() {
+            actor.play(anim);
+        }
+        Some(actor)
+    }
+
     fn find_member_by_name(&self, file: &str, name: &str, assets: &AssetStore) -> Option<u32> {
         assets.files.get(file)?.cast_members.iter()
             .find(|(_, m)| m.name == name)
@@ -1204,7 +1038,10 @@ impl SceneHandler {
         let mut events = Vec::new();
         for actor in &mut self.actors {
             actor.update_mouse_track(mx, my);
-            if let Some(ActorEvent::AnimationFinished { actor_name, anim_name }) = actor.tick() {
+            if self.skip_idle_ticks && actor.is_idle() {
+                continue;
+            }
+            if let Some(ActorEvent::AnimationFinished { actor_name, anim_name }) = actor.tick(self.tempo) {
                 events.push(SceneEvent::ActorAnimFinished { actor_name, anim_name });
             }
         }
@@ -1214,14 +1051,27 @@ impl SceneHandler {
     // ─── Actor control (used by SceneScript) ────────────────────────────
 
     /// Play a named animation on a named actor
-    pub fn play_actor_anim(&mut self, actor_name: &str, anim_name: &str) {
+    /// Play an actor animation. Returns `false` if the actor or a playable
+    /// animation is missing, so callers waiting on it can move on.
+    pub fn play_actor_anim(&mut self, actor_name: &str, anim_name: &str) -> bool {
         for actor in &mut self.actors {
             if actor.name == actor_name {
-                actor.play(anim_name);
-                return;
+                return actor.play(anim_name);
             }
         }
         tracing::warn!("play_actor_anim: actor '{}' not found", actor_name);
+        false
+    }
+
+    /// Salka waits in the yard while she's with Mulle (#Dog, until
+    /// delivered to Figge). Uses her idle frames from the RoadDog scene (85).
+    pub fn add_yard_dog(&mut self, assets: &AssetStore) {
+        self.spawn_actors("YardDog", assets);
+    }
+
+    /// Add a seasonal decoration (static, or following an actor)
+    pub fn add_decoration(&mut self, decoration: Decoration) {
+        self.decorations.push(decoration);
     }
 
     /// Set actor visibility
@@ -1322,6 +1172,19 @@ impl SceneHandler {
             result.push(sprite);
         }
 
+        // Seasonal decorations — attached ones ride along just above their actor
+        for deco in &self.decorations {
+            let mut sprite = deco.sprite.clone();
+            if let Some(name) = &deco.actor {
+                let Some(actor) = self.actors.iter().find(|a| &a.name == name) else { continue };
+                sprite.x += actor.x;
+                sprite.y += actor.y;
+                sprite.z_order = actor.z_order + 1;
+                sprite.visible = actor.visible;
+            }
+            result.push(sprite);
+        }
+
         // Sort by z_order
         result.sort_by_key(|s| s.z_order);
         result
@@ -1570,13 +1433,7 @@ impl SceneHandler {
 
     pub fn on_char_input(&mut self, ch: char) {
         if self.scene != Scene::Menu { return; }
-        if ch == '\x08' {
-            self.input_text.pop();
-        } else if ch.is_ascii_graphic() || ch == ' ' {
-            if self.input_text.len() < 20 {
-                self.input_text.push(ch);
-            }
-        }
+        self.input_text.on_char(ch);
     }
 
     pub fn on_enter(&mut self, _assets: &AssetStore) -> Option<Scene> {
@@ -1591,8 +1448,8 @@ impl SceneHandler {
     }
 
     pub fn effective_name(&self) -> String {
-        if !self.input_text.trim().is_empty() {
-            self.input_text.trim().to_string()
+        if !self.input_text.text().trim().is_empty() {
+            self.input_text.text().trim().to_string()
         } else if let Some(idx) = self.selected_name {
             self.saved_names.get(idx).cloned().unwrap_or_default()
         } else {
@@ -1650,9 +1507,6 @@ impl SceneHandler {
     pub fn draw_ui(&mut self, fb: &mut [u32]) {
         if self.scene != Scene::Menu { return; }
 
-        self.frame_counter = self.frame_counter.wrapping_add(1);
-        self.cursor_visible = (self.frame_counter / 15) % 2 == 0;
-
         // Name input field — the paper area in the background is already white,
         // so we only draw a subtle tint + outline instead of an opaque white box.
         font::draw_rect(fb, Self::NAME_FIELD_X, Self::NAME_FIELD_Y,
@@ -1663,15 +1517,10 @@ impl SceneHandler {
         font::draw_text(fb, Self::NAME_FIELD_X + 4, Self::NAME_FIELD_Y + 4,
             "Dein Name:", 0xFF666644);
 
-        let display_text = self.input_text.clone();
         // Text on the lower half of the field
         let text_y = Self::NAME_FIELD_Y + 22;
-        font::draw_text(fb, Self::NAME_FIELD_X + 4, text_y,
-            &display_text, 0xFF000000);
-        if self.cursor_visible && self.selected_name.is_none() {
-            let cx = Self::NAME_FIELD_X + 4 + font::text_width(&display_text);
-            font::draw_text(fb, cx, text_y, "_", 0xFF000000);
-        }
+        let focused = self.selected_name.is_none();
+        self.input_text.draw(fb, Self::NAME_FIELD_X + 4, text_y, 0xFF000000, focused);
 
         // Name list — same approach: subtle tint on the existing paper
         font::draw_rect(fb, Self::NAME_LIST_X, Self::NAME_LIST_Y,
@@ -1690,12 +1539,14 @@ impl SceneHandler {
                 if self.selected_name == Some(i) {
                     font::draw_rect(fb, Self::NAME_LIST_X + 1, iy,
                         Self::NAME_LIST_W - 2, Self::NAME_LIST_ITEM_H, 0xFF4444AA);
-                    font::draw_text(fb, Self::NAME_LIST_X + 8, iy + 5,
-                        &name, 0xFFFFFFFF);
-                } else {
-                    font::draw_text(fb, Self::NAME_LIST_X + 8, iy + 5,
-                        &name, 0xFF000000);
                 }
+                // Car thumbnail left of the name (names without one stay aligned)
+                if let Some(thumb) = self.thumbnails.get(&name) {
+                    thumb.draw_to(fb, Self::NAME_LIST_X + 4, iy + 1);
+                }
+                let color = if self.selected_name == Some(i) { 0xFFFFFFFF } else { 0xFF000000 };
+                font::draw_text(fb, Self::NAME_LIST_X + 8 + avatars::THUMB_WIDTH as i32, iy + 5,
+                    &name, color);
             }
         }
 
@@ -1723,6 +1574,6 @@ impl SceneHandler {
 
         // Hint
         font::draw_text_shadow(fb, Self::PLAY_BTN_X, Self::DELETE_BTN_Y + 34,
-            "Enter=Spielen | F1-F9=Szene", 0xFFCCCCCC);
+            "Enter=Spielen | F1-F9=Szene | F10=Speedrun", 0xFFCCCCCC);
     }
 }
@@ -0,0 +1,349 @@
+//! Seasonal content — date-based easter eggs
+//!
+//! A small schedule (data/seasonal.json, overridable via
+//! `<game_dir>/mods/seasonal.json`) lists events with a date window or the
+//! profile's birthday, the scenes they decorate and the sprites to add:
+//! a party hat on Mulle on the player's birthday, bunting in the yard at
+//! Christmas, New Year and midsummer. Sprites are either cast members (by
+//! name) or simple shapes drawn here, so the stock game data is enough.
+//! The decorations are handed to the SceneHandler on scene entry.
+
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::assets::AssetStore;
+use crate::engine::Sprite;
+use crate::game::Scene;
+
+/// Z-order for static decorations (above backgrounds, below most actors)
+const DECORATION_Z: i32 = 12;
+
+// ─── Dates ──────────────────────────────────────────────────────────────
+
+/// A day in the year (no year — events repeat annually)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Date {
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Today's date (UTC)
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self::from_unix_days((secs / 86_400) as i64)
+    }
+
+    /// Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    fn from_unix_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        Self { month, day }
+    }
+
+    /// Inclusive range check; `from > to` wraps over New Year
+    fn within(self, from: Date, to: Date) -> bool {
+        if from <= to {
+            from <= self && self <= to
+        } else {
+            self >= from || self <= to
+        }
+    }
+}
+
+// ─── Schedule data ──────────────────────────────────────────────────────
+
+/// When an event is active
+#[derive(Debug, Clone, Default, Deserialize)]
+struct When {
+    /// Active on the profile's birthday
+    #[serde(default)]
+    birthday: bool,
+    from: Option<Date>,
+    to: Option<Date>,
+}
+
+/// How a decoration sprite is made
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "shape", rename_all = "snake_case")]
+enum Shape {
+    /// A cast bitmap, looked up by member name
+    Member { name: String },
+    /// Striped cone hat
+    PartyHat {
+        #[serde(default)]
+        colors: Vec<String>,
+    },
+    /// A line of triangular pennants
+    Bunting {
+        width: u32,
+        #[serde(default)]
+        colors: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SpriteDef {
+    #[serde(flatten)]
+    shape: Shape,
+    /// Attach to this actor (x/y relative to the actor's position)
+    actor: Option<String>,
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Event {
+    id: String,
+    when: When,
+    /// Scene names as in `Scene`'s Debug output ("Yard", "Garage", …)
+    scenes: Vec<String>,
+    sprites: Vec<SpriteDef>,
+}
+
+/// A decoration sprite for the SceneHandler
+pub struct Decoration {
+    /// Pixels + position (relative to the actor when attached)
+    pub sprite: Sprite,
+    /// Actor to follow, if any
+    pub actor: Option<String>,
+}
+
+/// All scheduled seasonal events
+#[derive(Debug, Default, Deserialize)]
+pub struct SeasonalCalendar {
+    events: Vec<Event>,
+}
+
+impl SeasonalCalendar {
+    /// Built-in schedule, replaced by `<game_dir>/mods/seasonal.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("seasonal.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(cal) => {
+                    tracing::info!("Seasonal schedule loaded from {}", path.display());
+                    return cal;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/seasonal.json"))
+            .expect("Failed to parse seasonal.json")
+    }
+
+    /// IDs of the events active on `today`
+    #[allow(dead_code)] // Schedule checks in tests / dev tooling
+    pub fn active_events(&self, today: Date, birthday: Option<Date>) -> Vec<&str> {
+        self.events.iter()
+            .filter(|e| e.is_active(today, birthday))
+            .map(|e| e.id.as_str())
+            .collect()
+    }
+
+    /// Decorations to add to `scene` today
+    pub fn decorations(
+        &self,
+        scene: Scene,
+        today: Date,
+        birthday: Option<Date>,
+        assets: &AssetStore,
+    ) -> Vec<Decoration> {
+        let scene_name = format!("{:?}", scene);
+        let mut out = Vec::new();
+        for event in &self.events {
+            if !event.scenes.contains(&scene_name) || !event.is_active(today, birthday) {
+                continue;
+            }
+            tracing::info!("Seasonal event '{}' active in {}", event.id, scene_name);
+            for def in &event.sprites {
+                if let Some(sprite) = def.build(&event.id, assets) {
+                    out.push(Decoration { sprite, actor: def.actor.clone() });
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Event {
+    fn is_active(&self, today: Date, birthday: Option<Date>) -> bool {
+        if self.when.birthday && birthday == Some(today) {
+            return true;
+        }
+        match (self.when.from, self.when.to) {
+            (Some(from), Some(to)) => today.within(from, to),
+            (Some(day), None) | (None, Some(day)) => today == day,
+            (None, None) => false,
+        }
+    }
+}
+
+impl SpriteDef {
+    fn build(&self, event_id: &str, assets: &AssetStore) -> Option<Sprite> {
+        let colors: Vec<u32> = match &self.shape {
+            Shape::PartyHat { colors } | Shape::Bunting { colors, .. } => {
+                colors.iter().filter_map(|c| parse_color(c)).collect()
+            }
+            Shape::Member { .. } => Vec::new(),
+        };
+        let colors = if colors.is_empty() { vec![0xFFE83A7A, 0xFFFFD23F] } else { colors };
+
+        let (width, height, pixels) = match &self.shape {
+            Shape::Member { name } => {
+                let Some(bmp) = assets.find_bitmap_by_name(name) else {
+                    tracing::warn!("Seasonal '{}': cast member '{}' not found", event_id, name);
+                    return None;
+                };
+                (bmp.width, bmp.height, bmp.pixels)
+            }
+            Shape::PartyHat { .. } => {
+                let (w, h, px) = party_hat(&colors);
+                (w, h, argb_to_rgba(&px))
+            }
+            Shape::Bunting { width, .. } => {
+                let (w, h, px) = bunting(*width, &colors);
+                (w, h, argb_to_rgba(&px))
+            }
+        };
+        Some(Sprite {
+            x: self.x,
+            y: self.y,
+            width,
+            height,
+            pixels: pixels.into(),
+            visible: true,
+            z_order: DECORATION_Z,
+            name: format!("seasonal:{}", event_id),
+            interactive: false,
+            member_num: 0,
+        })
+    }
+}
+
+/// "#RRGGBB" → opaque ARGB
+fn parse_color(s: &str) -> Option<u32> {
+    let hex = s.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().map(|rgb| 0xFF00_0000 | rgb)
+}
+
+/// ARGB pixels → RGBA bytes (sprite pixel format)
+pub fn argb_to_rgba(px: &[u32]) -> Vec<u8> {
+    px.iter()
+        .flat_map(|&p| [(p >> 16) as u8, (p >> 8) as u8, p as u8, (p >> 24) as u8])
+        .collect()
+}
+
+// ─── Shapes ─────────────────────────────────────────────────────────────
+
+const HAT_W: u32 = 28;
+const HAT_H: u32 = 32;
+
+/// Cone hat with diagonal stripes and a pom-pom on top
+fn party_hat(colors: &[u32]) -> (u32, u32, Vec<u32>) {
+    let mut px = vec![0u32; (HAT_W * HAT_H) as usize];
+    let cx = HAT_W as i32 / 2;
+    for y in 0..HAT_H as i32 {
+        // Pom-pom: top 6 rows
+        if y < 6 {
+            for x in 0..HAT_W as i32 {
+                let (dx, dy) = (x - cx, y - 3);
+                if dx * dx + dy * dy <= 9 {
+                    px[(y * HAT_W as i32 + x) as usize] = 0xFFFFFFFF;
+                }
+            }
+            continue;
+        }
+        let half = (y - 6) * cx / (HAT_H as i32 - 6);
+        for x in (cx - half).max(0)..(cx + half + 1).min(HAT_W as i32) {
+            let stripe = ((x + y) / 5) as usize % colors.len();
+            px[(y * HAT_W as i32 + x) as usize] = colors[stripe];
+        }
+    }
+    (HAT_W, HAT_H, px)
+}
+
+const PENNANT_W: u32 = 16;
+const PENNANT_H: u32 = 18;
+
+/// A string with triangular pennants hanging below it
+fn bunting(width: u32, colors: &[u32]) -> (u32, u32, Vec<u32>) {
+    let width = width.max(PENNANT_W);
+    let height = PENNANT_H + 2;
+    let mut px = vec![0u32; (width * height) as usize];
+    // The string
+    for x in 0..width as usize {
+        px[x] = 0xFF3A2A1A;
+        px[width as usize + x] = 0xFF3A2A1A;
+    }
+    // Pennants with a small gap between them
+    let step = PENNANT_W + 4;
+    for (i, left) in (0..width - PENNANT_W + 1).step_by(step as usize).enumerate() {
+        let color = colors[i % colors.len()];
+        for row in 0..PENNANT_H {
+            let inset = row * PENNANT_W / (2 * PENNANT_H);
+            for x in left + inset..left + PENNANT_W - inset {
+                px[((row + 2) * width + x) as usize] = color;
+            }
+        }
+    }
+    (width, height, px)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u8, day: u8) -> Date {
+        Date { month, day }
+    }
+
+    #[test]
+    fn unix_days_to_date() {
+        assert_eq!(Date::from_unix_days(0), date(1, 1));
+        // 2024-02-29 (leap day)
+        assert_eq!(Date::from_unix_days(19_782), date(2, 29));
+        // 2026-12-24
+        assert_eq!(Date::from_unix_days(20_811), date(12, 24));
+    }
+
+    #[test]
+    fn builtin_schedule() {
+        let cal = SeasonalCalendar::builtin();
+        assert_eq!(cal.active_events(date(12, 24), None), vec!["christmas"]);
+        // New Year wraps over the year boundary
+        assert_eq!(cal.active_events(date(1, 1), None), vec!["new_year"]);
+        assert!(cal.active_events(date(3, 10), None).is_empty());
+        let bday = Some(date(3, 10));
+        assert_eq!(cal.active_events(date(3, 10), bday), vec!["birthday"]);
+    }
+
+    #[test]
+    fn shapes_have_pixels() {
+        let (w, h, px) = party_hat(&[0xFF112233]);
+        assert_eq!(px.len(), (w * h) as usize);
+        assert!(px.contains(&0xFF112233));
+        let (w, h, px) = bunting(100, &[0xFF445566]);
+        assert_eq!((w, px.len()), (100, (100 * h) as usize));
+        assert_eq!(parse_color("#445566"), Some(0xFF445566));
+        assert_eq!(parse_color("445566"), None);
+        assert_eq!(argb_to_rgba(&[0xFF445566, 0]), vec![0x44, 0x55, 0x66, 0xFF, 0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,201 @@
+//! Speedrun mode — real-time timer, medal splits and route log
+//!
+//! F10 on the main menu arms a run: the session RNG is reseeded with a
+//! fixed seed (same random destinations and events for everybody; #Random
+//! junk rewards draw from the same stream, but what they hand out still
+//! depends on the parts a runner owns by then) and assists are off (dev menu and cheats, scene warps, part search). The
+//! timer starts when the menu is left. The overlay shows the time, the
+//! medal splits and the held inputs. F10 again ends the run and writes a
+//! summary to `<game_dir>/speedruns/run_<timestamp>.json`.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::engine::font;
+use crate::game::Scene;
+
+/// Fixed RNG seed for runs ("MULLE")
+pub const SPEEDRUN_SEED: u64 = 0x4D554C4C45;
+
+/// Held inputs for the input display
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Inputs {
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool,
+    pub mouse: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Split {
+    pub medal: u32,
+    pub time_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RouteStep {
+    pub scene: Scene,
+    pub time_ms: u64,
+}
+
+/// Exported run summary
+#[derive(Debug, Serialize)]
+pub struct RunSummary<'a> {
+    pub version: &'static str,
+    pub seed: u64,
+    pub total_ms: u64,
+    pub splits: &'a [Split],
+    pub route: &'a [RouteStep],
+}
+
+#[derive(Debug, Default)]
+pub struct Speedrun {
+    /// None while armed on the main menu
+    start: Option<Instant>,
+    /// Set when the run was ended (timer frozen)
+    end: Option<Instant>,
+    pub splits: Vec<Split>,
+    pub route: Vec<RouteStep>,
+    pub inputs: Inputs,
+}
+
+impl Speedrun {
+    pub fn is_running(&self) -> bool {
+        self.start.is_some() && self.end.is_none()
+    }
+
+    pub fn start(&mut self, now: Instant) {
+        if self.start.is_none() {
+            self.start = Some(now);
+            tracing::info!("Speedrun started");
+        }
+    }
+
+    pub fn finish(&mut self, now: Instant) {
+        if self.is_running() {
+            self.end = Some(now);
+        }
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        match self.start {
+            Some(start) => self.end.unwrap_or(now).duration_since(start),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// First time a medal is earned
+    pub fn split(&mut self, medal: u32, now: Instant) {
+        if self.is_running() && !self.splits.iter().any(|s| s.medal == medal) {
+            let time_ms = self.elapsed(now).as_millis() as u64;
+            tracing::info!("Speedrun split: medal {} at {}", medal, format_time(self.elapsed(now)));
+            self.splits.push(Split { medal, time_ms });
+        }
+    }
+
+    /// Scene entered (route tracking)
+    pub fn enter_scene(&mut self, scene: Scene, now: Instant) {
+        if self.is_running() {
+            self.route.push(RouteStep { scene, time_ms: self.elapsed(now).as_millis() as u64 });
+        }
+    }
+
+    pub fn summary(&self, seed: u64, now: Instant) -> RunSummary<'_> {
+        RunSummary {
+            version: env!("CARGO_PKG_VERSION"),
+            seed,
+            total_ms: self.elapsed(now).as_millis() as u64,
+            splits: &self.splits,
+            route: &self.route,
+        }
+    }
+
+    /// Write the summary into `<game_dir>/speedruns/` and return its path
+    pub fn export(&self, game_dir: &Path, seed: u64, now: Instant) -> Result<PathBuf> {
+        let dir = game_dir.join("speedruns");
+        std::fs::create_dir_all(&dir)?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let path = dir.join(format!("run_{}.json", timestamp));
+        std::fs::write(&path, serde_json::to_string_pretty(&self.summary(seed, now))?)?;
+        Ok(path)
+    }
+
+    /// Timer, splits and input display in the top-right corner
+    pub fn draw(&self, fb: &mut [u32], now: Instant) {
+        const X: i32 = 520;
+        let h = 40 + self.splits.len() as i32 * 12;
+        font::draw_rect(fb, X, 64, 112, h, 0xCC000000);
+        let color = if self.start.is_none() { 0xFF999999 } else if self.end.is_some() { 0xFF66FF66 } else { 0xFFFFFFFF };
+        font::draw_text_shadow(fb, X + 6, 70, &format_time(self.elapsed(now)), color);
+        for (i, split) in self.splits.iter().enumerate() {
+            let text = format!("M{} {}", split.medal, format_time(Duration::from_millis(split.time_ms)));
+            font::draw_text(fb, X + 6, 84 + i as i32 * 12, &text, 0xFFFFDD44);
+        }
+
+        // Input display: arrow keys + mouse button
+        let y = 64 + h - 16;
+        let keys = [
+            ("<", self.inputs.left),
+            ("^", self.inputs.up),
+            ("v", self.inputs.down),
+            (">", self.inputs.right),
+            ("M", self.inputs.mouse),
+        ];
+        for (i, (label, held)) in keys.iter().enumerate() {
+            let x = X + 6 + i as i32 * 20;
+            let bg = if *held { 0xFFFFDD44 } else { 0xFF333333 };
+            font::draw_rect(fb, x, y, 16, 12, bg);
+            let fg = if *held { 0xFF000000 } else { 0xFF999999 };
+            font::draw_text(fb, x + 5, y + 2, label, fg);
+        }
+    }
+}
+
+/// m:ss.cc
+pub fn format_time(d: Duration) -> String {
+    let cs = d.as_millis() / 10;
+    format!("{}:{:02}.{:02}", cs / 6000, (cs / 100) % 60, cs % 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_format() {
+        assert_eq!(format_time(Duration::from_millis(0)), "0:00.00");
+        assert_eq!(format_time(Duration::from_millis(83_456)), "1:23.45");
+        assert_eq!(format_time(Duration::from_secs(3725)), "62:05.00");
+    }
+
+    #[test]
+    fn splits_only_while_running_and_once() {
+        let t0 = Instant::now();
+        let mut run = Speedrun::default();
+        run.split(1, t0);
+        run.enter_scene(Scene::Garage, t0);
+        assert!(run.splits.is_empty() && run.route.is_empty());
+
+        run.start(t0);
+        run.enter_scene(Scene::Garage, t0 + Duration::from_secs(1));
+        run.split(1, t0 + Duration::from_secs(5));
+        run.split(1, t0 + Duration::from_secs(9));
+        assert_eq!(run.splits.len(), 1);
+        assert_eq!(run.splits[0].time_ms, 5000);
+
+        run.finish(t0 + Duration::from_secs(10));
+        assert_eq!(run.elapsed(t0 + Duration::from_secs(60)), Duration::from_secs(10));
+        run.split(2, t0 + Duration::from_secs(11));
+        assert_eq!(run.splits.len(), 1);
+
+        let json = serde_json::to_string(&run.summary(SPEEDRUN_SEED, t0)).unwrap();
+        assert!(json.contains("\"total_ms\":10000") && json.contains("\"Garage\""));
+    }
+}
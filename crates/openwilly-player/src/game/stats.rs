@@ -0,0 +1,153 @@
+//! Profile statistics — counters collected while playing
+//!
+//! Kilometers driven, fuel used, parts collected, destinations visited,
+//! races won and time played. The counters live in the save profile and are
+//! bumped by the driving / garage systems; F2 shows them as an overlay.
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::font;
+use crate::engine::{SCREEN_WIDTH, SCREEN_HEIGHT};
+use crate::game::i18n::{self, Language};
+
+/// Driving speed is in px/frame; the debug HUD shows `speed * 30` as km/h,
+/// so one frame at speed 1.0 covers 30 km/h / (30 fps · 3600 s/h) km.
+const KM_PER_SPEED_FRAME: f64 = 1.0 / 3600.0;
+/// Fuel units per liter (a car's tank holds `fuel_volume * 12` units)
+const FUEL_UNITS_PER_LITER: f64 = 12.0;
+
+const PANEL_W: i32 = 320;
+const PANEL_H: i32 = 220;
+
+/// Per-profile counters
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileStats {
+    #[serde(default)]
+    pub km_driven: f64,
+    /// Fuel used, in fuel units (see `FUEL_UNITS_PER_LITER`)
+    #[serde(default)]
+    pub fuel_used: f64,
+    /// Parts that arrived in the yard (rewards, Figge, deliveries)
+    #[serde(default)]
+    pub parts_collected: u32,
+    /// Destination scene numbers (82-94) visited at least once
+    #[serde(default)]
+    pub destinations: Vec<u8>,
+    /// Destinations seen on the map (see `discovery`)
+    #[serde(default)]
+    pub discovered: Vec<u8>,
+    #[serde(default)]
+    pub races_won: u32,
+    #[serde(default)]
+    pub play_seconds: u64,
+}
+
+impl ProfileStats {
+    /// Account one driving frame: current speed and how much fuel the
+    /// frame burned (refuelling doesn't count)
+    pub fn add_drive_frame(&mut self, speed: f32, fuel_burned: f32) {
+        self.km_driven += speed.abs() as f64 * KM_PER_SPEED_FRAME;
+        if fuel_burned > 0.0 {
+            self.fuel_used += fuel_burned as f64;
+        }
+    }
+
+    /// Remember a destination. Returns true on the first visit.
+    pub fn visit(&mut self, destination: u8) -> bool {
+        if self.destinations.contains(&destination) {
+            return false;
+        }
+        self.destinations.push(destination);
+        true
+    }
+
+    /// Remember a destination seen on the map. Returns true on discovery.
+    pub fn discover(&mut self, destination: u8) -> bool {
+        if self.discovered.contains(&destination) {
+            return false;
+        }
+        self.discovered.push(destination);
+        true
+    }
+
+    pub fn liters_used(&self) -> f64 {
+        self.fuel_used / FUEL_UNITS_PER_LITER
+    }
+}
+
+/// "1:05 h" / "12 min"
+pub fn format_play_time(seconds: u64) -> String {
+    let minutes = seconds / 60;
+    if minutes >= 60 {
+        format!("{}:{:02} h", minutes / 60, minutes % 60)
+    } else {
+        format!("{} min", minutes)
+    }
+}
+
+/// Draw the statistics overlay for a profile
+pub fn draw_stats(fb: &mut [u32], player: &str, stats: &ProfileStats, lang: Language) {
+    let x = (SCREEN_WIDTH as i32 - PANEL_W) / 2;
+    let y = (SCREEN_HEIGHT as i32 - PANEL_H) / 2;
+    font::draw_rect(fb, x, y, PANEL_W, PANEL_H, 0xEE1A1A2E);
+    font::draw_rect_outline(fb, x, y, PANEL_W, PANEL_H, 0xFF6666CC);
+
+    let title = format!("{}: {}", i18n::t(lang, "stats_title"), player);
+    font::draw_text_shadow(fb, x + (PANEL_W - font::text_width(&title)) / 2, y + 14, &title, 0xFFFFDD44);
+
+    let rows = [
+        ("stats_km", format!("{:.1} km", stats.km_driven)),
+        ("stats_fuel", format!("{:.1} l", stats.liters_used())),
+        ("stats_parts", stats.parts_collected.to_string()),
+        ("stats_discovered", format!("{}/13", stats.discovered.len())),
+        ("stats_destinations", format!("{}/13", stats.destinations.len())),
+        ("stats_races", stats.races_won.to_string()),
+        ("stats_time", format_play_time(stats.play_seconds)),
+    ];
+    for (i, (key, value)) in rows.iter().enumerate() {
+        let ry = y + 44 + i as i32 * 20;
+        font::draw_text(fb, x + 24, ry, i18n::t(lang, key), 0xFFCCCCCC);
+        font::draw_text(fb, x + PANEL_W - 24 - font::text_width(value), ry, value, 0xFFFFFFFF);
+    }
+
+    let hint = i18n::t(lang, "stats_hint");
+    font::draw_text(fb, x + (PANEL_W - font::text_width(hint)) / 2, y + PANEL_H - 18, hint, 0xFF888888);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drive_frames_accumulate() {
+        let mut stats = ProfileStats::default();
+        // One hour at "30 km/h" (speed 1.0)
+        for _ in 0..30 * 3600 {
+            stats.add_drive_frame(1.0, 0.0);
+        }
+        assert!((stats.km_driven - 30.0).abs() < 0.01);
+        // Refuelling (negative burn) isn't fuel used
+        stats.add_drive_frame(0.0, 24.0);
+        stats.add_drive_frame(0.0, -60.0);
+        assert_eq!(stats.liters_used(), 2.0);
+    }
+
+    #[test]
+    fn destinations_counted_once() {
+        let mut stats = ProfileStats::default();
+        assert!(stats.visit(85));
+        assert!(!stats.visit(85));
+        assert!(stats.visit(92));
+        assert_eq!(stats.destinations, vec![85, 92]);
+        assert!(stats.discover(92));
+        assert!(!stats.discover(92));
+        assert_eq!(stats.discovered, vec![92]);
+    }
+
+    #[test]
+    fn play_time_format() {
+        assert_eq!(format_play_time(59), "0 min");
+        assert_eq!(format_play_time(12 * 60 + 5), "12 min");
+        assert_eq!(format_play_time(3600 + 5 * 60), "1:05 h");
+    }
+}
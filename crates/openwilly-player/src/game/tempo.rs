@@ -0,0 +1,92 @@
+//! Scene tempo — how fast the authored animations play
+//!
+//! Game logic runs at 30 Hz, but the movies were made for Director's
+//! tempo (12 fps in the menus), where a cel changes at most once per
+//! score frame. A scene's tempo is the first one its movie's score sets;
+//! casts have no score, so otherwise the table (data/tempo.json, replaced
+//! by `<game_dir>/mods/tempo.json`) decides. Actor animations play at
+//! their own rate capped by the tempo, "one cel per frame" animations at
+//! the tempo itself. Driving physics keep running at 30 Hz.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::game::scenes::LOGIC_FPS;
+use crate::game::Scene;
+
+#[derive(Debug, Deserialize)]
+pub struct TempoTable {
+    /// Tempo of scenes without an entry (30 = animations at their own rate)
+    #[serde(default = "logic_fps")]
+    default: u32,
+    /// Scene names as in `Scene`'s Debug output ("Menu", "Destination(85)", …)
+    #[serde(default)]
+    scenes: HashMap<String, u32>,
+}
+
+fn logic_fps() -> u32 {
+    LOGIC_FPS
+}
+
+impl TempoTable {
+    /// Built-in table, replaced by `<game_dir>/mods/tempo.json` if present
+    pub fn load(game_dir: &Path) -> Self {
+        let path = game_dir.join("mods").join("tempo.json");
+        if let Ok(json) = std::fs::read_to_string(&path) {
+            match serde_json::from_str(&json) {
+                Ok(table) => {
+                    tracing::info!("Scene tempi loaded from {}", path.display());
+                    return table;
+                }
+                Err(e) => tracing::warn!("Invalid {}: {}", path.display(), e),
+            }
+        }
+        Self::builtin()
+    }
+
+    fn builtin() -> Self {
+        serde_json::from_str(include_str!("../../data/tempo.json"))
+            .expect("Failed to parse tempo.json")
+    }
+
+    /// Tempo of a scene in fps: the score's, else the table's
+    pub fn tempo(&self, scene: Scene, score_tempo: Option<u8>) -> u32 {
+        score_tempo.map(u32::from)
+            .or_else(|| self.scenes.get(&format!("{:?}", scene)).copied())
+            .unwrap_or(self.default)
+            .clamp(1, LOGIC_FPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::scenes::{AnimFrame, Animation};
+
+    fn frames_shown(fps: u32, tempo: u32, ticks: u32) -> usize {
+        let mut anim = Animation::new("test", fps, true);
+        anim.frames = vec![AnimFrame { width: 1, height: 1, pixels: vec![0; 4].into(), reg_x: 0, reg_y: 0 }; 100];
+        anim.play();
+        (0..ticks).filter(|_| anim.tick(tempo)).count()
+    }
+
+    #[test]
+    fn score_tempo_wins_over_the_table() {
+        let table = TempoTable::builtin();
+        assert_eq!(table.tempo(Scene::Menu, None), 12);
+        assert_eq!(table.tempo(Scene::Menu, Some(15)), 15);
+        assert_eq!(table.tempo(Scene::Garage, None), LOGIC_FPS);
+        assert_eq!(table.tempo(Scene::Garage, Some(60)), LOGIC_FPS);
+    }
+
+    #[test]
+    fn animations_are_capped_by_the_tempo() {
+        // One second of logic ticks
+        assert_eq!(frames_shown(10, 30, 30), 10);
+        assert_eq!(frames_shown(15, 12, 30), 12);
+        assert_eq!(frames_shown(0, 12, 30), 12);
+        assert_eq!(frames_shown(5, 12, 30), 5);
+    }
+}
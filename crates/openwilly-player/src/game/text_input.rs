@@ -0,0 +1,107 @@
+//! Single-line text entry widget
+//!
+//! Shared by the main-menu name field and the car-show naming dialog.
+//! Only handles the text buffer and caret blink; the owning scene draws
+//! the frame around it.
+
+use crate::engine::font;
+
+/// Caret blink cycle in frames: shown for the first half, hidden for the second
+const CARET_PERIOD: u32 = 30;
+
+/// A single-line text buffer with a character limit and blinking caret
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    text: String,
+    max_len: usize,
+    frame_counter: u32,
+}
+
+impl TextInput {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            text: String::new(),
+            max_len,
+            frame_counter: 0,
+        }
+    }
+
+    /// Feed a typed character. `'\x08'` deletes the last character.
+    /// Characters the UI font can't draw are ignored.
+    /// Returns `true` if the buffer changed.
+    pub fn on_char(&mut self, ch: char) -> bool {
+        if ch == '\x08' {
+            return self.text.pop().is_some();
+        }
+        if font::has_glyph(ch) && self.text.chars().count() < self.max_len {
+            self.text.push(ch);
+            return true;
+        }
+        false
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.chars().take(self.max_len).collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+    }
+
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Draw the text (and caret when `focused`) at the given position.
+    /// Call once per frame — advances the caret blink.
+    pub fn draw(&mut self, fb: &mut [u32], x: i32, y: i32, color: u32, focused: bool) {
+        self.frame_counter = (self.frame_counter + 1) % CARET_PERIOD;
+        font::draw_text(fb, x, y, &self.text, color);
+        if focused && self.frame_counter < CARET_PERIOD / 2 {
+            let cx = x + font::text_width(&self.text);
+            font::draw_text(fb, cx, y, "_", color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn respects_max_len() {
+        let mut input = TextInput::new(3);
+        for ch in "abcdef".chars() {
+            input.on_char(ch);
+        }
+        assert_eq!(input.text(), "abc");
+    }
+
+    #[test]
+    fn backspace_and_control_chars() {
+        let mut input = TextInput::new(10);
+        input.on_char('a');
+        input.on_char('\n');
+        input.on_char('b');
+        assert_eq!(input.text(), "ab");
+        assert!(input.on_char('\x08'));
+        assert_eq!(input.text(), "a");
+        input.on_char('\x08');
+        assert!(!input.on_char('\x08'), "backspace on empty buffer is a no-op");
+    }
+
+    #[test]
+    fn umlauts_count_as_one_character() {
+        let mut input = TextInput::new(4);
+        for ch in "Åsa€öü".chars() {
+            input.on_char(ch);
+        }
+        assert_eq!(input.text(), "Åsaö");
+        assert!(input.on_char('\x08'));
+        assert_eq!(input.text(), "Åsa");
+    }
+}
@@ -0,0 +1,96 @@
+//! Loading tips — short hints under the transition progress bar
+//!
+//! Tips are i18n keys (`tip_*`, texts in the i18n catalog). The next tip is
+//! picked at random, weighted by how many transitions ago each tip was last
+//! shown, so the same hint never appears twice in a row and unseen ones
+//! come up first.
+
+use rand::Rng;
+
+use crate::engine::font;
+use crate::game::i18n::{self, Language};
+
+/// All tips (i18n keys)
+pub const TIPS: &[&str] = &[
+    "tip_drive_keys",
+    "tip_road_legal",
+    "tip_fuel",
+    "tip_destinations",
+    "tip_junkyard",
+    "tip_horn",
+    "tip_gallery",
+    "tip_stats",
+    "tip_escape",
+];
+
+/// Weighted tip rotation
+pub struct Tips {
+    /// Transitions since each tip was last shown
+    age: Vec<u32>,
+}
+
+impl Default for Tips {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tips {
+    pub fn new() -> Self {
+        Self { age: vec![TIPS.len() as u32; TIPS.len()] }
+    }
+
+    /// Pick the next tip (weight = age²; the current tip has weight 0)
+    pub fn next(&mut self, rng: &mut impl Rng) -> &'static str {
+        let total: u64 = self.age.iter().map(|&a| (a as u64).pow(2)).sum();
+        let mut roll = rng.gen_range(0..total.max(1));
+        let mut pick = 0;
+        for (i, &a) in self.age.iter().enumerate() {
+            let w = (a as u64).pow(2);
+            if roll < w {
+                pick = i;
+                break;
+            }
+            roll -= w;
+        }
+        for a in &mut self.age {
+            *a = a.saturating_add(1);
+        }
+        self.age[pick] = 0;
+        TIPS[pick]
+    }
+}
+
+/// Draw a tip centered at `y`
+pub fn draw_tip(fb: &mut [u32], y: i32, key: &str, lang: Language) {
+    let text = i18n::t(lang, key);
+    let w = font::text_width(text);
+    font::draw_text_shadow(fb, (640 - w) / 2, y, text, 0xFFFFFFFF);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn no_immediate_repeats_and_all_tips_come_up() {
+        let mut tips = Tips::new();
+        let mut rng = StdRng::seed_from_u64(7);
+        let picks: Vec<&str> = (0..TIPS.len() * 3).map(|_| tips.next(&mut rng)).collect();
+        assert!(picks.windows(2).all(|w| w[0] != w[1]));
+        assert!(TIPS.iter().all(|t| picks.contains(t)));
+    }
+
+    #[test]
+    fn tips_fit_on_screen() {
+        for lang in [Language::German, Language::English] {
+            for key in TIPS {
+                let text = i18n::t(lang, key);
+                assert_ne!(text, "???", "{}", key);
+                assert!(font::text_width(text) <= 620, "{:?} {}", lang, key);
+            }
+        }
+    }
+}
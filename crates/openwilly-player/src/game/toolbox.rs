@@ -2,9 +2,13 @@
 //!
 //! A tab at the right edge of the screen that can be clicked to open a popup
 //! menu with Home/Quit/Cancel buttons (plus Steering/Diploma placeholders).
+//! Below the popup drawn buttons toggle the driving minimap and call
+//! Figge's tow truck.
 
 use crate::assets::AssetStore;
-use crate::engine::Sprite;
+use crate::engine::font;
+use crate::engine::{Sprite, SpritePixels};
+use crate::game::i18n::{self, Language};
 
 /// Popup menu button regions (in popup-local coordinates)
 struct MenuButton {
@@ -30,6 +34,10 @@ pub enum PopupAction {
     Steering,
     /// Show earned medals / diploma info
     Diploma,
+    /// Show / hide the minimap
+    Minimap,
+    /// Call Figge's tow truck
+    Tow,
 }
 
 /// Toolbox state for the world scene
@@ -44,6 +52,8 @@ pub struct Toolbox {
     pub hovered: bool,
     /// Whether hint sound was already played this hover
     hover_sound_played: bool,
+    /// Whether the minimap is shown while driving
+    pub minimap: bool,
 }
 
 struct SpriteData {
@@ -51,7 +61,7 @@ struct SpriteData {
     y: i32,
     width: u32,
     height: u32,
-    pixels: Vec<u8>,
+    pixels: SpritePixels,
     member_num: u32,
 }
 
@@ -60,8 +70,10 @@ const ICON_X: i32 = 659;
 const ICON_Y: i32 = 439;
 const ICON_SLIDE: i32 = 40;
 
-/// Popup hover sound for toolbox tab
-pub const TOOLBOX_HOVER_SOUND: &str = "00e040v0";
+/// Minimap toggle button (screen coordinates, drawn while the popup is open)
+const MINIMAP_BUTTON: (i32, i32, i32, i32) = (160, 446, 150, 22);
+/// Tow truck button, next to the minimap button
+const TOW_BUTTON: (i32, i32, i32, i32) = (330, 446, 150, 22);
 
 /// Menu button definitions with click regions (relative to popup sprite origin)
 const MENU_BUTTONS: [MenuButton; 5] = [
@@ -93,6 +105,7 @@ impl Toolbox {
             popup,
             hovered: false,
             hover_sound_played: false,
+            minimap: true,
         }
     }
 
@@ -155,6 +168,14 @@ impl Toolbox {
         if !self.popup_open {
             return None;
         }
+        let (bx, by, bw, bh) = MINIMAP_BUTTON;
+        if screen_x >= bx && screen_y >= by && screen_x < bx + bw && screen_y < by + bh {
+            return Some(PopupAction::Minimap);
+        }
+        let (bx, by, bw, bh) = TOW_BUTTON;
+        if screen_x >= bx && screen_y >= by && screen_x < bx + bw && screen_y < by + bh {
+            return Some(PopupAction::Tow);
+        }
         let popup = self.popup.as_ref()?;
         // Convert screen coords to popup-local coords
         let lx = screen_x - popup.x;
@@ -171,7 +192,34 @@ impl Toolbox {
         None
     }
 
+    /// Draw the minimap toggle button (only while the popup is open)
+    pub fn draw_minimap_button(&self, fb: &mut [u32], lang: Language) {
+        if !self.popup_open {
+            return;
+        }
+        let (bx, by, bw, bh) = MINIMAP_BUTTON;
+        let label = i18n::t(lang, if self.minimap { "minimap_on" } else { "minimap_off" });
+        font::draw_rect(fb, bx, by, bw, bh, 0xDD1A1A2E);
+        font::draw_rect_outline(fb, bx, by, bw, bh, 0xFF6666CC);
+        font::draw_text_shadow(fb, bx + (bw - font::text_width(label)) / 2, by + 7, label, 0xFFFFFFFF);
+    }
 
+    /// Draw the tow truck button (only while the popup is open), greyed
+    /// out with the remaining seconds while Figge is on cooldown
+    pub fn draw_tow_button(&self, fb: &mut [u32], lang: Language, cooldown_secs: u32) {
+        if !self.popup_open {
+            return;
+        }
+        let (bx, by, bw, bh) = TOW_BUTTON;
+        let (label, color) = if cooldown_secs > 0 {
+            (format!("{} ({} s)", i18n::t(lang, "tow_call"), cooldown_secs), 0xFF888888)
+        } else {
+            (i18n::t(lang, "tow_call").to_string(), 0xFFFFFFFF)
+        };
+        font::draw_rect(fb, bx, by, bw, bh, 0xDD1A1A2E);
+        font::draw_rect_outline(fb, bx, by, bw, bh, 0xFF6666CC);
+        font::draw_text_shadow(fb, bx + (bw - font::text_width(&label)) / 2, by + 7, &label, color);
+    }
 
     /// Update hover state based on mouse position
     pub fn update_hover(&mut self, x: i32, y: i32) -> bool {
@@ -216,7 +264,7 @@ fn load_sprite(assets: &AssetStore, file: &str, member: u32, default_x: i32, def
         y: if px == 0 && py == 0 { default_y } else { py },
         width: bmp.width,
         height: bmp.height,
-        pixels: bmp.pixels,
+        pixels: bmp.pixels.into(),
         member_num: member,
     })
 }
@@ -248,6 +296,7 @@ mod tests {
             popup: None,
             hovered: false,
             hover_sound_played: false,
+            minimap: true,
         };
         assert!(!tb.popup_open);
         tb.toggle();
@@ -255,4 +304,23 @@ mod tests {
         tb.toggle();
         assert!(!tb.popup_open);
     }
+
+    #[test]
+    fn minimap_button_only_while_open() {
+        let mut tb = Toolbox {
+            popup_open: false,
+            icon: None,
+            popup: None,
+            hovered: false,
+            hover_sound_played: false,
+            minimap: true,
+        };
+        let (bx, by, _, _) = MINIMAP_BUTTON;
+        assert_eq!(tb.popup_hit(bx + 5, by + 5), None);
+        tb.toggle();
+        assert_eq!(tb.popup_hit(bx + 5, by + 5), Some(PopupAction::Minimap));
+        assert_eq!(tb.popup_hit(bx - 5, by + 5), None);
+        let (tx, ty, _, _) = TOW_BUTTON;
+        assert_eq!(tb.popup_hit(tx + 5, ty + 5), Some(PopupAction::Tow));
+    }
 }
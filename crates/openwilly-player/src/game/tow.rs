@@ -0,0 +1,160 @@
+//! Tow truck — call Figge from the toolbox when the car is stuck or the tank is empty
+//!
+//! Mulle phones Figge (subtitled call), the screen fades to black and the
+//! car reappears next to the nearest gas station with a splash of fuel,
+//! enough to roll up to the pump. A running race is called off. Figge only
+//! comes once every `COOLDOWN_SECS`, the toolbox button shows the wait.
+
+use crate::game::driving::{self, MapObjectType, WorldMap, TERRAIN_WALL};
+
+/// Dialog id of the phone call (subtitles only, see `DialogManager`)
+pub const CALL_DIALOG: &str = "tow_call";
+/// Fuel after the tow, as a fraction of the tank
+pub const TOW_FUEL_FRACTION: f32 = 0.15;
+/// Seconds until Figge can be called again
+pub const COOLDOWN_SECS: u32 = 120;
+const FPS: u32 = 30;
+const FADE_FRAMES: u32 = 20;
+/// Drop-off distance from the pump (outside its trigger radius)
+const DROP_DISTANCE: f32 = 20.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    Idle,
+    /// Phone call running
+    Calling,
+    FadeOut(u32),
+    FadeIn(u32),
+}
+
+pub struct Tow {
+    phase: Phase,
+    /// Frames until the next call is allowed
+    cooldown: u32,
+}
+
+impl Default for Tow {
+    fn default() -> Self {
+        Self { phase: Phase::Idle, cooldown: 0 }
+    }
+}
+
+impl Tow {
+    /// Seconds left on the cooldown (0 = Figge can be called)
+    pub fn cooldown_secs(&self) -> u32 {
+        self.cooldown.div_ceil(FPS)
+    }
+
+    /// Tow running — the car stays put
+    pub fn is_active(&self) -> bool {
+        self.phase != Phase::Idle
+    }
+
+    /// Call Figge. False while on cooldown or already towing.
+    pub fn call(&mut self) -> bool {
+        if self.is_active() || self.cooldown > 0 {
+            return false;
+        }
+        self.phase = Phase::Calling;
+        self.cooldown = COOLDOWN_SECS * FPS;
+        true
+    }
+
+    /// Advance one frame. Returns true on the black frame where the car
+    /// has to be moved.
+    pub fn update(&mut self, talking: bool) -> bool {
+        self.cooldown = self.cooldown.saturating_sub(1);
+        let (phase, respawn) = match self.phase {
+            Phase::Idle => (Phase::Idle, false),
+            Phase::Calling if talking => (Phase::Calling, false),
+            Phase::Calling => (Phase::FadeOut(0), false),
+            Phase::FadeOut(f) if f + 1 >= FADE_FRAMES => (Phase::FadeIn(FADE_FRAMES), true),
+            Phase::FadeOut(f) => (Phase::FadeOut(f + 1), false),
+            Phase::FadeIn(f) if f <= 1 => (Phase::Idle, false),
+            Phase::FadeIn(f) => (Phase::FadeIn(f - 1), false),
+        };
+        self.phase = phase;
+        respawn
+    }
+
+    /// Fade to / from black
+    pub fn draw(&self, fb: &mut [u32]) {
+        let frames = match self.phase {
+            Phase::FadeOut(f) | Phase::FadeIn(f) => f,
+            _ => return,
+        };
+        let keep = FADE_FRAMES - frames.min(FADE_FRAMES);
+        for px in fb.iter_mut() {
+            let r = ((*px >> 16) & 0xFF) * keep / FADE_FRAMES;
+            let g = ((*px >> 8) & 0xFF) * keep / FADE_FRAMES;
+            let b = (*px & 0xFF) * keep / FADE_FRAMES;
+            *px = 0xFF000000 | (r << 16) | (g << 8) | b;
+        }
+    }
+}
+
+/// Gas station closest to the car across the whole world grid:
+/// (tile col, tile row, pump x, pump y)
+pub fn nearest_gas_station(wm: &WorldMap, col: usize, row: usize, x: f32, y: f32) -> Option<(usize, usize, i32, i32)> {
+    driving::nearest_object(wm, (col, row), (x, y), |o| o.enabled && o.obj_type == MapObjectType::Gas)
+        .map(|(c, r, obj, _)| (c, r, obj.x, obj.y))
+}
+
+/// Where Figge leaves the car: next to the pump on drivable ground
+/// (`terrain` takes pixel coordinates), the pump itself if all sides are walls
+pub fn drop_point<F>(pump: (i32, i32), terrain: F) -> (f32, f32)
+where
+    F: Fn(f32, f32) -> u8,
+{
+    let (px, py) = (pump.0 as f32, pump.1 as f32);
+    [(0.0, DROP_DISTANCE), (0.0, -DROP_DISTANCE), (DROP_DISTANCE, 0.0), (-DROP_DISTANCE, 0.0)]
+        .into_iter()
+        .map(|(dx, dy)| (px + dx, py + dy))
+        .find(|&(x, y)| (10.0..=632.0).contains(&x) && (8.0..=394.0).contains(&y) && terrain(x, y) < TERRAIN_WALL)
+        .unwrap_or((px, py))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_fade_respawn_cooldown() {
+        let mut tow = Tow::default();
+        assert!(tow.call());
+        assert!(!tow.call());
+        // Waits for the phone call
+        assert!(!tow.update(true));
+        assert!(tow.is_active());
+        let respawn_frame = (1..=FADE_FRAMES + 1).find(|_| tow.update(false));
+        assert!(respawn_frame.is_some());
+        for _ in 0..FADE_FRAMES {
+            tow.update(false);
+        }
+        assert!(!tow.is_active());
+        assert!(tow.cooldown_secs() > 0 && !tow.call());
+        for _ in 0..COOLDOWN_SECS * FPS {
+            tow.update(false);
+        }
+        assert_eq!(tow.cooldown_secs(), 0);
+        assert!(tow.call());
+    }
+
+    #[test]
+    fn finds_closest_pump() {
+        let wm = WorldMap::default_map();
+        let (col, row) = wm.start_tile;
+        let (c, r, x, y) = nearest_gas_station(&wm, col, row, wm.start_pos.0, wm.start_pos.1).unwrap();
+        let tile = wm.get_tile(wm.tile_at(c, r).unwrap()).unwrap();
+        assert!(tile.objects.iter().any(|o| o.obj_type == MapObjectType::Gas && (o.x, o.y) == (x, y)));
+        // A car sitting on a pump is sent to that pump
+        assert_eq!(nearest_gas_station(&wm, c, r, x as f32, y as f32), Some((c, r, x, y)));
+    }
+
+    #[test]
+    fn drop_point_avoids_walls() {
+        let wall_below = |_x: f32, y: f32| if y > 100.0 { TERRAIN_WALL } else { 0 };
+        assert_eq!(drop_point((200, 100), wall_below), (200.0, 80.0));
+        assert_eq!(drop_point((200, 100), |_, _| TERRAIN_WALL), (200.0, 100.0));
+    }
+}
@@ -0,0 +1,149 @@
+//! Driving tutorial for first-time profiles
+//!
+//! The first time a profile gets to the world map, a scene script
+//! (`scene_script::build_tutorial_script`) walks through steering,
+//! refueling and visiting a destination. The script decides what happens
+//! next; this module keeps what it shows (hint banner, arrow target),
+//! measures the distance for the steering step and finds the map objects
+//! the arrow points at. Finishing or skipping (Tab) sets `#TutorialDone`.
+
+use crate::engine::font;
+use crate::game::driving::{self, MapObject, MapObjectType, WorldMap, MAP_HEIGHT, MAP_WIDTH};
+use crate::game::i18n::{self, Language};
+
+/// Save flag (stuff list) of a finished or skipped tutorial
+pub const TUTORIAL_DONE: &str = "#TutorialDone";
+/// Pixels to drive before the steering step counts
+const STEER_DISTANCE: f32 = 150.0;
+/// Arrow shaft from / to this distance around the car
+const ARROW_INNER: f32 = 26.0;
+const ARROW_OUTER: f32 = 52.0;
+const ARROW_COLOR: u32 = 0xFFFFD23F;
+const BANNER_Y: i32 = 26;
+
+/// What the arrow points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    GasStation,
+    Destination,
+}
+
+impl Target {
+    /// Target named in the script, None hides the arrow
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "gas" => Some(Target::GasStation),
+            "destination" => Some(Target::Destination),
+            _ => None,
+        }
+    }
+
+    fn matches(self, obj: &MapObject) -> bool {
+        match self {
+            Target::GasStation => obj.obj_type == MapObjectType::Gas,
+            Target::Destination => {
+                matches!(obj.obj_type, MapObjectType::Destination | MapObjectType::RandomDestination)
+                    && obj.dir_resource.is_some()
+            }
+        }
+    }
+}
+
+/// On-screen state of a running tutorial
+#[derive(Debug, Default)]
+pub struct Tutorial {
+    /// i18n key of the banner text
+    pub hint: Option<String>,
+    pub target: Option<Target>,
+    driven: f32,
+    steered: bool,
+}
+
+impl Tutorial {
+    /// Count one frame of driving, true on the frame the steering
+    /// distance is reached
+    pub fn add_distance(&mut self, speed: f32) -> bool {
+        if self.steered {
+            return false;
+        }
+        self.driven += speed.abs();
+        self.steered = self.driven >= STEER_DISTANCE;
+        self.steered
+    }
+
+    /// Banner at the top of the map, arrow around the car towards
+    /// `offset` (target minus car in world pixels) and a frame around the
+    /// target when it is on screen
+    pub fn draw(&self, fb: &mut [u32], lang: Language, car: (f32, f32), offset: Option<(f32, f32)>) {
+        if let Some(key) = &self.hint {
+            let text = i18n::t(lang, key);
+            let skip = i18n::t(lang, "tut_skip");
+            let w = font::text_width(text).max(font::text_width(skip)) + 16;
+            let x = (640 - w) / 2;
+            font::draw_rect(fb, x, BANNER_Y, w, 30, 0xDD1A1A2E);
+            font::draw_rect_outline(fb, x, BANNER_Y, w, 30, ARROW_COLOR);
+            font::draw_text_shadow(fb, x + 8, BANNER_Y + 5, text, 0xFFFFFFFF);
+            font::draw_text(fb, x + 8, BANNER_Y + 18, skip, 0xFFAAAAAA);
+        }
+
+        let Some((dx, dy)) = offset else { return };
+        let dist = (dx * dx + dy * dy).sqrt();
+        if dist < 1.0 {
+            return;
+        }
+        let (ux, uy) = (dx / dist, dy / dist);
+        let (tx, ty) = (car.0 + dx, car.1 + dy);
+        if (0.0..MAP_WIDTH as f32).contains(&tx) && (0.0..MAP_HEIGHT as f32).contains(&ty) {
+            font::draw_rect_outline(fb, tx as i32 - 14, ty as i32 - 14, 28, 28, ARROW_COLOR);
+        }
+        if dist <= ARROW_OUTER {
+            return;
+        }
+        let dot = |fb: &mut [u32], x: f32, y: f32| font::draw_rect(fb, x as i32 - 1, y as i32 - 1, 3, 3, ARROW_COLOR);
+        let mut r = ARROW_INNER;
+        while r <= ARROW_OUTER {
+            dot(fb, car.0 + ux * r, car.1 + uy * r);
+            r += 2.0;
+        }
+        // Head: two wings back from the tip
+        let tip = (car.0 + ux * ARROW_OUTER, car.1 + uy * ARROW_OUTER);
+        for side in [-1.0, 1.0] {
+            let (wx, wy) = (-ux - side * uy, -uy + side * ux);
+            for step in 1..=5 {
+                let s = step as f32 * 2.0 * std::f32::consts::FRAC_1_SQRT_2;
+                dot(fb, tip.0 + wx * s, tip.1 + wy * s);
+            }
+        }
+    }
+}
+
+/// Nearest visible target object anywhere on the map, as an offset from
+/// the car in world pixels (tiles laid out side by side)
+pub fn target_offset(
+    wm: &WorldMap,
+    target: Target,
+    (col, row): (usize, usize),
+    (x, y): (f32, f32),
+    cache_list: &[String],
+    medals: &[String],
+) -> Option<(f32, f32)> {
+    driving::nearest_object(wm, (col, row), (x, y), |o| {
+        o.enabled && target.matches(o) && !o.is_hidden(cache_list, medals)
+    })
+    .map(|(_, _, _, offset)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steering_fires_once() {
+        let mut tutorial = Tutorial::default();
+        let fired: Vec<bool> = (0..40).map(|_| tutorial.add_distance(-5.0)).collect();
+        assert_eq!(fired.iter().filter(|&&f| f).count(), 1);
+        assert!(fired[29] && !fired[28]);
+        assert_eq!(Target::from_name("gas"), Some(Target::GasStation));
+        assert_eq!(Target::from_name(""), None);
+    }
+}
@@ -0,0 +1,273 @@
+//! `.willycar` — car exchange files for swapping cars via USB stick
+//!
+//! A single small binary file holds the car's blueprint (name, parts,
+//! rating, medals), a PNG thumbnail of its gallery card and a CRC-32 over
+//! everything before it. Exported files land in `<game_dir>/exchange`; the
+//! Car Gallery's import button picks up every `.willycar` file found there
+//! and shows the thumbnails of the cars it added.
+//!
+//! Layout (little endian):
+//! ```text
+//! "WILLYCAR" | version u8 | name (u8 len + UTF-8) | rating u8
+//! | medal count u8, medals (u8 len + UTF-8 each) | part count u16, parts u32 each
+//! | thumb width u16, thumb height u16, PNG length u32, PNG bytes | CRC-32 u32
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{ensure, Context, Result};
+
+use crate::assets::AssetStore;
+use crate::engine::canvas::Canvas;
+use crate::game::car_naming;
+use crate::game::gallery;
+use crate::game::i18n::Language;
+use crate::game::parts_db::PartsDB;
+use crate::game::save::GalleryEntry;
+
+const MAGIC: &[u8; 8] = b"WILLYCAR";
+const VERSION: u8 = 1;
+pub const EXTENSION: &str = "willycar";
+
+/// Thumbnail size (the 800×600 card, scaled down)
+const THUMB_WIDTH: usize = 160;
+const THUMB_HEIGHT: usize = 120;
+
+/// Sanity limits for imports
+const MAX_PARTS: usize = 256;
+const MAX_MEDALS: usize = gallery::MEDAL_COUNT;
+const MAX_THUMB_BYTES: usize = 256 * 1024;
+
+/// A decoded exchange file
+#[derive(Debug, Clone, PartialEq)]
+pub struct WillyCar {
+    pub entry: GalleryEntry,
+    /// PNG-encoded card thumbnail
+    pub thumbnail: Vec<u8>,
+}
+
+impl WillyCar {
+    /// Serialize to the binary file format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(64 + self.thumbnail.len());
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        write_str(&mut out, &self.entry.name);
+        out.push(self.entry.rating);
+        out.push(self.entry.medals.len() as u8);
+        for medal in &self.entry.medals {
+            write_str(&mut out, medal);
+        }
+        out.extend_from_slice(&(self.entry.parts.len() as u16).to_le_bytes());
+        for part in &self.entry.parts {
+            out.extend_from_slice(&part.to_le_bytes());
+        }
+        out.extend_from_slice(&(THUMB_WIDTH as u16).to_le_bytes());
+        out.extend_from_slice(&(THUMB_HEIGHT as u16).to_le_bytes());
+        out.extend_from_slice(&(self.thumbnail.len() as u32).to_le_bytes());
+        out.extend_from_slice(&self.thumbnail);
+        let crc = crc32(&out);
+        out.extend_from_slice(&crc.to_le_bytes());
+        out
+    }
+
+    /// Parse and validate a file. Unknown parts are rejected so a broken or
+    /// hand-edited file can never put an unbuildable car into the gallery.
+    pub fn from_bytes(bytes: &[u8], parts_db: &PartsDB) -> Result<Self> {
+        ensure!(bytes.len() >= MAGIC.len() + 5, "file too short");
+        let (body, crc_bytes) = bytes.split_at(bytes.len() - 4);
+        let stored = u32::from_le_bytes(crc_bytes.try_into().unwrap());
+        ensure!(crc32(body) == stored, "checksum mismatch (file damaged?)");
+
+        let mut r = Reader { data: body, pos: 0 };
+        ensure!(r.take(MAGIC.len())? == MAGIC, "not a .willycar file");
+        let version = r.u8()?;
+        ensure!(version == VERSION, "unsupported version {}", version);
+
+        let raw_name = r.string()?;
+        let name = car_naming::validate_name(&raw_name)
+            .map_err(|e| anyhow::anyhow!("invalid car name {:?}: {:?}", raw_name, e))?;
+        let rating = r.u8()?;
+        ensure!((1..=5).contains(&rating), "invalid rating {}", rating);
+
+        let medal_count = r.u8()? as usize;
+        ensure!(medal_count <= MAX_MEDALS, "too many medals ({})", medal_count);
+        let mut medals: Vec<String> = Vec::with_capacity(medal_count);
+        for _ in 0..medal_count {
+            let medal = r.string()?;
+            ensure!(gallery::MEDAL_IDS.contains(&medal.as_str()), "unknown medal {:?}", medal);
+            ensure!(!medals.contains(&medal), "medal {} listed twice", medal);
+            medals.push(medal);
+        }
+
+        let part_count = r.u16()? as usize;
+        ensure!(part_count > 0 && part_count <= MAX_PARTS, "invalid part count {}", part_count);
+        let mut parts = Vec::with_capacity(part_count);
+        for _ in 0..part_count {
+            let id = r.u32()?;
+            ensure!(parts_db.get(id).is_some(), "unknown part #{}", id);
+            parts.push(id);
+        }
+
+        let (tw, th) = (r.u16()? as usize, r.u16()? as usize);
+        ensure!((tw, th) == (THUMB_WIDTH, THUMB_HEIGHT), "unexpected thumbnail size {}×{}", tw, th);
+        let thumb_len = r.u32()? as usize;
+        ensure!(thumb_len <= MAX_THUMB_BYTES, "thumbnail too large");
+        let thumbnail = r.take(thumb_len)?.to_vec();
+        let thumb = Canvas::decode_png(&thumbnail).context("invalid thumbnail")?;
+        ensure!((thumb.width, thumb.height) == (tw, th), "thumbnail size mismatch");
+        ensure!(r.pos == body.len(), "trailing data");
+
+        Ok(Self { entry: GalleryEntry { name, parts, rating, medals }, thumbnail })
+    }
+
+    /// The card thumbnail, shown when the car is imported
+    pub fn thumbnail_canvas(&self) -> Result<Canvas> {
+        Canvas::decode_png(&self.thumbnail)
+    }
+}
+
+/// Exchange folder (copy `.willycar` files from/to the USB stick here)
+pub fn exchange_dir(game_dir: &Path) -> PathBuf {
+    game_dir.join("exchange")
+}
+
+/// Export a gallery car to `<game_dir>/exchange/<name>.willycar`
+pub fn export(entry: &GalleryEntry, parts_db: &PartsDB, assets: &AssetStore, lang: Language) -> Result<PathBuf> {
+    let thumbnail = gallery::render_card(entry, parts_db, assets, lang)
+        .scaled_down(THUMB_WIDTH, THUMB_HEIGHT)
+        .encode_png()?;
+    let car = WillyCar { entry: entry.clone(), thumbnail };
+    let dir = exchange_dir(&assets.game_dir);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.{}", file_stem(&entry.name), EXTENSION));
+    std::fs::write(&path, car.to_bytes())
+        .with_context(|| format!("Cannot write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Read every `.willycar` file in the exchange folder. Files that fail
+/// validation are logged and skipped.
+pub fn scan(game_dir: &Path, parts_db: &PartsDB) -> Vec<WillyCar> {
+    let Ok(entries) = std::fs::read_dir(exchange_dir(game_dir)) else { return Vec::new() };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e.eq_ignore_ascii_case(EXTENSION)))
+        .collect();
+    paths.sort();
+    paths.iter()
+        .filter_map(|path| {
+            let result = std::fs::read(path)
+                .map_err(anyhow::Error::from)
+                .and_then(|bytes| WillyCar::from_bytes(&bytes, parts_db));
+            match result {
+                Ok(car) => Some(car),
+                Err(e) => {
+                    tracing::warn!("Skipping {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Safe file stem for a car name
+fn file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect()
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    let bytes = &s.as_bytes()[..s.len().min(u8::MAX as usize)];
+    out.push(bytes.len() as u8);
+    out.extend_from_slice(bytes);
+}
+
+/// Bounds-checked little-endian reader
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        ensure!(self.pos + n <= self.data.len(), "unexpected end of file");
+        let slice = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.u8()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).context("invalid UTF-8")
+    }
+}
+
+/// CRC-32 (IEEE, as used by PNG/zip)
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    crc.update(data);
+    crc.sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> WillyCar {
+        let thumbnail = Canvas::new(THUMB_WIDTH, THUMB_HEIGHT, 0xFF336699).encode_png().unwrap();
+        WillyCar {
+            entry: GalleryEntry {
+                name: "Blitz".into(),
+                parts: vec![1, 82, 133, 152],
+                rating: 4,
+                medals: vec!["1".into()],
+            },
+            thumbnail,
+        }
+    }
+
+    #[test]
+    fn crc32_known_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn round_trip() {
+        let db = PartsDB::load();
+        let car = sample();
+        assert_eq!(WillyCar::from_bytes(&car.to_bytes(), &db).unwrap(), car);
+    }
+
+    #[test]
+    fn rejects_damaged_files() {
+        let db = PartsDB::load();
+        let mut bytes = sample().to_bytes();
+        bytes[12] ^= 0x01;
+        assert!(WillyCar::from_bytes(&bytes, &db).is_err());
+        assert!(WillyCar::from_bytes(b"WILLYCAR", &db).is_err());
+
+        let mut unknown = sample();
+        unknown.entry.parts.push(999_999);
+        assert!(WillyCar::from_bytes(&unknown.to_bytes(), &db).is_err());
+
+        for medals in [vec!["Gold"], vec!["1", "1"]] {
+            let mut car = sample();
+            car.entry.medals = medals.into_iter().map(String::from).collect();
+            assert!(WillyCar::from_bytes(&car.to_bytes(), &db).is_err());
+        }
+    }
+}
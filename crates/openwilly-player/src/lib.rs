@@ -0,0 +1,18 @@
+//! OpenWilly Player — Modern Rust recreation of Willy Werkel (Director 6)
+//!
+//! Architecture:
+//!   assets/      — Director file parser (.CXT, .DXR, .CST)
+//!   engine/      — Renderer, audio, input, scene system
+//!   game/        — Game logic (scenes, car building, driving, actors)
+//!   game_data    — Finding and loading the game files (directory, ISO, CD)
+//!
+//! The `openwilly` binary is a thin shell around this library (command
+//! line, window loop). Other front-ends and test harnesses drive a
+//! `game::GameState` directly: `GameState::headless` runs the game logic
+//! without window, sound or cast data, `GameState::apply_frame` feeds it
+//! one frame of input.
+
+pub mod assets;
+pub mod engine;
+pub mod game;
+pub mod game_data;